@@ -0,0 +1,66 @@
+//! Typed fetch and decode helpers of the gateway accounts
+
+use anchor_lang::AccountDeserialize;
+use gpl_civic_gateway::{
+    id,
+    state::{Registrar, VoterWeightRecord},
+};
+use gpl_voter_weight_plugin::client::PluginClientError;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+
+use crate::pda::{get_registrar_address, get_voter_weight_record_address};
+
+/// gateway program account which can be decoded from its on-chain data
+pub trait GatewayAccount: AccountDeserialize {
+    /// Decodes the account and checks it's owned by the gateway program
+    fn decode(account: &Account) -> Result<Self, PluginClientError> {
+        if account.owner != id() {
+            return Err(PluginClientError::InvalidAccountOwner(id()));
+        }
+
+        Ok(Self::try_deserialize(&mut account.data.as_slice())?)
+    }
+}
+
+impl GatewayAccount for Registrar {}
+
+impl GatewayAccount for VoterWeightRecord {}
+
+/// Fetches and decodes the gateway account
+/// Returns None if the account doesn't exist
+pub fn fetch_account<T: GatewayAccount>(
+    rpc_client: &RpcClient,
+    address: &Pubkey,
+) -> Result<Option<T>, PluginClientError> {
+    rpc_client
+        .get_account_with_commitment(address, rpc_client.commitment())?
+        .value
+        .map(|account| T::decode(&account))
+        .transpose()
+}
+
+/// Fetches Registrar of the Realm and governing_token_mint
+pub fn fetch_registrar(
+    rpc_client: &RpcClient,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+) -> Result<Option<Registrar>, PluginClientError> {
+    fetch_account(
+        rpc_client,
+        &get_registrar_address(realm, governing_token_mint),
+    )
+}
+
+/// Fetches VoterWeightRecord of the voter
+pub fn fetch_voter_weight_record(
+    rpc_client: &RpcClient,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+) -> Result<Option<VoterWeightRecord>, PluginClientError> {
+    fetch_account(
+        rpc_client,
+        &get_voter_weight_record_address(realm, governing_token_mint, governing_token_owner),
+    )
+}
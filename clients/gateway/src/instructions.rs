@@ -0,0 +1,96 @@
+//! Instruction builders of the gateway program
+//!
+//! The builders derive all the PDAs used by the instructions from the Realm, its governing_token_mint and the voter
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use gpl_civic_gateway::{accounts, id, instruction, state::VoterWeightAction};
+use solana_program::{instruction::Instruction, pubkey::Pubkey, system_program};
+
+use crate::pda::*;
+
+/// Builds gateway instruction from Anchor accounts and instruction data
+fn build_instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Creates CreateRegistrar instruction
+pub fn create_registrar(
+    governance_program_id: &Pubkey,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    gatekeeper_network: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts::CreateRegistrar {
+            registrar: get_registrar_address(realm, governing_token_mint),
+            governance_program_id: *governance_program_id,
+            realm: *realm,
+            governing_token_mint: *governing_token_mint,
+            realm_authority: *realm_authority,
+            gatekeeper_network: *gatekeeper_network,
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::CreateRegistrar {},
+    )
+}
+
+/// Creates CreateVoterWeightRecord instruction
+pub fn create_voter_weight_record(
+    governance_program_id: &Pubkey,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts::CreateVoterWeightRecord {
+            voter_weight_record: get_voter_weight_record_address(
+                realm,
+                governing_token_mint,
+                governing_token_owner,
+            ),
+            governance_program_id: *governance_program_id,
+            realm: *realm,
+            realm_governing_token_mint: *governing_token_mint,
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::CreateVoterWeightRecord {
+            governing_token_owner: *governing_token_owner,
+        },
+    )
+}
+
+/// Creates UpdateVoterWeightRecord instruction
+/// The gateway_token must be issued to the governing_token_owner by the Registrar gatekeeper_network
+pub fn update_voter_weight_record(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+    gateway_token: &Pubkey,
+    voter_weight_action: VoterWeightAction,
+    target: Option<Pubkey>,
+) -> Instruction {
+    build_instruction(
+        accounts::UpdateVoterWeightRecord {
+            registrar: get_registrar_address(realm, governing_token_mint),
+            gateway_token: *gateway_token,
+            voter_weight_record: get_voter_weight_record_address(
+                realm,
+                governing_token_mint,
+                governing_token_owner,
+            ),
+        },
+        instruction::UpdateVoterWeightRecord {
+            voter_weight_action,
+            target,
+        },
+    )
+}
@@ -0,0 +1,14 @@
+//! Rust client for the gpl-civic-gateway program
+//!
+//! The crate provides instruction builders for all the program instructions, PDA helpers
+//! and typed account fetch and decode helpers
+
+pub mod accounts;
+
+pub mod instructions;
+
+pub mod pda;
+
+pub use gpl_civic_gateway;
+pub use gpl_civic_gateway::id;
+pub use gpl_voter_weight_plugin::client::PluginClientError;
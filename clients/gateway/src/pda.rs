@@ -0,0 +1,4 @@
+//! PDA helpers of the gateway accounts and the related spl-governance accounts
+
+pub use gpl_civic_gateway::state::{get_registrar_address, get_voter_weight_record_address};
+pub use spl_governance::state::token_owner_record::get_token_owner_record_address;
@@ -0,0 +1,50 @@
+//! Clients the CLI commands are executed with
+
+use gpl_nft_voter_client::{accounts::fetch_nft_vote_records, gpl_nft_voter::state::NftVoteRecord};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    account::Account, hash::Hash, pubkey::Pubkey, signature::Signature, transaction::Transaction,
+};
+
+use crate::error::CliError;
+
+/// Client used by the CLI commands to read accounts and execute transactions
+pub trait CliClient {
+    /// Returns the account or None if it doesn't exist
+    fn get_account(&self, address: &Pubkey) -> Result<Option<Account>, CliError>;
+
+    /// Returns all NftVoteRecords of the Proposal
+    fn get_nft_vote_records(
+        &self,
+        proposal: &Pubkey,
+    ) -> Result<Vec<(Pubkey, NftVoteRecord)>, CliError>;
+
+    /// Returns the blockhash the transactions are signed with
+    fn get_latest_blockhash(&self) -> Result<Hash, CliError>;
+
+    /// Executes the transaction and waits for its confirmation
+    fn send_transaction(&self, transaction: &Transaction) -> Result<Signature, CliError>;
+}
+
+impl CliClient for RpcClient {
+    fn get_account(&self, address: &Pubkey) -> Result<Option<Account>, CliError> {
+        Ok(self
+            .get_account_with_commitment(address, self.commitment())?
+            .value)
+    }
+
+    fn get_nft_vote_records(
+        &self,
+        proposal: &Pubkey,
+    ) -> Result<Vec<(Pubkey, NftVoteRecord)>, CliError> {
+        Ok(fetch_nft_vote_records(self, proposal)?)
+    }
+
+    fn get_latest_blockhash(&self) -> Result<Hash, CliError> {
+        Ok(RpcClient::get_latest_blockhash(self)?)
+    }
+
+    fn send_transaction(&self, transaction: &Transaction) -> Result<Signature, CliError> {
+        Ok(self.send_and_confirm_transaction(transaction)?)
+    }
+}
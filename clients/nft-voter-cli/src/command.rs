@@ -0,0 +1,300 @@
+//! CLI commands and their execution
+
+use clap::Subcommand;
+use gpl_nft_voter_client::{
+    accounts::NftVoterAccount,
+    gpl_nft_voter::state::{
+        max_voter_weight_record::MaxVoterWeightRecord, NftVoteRecord, Registrar,
+    },
+    instructions,
+    pda::{
+        get_max_voter_weight_record_address, get_nft_vote_record_address, get_registrar_address,
+    },
+};
+use serde_json::Value;
+use solana_sdk::{
+    instruction::Instruction, message::Message, pubkey::Pubkey, signer::Signer,
+    transaction::Transaction,
+};
+
+use crate::client::CliClient;
+use crate::error::CliError;
+use crate::json::{max_voter_weight_record_to_json, nft_vote_record_to_json, registrar_to_json};
+
+/// Default max number of collections the Registrar is created for
+const DEFAULT_MAX_COLLECTIONS: u8 = 10;
+
+#[derive(Subcommand, Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Creates Registrar and MaxVoterWeightRecord for the Realm and governing_token_mint
+    CreateRegistrar {
+        /// spl-governance program the Realm belongs to
+        #[clap(long)]
+        governance_program_id: Pubkey,
+
+        #[clap(long)]
+        realm: Pubkey,
+
+        #[clap(long)]
+        governing_token_mint: Pubkey,
+
+        /// Max number of collections the Registrar is allocated for
+        #[clap(long, default_value_t = DEFAULT_MAX_COLLECTIONS)]
+        max_collections: u8,
+    },
+
+    /// Creates VoterWeightRecord of the voter
+    CreateVoterWeightRecord {
+        /// spl-governance program the Realm belongs to
+        #[clap(long)]
+        governance_program_id: Pubkey,
+
+        #[clap(long)]
+        realm: Pubkey,
+
+        #[clap(long)]
+        governing_token_mint: Pubkey,
+
+        /// The voter the record is created for (defaults to the keypair)
+        #[clap(long)]
+        governing_token_owner: Option<Pubkey>,
+    },
+
+    /// Adds or updates the collection configuration of the Registrar
+    ConfigureCollection {
+        #[clap(long)]
+        realm: Pubkey,
+
+        #[clap(long)]
+        governing_token_mint: Pubkey,
+
+        #[clap(long)]
+        collection: Pubkey,
+
+        /// Voter weight of each NFT of the collection
+        #[clap(long)]
+        weight: u64,
+
+        /// Number of NFTs in the collection
+        #[clap(long)]
+        size: u64,
+    },
+
+    /// Removes the collection configuration from the Registrar
+    RemoveCollection {
+        #[clap(long)]
+        realm: Pubkey,
+
+        #[clap(long)]
+        governing_token_mint: Pubkey,
+
+        #[clap(long)]
+        collection: Pubkey,
+    },
+
+    /// Prints Registrar of the Realm and governing_token_mint as JSON
+    ShowRegistrar {
+        #[clap(long)]
+        realm: Pubkey,
+
+        #[clap(long)]
+        governing_token_mint: Pubkey,
+    },
+
+    /// Prints MaxVoterWeightRecord of the Realm and governing_token_mint as JSON
+    ShowMaxVoterWeightRecord {
+        #[clap(long)]
+        realm: Pubkey,
+
+        #[clap(long)]
+        governing_token_mint: Pubkey,
+    },
+
+    /// Prints NftVoteRecord of the NFT for the Proposal as JSON
+    ShowNftVoteRecord {
+        #[clap(long)]
+        proposal: Pubkey,
+
+        #[clap(long)]
+        nft_mint: Pubkey,
+    },
+
+    /// Prints all NftVoteRecords of the Proposal as JSON
+    ListNftVoteRecords {
+        #[clap(long)]
+        proposal: Pubkey,
+    },
+}
+
+/// Configuration the commands are executed with
+pub struct CliConfig {
+    /// Signs the transactions as the fee payer and the realm authority
+    pub authority: Box<dyn Signer>,
+
+    /// Prints the unsigned transaction messages instead of executing them
+    pub dry_run: bool,
+}
+
+/// Executes the command and returns its output
+pub fn process_command(
+    client: &dyn CliClient,
+    config: &CliConfig,
+    command: &Command,
+) -> Result<String, CliError> {
+    let authority = config.authority.pubkey();
+
+    match command {
+        Command::CreateRegistrar {
+            governance_program_id,
+            realm,
+            governing_token_mint,
+            max_collections,
+        } => process_instructions(
+            client,
+            config,
+            &[
+                instructions::create_registrar(
+                    governance_program_id,
+                    realm,
+                    governing_token_mint,
+                    &authority,
+                    &authority,
+                    *max_collections,
+                ),
+                instructions::create_max_voter_weight_record(
+                    governance_program_id,
+                    realm,
+                    governing_token_mint,
+                    &authority,
+                ),
+            ],
+        ),
+        Command::CreateVoterWeightRecord {
+            governance_program_id,
+            realm,
+            governing_token_mint,
+            governing_token_owner,
+        } => process_instructions(
+            client,
+            config,
+            &[instructions::create_voter_weight_record(
+                governance_program_id,
+                realm,
+                governing_token_mint,
+                &governing_token_owner.unwrap_or(authority),
+                &authority,
+            )],
+        ),
+        Command::ConfigureCollection {
+            realm,
+            governing_token_mint,
+            collection,
+            weight,
+            size,
+        } => process_instructions(
+            client,
+            config,
+            &[instructions::configure_collection(
+                realm,
+                governing_token_mint,
+                &authority,
+                collection,
+                *weight,
+                *size,
+            )],
+        ),
+        Command::RemoveCollection {
+            realm,
+            governing_token_mint,
+            collection,
+        } => process_instructions(
+            client,
+            config,
+            &[instructions::remove_collection(
+                realm,
+                governing_token_mint,
+                &authority,
+                collection,
+            )],
+        ),
+        Command::ShowRegistrar {
+            realm,
+            governing_token_mint,
+        } => {
+            let address = get_registrar_address(realm, governing_token_mint);
+            let registrar = get_account::<Registrar>(client, &address)?;
+
+            to_json_output(registrar_to_json(&address, &registrar))
+        }
+        Command::ShowMaxVoterWeightRecord {
+            realm,
+            governing_token_mint,
+        } => {
+            let address = get_max_voter_weight_record_address(realm, governing_token_mint);
+            let max_voter_weight_record = get_account::<MaxVoterWeightRecord>(client, &address)?;
+
+            to_json_output(max_voter_weight_record_to_json(
+                &address,
+                &max_voter_weight_record,
+            ))
+        }
+        Command::ShowNftVoteRecord { proposal, nft_mint } => {
+            let address = get_nft_vote_record_address(proposal, nft_mint);
+            let nft_vote_record = get_account::<NftVoteRecord>(client, &address)?;
+
+            to_json_output(nft_vote_record_to_json(&address, &nft_vote_record))
+        }
+        Command::ListNftVoteRecords { proposal } => {
+            let nft_vote_records = client
+                .get_nft_vote_records(proposal)?
+                .iter()
+                .map(|(address, nft_vote_record)| nft_vote_record_to_json(address, nft_vote_record))
+                .collect();
+
+            to_json_output(Value::Array(nft_vote_records))
+        }
+    }
+}
+
+/// Signs the transaction with the given instructions and executes it
+/// In dry run mode the unsigned transaction message is returned serialized as base64 instead
+/// The dry run message has no recent blockhash and it doesn't make any RPC requests
+fn process_instructions(
+    client: &dyn CliClient,
+    config: &CliConfig,
+    instructions: &[Instruction],
+) -> Result<String, CliError> {
+    let message = Message::new(instructions, Some(&config.authority.pubkey()));
+
+    if config.dry_run {
+        let message_data = bincode::serialize(&message)
+            .map_err(|error| CliError::Transaction(error.to_string()))?;
+
+        return Ok(base64::encode(message_data));
+    }
+
+    let transaction = Transaction::new(
+        &[config.authority.as_ref()],
+        message,
+        client.get_latest_blockhash()?,
+    );
+
+    Ok(client.send_transaction(&transaction)?.to_string())
+}
+
+/// Fetches and decodes the nft-voter account and fails if it doesn't exist
+fn get_account<T: NftVoterAccount>(
+    client: &dyn CliClient,
+    address: &Pubkey,
+) -> Result<T, CliError> {
+    let account = client
+        .get_account(address)?
+        .ok_or(CliError::AccountNotFound(*address))?;
+
+    Ok(T::decode(address, &account)?)
+}
+
+fn to_json_output(value: Value) -> Result<String, CliError> {
+    // Serializing serde_json::Value can't fail
+    Ok(serde_json::to_string_pretty(&value).unwrap())
+}
@@ -0,0 +1,51 @@
+use std::fmt;
+
+use gpl_nft_voter_client::error::NftVoterClientError;
+use solana_sdk::pubkey::Pubkey;
+
+/// Errors returned by the CLI commands
+#[derive(Debug)]
+pub enum CliError {
+    /// nft-voter client request failed
+    Client(NftVoterClientError),
+
+    /// The requested account doesn't exist
+    AccountNotFound(Pubkey),
+
+    /// The transaction couldn't be serialized or executed
+    Transaction(String),
+
+    /// solana-program-test BanksClient request failed
+    ProgramTest(String),
+
+    /// The command is not supported by the client
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Client(error) => write!(f, "{}", error),
+            CliError::AccountNotFound(address) => write!(f, "Account {} not found", address),
+            CliError::Transaction(error) => write!(f, "Transaction failed: {}", error),
+            CliError::ProgramTest(error) => write!(f, "Program test request failed: {}", error),
+            CliError::Unsupported(command) => {
+                write!(f, "{} is not supported by the client", command)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<NftVoterClientError> for CliError {
+    fn from(error: NftVoterClientError) -> Self {
+        CliError::Client(error)
+    }
+}
+
+impl From<solana_client::client_error::ClientError> for CliError {
+    fn from(error: solana_client::client_error::ClientError) -> Self {
+        CliError::Client(error.into())
+    }
+}
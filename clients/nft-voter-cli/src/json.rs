@@ -0,0 +1,99 @@
+//! JSON representation of the nft-voter accounts printed by the CLI
+
+use gpl_nft_voter_client::gpl_nft_voter::state::{
+    max_voter_weight_record::MaxVoterWeightRecord, NftVoteRecord, Registrar,
+};
+use serde_json::{json, Value};
+use solana_sdk::pubkey::Pubkey;
+
+/// Returns JSON representation of the Registrar
+pub fn registrar_to_json(address: &Pubkey, registrar: &Registrar) -> Value {
+    let collection_configs = registrar
+        .collection_configs
+        .iter()
+        .map(|collection_config| {
+            json!({
+                "collection": collection_config.collection.to_string(),
+                "size": collection_config.size,
+                "weight": collection_config.weight,
+                "maxWeightBonus": collection_config.max_weight_bonus,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "address": address.to_string(),
+        "governanceProgramId": registrar.governance_program_id.to_string(),
+        "realm": registrar.realm.to_string(),
+        "governingTokenMint": registrar.governing_token_mint.to_string(),
+        "collectionConfigs": collection_configs,
+        "programmableNftPolicy": format!("{:?}", registrar.programmable_nft_policy),
+        "voterWeightCaps": {
+            "maxVoterWeight": registrar.voter_weight_caps.max_voter_weight,
+            "maxCollectionWeightShareBps": registrar.voter_weight_caps.max_collection_weight_share_bps,
+        },
+        "voterWeightingMode": format!("{:?}", registrar.voter_weighting_mode),
+        "ownershipSnapshotEnabled": registrar.ownership_snapshot_enabled,
+        "voteTrackingMode": format!("{:?}", registrar.vote_tracking_mode),
+    })
+}
+
+/// Returns JSON representation of the MaxVoterWeightRecord
+pub fn max_voter_weight_record_to_json(
+    address: &Pubkey,
+    max_voter_weight_record: &MaxVoterWeightRecord,
+) -> Value {
+    json!({
+        "address": address.to_string(),
+        "realm": max_voter_weight_record.realm.to_string(),
+        "governingTokenMint": max_voter_weight_record.governing_token_mint.to_string(),
+        "maxVoterWeight": max_voter_weight_record.max_voter_weight,
+        "maxVoterWeightExpiry": max_voter_weight_record.max_voter_weight_expiry,
+    })
+}
+
+/// Returns JSON representation of the NftVoteRecord
+pub fn nft_vote_record_to_json(address: &Pubkey, nft_vote_record: &NftVoteRecord) -> Value {
+    json!({
+        "address": address.to_string(),
+        "proposal": nft_vote_record.proposal.to_string(),
+        "nftMint": nft_vote_record.nft_mint.to_string(),
+        "governingTokenOwner": nft_vote_record.governing_token_owner.to_string(),
+        "payer": nft_vote_record.payer.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_nft_vote_record_to_json() {
+        // Arrange
+        let address = Pubkey::new_unique();
+
+        let nft_vote_record = NftVoteRecord {
+            account_discriminator: NftVoteRecord::ACCOUNT_DISCRIMINATOR,
+            proposal: Pubkey::new_unique(),
+            nft_mint: Pubkey::new_unique(),
+            governing_token_owner: Pubkey::new_unique(),
+            reserved: [0; 8],
+            payer: Pubkey::new_unique(),
+        };
+
+        // Act
+        let nft_vote_record_json = nft_vote_record_to_json(&address, &nft_vote_record);
+
+        // Assert
+        assert_eq!(nft_vote_record_json["address"], address.to_string());
+        assert_eq!(
+            nft_vote_record_json["nftMint"],
+            nft_vote_record.nft_mint.to_string()
+        );
+        assert_eq!(
+            nft_vote_record_json["governingTokenOwner"],
+            nft_vote_record.governing_token_owner.to_string()
+        );
+    }
+}
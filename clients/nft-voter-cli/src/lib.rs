@@ -0,0 +1,12 @@
+//! Admin CLI of the gpl-nft-voter program
+//!
+//! The commands are executed through CliClient which is implemented for RpcClient (local validator or any cluster)
+//! and can be implemented for other clients, for example BanksClient of solana-program-test in the tests
+
+pub mod client;
+
+pub mod command;
+
+pub mod error;
+
+pub mod json;
@@ -0,0 +1,56 @@
+use clap::Parser;
+use gpl_nft_voter_cli::command::{process_command, CliConfig, Command};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{commitment_config::CommitmentConfig, signature::read_keypair_file};
+
+#[derive(Parser, Debug)]
+#[clap(version, about = "Admin CLI of the gpl-nft-voter program")]
+struct Cli {
+    /// RPC URL of the cluster (defaults to the Solana CLI config)
+    #[clap(long, short = 'u')]
+    url: Option<String>,
+
+    /// Keypair of the fee payer and the realm authority (defaults to the Solana CLI config)
+    #[clap(long, short = 'k')]
+    keypair: Option<String>,
+
+    /// Prints the unsigned transaction messages serialized as base64 instead of executing them
+    #[clap(long)]
+    dry_run: bool,
+
+    #[clap(subcommand)]
+    command: Command,
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let solana_config = solana_cli_config::CONFIG_FILE
+        .as_ref()
+        .and_then(|config_file| solana_cli_config::Config::load(config_file).ok())
+        .unwrap_or_default();
+
+    let keypair_path = cli.keypair.unwrap_or(solana_config.keypair_path);
+    let authority = read_keypair_file(&keypair_path).unwrap_or_else(|error| {
+        eprintln!("Failed to read keypair {}: {}", keypair_path, error);
+        std::process::exit(1);
+    });
+
+    let rpc_client = RpcClient::new_with_commitment(
+        cli.url.unwrap_or(solana_config.json_rpc_url),
+        CommitmentConfig::confirmed(),
+    );
+
+    let config = CliConfig {
+        authority: Box::new(authority),
+        dry_run: cli.dry_run,
+    };
+
+    match process_command(&rpc_client, &config, &cli.command) {
+        Ok(output) => println!("{}", output),
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use anchor_lang::{AccountSerialize, AnchorSerialize};
+use gpl_nft_voter::state::{
+    get_nft_vote_record_address, get_registrar_address, NftVoteRecord, ProgrammableNftPolicy,
+    Registrar, VoteTrackingMode, VoterWeightCaps, VoterWeightingMode,
+};
+use gpl_nft_voter_cli::{
+    command::{process_command, CliConfig, Command},
+    error::CliError,
+};
+use program_test::program_test_client::ProgramTestClient;
+use solana_program_test::ProgramTest;
+use solana_sdk::{account::Account, hash::Hash, message::Message, pubkey::Pubkey, signer::Signer};
+use tokio::runtime::Runtime;
+
+mod program_test;
+
+fn start_program_test(program_test: ProgramTest) -> (ProgramTestClient, CliConfig) {
+    let runtime = Runtime::new().unwrap();
+    let (banks_client, payer, _) = runtime.block_on(program_test.start());
+
+    (
+        ProgramTestClient::new(runtime, banks_client),
+        CliConfig {
+            authority: Box::new(payer),
+            dry_run: false,
+        },
+    )
+}
+
+fn create_program_account(data: Vec<u8>) -> Account {
+    Account {
+        lamports: 1_000_000_000,
+        data,
+        owner: gpl_nft_voter::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+#[test]
+fn test_show_registrar() {
+    // Arrange
+    let realm = Pubkey::new_unique();
+    let governing_token_mint = Pubkey::new_unique();
+    let registrar_address = get_registrar_address(&realm, &governing_token_mint);
+
+    let registrar = Registrar {
+        governance_program_id: Pubkey::new_unique(),
+        realm,
+        governing_token_mint,
+        collection_configs: vec![],
+        programmable_nft_policy: ProgrammableNftPolicy::AllowAll,
+        voter_weight_caps: VoterWeightCaps::default(),
+        voter_weighting_mode: VoterWeightingMode::Linear,
+        ownership_snapshot_enabled: false,
+        vote_tracking_mode: VoteTrackingMode::NftVoteRecords,
+        reserved: [0; 112],
+    };
+
+    let mut registrar_data = vec![];
+    registrar.try_serialize(&mut registrar_data).unwrap();
+
+    let mut program_test = ProgramTest::default();
+    program_test.add_account(registrar_address, create_program_account(registrar_data));
+
+    let (client, config) = start_program_test(program_test);
+
+    // Act
+    let output = process_command(
+        &client,
+        &config,
+        &Command::ShowRegistrar {
+            realm,
+            governing_token_mint,
+        },
+    )
+    .unwrap();
+
+    // Assert
+    let registrar_json: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    assert_eq!(registrar_json["address"], registrar_address.to_string());
+    assert_eq!(registrar_json["realm"], realm.to_string());
+    assert_eq!(
+        registrar_json["governingTokenMint"],
+        governing_token_mint.to_string()
+    );
+}
+
+#[test]
+fn test_show_nft_vote_record() {
+    // Arrange
+    let proposal = Pubkey::new_unique();
+    let nft_mint = Pubkey::new_unique();
+    let nft_vote_record_address = get_nft_vote_record_address(&proposal, &nft_mint);
+
+    let nft_vote_record = NftVoteRecord {
+        account_discriminator: NftVoteRecord::ACCOUNT_DISCRIMINATOR,
+        proposal,
+        nft_mint,
+        governing_token_owner: Pubkey::new_unique(),
+        reserved: [0; 8],
+        payer: Pubkey::new_unique(),
+    };
+
+    let mut program_test = ProgramTest::default();
+    program_test.add_account(
+        nft_vote_record_address,
+        create_program_account(nft_vote_record.try_to_vec().unwrap()),
+    );
+
+    let (client, config) = start_program_test(program_test);
+
+    // Act
+    let output = process_command(
+        &client,
+        &config,
+        &Command::ShowNftVoteRecord { proposal, nft_mint },
+    )
+    .unwrap();
+
+    // Assert
+    let nft_vote_record_json: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    assert_eq!(
+        nft_vote_record_json["address"],
+        nft_vote_record_address.to_string()
+    );
+    assert_eq!(
+        nft_vote_record_json["governingTokenOwner"],
+        nft_vote_record.governing_token_owner.to_string()
+    );
+}
+
+#[test]
+fn test_show_registrar_with_missing_account_error() {
+    // Arrange
+    let realm = Pubkey::new_unique();
+    let governing_token_mint = Pubkey::new_unique();
+
+    let (client, config) = start_program_test(ProgramTest::default());
+
+    // Act
+    let err = process_command(
+        &client,
+        &config,
+        &Command::ShowRegistrar {
+            realm,
+            governing_token_mint,
+        },
+    )
+    .err()
+    .unwrap();
+
+    // Assert
+    assert!(matches!(err, CliError::AccountNotFound(address)
+        if address == get_registrar_address(&realm, &governing_token_mint)));
+}
+
+#[test]
+fn test_create_registrar_with_dry_run() {
+    // Arrange
+    let realm = Pubkey::new_unique();
+    let governing_token_mint = Pubkey::new_unique();
+
+    let (client, mut config) = start_program_test(ProgramTest::default());
+    config.dry_run = true;
+
+    // Act
+    let output = process_command(
+        &client,
+        &config,
+        &Command::CreateRegistrar {
+            governance_program_id: Pubkey::new_unique(),
+            realm,
+            governing_token_mint,
+            max_collections: 5,
+        },
+    )
+    .unwrap();
+
+    // Assert
+    let message: Message = bincode::deserialize(&base64::decode(output).unwrap()).unwrap();
+
+    assert_eq!(message.instructions.len(), 2);
+    assert_eq!(message.account_keys[0], config.authority.pubkey());
+    assert_eq!(message.header.num_required_signatures, 1);
+
+    // The recent blockhash is not requested in dry run mode
+    assert_eq!(message.recent_blockhash, Hash::default());
+}
@@ -0,0 +1 @@
+pub mod program_test_client;
@@ -0,0 +1,59 @@
+use std::cell::RefCell;
+
+use gpl_nft_voter::state::NftVoteRecord;
+use gpl_nft_voter_cli::{client::CliClient, error::CliError};
+use solana_program_test::BanksClient;
+use solana_sdk::{
+    account::Account, hash::Hash, pubkey::Pubkey, signature::Signature, transaction::Transaction,
+};
+use tokio::runtime::Runtime;
+
+/// Client executing the CLI commands against solana-program-test BanksClient
+/// The BanksClient must be started using the given runtime
+pub struct ProgramTestClient {
+    runtime: Runtime,
+    banks_client: RefCell<BanksClient>,
+}
+
+impl ProgramTestClient {
+    pub fn new(runtime: Runtime, banks_client: BanksClient) -> Self {
+        Self {
+            runtime,
+            banks_client: RefCell::new(banks_client),
+        }
+    }
+}
+
+impl CliClient for ProgramTestClient {
+    fn get_account(&self, address: &Pubkey) -> Result<Option<Account>, CliError> {
+        self.runtime
+            .block_on(self.banks_client.borrow_mut().get_account(*address))
+            .map_err(|error| CliError::ProgramTest(error.to_string()))
+    }
+
+    fn get_nft_vote_records(
+        &self,
+        _proposal: &Pubkey,
+    ) -> Result<Vec<(Pubkey, NftVoteRecord)>, CliError> {
+        // BanksClient doesn't support getProgramAccounts
+        Err(CliError::Unsupported("Listing NftVoteRecords"))
+    }
+
+    fn get_latest_blockhash(&self) -> Result<Hash, CliError> {
+        self.runtime
+            .block_on(self.banks_client.borrow_mut().get_latest_blockhash())
+            .map_err(|error| CliError::ProgramTest(error.to_string()))
+    }
+
+    fn send_transaction(&self, transaction: &Transaction) -> Result<Signature, CliError> {
+        self.runtime
+            .block_on(
+                self.banks_client
+                    .borrow_mut()
+                    .process_transaction(transaction.clone()),
+            )
+            .map_err(|error| CliError::Transaction(error.to_string()))?;
+
+        Ok(transaction.signatures[0])
+    }
+}
@@ -0,0 +1,295 @@
+//! Typed fetch and decode helpers of the nft-voter accounts
+
+use anchor_lang::{prelude::AccountInfo, AccountDeserialize};
+use gpl_nft_voter::{
+    id,
+    state::{
+        get_nft_holder_registration_data, get_nft_vote_record_data, get_vote_bitmap_data,
+        get_voter_weight_cap_tally_data, max_voter_weight_record::MaxVoterWeightRecord,
+        CollectionWeightOverrides, ConditionalWeightConfig, EscrowProgramConfig, MintDenylist,
+        NftDepositRecord, NftHolderRegistration, NftIndexMap, NftVoteDelegation, NftVoteRecord,
+        Registrar, VoteBitmap, VoterWeightCapTally, VoterWeightRecord, VoterWeightTally,
+    },
+};
+use gpl_voter_weight_plugin::client::PluginClientError;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+};
+use solana_sdk::{account::Account, pubkey::Pubkey};
+
+use crate::pda::{
+    get_max_voter_weight_record_address, get_nft_vote_record_address, get_registrar_address,
+    get_voter_weight_record_address,
+};
+
+/// nft-voter program account which can be decoded from its on-chain data
+pub trait NftVoterAccount: Sized {
+    /// Decodes the account and checks it's owned by the nft-voter program
+    fn decode(address: &Pubkey, account: &Account) -> Result<Self, PluginClientError>;
+}
+
+macro_rules! impl_anchor_nft_voter_account {
+    ($($account_type:ty),*) => {
+        $(
+            impl NftVoterAccount for $account_type {
+                fn decode(_address: &Pubkey, account: &Account) -> Result<Self, PluginClientError> {
+                    if account.owner != id() {
+                        return Err(PluginClientError::InvalidAccountOwner(id()));
+                    }
+
+                    Ok(<$account_type>::try_deserialize(&mut account.data.as_slice())?)
+                }
+            }
+        )*
+    };
+}
+
+impl_anchor_nft_voter_account!(
+    Registrar,
+    VoterWeightRecord,
+    MaxVoterWeightRecord,
+    VoterWeightTally,
+    CollectionWeightOverrides,
+    ConditionalWeightConfig,
+    EscrowProgramConfig,
+    MintDenylist,
+    NftDepositRecord,
+    NftIndexMap,
+    NftVoteDelegation
+);
+
+/// Decodes the account using the program deserializer of the accounts with explicit discriminators
+/// The program deserializers check the account owner and handle the legacy account layouts
+fn decode_with_account_info<T>(
+    address: &Pubkey,
+    account: &Account,
+    get_account_data: fn(&AccountInfo) -> anchor_lang::Result<T>,
+) -> Result<T, PluginClientError> {
+    let mut lamports = account.lamports;
+    let mut data = account.data.clone();
+
+    let account_info = AccountInfo::new(
+        address,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &account.owner,
+        account.executable,
+        account.rent_epoch,
+    );
+
+    Ok(get_account_data(&account_info)?)
+}
+
+impl NftVoterAccount for NftVoteRecord {
+    fn decode(address: &Pubkey, account: &Account) -> Result<Self, PluginClientError> {
+        decode_with_account_info(address, account, get_nft_vote_record_data)
+    }
+}
+
+impl NftVoterAccount for VoteBitmap {
+    fn decode(address: &Pubkey, account: &Account) -> Result<Self, PluginClientError> {
+        decode_with_account_info(address, account, get_vote_bitmap_data)
+    }
+}
+
+impl NftVoterAccount for NftHolderRegistration {
+    fn decode(address: &Pubkey, account: &Account) -> Result<Self, PluginClientError> {
+        decode_with_account_info(address, account, get_nft_holder_registration_data)
+    }
+}
+
+impl NftVoterAccount for VoterWeightCapTally {
+    fn decode(address: &Pubkey, account: &Account) -> Result<Self, PluginClientError> {
+        decode_with_account_info(address, account, get_voter_weight_cap_tally_data)
+    }
+}
+
+/// Fetches and decodes the nft-voter account
+/// Returns None if the account doesn't exist
+pub fn fetch_account<T: NftVoterAccount>(
+    rpc_client: &RpcClient,
+    address: &Pubkey,
+) -> Result<Option<T>, PluginClientError> {
+    rpc_client
+        .get_account_with_commitment(address, rpc_client.commitment())?
+        .value
+        .map(|account| T::decode(address, &account))
+        .transpose()
+}
+
+/// Fetches Registrar of the Realm and governing_token_mint
+pub fn fetch_registrar(
+    rpc_client: &RpcClient,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+) -> Result<Option<Registrar>, PluginClientError> {
+    fetch_account(
+        rpc_client,
+        &get_registrar_address(realm, governing_token_mint),
+    )
+}
+
+/// Fetches VoterWeightRecord of the voter
+pub fn fetch_voter_weight_record(
+    rpc_client: &RpcClient,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+) -> Result<Option<VoterWeightRecord>, PluginClientError> {
+    fetch_account(
+        rpc_client,
+        &get_voter_weight_record_address(realm, governing_token_mint, governing_token_owner),
+    )
+}
+
+/// Fetches MaxVoterWeightRecord of the Realm and governing_token_mint
+pub fn fetch_max_voter_weight_record(
+    rpc_client: &RpcClient,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+) -> Result<Option<MaxVoterWeightRecord>, PluginClientError> {
+    fetch_account(
+        rpc_client,
+        &get_max_voter_weight_record_address(realm, governing_token_mint),
+    )
+}
+
+/// Fetches NftVoteRecord of the NFT for the Proposal
+pub fn fetch_nft_vote_record(
+    rpc_client: &RpcClient,
+    proposal: &Pubkey,
+    nft_mint: &Pubkey,
+) -> Result<Option<NftVoteRecord>, PluginClientError> {
+    fetch_account(rpc_client, &get_nft_vote_record_address(proposal, nft_mint))
+}
+
+/// Fetches all NftVoteRecords of the Proposal
+/// The records are filtered by the NftVoteRecord discriminator followed by the Proposal
+pub fn fetch_nft_vote_records(
+    rpc_client: &RpcClient,
+    proposal: &Pubkey,
+) -> Result<Vec<(Pubkey, NftVoteRecord)>, PluginClientError> {
+    let mut filter_bytes = NftVoteRecord::ACCOUNT_DISCRIMINATOR.to_vec();
+    filter_bytes.extend_from_slice(proposal.as_ref());
+
+    let accounts = rpc_client.get_program_accounts_with_config(
+        &id(),
+        RpcProgramAccountsConfig {
+            filters: Some(vec![RpcFilterType::Memcmp(Memcmp {
+                offset: 0,
+                bytes: MemcmpEncodedBytes::Bytes(filter_bytes),
+                encoding: None,
+            })]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        },
+    )?;
+
+    accounts
+        .into_iter()
+        .map(|(address, account)| {
+            NftVoteRecord::decode(&address, &account).map(|record| (address, record))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use anchor_lang::{AccountSerialize, AnchorSerialize};
+    use gpl_nft_voter::state::{
+        ProgrammableNftPolicy, VoteTrackingMode, VoterWeightCaps, VoterWeightingMode,
+    };
+
+    #[test]
+    fn test_decode_registrar() {
+        // Arrange
+        let registrar = Registrar {
+            governance_program_id: Pubkey::new_unique(),
+            realm: Pubkey::new_unique(),
+            governing_token_mint: Pubkey::new_unique(),
+            collection_configs: vec![],
+            programmable_nft_policy: ProgrammableNftPolicy::AllowAll,
+            voter_weight_caps: VoterWeightCaps::default(),
+            voter_weighting_mode: VoterWeightingMode::Linear,
+            ownership_snapshot_enabled: false,
+            vote_tracking_mode: VoteTrackingMode::NftVoteRecords,
+            reserved: [0; 112],
+        };
+
+        let mut data = vec![];
+        registrar.try_serialize(&mut data).unwrap();
+
+        let account = Account {
+            lamports: 1,
+            data,
+            owner: id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        // Act
+        let decoded_registrar = Registrar::decode(&Pubkey::new_unique(), &account).unwrap();
+
+        // Assert
+        assert_eq!(decoded_registrar, registrar);
+    }
+
+    #[test]
+    fn test_decode_registrar_with_invalid_owner_error() {
+        // Arrange
+        let account = Account {
+            lamports: 1,
+            data: vec![],
+            owner: Pubkey::new_unique(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        // Act
+        let result = Registrar::decode(&Pubkey::new_unique(), &account);
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(PluginClientError::InvalidAccountOwner(program_id)) if program_id == id()
+        ));
+    }
+
+    #[test]
+    fn test_decode_nft_vote_record() {
+        // Arrange
+        let nft_vote_record = NftVoteRecord {
+            account_discriminator: NftVoteRecord::ACCOUNT_DISCRIMINATOR,
+            proposal: Pubkey::new_unique(),
+            nft_mint: Pubkey::new_unique(),
+            governing_token_owner: Pubkey::new_unique(),
+            reserved: [0; 8],
+            payer: Pubkey::new_unique(),
+        };
+
+        let account = Account {
+            lamports: 1,
+            data: nft_vote_record.try_to_vec().unwrap(),
+            owner: id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        // Act
+        let decoded_nft_vote_record =
+            NftVoteRecord::decode(&Pubkey::new_unique(), &account).unwrap();
+
+        // Assert
+        assert_eq!(decoded_nft_vote_record, nft_vote_record);
+    }
+}
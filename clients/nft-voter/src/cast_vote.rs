@@ -0,0 +1,248 @@
+//! Builder splitting votes cast with large NFT sets into several transactions
+//!
+//! CastNftVote is accumulative and the NFTs which don't fit into a single transaction can be cast using several transactions
+//! In this scenario only the last CastNftVote is bundled with spl-gov CastVote in the same transaction
+
+use gpl_nft_voter::state::Registrar;
+use solana_program::{instruction::Instruction, message::Message, pubkey::Pubkey};
+use spl_governance::{instruction::cast_vote, state::vote_record::Vote};
+
+use crate::instructions::cast_nft_vote;
+use crate::nft::NftAccounts;
+use crate::pda::{
+    get_max_voter_weight_record_address, get_token_owner_record_address,
+    get_voter_weight_record_address,
+};
+
+/// The max size of a serialized transaction (solana_sdk::packet::PACKET_DATA_SIZE)
+pub const MAX_TRANSACTION_SIZE: usize = 1232;
+
+/// Size of a transaction signature
+const SIGNATURE_SIZE: usize = 64;
+
+/// Returns the size of a serialized transaction with the given instructions
+pub fn get_transaction_size(instructions: &[Instruction], payer: &Pubkey) -> usize {
+    let message = Message::new(instructions, Some(payer));
+    let signatures_count = message.header.num_required_signatures as usize;
+
+    // The signatures are prefixed with their count encoded as compact-u16 which takes a single byte for less than 128 signatures
+    1 + signatures_count * SIGNATURE_SIZE + message.serialize().len()
+}
+
+/// Returns the transactions which cast vote on the Proposal with the given NFTs
+///
+/// The NFTs are split into CastNftVote instructions which fit into a transaction each
+/// and the last CastNftVote is bundled with spl-gov CastVote in the final transaction
+/// The transactions must be executed in the returned order and the final transaction can't be executed before the others
+///
+/// The NFTs locked in the voter's NftDepositRecord are counted by the program and nfts can be empty if the voter only uses them
+/// Note: An NFT which doesn't fit into a transaction on its own is still cast in a separate transaction
+#[allow(clippy::too_many_arguments)]
+pub fn get_cast_nft_vote_transactions(
+    registrar: &Registrar,
+    governance: &Pubkey,
+    proposal: &Pubkey,
+    proposal_owner_record: &Pubkey,
+    governing_token_owner: &Pubkey,
+    voter_authority: &Pubkey,
+    payer: &Pubkey,
+    nfts: &[NftAccounts],
+    proposal_transaction: Option<&Pubkey>,
+    vote: Vote,
+) -> Vec<Vec<Instruction>> {
+    let cast_nft_vote_ix = |nfts: &[NftAccounts]| {
+        cast_nft_vote(
+            registrar,
+            proposal,
+            governing_token_owner,
+            voter_authority,
+            payer,
+            nfts,
+            proposal_transaction,
+        )
+    };
+
+    let cast_vote_ix = cast_vote(
+        &registrar.governance_program_id,
+        &registrar.realm,
+        governance,
+        proposal,
+        proposal_owner_record,
+        &get_token_owner_record_address(
+            &registrar.governance_program_id,
+            &registrar.realm,
+            &registrar.governing_token_mint,
+            governing_token_owner,
+        ),
+        voter_authority,
+        &registrar.governing_token_mint,
+        payer,
+        Some(get_voter_weight_record_address(
+            &registrar.realm,
+            &registrar.governing_token_mint,
+            governing_token_owner,
+        )),
+        Some(get_max_voter_weight_record_address(
+            &registrar.realm,
+            &registrar.governing_token_mint,
+        )),
+        vote,
+    );
+
+    let fits_transaction = |instructions: &[Instruction]| {
+        get_transaction_size(instructions, payer) <= MAX_TRANSACTION_SIZE
+    };
+
+    // Split the NFTs into batches which fit into a transaction with a single CastNftVote
+    let mut batches = vec![];
+    let mut start = 0;
+
+    for end in 1..=nfts.len() {
+        // A batch is closed before the NFT which doesn't fit and every batch takes at least one NFT
+        if end - start > 1
+            && (end - start > u8::MAX as usize
+                || !fits_transaction(&[cast_nft_vote_ix(&nfts[start..end])]))
+        {
+            batches.push(&nfts[start..end - 1]);
+            start = end - 1;
+        }
+    }
+
+    // The last batch must fit into the transaction together with CastVote
+    // and its leading NFTs are moved into a separate transaction if it doesn't
+    let last_batch = &nfts[start..];
+    let mut split = 0;
+
+    while split < last_batch.len()
+        && !fits_transaction(&[cast_nft_vote_ix(&last_batch[split..]), cast_vote_ix.clone()])
+    {
+        split += 1;
+    }
+
+    if split > 0 {
+        batches.push(&last_batch[..split]);
+    }
+
+    let mut transactions = batches
+        .into_iter()
+        .map(|batch| vec![cast_nft_vote_ix(batch)])
+        .collect::<Vec<_>>();
+
+    transactions.push(vec![cast_nft_vote_ix(&last_batch[split..]), cast_vote_ix]);
+
+    transactions
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use anchor_lang::AnchorDeserialize;
+    use gpl_nft_voter::state::{
+        CastNftVoteLayout, ProgrammableNftPolicy, VoteTrackingMode, VoterWeightCaps,
+        VoterWeightingMode,
+    };
+    use spl_governance::state::vote_record::VoteChoice;
+
+    fn create_test_registrar() -> Registrar {
+        Registrar {
+            governance_program_id: Pubkey::new_unique(),
+            realm: Pubkey::new_unique(),
+            governing_token_mint: Pubkey::new_unique(),
+            collection_configs: vec![],
+            programmable_nft_policy: ProgrammableNftPolicy::AllowAll,
+            voter_weight_caps: VoterWeightCaps::default(),
+            voter_weighting_mode: VoterWeightingMode::Linear,
+            ownership_snapshot_enabled: false,
+            vote_tracking_mode: VoteTrackingMode::NftVoteRecords,
+            reserved: [0; 112],
+        }
+    }
+
+    fn get_test_cast_nft_vote_transactions(
+        registrar: &Registrar,
+        nfts: &[NftAccounts],
+    ) -> (Pubkey, Vec<Vec<Instruction>>) {
+        let payer = Pubkey::new_unique();
+        let voter = Pubkey::new_unique();
+
+        let transactions = get_cast_nft_vote_transactions(
+            registrar,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &voter,
+            &voter,
+            &payer,
+            nfts,
+            None,
+            Vote::Approve(vec![VoteChoice {
+                rank: 0,
+                weight_percentage: 100,
+            }]),
+        );
+
+        (payer, transactions)
+    }
+
+    fn get_nft_count(cast_nft_vote_ix: &Instruction) -> usize {
+        CastNftVoteLayout::try_from_slice(&cast_nft_vote_ix.data[8..])
+            .unwrap()
+            .nft_account_counts
+            .len()
+    }
+
+    #[test]
+    fn test_get_cast_nft_vote_transactions() {
+        // Arrange
+        let registrar = create_test_registrar();
+        let collection = Pubkey::new_unique();
+
+        let nfts = (0..20)
+            .map(|_| NftAccounts::new(Pubkey::new_unique(), Pubkey::new_unique(), collection))
+            .collect::<Vec<_>>();
+
+        // Act
+        let (payer, transactions) = get_test_cast_nft_vote_transactions(&registrar, &nfts);
+
+        // Assert
+        assert!(transactions.len() > 1);
+
+        for transaction in transactions.iter() {
+            assert!(get_transaction_size(transaction, &payer) <= MAX_TRANSACTION_SIZE);
+            assert_eq!(transaction[0].program_id, gpl_nft_voter::id());
+        }
+
+        for transaction in transactions[..transactions.len() - 1].iter() {
+            assert_eq!(transaction.len(), 1);
+        }
+
+        let final_transaction = transactions.last().unwrap();
+        assert_eq!(final_transaction.len(), 2);
+        assert_eq!(
+            final_transaction[1].program_id,
+            registrar.governance_program_id
+        );
+
+        let nft_count = transactions
+            .iter()
+            .map(|transaction| get_nft_count(&transaction[0]))
+            .sum::<usize>();
+
+        assert_eq!(nft_count, nfts.len());
+    }
+
+    #[test]
+    fn test_get_cast_nft_vote_transactions_without_nfts() {
+        // Arrange
+        let registrar = create_test_registrar();
+
+        // Act
+        let (_, transactions) = get_test_cast_nft_vote_transactions(&registrar, &[]);
+
+        // Assert
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(transactions[0].len(), 2);
+        assert_eq!(get_nft_count(&transactions[0][0]), 0);
+    }
+}
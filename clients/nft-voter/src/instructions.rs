@@ -0,0 +1,1397 @@
+//! Instruction builders of the nft-voter program
+//!
+//! The builders derive all the PDAs used by the instructions from the Realm, its governing_token_mint and the voter
+//! The instructions which depend on the Registrar configuration (cast vote) take the Registrar account itself
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use gpl_nft_voter::{
+    accounts, id, instruction,
+    state::{
+        CastNftVoteLayout, ConditionalWeightRule, NftWeightOverride, ProgrammableNftPolicy,
+        Registrar, VoteTrackingMode, VoterWeightAction, VoterWeightCaps, VoterWeightingMode,
+    },
+};
+use solana_program::{
+    clock::UnixTimestamp,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+
+use crate::nft::{CompressedNftAccounts, NftAccounts};
+use crate::pda::*;
+
+/// Builds nft-voter instruction from Anchor accounts and instruction data followed by the given remaining accounts
+fn build_instruction(
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+    remaining_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.extend(remaining_accounts);
+
+    Instruction {
+        program_id: id(),
+        accounts: account_metas,
+        data: data.data(),
+    }
+}
+
+/// Returns the accounts of each of the given NFTs used to evaluate their voter weight
+fn get_nfts_account_metas(registrar: &Pubkey, nfts: &[NftAccounts]) -> Vec<Vec<AccountMeta>> {
+    nfts.iter()
+        .map(|nft| nft.get_account_metas(registrar))
+        .collect()
+}
+
+/// Returns the number of the accounts of each NFT passed as nft_account_counts
+fn get_nft_account_counts(nfts_account_metas: &[Vec<AccountMeta>]) -> Vec<u8> {
+    nfts_account_metas
+        .iter()
+        .map(|account_metas| account_metas.len() as u8)
+        .collect()
+}
+
+/// Creates CreateRegistrar instruction
+pub fn create_registrar(
+    governance_program_id: &Pubkey,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+    max_collections: u8,
+) -> Instruction {
+    build_instruction(
+        accounts::CreateRegistrar {
+            registrar: get_registrar_address(realm, governing_token_mint),
+            governance_program_id: *governance_program_id,
+            realm: *realm,
+            governing_token_mint: *governing_token_mint,
+            realm_authority: *realm_authority,
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::CreateRegistrar { max_collections },
+        vec![],
+    )
+}
+
+/// Creates ResizeRegistrar instruction
+pub fn resize_registrar(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+    max_collections: u8,
+) -> Instruction {
+    build_instruction(
+        accounts::ResizeRegistrar {
+            registrar: get_registrar_address(realm, governing_token_mint),
+            realm: *realm,
+            realm_authority: *realm_authority,
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::ResizeRegistrar { max_collections },
+        vec![],
+    )
+}
+
+/// Creates CreateVoterWeightRecord instruction
+pub fn create_voter_weight_record(
+    governance_program_id: &Pubkey,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts::CreateVoterWeightRecord {
+            voter_weight_record: get_voter_weight_record_address(
+                realm,
+                governing_token_mint,
+                governing_token_owner,
+            ),
+            governance_program_id: *governance_program_id,
+            realm: *realm,
+            realm_governing_token_mint: *governing_token_mint,
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::CreateVoterWeightRecord {
+            governing_token_owner: *governing_token_owner,
+        },
+        vec![],
+    )
+}
+
+/// Creates CreateMaxVoterWeightRecord instruction
+pub fn create_max_voter_weight_record(
+    governance_program_id: &Pubkey,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts::CreateMaxVoterWeightRecord {
+            max_voter_weight_record: get_max_voter_weight_record_address(
+                realm,
+                governing_token_mint,
+            ),
+            governance_program_id: *governance_program_id,
+            realm: *realm,
+            realm_governing_token_mint: *governing_token_mint,
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::CreateMaxVoterWeightRecord {},
+        vec![],
+    )
+}
+
+/// Creates UpdateVoterWeightRecord instruction
+pub fn update_voter_weight_record(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+    voter_weight_action: VoterWeightAction,
+    nfts: &[NftAccounts],
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+    let nfts_account_metas = get_nfts_account_metas(&registrar, nfts);
+
+    build_instruction(
+        accounts::UpdateVoterWeightRecord {
+            registrar,
+            voter_weight_record: get_voter_weight_record_address(
+                realm,
+                governing_token_mint,
+                governing_token_owner,
+            ),
+            mint_denylist: get_mint_denylist_address(&registrar),
+            nft_deposit_record: get_nft_deposit_record_address(&registrar, governing_token_owner),
+        },
+        instruction::UpdateVoterWeightRecord {
+            voter_weight_action,
+            nft_account_counts: get_nft_account_counts(&nfts_account_metas),
+        },
+        nfts_account_metas.into_iter().flatten().collect(),
+    )
+}
+
+/// Creates GetVoterWeight instruction
+/// The instruction returns VoterWeightQuote and it should be simulated
+pub fn get_voter_weight(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+    nfts: &[NftAccounts],
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    let nfts_account_metas = get_nfts_account_metas(&registrar, nfts);
+
+    build_instruction(
+        accounts::GetVoterWeight {
+            registrar,
+            governing_token_owner: *governing_token_owner,
+            mint_denylist: get_mint_denylist_address(&registrar),
+            nft_deposit_record: get_nft_deposit_record_address(&registrar, governing_token_owner),
+        },
+        instruction::GetVoterWeight {
+            nft_account_counts: get_nft_account_counts(&nfts_account_metas),
+        },
+        nfts_account_metas.into_iter().flatten().collect(),
+    )
+}
+
+/// Creates CreateVoterWeightTally instruction
+pub fn create_voter_weight_tally(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+    payer: &Pubkey,
+    max_nfts: u16,
+) -> Instruction {
+    let voter_weight_record =
+        get_voter_weight_record_address(realm, governing_token_mint, governing_token_owner);
+
+    build_instruction(
+        accounts::CreateVoterWeightTally {
+            registrar: get_registrar_address(realm, governing_token_mint),
+            voter_weight_record,
+            voter_weight_tally: get_voter_weight_tally_address(&voter_weight_record),
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::CreateVoterWeightTally { max_nfts },
+        vec![],
+    )
+}
+
+/// Creates UpdateVoterWeightTally instruction
+/// The tally is updated by the voter_authority which is the owner or its delegate
+#[allow(clippy::too_many_arguments)]
+pub fn update_voter_weight_tally(
+    governance_program_id: &Pubkey,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+    voter_authority: &Pubkey,
+    payer: &Pubkey,
+    voter_weight_action: VoterWeightAction,
+    nfts: &[NftAccounts],
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+    let voter_weight_record =
+        get_voter_weight_record_address(realm, governing_token_mint, governing_token_owner);
+
+    // The record of each NFT follows its accounts and it's not included in nft_account_counts
+    let nfts_account_metas = get_nfts_account_metas(&registrar, nfts);
+    let nft_account_counts = get_nft_account_counts(&nfts_account_metas);
+
+    let remaining_accounts = nfts_account_metas
+        .into_iter()
+        .zip(nfts)
+        .flat_map(|(mut account_metas, nft)| {
+            account_metas.push(AccountMeta::new(
+                get_nft_tally_record_address(&registrar, &nft.mint),
+                false,
+            ));
+            account_metas
+        })
+        .collect();
+
+    build_instruction(
+        accounts::UpdateVoterWeightTally {
+            registrar,
+            voter_weight_record,
+            voter_weight_tally: get_voter_weight_tally_address(&voter_weight_record),
+            governing_token_owner: *governing_token_owner,
+            voter_authority: *voter_authority,
+            payer: *payer,
+            mint_denylist: get_mint_denylist_address(&registrar),
+            nft_deposit_record: get_nft_deposit_record_address(&registrar, governing_token_owner),
+            nft_vote_delegation: get_nft_vote_delegation_address(&registrar, governing_token_owner),
+            token_owner_record: get_token_owner_record_address(
+                governance_program_id,
+                realm,
+                governing_token_mint,
+                governing_token_owner,
+            ),
+            system_program: system_program::id(),
+        },
+        instruction::UpdateVoterWeightTally {
+            voter_weight_action,
+            nft_account_counts,
+        },
+        remaining_accounts,
+    )
+}
+
+/// Creates CloseVoterWeightTally instruction
+pub fn close_voter_weight_tally(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+    beneficiary: &Pubkey,
+) -> Instruction {
+    let voter_weight_record =
+        get_voter_weight_record_address(realm, governing_token_mint, governing_token_owner);
+
+    build_instruction(
+        accounts::CloseVoterWeightTally {
+            voter_weight_record,
+            voter_weight_tally: get_voter_weight_tally_address(&voter_weight_record),
+            governing_token_owner: *governing_token_owner,
+            beneficiary: *beneficiary,
+        },
+        instruction::CloseVoterWeightTally {},
+        vec![],
+    )
+}
+
+/// Creates RelinquishNftVote instruction
+/// nft_vote_records are the NftVoteRecords of the voter for the Proposal to dispose
+#[allow(clippy::too_many_arguments)]
+pub fn relinquish_nft_vote(
+    governance_program_id: &Pubkey,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governance: &Pubkey,
+    proposal: &Pubkey,
+    governing_token_owner: &Pubkey,
+    beneficiary: &Pubkey,
+    nft_vote_records: &[Pubkey],
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::RelinquishNftVote {
+            registrar,
+            voter_weight_record: get_voter_weight_record_address(
+                realm,
+                governing_token_mint,
+                governing_token_owner,
+            ),
+            governance: *governance,
+            proposal: *proposal,
+            governing_token_owner: *governing_token_owner,
+            vote_record: get_vote_record_address(
+                governance_program_id,
+                realm,
+                governing_token_mint,
+                governing_token_owner,
+                proposal,
+            ),
+            beneficiary: *beneficiary,
+            voter_weight_cap_tally: get_voter_weight_cap_tally_address(
+                proposal,
+                governing_token_owner,
+            ),
+            nft_deposit_record: get_nft_deposit_record_address(&registrar, governing_token_owner),
+        },
+        instruction::RelinquishNftVote {},
+        nft_vote_records
+            .iter()
+            .map(|nft_vote_record| AccountMeta::new(*nft_vote_record, false))
+            .collect(),
+    )
+}
+
+/// Creates RevokeNftVote instruction
+/// The votes of the previous_owner are revoked by the current governing_token_owner of the transferred NFTs
+/// The instruction must be followed by spl-gov RevokeVote instruction in the same transaction
+#[allow(clippy::too_many_arguments)]
+pub fn revoke_nft_vote(
+    governance_program_id: &Pubkey,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governance: &Pubkey,
+    proposal: &Pubkey,
+    previous_owner: &Pubkey,
+    governing_token_owner: &Pubkey,
+    beneficiary: &Pubkey,
+    nfts: &[NftAccounts],
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    // The record of each NFT follows its accounts and it's not included in nft_account_counts
+    let nfts_account_metas = get_nfts_account_metas(&registrar, nfts);
+    let nft_account_counts = get_nft_account_counts(&nfts_account_metas);
+
+    let remaining_accounts = nfts_account_metas
+        .into_iter()
+        .zip(nfts)
+        .flat_map(|(mut account_metas, nft)| {
+            account_metas.push(AccountMeta::new(
+                get_nft_vote_record_address(proposal, &nft.mint),
+                false,
+            ));
+            account_metas
+        })
+        .collect();
+
+    build_instruction(
+        accounts::RevokeNftVote {
+            registrar,
+            voter_weight_record: get_voter_weight_record_address(
+                realm,
+                governing_token_mint,
+                previous_owner,
+            ),
+            governance: *governance,
+            proposal: *proposal,
+            governing_token_owner: *governing_token_owner,
+            vote_record: get_vote_record_address(
+                governance_program_id,
+                realm,
+                governing_token_mint,
+                previous_owner,
+                proposal,
+            ),
+            beneficiary: *beneficiary,
+            mint_denylist: get_mint_denylist_address(&registrar),
+            voter_weight_cap_tally: get_voter_weight_cap_tally_address(proposal, previous_owner),
+            instruction_sysvar_account: sysvar::instructions::id(),
+        },
+        instruction::RevokeNftVote { nft_account_counts },
+        remaining_accounts,
+    )
+}
+
+/// Creates CloseNftVoteRecords instruction
+/// nft_vote_records are (nft_vote_record, payer) pairs and VoteBitmaps can be provided in place of NftVoteRecords
+pub fn close_nft_vote_records(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governance: &Pubkey,
+    proposal: &Pubkey,
+    nft_vote_records: &[(Pubkey, Pubkey)],
+) -> Instruction {
+    build_instruction(
+        accounts::CloseNftVoteRecords {
+            registrar: get_registrar_address(realm, governing_token_mint),
+            governance: *governance,
+            proposal: *proposal,
+        },
+        instruction::CloseNftVoteRecords {},
+        nft_vote_records
+            .iter()
+            .flat_map(|(nft_vote_record, payer)| {
+                vec![
+                    AccountMeta::new(*nft_vote_record, false),
+                    AccountMeta::new(*payer, false),
+                ]
+            })
+            .collect(),
+    )
+}
+
+/// Creates ConfigureCollection instruction
+pub fn configure_collection(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    collection: &Pubkey,
+    weight: u64,
+    size: u64,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::ConfigureCollection {
+            registrar,
+            realm: *realm,
+            realm_authority: *realm_authority,
+            collection: *collection,
+            metadata: get_metadata_address(collection),
+            max_voter_weight_record: get_max_voter_weight_record_address(
+                realm,
+                governing_token_mint,
+            ),
+            collection_weight_overrides: get_collection_weight_overrides_address(
+                &registrar, collection,
+            ),
+        },
+        instruction::ConfigureCollection { weight, size },
+        vec![],
+    )
+}
+
+/// Creates RemoveCollection instruction
+pub fn remove_collection(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    collection: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts::RemoveCollection {
+            registrar: get_registrar_address(realm, governing_token_mint),
+            realm: *realm,
+            realm_authority: *realm_authority,
+            max_voter_weight_record: get_max_voter_weight_record_address(
+                realm,
+                governing_token_mint,
+            ),
+        },
+        instruction::RemoveCollection {
+            collection: *collection,
+        },
+        vec![],
+    )
+}
+
+/// Creates CreateCollectionWeightOverrides instruction
+pub fn create_collection_weight_overrides(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+    collection: &Pubkey,
+    overrides: Vec<NftWeightOverride>,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::CreateCollectionWeightOverrides {
+            registrar,
+            realm: *realm,
+            realm_authority: *realm_authority,
+            collection_weight_overrides: get_collection_weight_overrides_address(
+                &registrar, collection,
+            ),
+            max_voter_weight_record: get_max_voter_weight_record_address(
+                realm,
+                governing_token_mint,
+            ),
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::CreateCollectionWeightOverrides {
+            collection: *collection,
+            overrides,
+        },
+        vec![],
+    )
+}
+
+/// Creates ConfigureCollectionWeightOverrides instruction
+pub fn configure_collection_weight_overrides(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+    collection: &Pubkey,
+    overrides: Vec<NftWeightOverride>,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::ConfigureCollectionWeightOverrides {
+            registrar,
+            realm: *realm,
+            realm_authority: *realm_authority,
+            collection_weight_overrides: get_collection_weight_overrides_address(
+                &registrar, collection,
+            ),
+            max_voter_weight_record: get_max_voter_weight_record_address(
+                realm,
+                governing_token_mint,
+            ),
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::ConfigureCollectionWeightOverrides { overrides },
+        vec![],
+    )
+}
+
+/// Creates AddCollectionWeightOverrides instruction
+pub fn add_collection_weight_overrides(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+    collection: &Pubkey,
+    overrides: Vec<NftWeightOverride>,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::AddCollectionWeightOverrides {
+            registrar,
+            realm: *realm,
+            realm_authority: *realm_authority,
+            collection_weight_overrides: get_collection_weight_overrides_address(
+                &registrar, collection,
+            ),
+            max_voter_weight_record: get_max_voter_weight_record_address(
+                realm,
+                governing_token_mint,
+            ),
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::AddCollectionWeightOverrides { overrides },
+        vec![],
+    )
+}
+
+/// Creates RemoveCollectionWeightOverrides instruction
+pub fn remove_collection_weight_overrides(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+    collection: &Pubkey,
+    nft_mints: Vec<Pubkey>,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::RemoveCollectionWeightOverrides {
+            registrar,
+            realm: *realm,
+            realm_authority: *realm_authority,
+            collection_weight_overrides: get_collection_weight_overrides_address(
+                &registrar, collection,
+            ),
+            max_voter_weight_record: get_max_voter_weight_record_address(
+                realm,
+                governing_token_mint,
+            ),
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::RemoveCollectionWeightOverrides { nft_mints },
+        vec![],
+    )
+}
+
+/// Creates ConfigureProgrammableNftPolicy instruction
+pub fn configure_programmable_nft_policy(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    programmable_nft_policy: ProgrammableNftPolicy,
+) -> Instruction {
+    build_instruction(
+        accounts::ConfigureProgrammableNftPolicy {
+            registrar: get_registrar_address(realm, governing_token_mint),
+            realm: *realm,
+            realm_authority: *realm_authority,
+        },
+        instruction::ConfigureProgrammableNftPolicy {
+            programmable_nft_policy,
+        },
+        vec![],
+    )
+}
+
+/// Creates ConfigureVoterWeightCaps instruction
+pub fn configure_voter_weight_caps(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    voter_weight_caps: VoterWeightCaps,
+) -> Instruction {
+    build_instruction(
+        accounts::ConfigureVoterWeightCaps {
+            registrar: get_registrar_address(realm, governing_token_mint),
+            realm: *realm,
+            realm_authority: *realm_authority,
+        },
+        instruction::ConfigureVoterWeightCaps { voter_weight_caps },
+        vec![],
+    )
+}
+
+/// Creates ConfigureVoterWeightingMode instruction
+pub fn configure_voter_weighting_mode(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    voter_weighting_mode: VoterWeightingMode,
+) -> Instruction {
+    build_instruction(
+        accounts::ConfigureVoterWeightingMode {
+            registrar: get_registrar_address(realm, governing_token_mint),
+            realm: *realm,
+            realm_authority: *realm_authority,
+            max_voter_weight_record: get_max_voter_weight_record_address(
+                realm,
+                governing_token_mint,
+            ),
+        },
+        instruction::ConfigureVoterWeightingMode {
+            voter_weighting_mode,
+        },
+        vec![],
+    )
+}
+
+/// Creates ConfigureOwnershipSnapshot instruction
+pub fn configure_ownership_snapshot(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    ownership_snapshot_enabled: bool,
+) -> Instruction {
+    build_instruction(
+        accounts::ConfigureOwnershipSnapshot {
+            registrar: get_registrar_address(realm, governing_token_mint),
+            realm: *realm,
+            realm_authority: *realm_authority,
+        },
+        instruction::ConfigureOwnershipSnapshot {
+            ownership_snapshot_enabled,
+        },
+        vec![],
+    )
+}
+
+/// Creates ConfigureVoteTrackingMode instruction
+pub fn configure_vote_tracking_mode(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    vote_tracking_mode: VoteTrackingMode,
+) -> Instruction {
+    build_instruction(
+        accounts::ConfigureVoteTrackingMode {
+            registrar: get_registrar_address(realm, governing_token_mint),
+            realm: *realm,
+            realm_authority: *realm_authority,
+        },
+        instruction::ConfigureVoteTrackingMode { vote_tracking_mode },
+        vec![],
+    )
+}
+
+/// Creates CreateNftIndexMap instruction
+pub fn create_nft_index_map(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+    collection: &Pubkey,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::CreateNftIndexMap {
+            registrar,
+            realm: *realm,
+            realm_authority: *realm_authority,
+            nft_index_map: get_nft_index_map_address(&registrar, collection),
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::CreateNftIndexMap {
+            collection: *collection,
+        },
+        vec![],
+    )
+}
+
+/// Creates AddNftIndexMapMints instruction
+pub fn add_nft_index_map_mints(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+    collection: &Pubkey,
+    mints: Vec<Pubkey>,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::AddNftIndexMapMints {
+            registrar,
+            realm: *realm,
+            realm_authority: *realm_authority,
+            nft_index_map: get_nft_index_map_address(&registrar, collection),
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::AddNftIndexMapMints {
+            collection: *collection,
+            mints,
+        },
+        vec![],
+    )
+}
+
+/// Creates AddEscrowProgram instruction
+#[allow(clippy::too_many_arguments)]
+pub fn add_escrow_program(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+    program_id: &Pubkey,
+    receipt_discriminator: [u8; 8],
+    beneficial_owner_offset: u32,
+    mint_offset: u32,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::AddEscrowProgram {
+            registrar,
+            realm: *realm,
+            realm_authority: *realm_authority,
+            escrow_program_config: get_escrow_program_config_address(&registrar, program_id),
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::AddEscrowProgram {
+            program_id: *program_id,
+            receipt_discriminator,
+            beneficial_owner_offset,
+            mint_offset,
+        },
+        vec![],
+    )
+}
+
+/// Creates RemoveEscrowProgram instruction
+pub fn remove_escrow_program(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    program_id: &Pubkey,
+    beneficiary: &Pubkey,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::RemoveEscrowProgram {
+            registrar,
+            realm: *realm,
+            realm_authority: *realm_authority,
+            escrow_program_config: get_escrow_program_config_address(&registrar, program_id),
+            beneficiary: *beneficiary,
+        },
+        instruction::RemoveEscrowProgram {},
+        vec![],
+    )
+}
+
+/// Creates CreateConditionalWeightConfig instruction
+pub fn create_conditional_weight_config(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+    rules: Vec<ConditionalWeightRule>,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::CreateConditionalWeightConfig {
+            registrar,
+            realm: *realm,
+            realm_authority: *realm_authority,
+            conditional_weight_config: get_conditional_weight_config_address(&registrar),
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::CreateConditionalWeightConfig { rules },
+        vec![],
+    )
+}
+
+/// Creates ConfigureConditionalWeightRules instruction
+pub fn configure_conditional_weight_rules(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+    rules: Vec<ConditionalWeightRule>,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::ConfigureConditionalWeightRules {
+            registrar,
+            realm: *realm,
+            realm_authority: *realm_authority,
+            conditional_weight_config: get_conditional_weight_config_address(&registrar),
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::ConfigureConditionalWeightRules { rules },
+        vec![],
+    )
+}
+
+/// Creates CreateMintDenylist instruction
+pub fn create_mint_denylist(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+    mints: Vec<Pubkey>,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::CreateMintDenylist {
+            registrar,
+            realm: *realm,
+            realm_authority: *realm_authority,
+            mint_denylist: get_mint_denylist_address(&registrar),
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::CreateMintDenylist { mints },
+        vec![],
+    )
+}
+
+/// Creates AddDeniedMints instruction
+pub fn add_denied_mints(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+    mints: Vec<Pubkey>,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::AddDeniedMints {
+            registrar,
+            realm: *realm,
+            realm_authority: *realm_authority,
+            mint_denylist: get_mint_denylist_address(&registrar),
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::AddDeniedMints { mints },
+        vec![],
+    )
+}
+
+/// Creates RemoveDeniedMints instruction
+pub fn remove_denied_mints(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+    mints: Vec<Pubkey>,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::RemoveDeniedMints {
+            registrar,
+            realm: *realm,
+            realm_authority: *realm_authority,
+            mint_denylist: get_mint_denylist_address(&registrar),
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::RemoveDeniedMints { mints },
+        vec![],
+    )
+}
+
+/// Creates CreateNftDepositRecord instruction
+pub fn create_nft_deposit_record(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+    payer: &Pubkey,
+    max_nfts: u16,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::CreateNftDepositRecord {
+            registrar,
+            nft_deposit_record: get_nft_deposit_record_address(&registrar, governing_token_owner),
+            governing_token_owner: *governing_token_owner,
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::CreateNftDepositRecord { max_nfts },
+        vec![],
+    )
+}
+
+/// Creates DepositNft instruction
+pub fn deposit_nft(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+    payer: &Pubkey,
+    nft: &NftAccounts,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    // The NFT weight override is the only NFT account which is provided in remaining accounts
+    let remaining_accounts = if nft.has_weight_override {
+        vec![AccountMeta::new_readonly(
+            get_collection_weight_overrides_address(&registrar, &nft.collection),
+            false,
+        )]
+    } else {
+        vec![]
+    };
+
+    build_instruction(
+        accounts::DepositNft {
+            registrar,
+            nft_deposit_record: get_nft_deposit_record_address(&registrar, governing_token_owner),
+            governing_token_owner: *governing_token_owner,
+            nft_token_account: nft.token_account,
+            nft_metadata: get_metadata_address(&nft.mint),
+            nft_mint: nft.mint,
+            nft_locker_authority: get_nft_locker_authority_address(&registrar),
+            nft_vault: get_nft_vault_address(&registrar, &nft.mint),
+            mint_denylist: get_mint_denylist_address(&registrar),
+            payer: *payer,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::id(),
+            rent: sysvar::rent::id(),
+        },
+        instruction::DepositNft {},
+        remaining_accounts,
+    )
+}
+
+/// Creates WithdrawNft instruction
+#[allow(clippy::too_many_arguments)]
+pub fn withdraw_nft(
+    governance_program_id: &Pubkey,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+    nft_token_account: &Pubkey,
+    nft_mint: &Pubkey,
+    beneficiary: &Pubkey,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::WithdrawNft {
+            registrar,
+            nft_deposit_record: get_nft_deposit_record_address(&registrar, governing_token_owner),
+            governing_token_owner: *governing_token_owner,
+            voter_weight_record: get_voter_weight_record_address(
+                realm,
+                governing_token_mint,
+                governing_token_owner,
+            ),
+            token_owner_record: get_token_owner_record_address(
+                governance_program_id,
+                realm,
+                governing_token_mint,
+                governing_token_owner,
+            ),
+            nft_token_account: *nft_token_account,
+            nft_mint: *nft_mint,
+            nft_locker_authority: get_nft_locker_authority_address(&registrar),
+            nft_vault: get_nft_vault_address(&registrar, nft_mint),
+            beneficiary: *beneficiary,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::WithdrawNft {},
+        vec![],
+    )
+}
+
+/// Creates CreateNftVoteDelegation instruction
+#[allow(clippy::too_many_arguments)]
+pub fn create_nft_vote_delegation(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+    payer: &Pubkey,
+    delegate: &Pubkey,
+    collection: Option<Pubkey>,
+    expiry: Option<UnixTimestamp>,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::CreateNftVoteDelegation {
+            registrar,
+            nft_vote_delegation: get_nft_vote_delegation_address(&registrar, governing_token_owner),
+            governing_token_owner: *governing_token_owner,
+            payer: *payer,
+            system_program: system_program::id(),
+        },
+        instruction::CreateNftVoteDelegation {
+            delegate: *delegate,
+            collection,
+            expiry,
+        },
+        vec![],
+    )
+}
+
+/// Creates RevokeNftVoteDelegation instruction
+pub fn revoke_nft_vote_delegation(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+    beneficiary: &Pubkey,
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    build_instruction(
+        accounts::RevokeNftVoteDelegation {
+            registrar,
+            nft_vote_delegation: get_nft_vote_delegation_address(&registrar, governing_token_owner),
+            governing_token_owner: *governing_token_owner,
+            beneficiary: *beneficiary,
+        },
+        instruction::RevokeNftVoteDelegation {},
+        vec![],
+    )
+}
+
+/// Creates RegisterNftHolders instruction
+pub fn register_nft_holders(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    nft_holder: &Pubkey,
+    payer: &Pubkey,
+    nfts: &[NftAccounts],
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    // The record of each NFT follows its accounts and it's not included in nft_account_counts
+    let nfts_account_metas = get_nfts_account_metas(&registrar, nfts);
+    let nft_account_counts = get_nft_account_counts(&nfts_account_metas);
+
+    let remaining_accounts = nfts_account_metas
+        .into_iter()
+        .zip(nfts)
+        .flat_map(|(mut account_metas, nft)| {
+            account_metas.push(AccountMeta::new(
+                get_nft_holder_registration_address(&registrar, &nft.mint),
+                false,
+            ));
+            account_metas
+        })
+        .collect();
+
+    build_instruction(
+        accounts::RegisterNftHolders {
+            registrar,
+            nft_holder: *nft_holder,
+            payer: *payer,
+            mint_denylist: get_mint_denylist_address(&registrar),
+            system_program: system_program::id(),
+        },
+        instruction::RegisterNftHolders { nft_account_counts },
+        remaining_accounts,
+    )
+}
+
+/// Creates RegisterCompressedNftHolders instruction
+pub fn register_compressed_nft_holders(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    nft_holder: &Pubkey,
+    payer: &Pubkey,
+    compressed_nfts: &[CompressedNftAccounts],
+) -> Instruction {
+    let registrar = get_registrar_address(realm, governing_token_mint);
+
+    let remaining_accounts = compressed_nfts
+        .iter()
+        .flat_map(|compressed_nft| {
+            let mut account_metas = vec![
+                AccountMeta::new_readonly(compressed_nft.merkle_tree, false),
+                AccountMeta::new(
+                    get_nft_holder_registration_address(&registrar, &compressed_nft.get_asset_id()),
+                    false,
+                ),
+            ];
+            account_metas.extend(compressed_nft.get_proof_account_metas());
+            account_metas
+        })
+        .collect();
+
+    build_instruction(
+        accounts::RegisterNftHolders {
+            registrar,
+            nft_holder: *nft_holder,
+            payer: *payer,
+            mint_denylist: get_mint_denylist_address(&registrar),
+            system_program: system_program::id(),
+        },
+        instruction::RegisterCompressedNftHolders {
+            compressed_nfts: compressed_nfts
+                .iter()
+                .map(|compressed_nft| compressed_nft.asset.clone())
+                .collect(),
+        },
+        remaining_accounts,
+    )
+}
+
+/// Returns CastNftVote accounts which are shared by CastNftVote and CastCompressedNftVote
+fn get_cast_nft_vote_accounts(
+    registrar: &Registrar,
+    proposal: &Pubkey,
+    governing_token_owner: &Pubkey,
+    voter_authority: &Pubkey,
+    payer: &Pubkey,
+) -> accounts::CastNftVote {
+    let registrar_address =
+        get_registrar_address(&registrar.realm, &registrar.governing_token_mint);
+
+    accounts::CastNftVote {
+        registrar: registrar_address,
+        voter_weight_record: get_voter_weight_record_address(
+            &registrar.realm,
+            &registrar.governing_token_mint,
+            governing_token_owner,
+        ),
+        governing_token_owner: *governing_token_owner,
+        voter_authority: *voter_authority,
+        payer: *payer,
+        proposal: *proposal,
+        instruction_sysvar_account: sysvar::instructions::id(),
+        governance_program: registrar.governance_program_id,
+        conditional_weight_config: get_conditional_weight_config_address(&registrar_address),
+        mint_denylist: get_mint_denylist_address(&registrar_address),
+        voter_weight_cap_tally: get_voter_weight_cap_tally_address(proposal, governing_token_owner),
+        nft_deposit_record: get_nft_deposit_record_address(
+            &registrar_address,
+            governing_token_owner,
+        ),
+        nft_deposit_vote_record: get_nft_deposit_vote_record_address(
+            &registrar_address,
+            proposal,
+            governing_token_owner,
+        ),
+        nft_vote_delegation: get_nft_vote_delegation_address(
+            &registrar_address,
+            governing_token_owner,
+        ),
+        token_owner_record: get_token_owner_record_address(
+            &registrar.governance_program_id,
+            &registrar.realm,
+            &registrar.governing_token_mint,
+            governing_token_owner,
+        ),
+        system_program: system_program::id(),
+    }
+}
+
+/// Returns the accounts which record the NFT vote for the Registrar VoteTrackingMode:
+/// nft_vote_record or vote_bitmap followed by nft_index_map
+fn get_nft_vote_record_account_metas(
+    registrar_address: &Pubkey,
+    registrar: &Registrar,
+    proposal: &Pubkey,
+    nft_mint: &Pubkey,
+    collection: &Pubkey,
+) -> Vec<AccountMeta> {
+    match registrar.vote_tracking_mode {
+        VoteTrackingMode::NftVoteRecords => vec![AccountMeta::new(
+            get_nft_vote_record_address(proposal, nft_mint),
+            false,
+        )],
+        VoteTrackingMode::VoteBitmaps => vec![
+            AccountMeta::new(get_vote_bitmap_address(proposal, collection), false),
+            AccountMeta::new_readonly(
+                get_nft_index_map_address(registrar_address, collection),
+                false,
+            ),
+        ],
+    }
+}
+
+/// Creates CastNftVote instruction
+/// The Registrar account is used to resolve the remaining accounts required by its configuration
+/// and proposal_transaction must be provided for Proposals matching a conditional weight rule
+pub fn cast_nft_vote(
+    registrar: &Registrar,
+    proposal: &Pubkey,
+    governing_token_owner: &Pubkey,
+    voter_authority: &Pubkey,
+    payer: &Pubkey,
+    nfts: &[NftAccounts],
+    proposal_transaction: Option<&Pubkey>,
+) -> Instruction {
+    let registrar_address =
+        get_registrar_address(&registrar.realm, &registrar.governing_token_mint);
+
+    let mut remaining_accounts = vec![];
+    let mut nft_account_counts = vec![];
+
+    for nft in nfts {
+        let nft_account_metas = nft.get_account_metas(&registrar_address);
+        nft_account_counts.push(nft_account_metas.len() as u8);
+        remaining_accounts.extend(nft_account_metas);
+
+        if registrar.ownership_snapshot_enabled {
+            remaining_accounts.push(AccountMeta::new_readonly(
+                get_nft_holder_registration_address(&registrar_address, &nft.mint),
+                false,
+            ));
+        }
+
+        remaining_accounts.extend(get_nft_vote_record_account_metas(
+            &registrar_address,
+            registrar,
+            proposal,
+            &nft.mint,
+            &nft.collection,
+        ));
+    }
+
+    if let Some(proposal_transaction) = proposal_transaction {
+        remaining_accounts.push(AccountMeta::new_readonly(*proposal_transaction, false));
+    }
+
+    build_instruction(
+        get_cast_nft_vote_accounts(
+            registrar,
+            proposal,
+            governing_token_owner,
+            voter_authority,
+            payer,
+        ),
+        instruction::CastNftVote {
+            layout: CastNftVoteLayout {
+                nft_account_counts,
+                with_proposal_transaction: proposal_transaction.is_some(),
+            },
+        },
+        remaining_accounts,
+    )
+}
+
+/// Creates CastCompressedNftVote instruction
+/// The Registrar account is used to resolve the remaining accounts required by its configuration
+/// and proposal_transaction must be provided for Proposals matching a conditional weight rule
+pub fn cast_compressed_nft_vote(
+    registrar: &Registrar,
+    proposal: &Pubkey,
+    governing_token_owner: &Pubkey,
+    voter_authority: &Pubkey,
+    payer: &Pubkey,
+    compressed_nfts: &[CompressedNftAccounts],
+    proposal_transaction: Option<&Pubkey>,
+) -> Instruction {
+    let registrar_address =
+        get_registrar_address(&registrar.realm, &registrar.governing_token_mint);
+
+    let mut remaining_accounts = vec![];
+
+    for compressed_nft in compressed_nfts {
+        let asset_id = compressed_nft.get_asset_id();
+
+        remaining_accounts.push(AccountMeta::new_readonly(compressed_nft.merkle_tree, false));
+
+        remaining_accounts.extend(get_nft_vote_record_account_metas(
+            &registrar_address,
+            registrar,
+            proposal,
+            &asset_id,
+            &compressed_nft.get_collection(),
+        ));
+
+        if registrar.ownership_snapshot_enabled {
+            remaining_accounts.push(AccountMeta::new_readonly(
+                get_nft_holder_registration_address(&registrar_address, &asset_id),
+                false,
+            ));
+        }
+
+        remaining_accounts.extend(compressed_nft.get_proof_account_metas());
+    }
+
+    if let Some(proposal_transaction) = proposal_transaction {
+        remaining_accounts.push(AccountMeta::new_readonly(*proposal_transaction, false));
+    }
+
+    build_instruction(
+        get_cast_nft_vote_accounts(
+            registrar,
+            proposal,
+            governing_token_owner,
+            voter_authority,
+            payer,
+        ),
+        instruction::CastCompressedNftVote {
+            compressed_nfts: compressed_nfts
+                .iter()
+                .map(|compressed_nft| compressed_nft.asset.clone())
+                .collect(),
+            with_proposal_transaction: proposal_transaction.is_some(),
+        },
+        remaining_accounts,
+    )
+}
@@ -0,0 +1,18 @@
+//! Rust client for the gpl-nft-voter program
+//!
+//! The crate provides instruction builders for all the program instructions, PDA helpers,
+//! typed account fetch and decode helpers and a builder splitting votes with large NFT sets into several transactions
+
+pub mod accounts;
+
+pub mod cast_vote;
+
+pub mod instructions;
+
+pub mod nft;
+
+pub mod pda;
+
+pub use gpl_nft_voter;
+pub use gpl_nft_voter::id;
+pub use gpl_voter_weight_plugin::client::PluginClientError;
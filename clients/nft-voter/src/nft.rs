@@ -0,0 +1,127 @@
+//! Accounts of the NFTs provided to the program instructions in remaining accounts
+
+use gpl_nft_voter::state::CompressedNftAsset;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+
+use crate::pda::{
+    get_asset_id, get_collection_weight_overrides_address, get_escrow_program_config_address,
+    get_metadata_address, get_token_record_address,
+};
+
+/// Escrow program holding an NFT on behalf of its beneficial owner
+#[derive(Debug, Clone, PartialEq)]
+pub struct NftEscrowAccounts {
+    /// The escrow program allowed for the Registrar using AddEscrowProgram
+    pub escrow_program_id: Pubkey,
+
+    /// The escrow receipt recording the beneficial owner of the NFT
+    pub escrow_receipt: Pubkey,
+}
+
+/// Accounts of an NFT used to evaluate its voter weight
+#[derive(Debug, Clone, PartialEq)]
+pub struct NftAccounts {
+    /// The token account holding the NFT
+    pub token_account: Pubkey,
+
+    /// The mint of the NFT
+    pub mint: Pubkey,
+
+    /// The verified collection of the NFT
+    pub collection: Pubkey,
+
+    /// Indicates the NFT is programmable and its TokenRecord must be provided
+    pub is_programmable: bool,
+
+    /// The escrow program holding the NFT if it's escrowed
+    pub escrow: Option<NftEscrowAccounts>,
+
+    /// Indicates the NFT has weight override in CollectionWeightOverrides of its collection
+    pub has_weight_override: bool,
+}
+
+impl NftAccounts {
+    pub fn new(token_account: Pubkey, mint: Pubkey, collection: Pubkey) -> Self {
+        Self {
+            token_account,
+            mint,
+            collection,
+            is_programmable: false,
+            escrow: None,
+            has_weight_override: false,
+        }
+    }
+
+    /// Returns the NFT accounts in the order the program resolves them: nft, nft_metadata,
+    /// token_record (only for programmable NFTs), escrow_program_config and escrow_receipt (only for escrowed NFTs)
+    /// and collection_weight_overrides (only for NFTs with weight override)
+    pub fn get_account_metas(&self, registrar: &Pubkey) -> Vec<AccountMeta> {
+        let mut account_metas = vec![
+            AccountMeta::new_readonly(self.token_account, false),
+            AccountMeta::new_readonly(get_metadata_address(&self.mint), false),
+        ];
+
+        if self.is_programmable {
+            account_metas.push(AccountMeta::new_readonly(
+                get_token_record_address(&self.mint, &self.token_account),
+                false,
+            ));
+        }
+
+        if let Some(ref escrow) = self.escrow {
+            account_metas.push(AccountMeta::new_readonly(
+                get_escrow_program_config_address(registrar, &escrow.escrow_program_id),
+                false,
+            ));
+            account_metas.push(AccountMeta::new_readonly(escrow.escrow_receipt, false));
+        }
+
+        if self.has_weight_override {
+            account_metas.push(AccountMeta::new_readonly(
+                get_collection_weight_overrides_address(registrar, &self.collection),
+                false,
+            ));
+        }
+
+        account_metas
+    }
+}
+
+/// Compressed NFT (Bubblegum) with the Merkle proof of its leaf
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompressedNftAccounts {
+    /// The Merkle tree of the compressed NFT
+    pub merkle_tree: Pubkey,
+
+    /// The leaf data of the compressed NFT
+    pub asset: CompressedNftAsset,
+
+    /// Proof nodes from the leaf up to the root (max_depth of the tree)
+    pub proof: Vec<Pubkey>,
+}
+
+impl CompressedNftAccounts {
+    /// Returns the asset id of the compressed NFT which is used in place of the NFT mint
+    pub fn get_asset_id(&self) -> Pubkey {
+        get_asset_id(&self.merkle_tree, self.asset.nonce)
+    }
+
+    /// Returns the collection of the compressed NFT
+    /// The program rejects NFTs without a verified collection and the default key is returned for them
+    pub fn get_collection(&self) -> Pubkey {
+        self.asset
+            .metadata
+            .collection
+            .as_ref()
+            .map(|collection| collection.key)
+            .unwrap_or_default()
+    }
+
+    /// Returns the proof nodes account metas
+    pub fn get_proof_account_metas(&self) -> Vec<AccountMeta> {
+        self.proof
+            .iter()
+            .map(|node| AccountMeta::new_readonly(*node, false))
+            .collect()
+    }
+}
@@ -0,0 +1,35 @@
+//! PDA helpers of the nft-voter accounts and the related spl-governance and mpl-token-metadata accounts
+
+use solana_program::pubkey::Pubkey;
+
+pub use gpl_nft_voter::state::max_voter_weight_record::get_max_voter_weight_record_address;
+pub use gpl_nft_voter::state::{
+    get_collection_weight_overrides_address, get_conditional_weight_config_address,
+    get_escrow_program_config_address, get_mint_denylist_address, get_nft_deposit_record_address,
+    get_nft_holder_registration_address, get_nft_index_map_address,
+    get_nft_locker_authority_address, get_nft_tally_record_address, get_nft_vault_address,
+    get_nft_vote_delegation_address, get_nft_vote_record_address, get_registrar_address,
+    get_vote_bitmap_address, get_voter_weight_cap_tally_address, get_voter_weight_record_address,
+    get_voter_weight_tally_address,
+};
+pub use gpl_nft_voter::tools::bubblegum::get_asset_id;
+pub use gpl_nft_voter::tools::governance::get_vote_record_address;
+pub use gpl_nft_voter::tools::token_metadata::get_token_record_address;
+pub use spl_governance::state::token_owner_record::get_token_owner_record_address;
+
+/// Returns NftVoteRecord PDA address of the NFTs locked in the voter's NftDepositRecord
+pub fn get_nft_deposit_vote_record_address(
+    registrar: &Pubkey,
+    proposal: &Pubkey,
+    governing_token_owner: &Pubkey,
+) -> Pubkey {
+    get_nft_vote_record_address(
+        proposal,
+        &get_nft_deposit_record_address(registrar, governing_token_owner),
+    )
+}
+
+/// Returns mpl-token-metadata Metadata PDA address of the given mint
+pub fn get_metadata_address(mint: &Pubkey) -> Pubkey {
+    mpl_token_metadata::pda::find_metadata_account(mint).0
+}
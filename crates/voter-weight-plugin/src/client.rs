@@ -0,0 +1,47 @@
+//! Shared types of the voter weight plugin Rust clients
+
+use std::fmt;
+
+use anchor_lang::prelude::Pubkey;
+use solana_client::client_error::ClientError;
+
+/// Errors returned by the voter weight plugin clients
+#[derive(Debug)]
+pub enum PluginClientError {
+    /// RPC request failed
+    Rpc(ClientError),
+
+    /// The account is not owned by the given plugin program
+    InvalidAccountOwner(Pubkey),
+
+    /// The account data can't be decoded as the requested plugin account
+    InvalidAccountData(anchor_lang::error::Error),
+}
+
+impl fmt::Display for PluginClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PluginClientError::Rpc(error) => write!(f, "RPC request failed: {}", error),
+            PluginClientError::InvalidAccountOwner(program_id) => {
+                write!(f, "Account is not owned by the program {}", program_id)
+            }
+            PluginClientError::InvalidAccountData(error) => {
+                write!(f, "Invalid account data: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PluginClientError {}
+
+impl From<ClientError> for PluginClientError {
+    fn from(error: ClientError) -> Self {
+        PluginClientError::Rpc(error)
+    }
+}
+
+impl From<anchor_lang::error::Error> for PluginClientError {
+    fn from(error: anchor_lang::error::Error) -> Self {
+        PluginClientError::InvalidAccountData(error)
+    }
+}
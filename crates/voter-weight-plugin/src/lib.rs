@@ -0,0 +1,19 @@
+//! Shared core of the spl-governance voter weight plugins (addins)
+//!
+//! The crate provides PDA helpers and space of the spl-governance-addin-api accounts, voter weight action and expiry helpers
+//! and Realm authority validation
+//! The error type shared by the plugin Rust clients is available with the client feature
+//!
+//! The addin-api accounts are defined by each plugin program for Anchor to export them to IDL
+//! The crate doesn't depend on spl-governance and can be used by plugins built for any spl-governance version
+
+#[cfg(feature = "client")]
+pub mod client;
+
+pub mod pda;
+
+pub mod realm;
+
+pub mod space;
+
+pub mod voter_weight;
@@ -0,0 +1,77 @@
+//! PDA helpers of the accounts shared by the voter weight plugins
+
+use anchor_lang::prelude::Pubkey;
+
+/// Returns Registrar PDA seeds
+pub fn get_registrar_seeds<'a>(
+    realm: &'a Pubkey,
+    governing_token_mint: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [b"registrar", realm.as_ref(), governing_token_mint.as_ref()]
+}
+
+/// Returns Registrar PDA address of the given plugin program
+pub fn get_registrar_address(
+    program_id: &Pubkey,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_registrar_seeds(realm, governing_token_mint),
+        program_id,
+    )
+    .0
+}
+
+/// Returns VoterWeightRecord PDA seeds
+pub fn get_voter_weight_record_seeds<'a>(
+    realm: &'a Pubkey,
+    governing_token_mint: &'a Pubkey,
+    governing_token_owner: &'a Pubkey,
+) -> [&'a [u8]; 4] {
+    [
+        b"voter-weight-record",
+        realm.as_ref(),
+        governing_token_mint.as_ref(),
+        governing_token_owner.as_ref(),
+    ]
+}
+
+/// Returns VoterWeightRecord PDA address of the given plugin program
+pub fn get_voter_weight_record_address(
+    program_id: &Pubkey,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_voter_weight_record_seeds(realm, governing_token_mint, governing_token_owner),
+        program_id,
+    )
+    .0
+}
+
+/// Returns MaxVoterWeightRecord PDA seeds
+pub fn get_max_voter_weight_record_seeds<'a>(
+    realm: &'a Pubkey,
+    governing_token_mint: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        b"max-voter-weight-record",
+        realm.as_ref(),
+        governing_token_mint.as_ref(),
+    ]
+}
+
+/// Returns MaxVoterWeightRecord PDA address of the given plugin program
+pub fn get_max_voter_weight_record_address(
+    program_id: &Pubkey,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_max_voter_weight_record_seeds(realm, governing_token_mint),
+        program_id,
+    )
+    .0
+}
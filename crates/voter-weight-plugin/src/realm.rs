@@ -0,0 +1,130 @@
+//! Realm helpers shared by the voter weight plugins
+//!
+//! The Realm account itself is read by the plugins using the spl-governance version they are built for
+
+use anchor_lang::{error::Error, prelude::*};
+
+/// Returns true if the authority is the current authority of the Realm
+/// Realms without authority can't be configured by anyone and false is returned for them
+pub fn is_realm_authority(realm_authority: Option<Pubkey>, authority: &Pubkey) -> bool {
+    realm_authority == Some(*authority)
+}
+
+/// Asserts the authority is the current authority of the Realm
+/// The error of the plugin program is returned if it's not the Realm authority
+pub fn assert_realm_authority(
+    realm_authority: Option<Pubkey>,
+    authority: &Pubkey,
+    error: impl Into<Error>,
+) -> Result<()> {
+    if !is_realm_authority(realm_authority, authority) {
+        return Err(error.into());
+    }
+
+    Ok(())
+}
+
+/// Asserts the Realm has no Proposals in voting
+/// Changes to the plugin configuration can accidentally tip the scales for outstanding Proposals and hence they are disallowed
+pub fn assert_no_voting_proposals(
+    voting_proposal_count: u64,
+    error: impl Into<Error>,
+) -> Result<()> {
+    if voting_proposal_count > 0 {
+        return Err(error.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_is_realm_authority() {
+        // Arrange
+        let realm_authority = Pubkey::new_unique();
+
+        // Act
+        let is_authority = is_realm_authority(Some(realm_authority), &realm_authority);
+
+        // Assert
+        assert!(is_authority);
+    }
+
+    #[test]
+    fn test_is_realm_authority_with_other_authority() {
+        // Arrange
+        let realm_authority = Pubkey::new_unique();
+
+        // Act
+        let is_authority = is_realm_authority(Some(realm_authority), &Pubkey::new_unique());
+
+        // Assert
+        assert!(!is_authority);
+    }
+
+    #[test]
+    fn test_is_realm_authority_without_realm_authority() {
+        // Arrange
+        let authority = Pubkey::new_unique();
+
+        // Act
+        let is_authority = is_realm_authority(None, &authority);
+
+        // Assert
+        assert!(!is_authority);
+    }
+
+    #[test]
+    fn test_assert_realm_authority() {
+        // Arrange
+        let realm_authority = Pubkey::new_unique();
+
+        // Act
+        let result = assert_realm_authority(
+            Some(realm_authority),
+            &realm_authority,
+            ErrorCode::ConstraintSigner,
+        );
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_assert_realm_authority_with_other_authority_error() {
+        // Arrange
+        let realm_authority = Pubkey::new_unique();
+
+        // Act
+        let result = assert_realm_authority(
+            Some(realm_authority),
+            &Pubkey::new_unique(),
+            ErrorCode::ConstraintSigner,
+        );
+
+        // Assert
+        assert_eq!(result.unwrap_err(), ErrorCode::ConstraintSigner.into());
+    }
+
+    #[test]
+    fn test_assert_no_voting_proposals() {
+        // Act
+        let result = assert_no_voting_proposals(0, ErrorCode::ConstraintSigner);
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_assert_no_voting_proposals_with_voting_proposal_error() {
+        // Act
+        let result = assert_no_voting_proposals(1, ErrorCode::ConstraintSigner);
+
+        // Assert
+        assert_eq!(result.unwrap_err(), ErrorCode::ConstraintSigner.into());
+    }
+}
@@ -0,0 +1,7 @@
+//! Account space constants shared by the voter weight plugins
+
+/// The size of Anchor account discriminator
+pub const DISCRIMINATOR_SIZE: usize = 8;
+
+/// The size of Pubkey
+pub const PUBKEY_SIZE: usize = 32;
@@ -0,0 +1,130 @@
+//! Voter weight helpers shared by the voter weight plugins
+//!
+//! VoterWeightRecord and MaxVoterWeightRecord accounts are defined by each plugin program for Anchor to export them to IDL
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::space::{DISCRIMINATOR_SIZE, PUBKEY_SIZE};
+
+/// The space of VoterWeightRecord account as defined in spl-governance-addin-api
+pub const VOTER_WEIGHT_RECORD_SPACE: usize =
+    DISCRIMINATOR_SIZE + PUBKEY_SIZE * 4 + 8 + 1 + 8 + 1 + 1 + 1 + 8;
+
+/// The space of MaxVoterWeightRecord account as defined in spl-governance-addin-api
+pub const MAX_VOTER_WEIGHT_RECORD_SPACE: usize =
+    DISCRIMINATOR_SIZE + PUBKEY_SIZE * 2 + 8 + 1 + 8 + 8;
+
+/// The expiry of the weight which is already expired
+/// It's used for new records to prevent them from being used before the weight is evaluated
+pub const EXPIRED_WEIGHT_EXPIRY: Option<u64> = Some(0);
+
+/// Returns true if the weight with the given expiry is still valid as of the current slot
+/// The weight without expiry never expires
+pub fn is_weight_active(weight_expiry: Option<u64>, current_slot: u64) -> bool {
+    match weight_expiry {
+        Some(expiry) => expiry >= current_slot,
+        None => true,
+    }
+}
+
+/// Returns true if the weight is evaluated for the given action and target
+/// The VoterWeightAction enum is defined by each plugin program and hence the action is generic
+pub fn is_weight_action_target<A: PartialEq>(
+    weight_action: Option<A>,
+    weight_action_target: Option<Pubkey>,
+    action: A,
+    target: &Pubkey,
+) -> bool {
+    weight_action == Some(action) && weight_action_target == Some(*target)
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_is_weight_active() {
+        // Arrange
+        let current_slot = 10;
+
+        // Act
+        let is_active = is_weight_active(Some(current_slot), current_slot);
+
+        // Assert
+        assert!(is_active);
+    }
+
+    #[test]
+    fn test_is_weight_active_with_expired_weight() {
+        // Arrange
+        let current_slot = 10;
+
+        // Act
+        let is_active = is_weight_active(Some(current_slot - 1), current_slot);
+
+        // Assert
+        assert!(!is_active);
+    }
+
+    #[test]
+    fn test_is_weight_active_without_expiry() {
+        // Arrange
+        let current_slot = 10;
+
+        // Act
+        let is_active = is_weight_active(None, current_slot);
+
+        // Assert
+        assert!(is_active);
+    }
+
+    #[test]
+    fn test_is_weight_action_target() {
+        // Arrange
+        let target = Pubkey::new_unique();
+
+        // Act
+        let is_action_target = is_weight_action_target(Some(1), Some(target), 1, &target);
+
+        // Assert
+        assert!(is_action_target);
+    }
+
+    #[test]
+    fn test_is_weight_action_target_with_other_action() {
+        // Arrange
+        let target = Pubkey::new_unique();
+
+        // Act
+        let is_action_target = is_weight_action_target(Some(2), Some(target), 1, &target);
+
+        // Assert
+        assert!(!is_action_target);
+    }
+
+    #[test]
+    fn test_is_weight_action_target_with_other_target() {
+        // Arrange
+        let target = Pubkey::new_unique();
+
+        // Act
+        let is_action_target =
+            is_weight_action_target(Some(1), Some(Pubkey::new_unique()), 1, &target);
+
+        // Assert
+        assert!(!is_action_target);
+    }
+
+    #[test]
+    fn test_is_weight_action_target_without_action() {
+        // Arrange
+        let target = Pubkey::new_unique();
+
+        // Act
+        let is_action_target = is_weight_action_target(None, Some(target), 1, &target);
+
+        // Assert
+        assert!(!is_action_target);
+    }
+}
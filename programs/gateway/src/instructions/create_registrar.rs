@@ -2,6 +2,7 @@ use crate::error::GatewayError;
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
+use gpl_voter_weight_plugin::realm::assert_realm_authority;
 use spl_governance::state::realm;
 
 /// Creates an add-in Registrar for spl-gov Realm
@@ -73,10 +74,11 @@ pub fn create_registrar(ctx: Context<CreateRegistrar>) -> Result<()> {
         &registrar.governing_token_mint,
     )?;
 
-    require!(
-        realm.authority.unwrap() == ctx.accounts.realm_authority.key(),
-        GatewayError::InvalidRealmAuthority
-    );
+    assert_realm_authority(
+        realm.authority,
+        &ctx.accounts.realm_authority.key(),
+        GatewayError::InvalidRealmAuthority,
+    )?;
 
     Ok(())
 }
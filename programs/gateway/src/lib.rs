@@ -7,8 +7,6 @@ use instructions::*;
 
 pub mod state;
 
-pub mod tools;
-
 use crate::state::*;
 
 declare_id!("Ggatr3wgDLySEwA2qEjt1oiw4BUzp5yMLJyz21919dq6");
@@ -1,8 +1,6 @@
-use crate::{
-    id,
-    tools::anchor::{DISCRIMINATOR_SIZE, PUBKEY_SIZE},
-};
+use crate::id;
 use anchor_lang::prelude::*;
+use gpl_voter_weight_plugin::space::{DISCRIMINATOR_SIZE, PUBKEY_SIZE};
 
 /// Registrar which stores Civic Pass voting configuration for the given Realm
 #[account]
@@ -34,17 +32,11 @@ impl Registrar {
     }
 }
 
-/// Returns Registrar PDA seeds
-pub fn get_registrar_seeds<'a>(
-    realm: &'a Pubkey,
-    governing_token_mint: &'a Pubkey,
-) -> [&'a [u8]; 3] {
-    [b"registrar", realm.as_ref(), governing_token_mint.as_ref()]
-}
+pub use gpl_voter_weight_plugin::pda::get_registrar_seeds;
 
 /// Returns Registrar PDA address
 pub fn get_registrar_address(realm: &Pubkey, governing_token_mint: &Pubkey) -> Pubkey {
-    Pubkey::find_program_address(&get_registrar_seeds(realm, governing_token_mint), &id()).0
+    gpl_voter_weight_plugin::pda::get_registrar_address(&id(), realm, governing_token_mint)
 }
 
 #[cfg(test)]
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
+use gpl_voter_weight_plugin::voter_weight::{
+    is_weight_active, EXPIRED_WEIGHT_EXPIRY, VOTER_WEIGHT_RECORD_SPACE,
+};
 
-use crate::tools::anchor::{DISCRIMINATOR_SIZE, PUBKEY_SIZE};
+use crate::id;
 
 /// VoterWeightAction enum as defined in spl-governance-addin-api
 /// It's redefined here for Anchor to export it to IDL
@@ -70,7 +73,12 @@ pub struct VoterWeightRecord {
 
 impl VoterWeightRecord {
     pub fn get_space() -> usize {
-        DISCRIMINATOR_SIZE + PUBKEY_SIZE * 4 + 8 + 1 + 8 + 1 + 1 + 1 + 8
+        VOTER_WEIGHT_RECORD_SPACE
+    }
+
+    /// Returns true if voter_weight is still valid as of the given slot
+    pub fn is_voter_weight_active(&self, current_slot: Slot) -> bool {
+        is_weight_active(self.voter_weight_expiry, current_slot)
     }
 }
 
@@ -81,7 +89,7 @@ impl Default for VoterWeightRecord {
             governing_token_mint: Default::default(),
             governing_token_owner: Default::default(),
             voter_weight: Default::default(),
-            voter_weight_expiry: Some(0),
+            voter_weight_expiry: EXPIRED_WEIGHT_EXPIRY,
             weight_action: Some(VoterWeightAction::CastVote),
             weight_action_target: Some(Default::default()),
             reserved: Default::default(),
@@ -89,10 +97,27 @@ impl Default for VoterWeightRecord {
     }
 }
 
+pub use gpl_voter_weight_plugin::pda::get_voter_weight_record_seeds;
+
+/// Returns VoterWeightRecord PDA address
+pub fn get_voter_weight_record_address(
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+) -> Pubkey {
+    gpl_voter_weight_plugin::pda::get_voter_weight_record_address(
+        &id(),
+        realm,
+        governing_token_mint,
+        governing_token_owner,
+    )
+}
+
 #[cfg(test)]
 mod test {
 
     use super::*;
+    use gpl_voter_weight_plugin::space::DISCRIMINATOR_SIZE;
 
     #[test]
     fn test_get_space() {
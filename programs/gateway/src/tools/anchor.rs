@@ -1,2 +0,0 @@
-pub const DISCRIMINATOR_SIZE: usize = 8;
-pub const PUBKEY_SIZE: usize = 32;
@@ -79,4 +79,178 @@ pub enum NftVoterError {
 
     #[msg("Must include proposals transaction since the proposal ressembles a phase vote")]
     MustIncludeProposalTransactionForPhaseVotes,
+
+    #[msg("Max collections can't be less than the number of configured collections")]
+    InvalidMaxCollections,
+
+    #[msg("Invalid Merkle tree account")]
+    InvalidMerkleTreeAccount,
+
+    #[msg("Merkle tree must be a Bubblegum tree")]
+    InvalidMerkleTreeAuthority,
+
+    #[msg("Invalid Merkle proof for compressed NFT")]
+    InvalidMerkleProof,
+
+    #[msg("Invalid TokenRecord account")]
+    InvalidTokenRecord,
+
+    #[msg("Programmable NFT is locked")]
+    ProgrammableNftLocked,
+
+    #[msg("Programmable NFT is delegated")]
+    ProgrammableNftDelegated,
+
+    #[msg("Invalid EscrowProgramConfig")]
+    InvalidEscrowProgramConfig,
+
+    #[msg("Escrow program is not allowed")]
+    EscrowProgramNotAllowed,
+
+    #[msg("Invalid escrow receipt")]
+    InvalidEscrowReceipt,
+
+    #[msg("RevokeVote Is Not Allowed")]
+    RevokeVoteIsNotAllowed,
+
+    #[msg("Cannot revoke own NFT vote")]
+    CannotRevokeOwnNftVote,
+
+    #[msg("Invalid Proposal state for revoke")]
+    InvalidProposalStateForRevoke,
+
+    #[msg("Invalid NFT mint for NftVoteRecord")]
+    InvalidNftMintForNftVoteRecord,
+
+    #[msg("VoterWeightTally is full")]
+    VoterWeightTallyIsFull,
+
+    #[msg("Invalid governance program for Registrar")]
+    InvalidGovernanceProgram,
+
+    #[msg("Invalid ConditionalWeightConfig")]
+    InvalidConditionalWeightConfig,
+
+    #[msg("Invalid conditional weight rule")]
+    InvalidConditionalWeightRule,
+
+    #[msg("Invalid spl-governance CastVote instruction")]
+    InvalidCastVoteInstruction,
+
+    #[msg("Remaining accounts don't match the provided layout")]
+    InvalidRemainingAccountsLayout,
+
+    #[msg("Invalid CollectionWeightOverrides")]
+    InvalidCollectionWeightOverrides,
+
+    #[msg("Invalid NFT weight override")]
+    InvalidNftWeightOverride,
+
+    #[msg("Invalid MintDenylist")]
+    InvalidMintDenylist,
+
+    #[msg("Invalid denied mint")]
+    InvalidDeniedMint,
+
+    #[msg("NFT mint is denied")]
+    NftMintDenied,
+
+    #[msg("Invalid voter weight caps")]
+    InvalidVoterWeightCaps,
+
+    #[msg("Cannot configure voter weight caps with voting proposals")]
+    CannotConfigureVoterWeightCapsWithVotingProposals,
+
+    #[msg("Cannot configure voter weighting mode with voting proposals")]
+    CannotConfigureVoterWeightingModeWithVotingProposals,
+
+    #[msg("Invalid NFT holder registration")]
+    InvalidNftHolderRegistration,
+
+    #[msg("NFT holder is not registered")]
+    NftHolderNotRegistered,
+
+    #[msg("NFT was acquired after voting started")]
+    NftAcquiredAfterVotingStarted,
+
+    #[msg("Proposal voting not started")]
+    ProposalVotingNotStarted,
+
+    #[msg("Cannot configure ownership snapshot with voting proposals")]
+    CannotConfigureOwnershipSnapshotWithVotingProposals,
+
+    #[msg("NftDepositRecord is full")]
+    NftDepositRecordIsFull,
+
+    #[msg("NFT is not deposited")]
+    NftNotDeposited,
+
+    #[msg("Programmable NFTs can't be deposited")]
+    CannotDepositProgrammableNft,
+
+    #[msg("Cannot withdraw NFT with active votes")]
+    CannotWithdrawNftWithActiveVotes,
+
+    #[msg("Invalid TokenOwnerRecord for NftDepositRecord")]
+    InvalidTokenOwnerRecordForNftDepositRecord,
+
+    #[msg("Voter authority must be the owner or its delegate")]
+    InvalidVoterAuthority,
+
+    #[msg("NftVoteDelegation expired")]
+    NftVoteDelegationExpired,
+
+    #[msg("NFT collection is not delegated")]
+    NftCollectionNotDelegated,
+
+    #[msg("Invalid NftVoteDelegation expiry")]
+    InvalidNftVoteDelegationExpiry,
+
+    #[msg("Proposal voting is not finalized")]
+    ProposalVotingNotFinalized,
+
+    #[msg("Invalid payer for NftVoteRecord")]
+    InvalidPayerForNftVoteRecord,
+
+    #[msg("Cannot configure vote tracking mode with voting proposals")]
+    CannotConfigureVoteTrackingModeWithVotingProposals,
+
+    #[msg("Invalid NftIndexMap")]
+    InvalidNftIndexMap,
+
+    #[msg("NftIndexMap is full")]
+    NftIndexMapIsFull,
+
+    #[msg("NFT is already in NftIndexMap")]
+    NftAlreadyInIndexMap,
+
+    #[msg("NFT is not in NftIndexMap")]
+    NftNotInIndexMap,
+
+    #[msg("Invalid VoteBitmap")]
+    InvalidVoteBitmap,
+
+    #[msg("NFT index is out of VoteBitmap range")]
+    NftIndexOutOfRange,
+
+    #[msg("NFT votes tracked with VoteBitmaps can't be revoked")]
+    CannotRevokeNftVoteWithVoteBitmaps,
+
+    #[msg("NftVoteRecord of NftDepositRecord must be relinquished by the voter")]
+    CannotCloseNftDepositVoteRecord,
+
+    #[msg("Cannot configure conditional weight rules with voting proposals")]
+    CannotConfigureConditionalWeightRulesWithVotingProposals,
+
+    #[msg("RevokeNftVote must be bundled with spl-governance RevokeVote instruction")]
+    MustIncludeRevokeVoteInstruction,
+
+    #[msg("Invalid spl-governance RevokeVote instruction")]
+    InvalidRevokeVoteInstruction,
+
+    #[msg("Invalid NftTallyRecord")]
+    InvalidNftTallyRecord,
+
+    #[msg("NFT is already counted by VoterWeightTally of another voter")]
+    NftAlreadyTallied,
 }
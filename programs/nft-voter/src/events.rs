@@ -0,0 +1,90 @@
+//! Events emitted by nft-voter instructions for off-chain indexers
+use anchor_lang::prelude::*;
+
+/// Emitted when a Registrar is created by CreateRegistrar
+#[event]
+pub struct RegistrarCreated {
+    /// The created Registrar
+    pub registrar: Pubkey,
+
+    /// spl-governance program the Realm belongs to
+    pub governance_program_id: Pubkey,
+
+    /// Realm of the Registrar
+    pub realm: Pubkey,
+
+    /// Governing token mint the Registrar is for
+    pub governing_token_mint: Pubkey,
+
+    /// The max number of collections the Registrar was created for
+    pub max_collections: u8,
+}
+
+/// Emitted when a collection is configured by ConfigureCollection
+#[event]
+pub struct CollectionConfigured {
+    /// Registrar the collection is configured for
+    pub registrar: Pubkey,
+
+    /// The configured collection
+    pub collection: Pubkey,
+
+    /// The weight of the collection before the change
+    /// None if the collection wasn't configured before
+    pub old_weight: Option<u64>,
+
+    /// The new weight of the collection
+    pub new_weight: u64,
+
+    /// The size of the collection before the change
+    /// None if the collection wasn't configured before
+    pub old_size: Option<u64>,
+
+    /// The new size of the collection
+    pub new_size: u64,
+
+    /// The new max voter weight of the Registrar stored in MaxVoterWeightRecord
+    pub max_voter_weight: u64,
+}
+
+/// Emitted when NFT vote is cast by CastNftVote or CastCompressedNftVote
+#[event]
+pub struct NftVoteCast {
+    /// The NFT voting Registrar
+    pub registrar: Pubkey,
+
+    /// Proposal the vote is cast on
+    pub proposal: Pubkey,
+
+    /// The voter who owns the NFTs
+    pub governing_token_owner: Pubkey,
+
+    /// The mints of the NFTs which voted in the instruction
+    /// For compressed NFTs it's the asset ids of the NFTs
+    pub nft_mints: Vec<Pubkey>,
+
+    /// The voter weight added to VoterWeightRecord by the instruction
+    pub voter_weight_added: u64,
+
+    /// The total voter weight accumulated in VoterWeightRecord for the Proposal
+    pub voter_weight: u64,
+}
+
+/// Emitted when NFT votes are relinquished by RelinquishNftVote
+#[event]
+pub struct NftVoteRelinquished {
+    /// The NFT voting Registrar
+    pub registrar: Pubkey,
+
+    /// Proposal the votes were cast on
+    pub proposal: Pubkey,
+
+    /// The voter who cast the votes
+    pub governing_token_owner: Pubkey,
+
+    /// The mints of the NFTs whose NftVoteRecords were disposed
+    pub nft_mints: Vec<Pubkey>,
+
+    /// The beneficiary who received the rent of the disposed NftVoteRecords
+    pub beneficiary: Pubkey,
+}
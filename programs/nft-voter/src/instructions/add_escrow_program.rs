@@ -0,0 +1,68 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use crate::tools::governance::get_realm_data_for_realm_authority;
+use anchor_lang::prelude::*;
+
+/// Adds escrow (staking) program to the allowlist of the Registrar
+/// NFTs held by the escrow program can vote using the escrow receipts issued by the program
+#[derive(Accounts)]
+#[instruction(program_id: Pubkey)]
+pub struct AddEscrowProgram<'info> {
+    /// Registrar for which we add the escrow program
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// The allowlist entry of the escrow program
+    #[account(
+        init,
+        seeds = [b"escrow-program-config".as_ref(), registrar.key().as_ref(), program_id.as_ref()],
+        bump,
+        payer = payer,
+        space = EscrowProgramConfig::get_space()
+    )]
+    pub escrow_program_config: Account<'info, EscrowProgramConfig>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Adds escrow program with the layout of its receipt accounts
+///
+/// receipt_discriminator is the account discriminator of the receipt accounts
+/// beneficial_owner_offset and mint_offset are the offsets of the voter and the NFT mint Pubkeys in the receipt data
+pub fn add_escrow_program(
+    ctx: Context<AddEscrowProgram>,
+    program_id: Pubkey,
+    receipt_discriminator: [u8; 8],
+    beneficial_owner_offset: u32,
+    mint_offset: u32,
+) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    get_realm_data_for_realm_authority(
+        registrar,
+        &ctx.accounts.realm,
+        &ctx.accounts.realm_authority.key(),
+    )?;
+
+    let escrow_program_config = &mut ctx.accounts.escrow_program_config;
+
+    escrow_program_config.registrar = registrar.key();
+    escrow_program_config.program_id = program_id;
+    escrow_program_config.receipt_discriminator = receipt_discriminator;
+    escrow_program_config.beneficial_owner_offset = beneficial_owner_offset;
+    escrow_program_config.mint_offset = mint_offset;
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use crate::tools::governance::get_realm_data_for_realm_authority;
+use anchor_lang::prelude::*;
+
+/// Adds NFT mints to NftIndexMap
+/// The account is resized to fit the mints and the payer tops up the rent
+///
+/// Note: The map isn't deserialized because maps of large collections don't fit into the heap
+#[derive(Accounts)]
+#[instruction(collection: Pubkey, mints: Vec<Pubkey>)]
+pub struct AddNftIndexMapMints<'info> {
+    /// Registrar the index map belongs to
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// CHECK: Checked and extended in place in instruction
+    #[account(
+        mut,
+        seeds = [b"nft-index-map".as_ref(), registrar.key().as_ref(), collection.as_ref()],
+        bump
+    )]
+    pub nft_index_map: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Adds the given mints to NftIndexMap of the collection and assigns them the next indexes in the given order
+///
+/// The mints can't be already in the map and the number of NFTs in the map can't exceed the collection size
+/// Note: The mints can be added while Proposals are in Voting state because the indexes of the NFTs already in the map don't change
+pub fn add_nft_index_map_mints(
+    ctx: Context<AddNftIndexMapMints>,
+    collection: Pubkey,
+    mints: Vec<Pubkey>,
+) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    get_realm_data_for_realm_authority(
+        registrar,
+        &ctx.accounts.realm,
+        &ctx.accounts.realm_authority.key(),
+    )?;
+
+    let collection_config = registrar.get_collection_config(collection)?;
+
+    extend_nft_index_map(
+        &ctx.accounts.nft_index_map,
+        &registrar.key(),
+        &collection,
+        mints.len(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &Rent::get()?,
+    )?;
+
+    insert_nft_index_map_mints(
+        &ctx.accounts.nft_index_map,
+        &registrar.key(),
+        &collection,
+        &mints,
+        collection_config.size,
+    )
+}
@@ -0,0 +1,200 @@
+use crate::error::NftVoterError;
+use crate::events::NftVoteCast;
+use crate::instructions::CastNftVote;
+use crate::state::*;
+use crate::tools::account_compression::get_merkle_tree_header;
+use crate::tools::governance::{add_cast_vote_voter_weight, get_cast_vote_instruction};
+use anchor_lang::prelude::*;
+
+/// Casts vote with compressed NFTs (Bubblegum)
+/// It uses the same accounts as CastNftVote and the same NftVoteRecord accounts to track voting NFTs
+/// For compressed NFTs NftVoteRecord.nft_mint is the asset id of the NFT
+///
+/// For each compressed NFT the following remaining accounts must be provided:
+/// merkle_tree, nft_vote_record, nft_holder_registration (only when the Registrar has the ownership snapshot enabled)
+/// and proof nodes from the leaf up to the root (max_depth of the tree)
+/// When the Registrar uses VoteTrackingMode::VoteBitmaps then the VoteBitmap of the NFT collection is provided instead of nft_vote_record
+/// followed by the NftIndexMap of the collection
+/// If the Proposal is a phase vote then ProposalTransaction must be provided as the last remaining account
+/// and with_proposal_transaction must be set, the same way CastNftVoteLayout.with_proposal_transaction is used by CastNftVote
+///
+/// The instruction is accumulative with CastNftVote and both can be used to vote on the same Proposal
+/// The NFTs locked in the voter's NftDepositRecord are counted by whichever of the instructions is executed first
+pub fn cast_compressed_nft_vote<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, CastNftVote<'info>>,
+    compressed_nfts: Vec<CompressedNftAsset>,
+    with_proposal_transaction: bool,
+) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let governing_token_owner = &ctx.accounts.governing_token_owner.key();
+
+    // Ensure all voting nfts in the batch are unique
+    let mut unique_nft_mints = vec![];
+
+    let rent = Rent::get()?;
+
+    let cast_vote_spl_ix = get_cast_vote_instruction(
+        &ctx.accounts.instruction_sysvar_account,
+        &registrar.governance_program_id,
+    )?;
+
+    let delegated_collection = resolve_voter_authority_collection(
+        registrar,
+        governing_token_owner,
+        &ctx.accounts.voter_authority.key(),
+        &ctx.accounts.nft_vote_delegation,
+        &ctx.accounts.token_owner_record,
+    )?;
+
+    let conditional_weight_rules =
+        get_conditional_weight_rules(&ctx.accounts.conditional_weight_config, &registrar.key())?;
+
+    let ownership_snapshot_voting_at =
+        get_ownership_snapshot_voting_at(registrar, &ctx.accounts.proposal)?;
+
+    let with_vote_bitmaps = registrar.vote_tracking_mode == VoteTrackingMode::VoteBitmaps;
+
+    // The accounts preceding the proof nodes: merkle_tree, nft_vote_record (or vote_bitmap),
+    // nft_index_map when VoteBitmaps are used and nft_holder_registration when the ownership snapshot is enabled
+    let nft_accounts_len =
+        2 + with_vote_bitmaps as usize + ownership_snapshot_voting_at.is_some() as usize;
+
+    // Every NFT has its own NftVoteRecord unless VoteBitmaps are used
+    let mut nft_vote_record_count = if with_vote_bitmaps {
+        0
+    } else {
+        compressed_nfts.len() as u32
+    };
+
+    // NftVoteRecord of the locked NFTs is created when they are counted for the Proposal for the first time
+    if !ctx.accounts.nft_deposit_record.data_is_empty()
+        && ctx.accounts.nft_deposit_vote_record.data_is_empty()
+    {
+        nft_vote_record_count += 1;
+    }
+
+    // The NFTs locked in the NftDepositRecord are counted once per Proposal
+    let mut nft_vote_infos = resolve_nft_deposit_vote_infos(
+        registrar,
+        &ctx.accounts.nft_deposit_record,
+        &ctx.accounts.nft_deposit_vote_record,
+        &ctx.accounts.mint_denylist,
+        &ctx.accounts.proposal,
+        governing_token_owner,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &rent,
+    )?;
+
+    let mut remaining_accounts = ctx.remaining_accounts;
+
+    for compressed_nft in compressed_nfts.iter() {
+        require!(
+            remaining_accounts.len() >= nft_accounts_len,
+            NftVoterError::InvalidMerkleProof
+        );
+
+        let merkle_tree_info = &remaining_accounts[0];
+        let nft_vote_record_info = &remaining_accounts[1];
+        let nft_index_map_info = with_vote_bitmaps.then(|| &remaining_accounts[2]);
+
+        let proof_len = get_merkle_tree_header(merkle_tree_info)?.max_depth as usize;
+
+        require!(
+            remaining_accounts.len() >= nft_accounts_len + proof_len,
+            NftVoterError::InvalidMerkleProof
+        );
+
+        let proof_infos = &remaining_accounts[nft_accounts_len..nft_accounts_len + proof_len];
+
+        let nft_vote_info = resolve_compressed_nft_vote_info(
+            registrar,
+            &ctx.accounts.mint_denylist,
+            governing_token_owner,
+            merkle_tree_info,
+            proof_infos,
+            compressed_nft,
+            &mut unique_nft_mints,
+        )?;
+
+        // The compressed NFT must be held by the voter since before the Proposal voting started
+        if let Some(voting_at) = ownership_snapshot_voting_at {
+            assert_nft_held_before_voting(
+                &remaining_accounts[2 + with_vote_bitmaps as usize],
+                &registrar.key(),
+                &nft_vote_info.mint,
+                governing_token_owner,
+                voting_at,
+            )?;
+        }
+
+        record_nft_vote(
+            registrar,
+            &ctx.accounts.payer.to_account_info(),
+            nft_vote_record_info,
+            nft_index_map_info,
+            &ctx.accounts.proposal.key(),
+            &nft_vote_info,
+            governing_token_owner,
+            &ctx.accounts.system_program.to_account_info(),
+            &rent,
+        )?;
+
+        nft_vote_infos.push(nft_vote_info);
+
+        remaining_accounts = &remaining_accounts[nft_accounts_len + proof_len..];
+    }
+
+    let proposal_transaction_info = if with_proposal_transaction {
+        let (proposal_transaction_info, rest) = remaining_accounts
+            .split_first()
+            .ok_or(NftVoterError::InvalidRemainingAccountsLayout)?;
+
+        remaining_accounts = rest;
+
+        Some(proposal_transaction_info)
+    } else {
+        None
+    };
+
+    // All the remaining accounts must be consumed by the compressed NFTs and ProposalTransaction
+    require!(
+        remaining_accounts.is_empty(),
+        NftVoterError::InvalidRemainingAccountsLayout
+    );
+
+    assert_nft_vote_infos_delegated(delegated_collection, &nft_vote_infos)?;
+
+    let voter_weight = add_capped_cast_vote_weight(
+        registrar,
+        &ctx.accounts.voter_weight_cap_tally,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.proposal.key(),
+        governing_token_owner,
+        &ctx.accounts.system_program.to_account_info(),
+        &rent,
+        &nft_vote_infos,
+        nft_vote_record_count,
+    )?;
+
+    let voter_weight_added = add_cast_vote_voter_weight(
+        &ctx.accounts.proposal,
+        &registrar.governance_program_id,
+        proposal_transaction_info,
+        cast_vote_spl_ix,
+        &conditional_weight_rules,
+        voter_weight,
+        &mut ctx.accounts.voter_weight_record,
+    )?;
+
+    emit!(NftVoteCast {
+        registrar: registrar.key(),
+        proposal: ctx.accounts.proposal.key(),
+        governing_token_owner: *governing_token_owner,
+        nft_mints: nft_vote_infos.iter().map(|info| info.mint).collect(),
+        voter_weight_added,
+        voter_weight: ctx.accounts.voter_weight_record.voter_weight,
+    });
+
+    Ok(())
+}
@@ -1,13 +1,10 @@
 use crate::error::NftVoterError;
-use crate::tools::governance::{add_voter_weight, DedSplGovernanceProgram};
-use crate::{id, state::*};
+use crate::events::NftVoteCast;
+use crate::state::*;
+use crate::tools::governance::{add_cast_vote_voter_weight, get_cast_vote_instruction};
 use anchor_lang::prelude::*;
 use anchor_lang::Accounts;
-use itertools::Itertools;
 use solana_program::sysvar;
-use solana_program::sysvar::instructions::get_instruction_relative;
-
-use spl_governance_tools::account::create_and_serialize_account_signed;
 
 /// Casts NFT vote. The NFTs used for voting are tracked using NftVoteRecord accounts
 /// This instruction updates VoterWeightRecord which is valid for the current Slot and the target Proposal only
@@ -19,6 +16,20 @@ use spl_governance_tools::account::create_and_serialize_account_signed;
 /// CastNftVote instruction and NftVoteRecord are not directional. They don't record vote choice (ex Yes/No)
 /// VoteChoice is recorded by spl-gov in VoteRecord and this CastNftVote only tracks voting NFTs
 ///
+/// For each NFT the following remaining accounts must be provided: nft, nft_metadata,
+/// token_record (only for programmable NFTs), escrow_program_config and escrow_receipt (only for NFTs held by an escrow program),
+/// collection_weight_overrides (only for NFTs with weight override),
+/// nft_holder_registration (only when the Registrar has the ownership snapshot enabled) and nft_vote_record
+/// When the Registrar uses VoteTrackingMode::VoteBitmaps then the VoteBitmap of the NFT collection is provided instead of nft_vote_record
+/// followed by the NftIndexMap of the collection
+/// If the Proposal is a phase vote then ProposalTransaction must be provided as the last remaining account
+/// The number of the accounts of each NFT and the presence of ProposalTransaction are specified by CastNftVoteLayout
+///
+/// If the voter has NftDepositRecord then the NFTs locked in it are counted without providing their accounts
+///
+/// The vote can be cast by the owner's delegate and NftVoteRecords still record the owner as the voter
+/// If the delegation is scoped to a collection then all the voting NFTs must be from the collection
+///
 #[derive(Accounts)]
 pub struct CastNftVote<'info> {
     /// The NFT voting registrar
@@ -34,20 +45,23 @@ pub struct CastNftVote<'info> {
     )]
     pub voter_weight_record: Account<'info, VoterWeightRecord>,
 
-    /// The token owner who casts the vote
+    /// The token owner the vote is cast for
+    /// CHECK: The owner or its delegate must sign as voter_authority
     #[account(
         address = voter_weight_record.governing_token_owner @ NftVoterError::InvalidTokenOwnerForVoterWeightRecord
     )]
-    pub governing_token_owner: Signer<'info>,
+    pub governing_token_owner: UncheckedAccount<'info>,
+
+    /// The authority who casts the vote: governing_token_owner, the delegate from the owner's NftVoteDelegation
+    /// or the governance_delegate from the owner's spl-gov TokenOwnerRecord
+    pub voter_authority: Signer<'info>,
 
     /// The account which pays for the transaction
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    /// CHECK
-    #[account(
-        owner = governance_program.key()
-    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    #[account(owner = registrar.governance_program_id)]
     pub proposal: AccountInfo<'info>,
 
     // // CHECK
@@ -57,61 +71,203 @@ pub struct CastNftVote<'info> {
     // pub proposal_transaction: AccountInfo<'info>,
     /// CHECK: Accounts checked in instruction
     #[account(address = sysvar::instructions::id())]
-    instruction_sysvar_account: AccountInfo<'info>,
-    pub governance_program: Program<'info, DedSplGovernanceProgram>,
+    pub instruction_sysvar_account: AccountInfo<'info>,
+
+    /// CHECK: spl-governance instance specified in registrar.governance_program_id
+    #[account(
+        address = registrar.governance_program_id @ NftVoterError::InvalidGovernanceProgram
+    )]
+    pub governance_program: UncheckedAccount<'info>,
+
+    /// ConditionalWeightConfig of the Registrar
+    /// The account doesn't have to exist and the default conditional weight rules are used in such case
+    /// CHECK: Deserialized in instruction if it exists
+    #[account(
+        seeds = [b"conditional-weight-config".as_ref(), registrar.key().as_ref()],
+        bump
+    )]
+    pub conditional_weight_config: UncheckedAccount<'info>,
+
+    /// MintDenylist of the Registrar
+    /// The account doesn't have to exist and no NFT mints are denied in such case
+    /// CHECK: Checked in instruction if it exists
+    #[account(
+        seeds = [b"mint-denylist".as_ref(), registrar.key().as_ref()],
+        bump
+    )]
+    pub mint_denylist: UncheckedAccount<'info>,
+
+    /// VoterWeightCapTally of the voter for the Proposal
+    /// The account is only created and used when the Registrar has voter_weight caps
+    /// CHECK: Created and deserialized in instruction if the Registrar has voter_weight caps
+    #[account(
+        mut,
+        seeds = [
+            b"voter-weight-cap-tally".as_ref(),
+            proposal.key().as_ref(),
+            governing_token_owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub voter_weight_cap_tally: UncheckedAccount<'info>,
+
+    /// NftDepositRecord of the voter
+    /// The account doesn't have to exist and only the NFTs provided in remaining accounts are used in such case
+    /// CHECK: Deserialized and updated in instruction if it exists
+    #[account(
+        mut,
+        seeds = [
+            b"nft-deposit-record".as_ref(),
+            registrar.key().as_ref(),
+            governing_token_owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub nft_deposit_record: UncheckedAccount<'info>,
+
+    /// NftVoteRecord of the NFTs locked in the NftDepositRecord
+    /// It's created when the locked NFTs are counted for the Proposal for the first time
+    /// CHECK: Created in instruction if NftDepositRecord exists
+    #[account(
+        mut,
+        seeds = [
+            b"nft-vote-record".as_ref(),
+            proposal.key().as_ref(),
+            nft_deposit_record.key().as_ref()
+        ],
+        bump
+    )]
+    pub nft_deposit_vote_record: UncheckedAccount<'info>,
+
+    /// NftVoteDelegation of the governing_token_owner
+    /// The account doesn't have to exist and it's only used when voter_authority is not the owner
+    /// CHECK: Deserialized in instruction if it exists
+    #[account(
+        seeds = [
+            b"nft-vote-delegation".as_ref(),
+            registrar.key().as_ref(),
+            governing_token_owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub nft_vote_delegation: UncheckedAccount<'info>,
+
+    /// spl-gov TokenOwnerRecord of the governing_token_owner
+    /// It's only used when voter_authority is not the owner to check TokenOwnerRecord.governance_delegate
+    /// CHECK: Checked in instruction if it's used
+    pub token_owner_record: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
 /// Casts vote with the NFT
 pub fn cast_nft_vote<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, CastNftVote<'info>>,
+    layout: CastNftVoteLayout,
 ) -> Result<()> {
     let registrar = &ctx.accounts.registrar;
     let governing_token_owner = &ctx.accounts.governing_token_owner.key();
-    let mut voter_weight = 0u64;
 
     // Ensure all voting nfts in the batch are unique
     let mut unique_nft_mints = vec![];
 
-    let proposal_transaction_info = if ctx.remaining_accounts.len() % 3 != 0 {
-        ctx.remaining_accounts.last()
-    } else {
-        None
-    };
-
     let rent = Rent::get()?;
 
-    let cast_vote_spl_ix_result =
-        get_instruction_relative(1, &ctx.accounts.instruction_sysvar_account);
-    let cast_vote_spl_ix = if let Ok(ix) = cast_vote_spl_ix_result {
-        //Check there are no more instructions after cast vote
-        get_instruction_relative(2, &ctx.accounts.instruction_sysvar_account).unwrap_err();
-        require_keys_eq!(ix.program_id, DedSplGovernanceProgram::id());
-        Some(ix)
+    let cast_vote_spl_ix = get_cast_vote_instruction(
+        &ctx.accounts.instruction_sysvar_account,
+        &registrar.governance_program_id,
+    )?;
+
+    let delegated_collection = resolve_voter_authority_collection(
+        registrar,
+        governing_token_owner,
+        &ctx.accounts.voter_authority.key(),
+        &ctx.accounts.nft_vote_delegation,
+        &ctx.accounts.token_owner_record,
+    )?;
+
+    let conditional_weight_rules =
+        get_conditional_weight_rules(&ctx.accounts.conditional_weight_config, &registrar.key())?;
+
+    let ownership_snapshot_voting_at =
+        get_ownership_snapshot_voting_at(registrar, &ctx.accounts.proposal)?;
+
+    let with_vote_bitmaps = registrar.vote_tracking_mode == VoteTrackingMode::VoteBitmaps;
+
+    // The accounts of every NFT are followed by nft_vote_record (or vote_bitmap)
+    // and nft_holder_registration when the ownership snapshot is enabled and nft_index_map when VoteBitmaps are used
+    let vote_tracking_accounts =
+        1 + ownership_snapshot_voting_at.is_some() as usize + with_vote_bitmaps as usize;
+
+    require!(
+        ctx.remaining_accounts.len()
+            == layout
+                .nft_account_counts
+                .iter()
+                .map(|count| *count as usize + vote_tracking_accounts)
+                .sum::<usize>()
+                + layout.with_proposal_transaction as usize,
+        NftVoterError::InvalidRemainingAccountsLayout
+    );
+
+    // Every NFT has its own NftVoteRecord unless VoteBitmaps are used
+    let mut nft_vote_record_count = if with_vote_bitmaps {
+        0
     } else {
-        None
+        layout.nft_account_counts.len() as u32
     };
 
-    for (nft_info, nft_metadata_info, nft_vote_record_info) in
-        ctx.remaining_accounts.iter().tuples()
+    // NftVoteRecord of the locked NFTs is created when they are counted for the Proposal for the first time
+    if !ctx.accounts.nft_deposit_record.data_is_empty()
+        && ctx.accounts.nft_deposit_vote_record.data_is_empty()
     {
-        let (nft_vote_weight, nft_mint) = resolve_nft_vote_weight_and_mint(
+        nft_vote_record_count += 1;
+    }
+
+    // The NFTs locked in the NftDepositRecord are counted once per Proposal
+    let mut nft_vote_infos = resolve_nft_deposit_vote_infos(
+        registrar,
+        &ctx.accounts.nft_deposit_record,
+        &ctx.accounts.nft_deposit_vote_record,
+        &ctx.accounts.mint_denylist,
+        &ctx.accounts.proposal,
+        governing_token_owner,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &rent,
+    )?;
+
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    for nft_account_count in layout.nft_account_counts {
+        let nft_vote_info = resolve_nft_vote_info(
             registrar,
+            &ctx.accounts.mint_denylist,
             governing_token_owner,
-            nft_info,
-            nft_metadata_info,
+            next_nft_accounts(account_info_iter, nft_account_count)?,
             &mut unique_nft_mints,
         )?;
 
-        voter_weight = voter_weight.checked_add(nft_vote_weight as u64).unwrap();
+        // The NFT must be held by the voter since before the Proposal voting started
+        if let Some(voting_at) = ownership_snapshot_voting_at {
+            let nft_holder_registration_info = next_account_info(account_info_iter)?;
+
+            assert_nft_held_before_voting(
+                nft_holder_registration_info,
+                &registrar.key(),
+                &nft_vote_info.mint,
+                governing_token_owner,
+                voting_at,
+            )?;
+        }
 
-        // Create NFT vote record to ensure the same NFT hasn't been already used for voting
-        // Note: The correct PDA of the NftVoteRecord is validated in create_and_serialize_account_signed
-        // It ensures the NftVoteRecord is for ('nft-vote-record',proposal,nft_mint) seeds
-        require!(
-            nft_vote_record_info.data_is_empty(),
-            NftVoterError::NftAlreadyVoted
-        );
+        let nft_vote_record_info = next_account_info(account_info_iter)?;
+
+        let nft_index_map_info = if with_vote_bitmaps {
+            Some(next_account_info(account_info_iter)?)
+        } else {
+            None
+        };
 
         // Note: proposal.governing_token_mint must match voter_weight_record.governing_token_mint
         // We don't verify it here because spl-gov does the check in cast_vote
@@ -119,60 +275,59 @@ pub fn cast_nft_vote<'a, 'b, 'c, 'info>(
 
         // Note: Once the NFT plugin is enabled the governing_token_mint is used only as identity
         // for the voting population and the tokens of that mint are no longer used
-        let nft_vote_record = NftVoteRecord {
-            account_discriminator: NftVoteRecord::ACCOUNT_DISCRIMINATOR,
-            proposal: ctx.accounts.proposal.key(),
-            nft_mint,
-            governing_token_owner: *governing_token_owner,
-            reserved: [0; 8],
-        };
-
-        // Anchor doesn't natively support dynamic account creation using remaining_accounts
-        // and we have to take it on the manual drive
-        create_and_serialize_account_signed(
+        record_nft_vote(
+            registrar,
             &ctx.accounts.payer.to_account_info(),
             nft_vote_record_info,
-            &nft_vote_record,
-            &get_nft_vote_record_seeds(&ctx.accounts.proposal.key(), &nft_mint),
-            &id(),
+            nft_index_map_info,
+            &ctx.accounts.proposal.key(),
+            &nft_vote_info,
+            governing_token_owner,
             &ctx.accounts.system_program.to_account_info(),
             &rent,
         )?;
-    }
 
-    let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+        nft_vote_infos.push(nft_vote_info);
+    }
 
-    if voter_weight_record.weight_action_target == Some(ctx.accounts.proposal.key())
-        && voter_weight_record.weight_action == Some(VoterWeightAction::CastVote)
-    {
-        // If cast_nft_vote is called for the same proposal then we keep accumulating the weight
-        // this way cast_nft_vote can be called multiple times in different transactions to allow voting with any number of NFTs
-        add_voter_weight(
-            &ctx.accounts.proposal,
-            &ctx.accounts.governance_program.key(),
-            proposal_transaction_info,
-            cast_vote_spl_ix,
-            voter_weight,
-            voter_weight_record,
-        )?;
+    let proposal_transaction_info = if layout.with_proposal_transaction {
+        Some(next_account_info(account_info_iter)?)
     } else {
-        voter_weight_record.voter_weight = 0;
-        add_voter_weight(
-            &ctx.accounts.proposal,
-            &ctx.accounts.governance_program.key(),
-            proposal_transaction_info,
-            cast_vote_spl_ix,
-            voter_weight,
-            voter_weight_record,
-        )?;
-    }
+        None
+    };
+
+    assert_nft_vote_infos_delegated(delegated_collection, &nft_vote_infos)?;
+
+    let voter_weight = add_capped_cast_vote_weight(
+        registrar,
+        &ctx.accounts.voter_weight_cap_tally,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.proposal.key(),
+        governing_token_owner,
+        &ctx.accounts.system_program.to_account_info(),
+        &rent,
+        &nft_vote_infos,
+        nft_vote_record_count,
+    )?;
 
-    // The record is only valid as of the current slot
-    voter_weight_record.voter_weight_expiry = Some(Clock::get()?.slot);
+    let voter_weight_added = add_cast_vote_voter_weight(
+        &ctx.accounts.proposal,
+        &registrar.governance_program_id,
+        proposal_transaction_info,
+        cast_vote_spl_ix,
+        &conditional_weight_rules,
+        voter_weight,
+        &mut ctx.accounts.voter_weight_record,
+    )?;
 
-    // The record is only valid for casting vote on the given Proposal
-    voter_weight_record.weight_action = Some(VoterWeightAction::CastVote);
-    voter_weight_record.weight_action_target = Some(ctx.accounts.proposal.key());
+    emit!(NftVoteCast {
+        registrar: registrar.key(),
+        proposal: ctx.accounts.proposal.key(),
+        governing_token_owner: *governing_token_owner,
+        nft_mints: nft_vote_infos.iter().map(|info| info.mint).collect(),
+        voter_weight_added,
+        voter_weight: ctx.accounts.voter_weight_record.voter_weight,
+    });
 
     Ok(())
 }
@@ -0,0 +1,98 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use spl_governance::state::{enums::ProposalState, governance, proposal};
+use spl_governance_tools::account::dispose_account;
+
+/// Closes NftVoteRecords of the Proposal once voting on the Proposal is finalized and refunds the rent to the original payers
+/// The instruction is permissionless and can be executed by any crank to clean up NftVoteRecords which were never relinquished
+///
+/// The NftVoteRecords and their payers are supplied in remaining_accounts as [nft_vote_record, payer] pairs
+/// and the records can be closed in batches using several transactions
+/// VoteBitmaps of the Proposal are closed the same way and can be supplied in place of NftVoteRecords
+///
+/// NftVoteRecords of the NFTs locked in NftDepositRecords can't be closed and the voters must relinquish them
+/// using RelinquishNftVote to be able to withdraw the NFTs
+#[derive(Accounts)]
+pub struct CloseNftVoteRecords<'info> {
+    /// The NFT voting Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    /// Governance account the Proposal is for
+    #[account(owner = registrar.governance_program_id)]
+    pub governance: UncheckedAccount<'info>,
+
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    #[account(owner = registrar.governance_program_id)]
+    pub proposal: UncheckedAccount<'info>,
+}
+
+pub fn close_nft_vote_records(ctx: Context<CloseNftVoteRecords>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    // Ensure the Governance belongs to Registrar.realm and is owned by Registrar.governance_program_id
+    let _governance = governance::get_governance_data_for_realm(
+        &registrar.governance_program_id,
+        &ctx.accounts.governance,
+        &registrar.realm,
+    )?;
+
+    // Ensure the Proposal belongs to Governance from Registrar.realm and Registrar.governing_token_mint and is owned by Registrar.governance_program_id
+    let proposal = proposal::get_proposal_data_for_governance_and_governing_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.proposal,
+        &ctx.accounts.governance.key(),
+        &registrar.governing_token_mint,
+    )?;
+
+    // NftVoteRecords are only needed while the Proposal can be voted on
+    // Once voting is finalized the records can't be used to cast or relinquish votes in spl-gov and can be closed
+    require!(
+        !matches!(
+            proposal.state,
+            ProposalState::Draft | ProposalState::SigningOff | ProposalState::Voting
+        ),
+        NftVoterError::ProposalVotingNotFinalized
+    );
+
+    require!(
+        ctx.remaining_accounts.len() % 2 == 0,
+        NftVoterError::InvalidRemainingAccountsLayout
+    );
+
+    for accounts in ctx.remaining_accounts.chunks(2) {
+        let nft_vote_record_info = &accounts[0];
+        let payer_info = &accounts[1];
+
+        let payer = if is_vote_bitmap(nft_vote_record_info)? {
+            get_vote_bitmap_data_for_proposal(nft_vote_record_info, &ctx.accounts.proposal.key())?
+                .payer
+        } else {
+            let nft_vote_record = get_nft_vote_record_data_for_proposal(
+                nft_vote_record_info,
+                &ctx.accounts.proposal.key(),
+            )?;
+
+            require!(
+                nft_vote_record.nft_mint
+                    != get_nft_deposit_record_address(
+                        &registrar.key(),
+                        &nft_vote_record.governing_token_owner
+                    ),
+                NftVoterError::CannotCloseNftDepositVoteRecord
+            );
+
+            nft_vote_record.payer
+        };
+
+        require!(
+            payer == payer_info.key(),
+            NftVoterError::InvalidPayerForNftVoteRecord
+        );
+
+        dispose_account(nft_vote_record_info, payer_info);
+    }
+
+    Ok(())
+}
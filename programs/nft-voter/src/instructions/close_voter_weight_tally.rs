@@ -0,0 +1,31 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Disposes VoterWeightTally and recovers the rent from the account
+#[derive(Accounts)]
+pub struct CloseVoterWeightTally<'info> {
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"voter-weight-tally".as_ref(), voter_weight_record.key().as_ref()],
+        bump,
+        close = beneficiary
+    )]
+    pub voter_weight_tally: Account<'info, VoterWeightTally>,
+
+    /// The owner of the VoterWeightRecord
+    #[account(
+        address = voter_weight_record.governing_token_owner @ NftVoterError::InvalidTokenOwnerForVoterWeightRecord
+    )]
+    pub governing_token_owner: Signer<'info>,
+
+    /// CHECK: The beneficiary who receives lamports from the disposed VoterWeightTally account can be any account
+    #[account(mut)]
+    pub beneficiary: UncheckedAccount<'info>,
+}
+
+pub fn close_voter_weight_tally(_ctx: Context<CloseVoterWeightTally>) -> Result<()> {
+    Ok(())
+}
@@ -5,11 +5,18 @@ use anchor_lang::{
     Accounts,
 };
 use anchor_spl::token::Mint;
+use gpl_voter_weight_plugin::realm::assert_no_voting_proposals;
 use mpl_token_metadata::state::{CollectionDetails, Metadata};
-use spl_governance::state::realm;
 
-use crate::state::{max_voter_weight_record::MaxVoterWeightRecord, CollectionConfig, Registrar};
-use crate::{error::NftVoterError, tools::token_metadata::get_token_metadata};
+use crate::state::{
+    get_collection_weight_overrides_bonus, max_voter_weight_record::MaxVoterWeightRecord,
+    CollectionConfig, Registrar,
+};
+use crate::{
+    error::NftVoterError,
+    events::CollectionConfigured,
+    tools::{governance::get_realm_data_for_realm_authority, token_metadata::get_token_metadata},
+};
 
 /// Configures NFT voting collection which defines what NFTs can be used for governances
 /// and what weight they have
@@ -47,6 +54,15 @@ pub struct ConfigureCollection<'info> {
         @ NftVoterError::InvalidMaxVoterWeightRecordMint,
     )]
     pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+
+    /// CollectionWeightOverrides of the collection used to calculate max voter weight
+    /// The account doesn't have to exist if the collection has no NFT weight overrides
+    /// CHECK: Deserialized in instruction if it exists
+    #[account(
+        seeds = [b"collection-weight-overrides".as_ref(), registrar.key().as_ref(), collection.key().as_ref()],
+        bump
+    )]
+    pub collection_weight_overrides: UncheckedAccount<'info>,
 }
 
 pub fn configure_collection(
@@ -76,26 +92,29 @@ pub fn configure_collection(
 
     let registrar = &mut ctx.accounts.registrar;
 
-    let realm = realm::get_realm_data_for_governing_token_mint(
-        &registrar.governance_program_id,
+    let realm = get_realm_data_for_realm_authority(
+        registrar,
         &ctx.accounts.realm,
-        &registrar.governing_token_mint,
+        &ctx.accounts.realm_authority.key(),
     )?;
 
-    require!(
-        realm.authority.unwrap() == ctx.accounts.realm_authority.key(),
-        NftVoterError::InvalidRealmAuthority
-    );
-
     // Changes to the collections config can accidentally tip the scales for outstanding proposals and hence we disallow it
-    if realm.voting_proposal_count > 0 {
-        return err!(NftVoterError::CannotConfigureCollectionWithVotingProposals);
-    }
+    assert_no_voting_proposals(
+        realm.voting_proposal_count.into(),
+        NftVoterError::CannotConfigureCollectionWithVotingProposals,
+    )?;
+
+    let max_weight_bonus = get_collection_weight_overrides_bonus(
+        &ctx.accounts.collection_weight_overrides,
+        &registrar.key(),
+        &collection.key(),
+        weight,
+    )?;
 
     let collection_config = CollectionConfig {
         collection: collection.key(),
         weight,
-        reserved: [0; 8],
+        max_weight_bonus,
         size,
     };
 
@@ -104,28 +123,36 @@ pub fn configure_collection(
         .iter()
         .position(|cc| cc.collection == collection.key());
 
+    let old_collection_config = collection_idx.map(|idx| registrar.collection_configs[idx]);
+
     if let Some(collection_idx) = collection_idx {
         registrar.collection_configs[collection_idx] = collection_config;
     } else {
         // Note: In the current runtime version push() would throw an error if we exceed
-        // max_collections specified when the Registrar was created
+        // max_collections specified when the Registrar was created or last resized
         registrar.collection_configs.push(collection_config);
     }
 
-    // TODO: if weight == 0 then remove the collection from config
-    // Currently if weight is set to 0 then the collection won't be removed but it won't have any governance power
+    // Note: If weight is set to 0 then the collection won't be removed but it won't have any governance power
+    // To remove the collection from the config RemoveCollection instruction should be used
 
     // Update MaxVoterWeightRecord based on max voting power of the collections
     let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
 
-    max_voter_weight_record.max_voter_weight = registrar
-        .collection_configs
-        .iter()
-        .try_fold(0u64, |sum, cc| sum.checked_add(cc.get_max_weight()))
-        .unwrap();
+    max_voter_weight_record.max_voter_weight = registrar.get_max_voter_weight();
 
     // The weight never expires and only changes when collections are configured
     max_voter_weight_record.max_voter_weight_expiry = None;
 
+    emit!(CollectionConfigured {
+        registrar: registrar.key(),
+        collection: collection.key(),
+        old_weight: old_collection_config.map(|cc| cc.weight),
+        new_weight: weight,
+        old_size: old_collection_config.map(|cc| cc.size),
+        new_size: size,
+        max_voter_weight: max_voter_weight_record.max_voter_weight,
+    });
+
     Ok(())
 }
@@ -0,0 +1,95 @@
+use crate::error::NftVoterError;
+use crate::state::max_voter_weight_record::MaxVoterWeightRecord;
+use crate::state::*;
+use crate::tools::governance::get_realm_data_for_realm_authority;
+use anchor_lang::prelude::*;
+use gpl_voter_weight_plugin::realm::assert_no_voting_proposals;
+
+/// Replaces per NFT weight overrides (rarity table) stored in CollectionWeightOverrides
+/// The account is resized to fit the overrides
+/// All the overrides must fit a single transaction and AddCollectionWeightOverrides / RemoveCollectionWeightOverrides
+/// should be used to change large rarity tables in place
+/// The instruction updates MaxVoterWeightRecord which is used by spl-gov to determine max voting power
+#[derive(Accounts)]
+#[instruction(overrides: Vec<NftWeightOverride>)]
+pub struct ConfigureCollectionWeightOverrides<'info> {
+    /// Registrar for which we configure the overrides
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = registrar @ NftVoterError::InvalidCollectionWeightOverrides,
+        realloc = CollectionWeightOverrides::get_space(overrides.len()),
+        realloc::payer = payer,
+        realloc::zero = false
+    )]
+    pub collection_weight_overrides: Account<'info, CollectionWeightOverrides>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Configures NFT weight overrides of the collection
+///
+/// Note: Overrides can only boost governance power and override weight lower than the collection weight is rejected
+pub fn configure_collection_weight_overrides(
+    ctx: Context<ConfigureCollectionWeightOverrides>,
+    overrides: Vec<NftWeightOverride>,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let realm = get_realm_data_for_realm_authority(
+        registrar,
+        &ctx.accounts.realm,
+        &ctx.accounts.realm_authority.key(),
+    )?;
+
+    // Changes to the weights can accidentally tip the scales for outstanding proposals and hence we disallow it
+    assert_no_voting_proposals(
+        realm.voting_proposal_count.into(),
+        NftVoterError::CannotConfigureCollectionWithVotingProposals,
+    )?;
+
+    let collection_weight_overrides = &mut ctx.accounts.collection_weight_overrides;
+    let collection = collection_weight_overrides.collection;
+
+    let collection_config = registrar.get_collection_config(collection)?;
+    assert_valid_nft_weight_overrides(&overrides, collection_config.weight)?;
+
+    collection_weight_overrides.overrides = overrides;
+
+    registrar.set_collection_max_weight_bonus(&collection, collection_weight_overrides)?;
+
+    // Update MaxVoterWeightRecord based on max voting power of the collections
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+    max_voter_weight_record.max_voter_weight = registrar.get_max_voter_weight();
+
+    // The weight never expires and only changes when collections are configured
+    max_voter_weight_record.max_voter_weight_expiry = None;
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use crate::tools::governance::get_realm_data_for_realm_authority;
+use anchor_lang::prelude::*;
+use gpl_voter_weight_plugin::realm::assert_no_voting_proposals;
+
+/// Replaces the conditional weight rules stored in ConditionalWeightConfig
+/// The account is resized to fit the rules
+/// The payer tops up the rent when the account grows and receives the excess lamports when it shrinks
+#[derive(Accounts)]
+#[instruction(rules: Vec<ConditionalWeightRule>)]
+pub struct ConfigureConditionalWeightRules<'info> {
+    /// Registrar for which we configure the rules
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = registrar @ NftVoterError::InvalidConditionalWeightConfig,
+        realloc = ConditionalWeightConfig::get_space(&rules),
+        realloc::payer = payer,
+        realloc::zero = false
+    )]
+    pub conditional_weight_config: Account<'info, ConditionalWeightConfig>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Configures conditional weight rules of the Registrar
+///
+/// The rules can't be changed while there are proposals in voting state
+pub fn configure_conditional_weight_rules(
+    ctx: Context<ConfigureConditionalWeightRules>,
+    rules: Vec<ConditionalWeightRule>,
+) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    let realm = get_realm_data_for_realm_authority(
+        registrar,
+        &ctx.accounts.realm,
+        &ctx.accounts.realm_authority.key(),
+    )?;
+
+    // Changes to the rules can accidentally tip the scales for outstanding proposals and hence we disallow it
+    assert_no_voting_proposals(
+        realm.voting_proposal_count.into(),
+        NftVoterError::CannotConfigureConditionalWeightRulesWithVotingProposals,
+    )?;
+
+    assert_valid_conditional_weight_rules(&rules)?;
+
+    ctx.accounts.conditional_weight_config.rules = rules;
+
+    Ok(())
+}
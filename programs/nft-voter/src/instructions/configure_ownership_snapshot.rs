@@ -0,0 +1,50 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use crate::tools::governance::get_realm_data_for_realm_authority;
+use anchor_lang::prelude::*;
+use gpl_voter_weight_plugin::realm::assert_no_voting_proposals;
+
+/// Enables or disables the ownership snapshot which requires NFTs to be registered to the voter
+/// before the Proposal voting started in order to vote on the Proposal
+#[derive(Accounts)]
+pub struct ConfigureOwnershipSnapshot<'info> {
+    /// Registrar for which we configure the ownership snapshot
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+}
+
+/// Sets ownership_snapshot_enabled of the Registrar
+///
+/// Note: The ownership snapshot can't be changed while there are Proposals in Voting state
+/// because it would change the NFTs which can vote on the Proposals in the middle of the vote
+pub fn configure_ownership_snapshot(
+    ctx: Context<ConfigureOwnershipSnapshot>,
+    ownership_snapshot_enabled: bool,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let realm = get_realm_data_for_realm_authority(
+        registrar,
+        &ctx.accounts.realm,
+        &ctx.accounts.realm_authority.key(),
+    )?;
+
+    assert_no_voting_proposals(
+        realm.voting_proposal_count.into(),
+        NftVoterError::CannotConfigureOwnershipSnapshotWithVotingProposals,
+    )?;
+
+    registrar.ownership_snapshot_enabled = ownership_snapshot_enabled;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use crate::tools::governance::get_realm_data_for_realm_authority;
+use anchor_lang::prelude::*;
+
+/// Configures the policy applied to programmable NFTs (pNFTs) used for voting
+#[derive(Accounts)]
+pub struct ConfigureProgrammableNftPolicy<'info> {
+    /// Registrar for which we configure the policy
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+}
+
+/// Sets the policy applied to programmable NFTs
+///
+/// Note: The policy is evaluated when NFT votes are cast and it doesn't affect NFT votes which were already cast
+pub fn configure_programmable_nft_policy(
+    ctx: Context<ConfigureProgrammableNftPolicy>,
+    programmable_nft_policy: ProgrammableNftPolicy,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    get_realm_data_for_realm_authority(
+        registrar,
+        &ctx.accounts.realm,
+        &ctx.accounts.realm_authority.key(),
+    )?;
+
+    registrar.programmable_nft_policy = programmable_nft_policy;
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use crate::tools::governance::get_realm_data_for_realm_authority;
+use anchor_lang::prelude::*;
+use gpl_voter_weight_plugin::realm::assert_no_voting_proposals;
+
+/// Configures the mode used to track the NFTs which voted on a Proposal
+#[derive(Accounts)]
+pub struct ConfigureVoteTrackingMode<'info> {
+    /// Registrar for which we configure the vote tracking mode
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+}
+
+/// Sets the vote_tracking_mode of the Registrar
+///
+/// Note: The vote tracking mode can't be changed while there are Proposals in Voting state
+/// because NFTs tracked by the previous mode could vote again on the Proposals
+pub fn configure_vote_tracking_mode(
+    ctx: Context<ConfigureVoteTrackingMode>,
+    vote_tracking_mode: VoteTrackingMode,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let realm = get_realm_data_for_realm_authority(
+        registrar,
+        &ctx.accounts.realm,
+        &ctx.accounts.realm_authority.key(),
+    )?;
+
+    assert_no_voting_proposals(
+        realm.voting_proposal_count.into(),
+        NftVoterError::CannotConfigureVoteTrackingModeWithVotingProposals,
+    )?;
+
+    registrar.vote_tracking_mode = vote_tracking_mode;
+
+    Ok(())
+}
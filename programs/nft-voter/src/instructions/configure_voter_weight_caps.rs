@@ -0,0 +1,52 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use crate::tools::governance::get_realm_data_for_realm_authority;
+use anchor_lang::prelude::*;
+use gpl_voter_weight_plugin::realm::assert_no_voting_proposals;
+
+/// Configures the caps applied to voter_weight cast on a Proposal by a single voter
+#[derive(Accounts)]
+pub struct ConfigureVoterWeightCaps<'info> {
+    /// Registrar for which we configure the caps
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+}
+
+/// Sets the voter_weight caps of the Registrar
+/// The caps are disabled when both of them are None
+///
+/// Note: The caps can't be changed while there are Proposals in Voting state
+/// because votes cast and revoked on the same Proposal must be capped the same way
+pub fn configure_voter_weight_caps(
+    ctx: Context<ConfigureVoterWeightCaps>,
+    voter_weight_caps: VoterWeightCaps,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let realm = get_realm_data_for_realm_authority(
+        registrar,
+        &ctx.accounts.realm,
+        &ctx.accounts.realm_authority.key(),
+    )?;
+
+    assert_no_voting_proposals(
+        realm.voting_proposal_count.into(),
+        NftVoterError::CannotConfigureVoterWeightCapsWithVotingProposals,
+    )?;
+
+    assert_valid_voter_weight_caps(&voter_weight_caps)?;
+
+    registrar.voter_weight_caps = voter_weight_caps;
+
+    Ok(())
+}
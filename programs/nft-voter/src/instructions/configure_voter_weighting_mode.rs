@@ -0,0 +1,69 @@
+use crate::error::NftVoterError;
+use crate::state::{max_voter_weight_record::MaxVoterWeightRecord, *};
+use crate::tools::governance::get_realm_data_for_realm_authority;
+use anchor_lang::prelude::*;
+use gpl_voter_weight_plugin::realm::assert_no_voting_proposals;
+
+/// Configures the weighting mode used to calculate voter_weight of the NFTs owned by a voter
+/// The instruction updates MaxVoterWeightRecord which is used by spl-gov to determine max voting power
+/// used to calculate voting quorum
+#[derive(Accounts)]
+pub struct ConfigureVoterWeightingMode<'info> {
+    /// Registrar for which we configure the weighting mode
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+/// Sets the voter_weighting_mode of the Registrar and updates max voter weight for the new mode
+///
+/// Note: The weighting mode can't be changed while there are Proposals in Voting state
+/// because votes cast and revoked on the same Proposal must be weighted the same way
+pub fn configure_voter_weighting_mode(
+    ctx: Context<ConfigureVoterWeightingMode>,
+    voter_weighting_mode: VoterWeightingMode,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let realm = get_realm_data_for_realm_authority(
+        registrar,
+        &ctx.accounts.realm,
+        &ctx.accounts.realm_authority.key(),
+    )?;
+
+    assert_no_voting_proposals(
+        realm.voting_proposal_count.into(),
+        NftVoterError::CannotConfigureVoterWeightingModeWithVotingProposals,
+    )?;
+
+    registrar.voter_weighting_mode = voter_weighting_mode;
+
+    // Update MaxVoterWeightRecord based on max voting power of the collections for the new mode
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+    max_voter_weight_record.max_voter_weight = registrar.get_max_voter_weight();
+
+    // The weight never expires and only changes when collections or the weighting mode are configured
+    max_voter_weight_record.max_voter_weight_expiry = None;
+
+    Ok(())
+}
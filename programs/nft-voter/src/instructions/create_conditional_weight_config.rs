@@ -0,0 +1,69 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use crate::tools::governance::get_realm_data_for_realm_authority;
+use anchor_lang::prelude::*;
+use gpl_voter_weight_plugin::realm::assert_no_voting_proposals;
+
+/// Creates ConditionalWeightConfig with the conditional weight rules of the Registrar
+/// Once the config is created its rules replace the default rules
+#[derive(Accounts)]
+#[instruction(rules: Vec<ConditionalWeightRule>)]
+pub struct CreateConditionalWeightConfig<'info> {
+    /// Registrar for which we create the config
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    #[account(
+        init,
+        seeds = [b"conditional-weight-config".as_ref(), registrar.key().as_ref()],
+        bump,
+        payer = payer,
+        space = ConditionalWeightConfig::get_space(&rules)
+    )]
+    pub conditional_weight_config: Account<'info, ConditionalWeightConfig>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates ConditionalWeightConfig with the given rules
+///
+/// Note: The default phase protocol rule is not retained and must be included in the rules if it's still required
+pub fn create_conditional_weight_config(
+    ctx: Context<CreateConditionalWeightConfig>,
+    rules: Vec<ConditionalWeightRule>,
+) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    let realm = get_realm_data_for_realm_authority(
+        registrar,
+        &ctx.accounts.realm,
+        &ctx.accounts.realm_authority.key(),
+    )?;
+
+    // Changes to the rules can accidentally tip the scales for outstanding proposals and hence we disallow it
+    assert_no_voting_proposals(
+        realm.voting_proposal_count.into(),
+        NftVoterError::CannotConfigureConditionalWeightRulesWithVotingProposals,
+    )?;
+
+    assert_valid_conditional_weight_rules(&rules)?;
+
+    let conditional_weight_config = &mut ctx.accounts.conditional_weight_config;
+
+    conditional_weight_config.registrar = registrar.key();
+    conditional_weight_config.rules = rules;
+
+    Ok(())
+}
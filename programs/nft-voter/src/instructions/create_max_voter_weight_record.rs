@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
+use gpl_voter_weight_plugin::voter_weight::EXPIRED_WEIGHT_EXPIRY;
 use spl_governance::state::realm;
 
 use crate::state::max_voter_weight_record::MaxVoterWeightRecord;
@@ -51,7 +52,7 @@ pub fn create_max_voter_weight_record(ctx: Context<CreateMaxVoterWeightRecord>)
     max_voter_weight_record.governing_token_mint = ctx.accounts.realm_governing_token_mint.key();
 
     // Set expiry to expired
-    max_voter_weight_record.max_voter_weight_expiry = Some(0);
+    max_voter_weight_record.max_voter_weight_expiry = EXPIRED_WEIGHT_EXPIRY;
 
     Ok(())
 }
@@ -0,0 +1,57 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use crate::tools::governance::get_realm_data_for_realm_authority;
+use anchor_lang::prelude::*;
+
+/// Creates MintDenylist with NFT mints which are not allowed to vote, for example stolen or exploited NFTs
+/// More mints can be added using AddDeniedMints
+#[derive(Accounts)]
+#[instruction(mints: Vec<Pubkey>)]
+pub struct CreateMintDenylist<'info> {
+    /// Registrar for which we create the denylist
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    #[account(
+        init,
+        seeds = [b"mint-denylist".as_ref(), registrar.key().as_ref()],
+        bump,
+        payer = payer,
+        space = MintDenylist::get_space(mints.len())
+    )]
+    pub mint_denylist: Account<'info, MintDenylist>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates MintDenylist with the given mints
+///
+/// Note: The denylist applies to votes cast after the change and the votes already cast are not affected
+pub fn create_mint_denylist(ctx: Context<CreateMintDenylist>, mints: Vec<Pubkey>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    get_realm_data_for_realm_authority(
+        registrar,
+        &ctx.accounts.realm,
+        &ctx.accounts.realm_authority.key(),
+    )?;
+
+    let mint_denylist = &mut ctx.accounts.mint_denylist;
+
+    mint_denylist.registrar = registrar.key();
+    mint_denylist.add_mints(&mints)?;
+
+    Ok(())
+}
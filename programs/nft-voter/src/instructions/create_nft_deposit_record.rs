@@ -0,0 +1,47 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Creates NftDepositRecord used to lock NFTs in the program vaults for persistent governance power
+/// This instruction should only be executed once per voter to create the account
+#[derive(Accounts)]
+#[instruction(max_nfts: u16)]
+pub struct CreateNftDepositRecord<'info> {
+    /// The NFT voting Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        init,
+        seeds = [
+            b"nft-deposit-record".as_ref(),
+            registrar.key().as_ref(),
+            governing_token_owner.key().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = NftDepositRecord::get_space(max_nfts)
+    )]
+    pub nft_deposit_record: Account<'info, NftDepositRecord>,
+
+    /// The voter who deposits the NFTs
+    pub governing_token_owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates NftDepositRecord for the given voter
+///
+/// max_nfts is used to allocate account size for the maximum number of NFTs which can be deposited
+pub fn create_nft_deposit_record(
+    ctx: Context<CreateNftDepositRecord>,
+    _max_nfts: u16,
+) -> Result<()> {
+    let nft_deposit_record = &mut ctx.accounts.nft_deposit_record;
+
+    nft_deposit_record.registrar = ctx.accounts.registrar.key();
+    nft_deposit_record.governing_token_owner = ctx.accounts.governing_token_owner.key();
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use crate::tools::governance::get_realm_data_for_realm_authority;
+use anchor_lang::prelude::*;
+
+/// Creates NftIndexMap for the collection which assigns the collection NFTs their bits in VoteBitmaps
+/// The NFTs are added using AddNftIndexMapMints
+#[derive(Accounts)]
+#[instruction(collection: Pubkey)]
+pub struct CreateNftIndexMap<'info> {
+    /// Registrar for which we create the index map
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    #[account(
+        init,
+        seeds = [b"nft-index-map".as_ref(), registrar.key().as_ref(), collection.as_ref()],
+        bump,
+        payer = payer,
+        space = NftIndexMap::get_space(0)
+    )]
+    pub nft_index_map: Account<'info, NftIndexMap>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates empty NftIndexMap for the given collection
+/// The collection must be configured for the Registrar
+pub fn create_nft_index_map(ctx: Context<CreateNftIndexMap>, collection: Pubkey) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    get_realm_data_for_realm_authority(
+        registrar,
+        &ctx.accounts.realm,
+        &ctx.accounts.realm_authority.key(),
+    )?;
+
+    registrar.get_collection_config(collection)?;
+
+    let nft_index_map = &mut ctx.accounts.nft_index_map;
+
+    nft_index_map.registrar = registrar.key();
+    nft_index_map.collection = collection;
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_program::clock::UnixTimestamp;
+
+/// Creates NftVoteDelegation which allows the delegate to cast votes with the owner's NFTs
+/// The owner can only have one delegation at a time and it has to be revoked before a new one is created
+#[derive(Accounts)]
+pub struct CreateNftVoteDelegation<'info> {
+    /// The NFT voting Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        init,
+        seeds = [
+            b"nft-vote-delegation".as_ref(),
+            registrar.key().as_ref(),
+            governing_token_owner.key().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = NftVoteDelegation::get_space()
+    )]
+    pub nft_vote_delegation: Account<'info, NftVoteDelegation>,
+
+    /// The owner of the NFTs who delegates the voting power
+    pub governing_token_owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates NftVoteDelegation for the given delegate
+///
+/// If collection is provided then the delegate can only vote with the owner's NFTs from the collection
+/// If expiry is provided then the delegation expires at the given time
+pub fn create_nft_vote_delegation(
+    ctx: Context<CreateNftVoteDelegation>,
+    delegate: Pubkey,
+    collection: Option<Pubkey>,
+    expiry: Option<UnixTimestamp>,
+) -> Result<()> {
+    if let Some(collection) = collection {
+        ctx.accounts.registrar.get_collection_config(collection)?;
+    }
+
+    if let Some(expiry) = expiry {
+        require!(
+            expiry > Clock::get()?.unix_timestamp,
+            NftVoterError::InvalidNftVoteDelegationExpiry
+        );
+    }
+
+    let nft_vote_delegation = &mut ctx.accounts.nft_vote_delegation;
+
+    nft_vote_delegation.registrar = ctx.accounts.registrar.key();
+    nft_vote_delegation.governing_token_owner = ctx.accounts.governing_token_owner.key();
+    nft_vote_delegation.delegate = delegate;
+    nft_vote_delegation.collection = collection;
+    nft_vote_delegation.expiry = expiry;
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
+use gpl_voter_weight_plugin::voter_weight::EXPIRED_WEIGHT_EXPIRY;
 use spl_governance::state::realm;
 
 /// Creates VoterWeightRecord used by spl-gov
@@ -57,7 +58,7 @@ pub fn create_voter_weight_record(
     voter_weight_record.governing_token_owner = governing_token_owner;
 
     // Set expiry to expired
-    voter_weight_record.voter_weight_expiry = Some(0);
+    voter_weight_record.voter_weight_expiry = EXPIRED_WEIGHT_EXPIRY;
 
     Ok(())
 }
@@ -0,0 +1,49 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Creates VoterWeightTally used to accumulate voter_weight for non voting actions using several transactions
+/// This instruction should only be executed once per VoterWeightRecord to create the account
+#[derive(Accounts)]
+#[instruction(max_nfts: u16)]
+pub struct CreateVoterWeightTally<'info> {
+    /// The NFT voting Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        constraint = voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidVoterWeightRecordMint,
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+
+    #[account(
+        init,
+        seeds = [b"voter-weight-tally".as_ref(), voter_weight_record.key().as_ref()],
+        bump,
+        payer = payer,
+        space = VoterWeightTally::get_space(max_nfts)
+    )]
+    pub voter_weight_tally: Account<'info, VoterWeightTally>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates VoterWeightTally for the given VoterWeightRecord
+///
+/// max_nfts is used to allocate account size for the maximum number of NFTs which can be tallied
+pub fn create_voter_weight_tally(
+    ctx: Context<CreateVoterWeightTally>,
+    _max_nfts: u16,
+) -> Result<()> {
+    let voter_weight_tally = &mut ctx.accounts.voter_weight_tally;
+
+    voter_weight_tally.voter_weight_record = ctx.accounts.voter_weight_record.key();
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+/// Deposits NFT into the program vault and locks it in the voter's NftDepositRecord
+/// The locked NFT gives the voter persistent governance power until it's withdrawn
+///
+/// The NFT is validated the same way as when casting vote with it and its weight is recorded at the time of the deposit
+/// If the NFT has weight override then collection_weight_overrides must be provided as the remaining account
+///
+/// Note: Programmable NFTs and NFTs held by escrow programs can't be deposited
+#[derive(Accounts)]
+pub struct DepositNft<'info> {
+    /// The NFT voting Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"nft-deposit-record".as_ref(),
+            registrar.key().as_ref(),
+            governing_token_owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub nft_deposit_record: Account<'info, NftDepositRecord>,
+
+    /// The voter who deposits the NFT
+    pub governing_token_owner: Signer<'info>,
+
+    /// The token account holding the NFT
+    #[account(
+        mut,
+        token::mint = nft_mint,
+        constraint = nft_token_account.owner == governing_token_owner.key() @ NftVoterError::VoterDoesNotOwnNft
+    )]
+    pub nft_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Validated in instruction
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    pub nft_mint: Account<'info, Mint>,
+
+    /// The NFT locker authority which owns the vaults
+    /// CHECK: PDA without data
+    #[account(
+        seeds = [b"nft-locker-authority".as_ref(), registrar.key().as_ref()],
+        bump
+    )]
+    pub nft_locker_authority: UncheckedAccount<'info>,
+
+    /// The vault the NFT is locked in
+    #[account(
+        init,
+        seeds = [b"nft-vault".as_ref(), registrar.key().as_ref(), nft_mint.key().as_ref()],
+        bump,
+        payer = payer,
+        token::mint = nft_mint,
+        token::authority = nft_locker_authority
+    )]
+    pub nft_vault: Account<'info, TokenAccount>,
+
+    /// MintDenylist of the Registrar
+    /// The account doesn't have to exist and no NFT mints are denied in such case
+    /// CHECK: Checked in instruction if it exists
+    #[account(
+        seeds = [b"mint-denylist".as_ref(), registrar.key().as_ref()],
+        bump
+    )]
+    pub mint_denylist: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn deposit_nft<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, DepositNft<'info>>,
+) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let governing_token_owner = &ctx.accounts.governing_token_owner.key();
+
+    // The NFT accounts are resolved in the same order as when casting vote with the NFT
+    let nft_accounts = [
+        vec![
+            ctx.accounts.nft_token_account.to_account_info(),
+            ctx.accounts.nft_metadata.to_account_info(),
+        ],
+        ctx.remaining_accounts.to_vec(),
+    ]
+    .concat();
+
+    let nft_vote_info = resolve_nft_vote_info(
+        registrar,
+        &ctx.accounts.mint_denylist,
+        governing_token_owner,
+        &nft_accounts,
+        &mut vec![],
+    )?;
+
+    // Programmable NFTs can't be transferred using spl-token
+    require!(
+        !nft_vote_info
+            .token_standard
+            .map_or(false, |ts| ts.is_programmable()),
+        NftVoterError::CannotDepositProgrammableNft
+    );
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.nft_token_account.to_account_info(),
+                to: ctx.accounts.nft_vault.to_account_info(),
+                authority: ctx.accounts.governing_token_owner.to_account_info(),
+            },
+        ),
+        1,
+    )?;
+
+    let account_size = ctx.accounts.nft_deposit_record.to_account_info().data_len();
+
+    ctx.accounts.nft_deposit_record.add_locked_nft(
+        LockedNft {
+            mint: nft_vote_info.mint,
+            collection: nft_vote_info.collection,
+            weight: nft_vote_info.weight,
+            deposited_at: Clock::get()?.unix_timestamp,
+        },
+        account_size,
+    )?;
+
+    Ok(())
+}
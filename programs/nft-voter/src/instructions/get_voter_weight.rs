@@ -0,0 +1,139 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_program::program::set_return_data;
+use spl_governance::tools::spl_token::get_spl_token_mint;
+
+/// Quotes voter_weight of the given NFTs without changing any state
+/// The quote is returned as VoterWeightQuote using the instruction return data
+/// and it's meant to be obtained by simulating the instruction
+///
+/// For each NFT the remaining accounts are the same as for UpdateVoterWeightRecord: nft, nft_metadata,
+/// token_record (only for programmable NFTs), escrow_program_config with escrow_receipt (only for NFTs held by an escrow program)
+/// and collection_weight_overrides (only for NFTs with weight override)
+/// The number of the accounts of each NFT is given by nft_account_counts
+///
+/// NFTs which can't be used to vote don't fail the instruction and they are quoted with the rejection error instead
+/// The NFTs locked in the voter's NftDepositRecord are always counted and don't have to be provided
+///
+/// Note: The quote doesn't include the Proposal specific checks like the ownership snapshot, NFTs which already voted
+/// or conditional weight rules
+#[derive(Accounts)]
+pub struct GetVoterWeight<'info> {
+    /// The NFT voting Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    /// CHECK: The voter whose NFTs are quoted can be any account
+    pub governing_token_owner: UncheckedAccount<'info>,
+
+    /// MintDenylist of the Registrar
+    /// The account doesn't have to exist and no NFT mints are denied in such case
+    /// CHECK: Checked in instruction if it exists
+    #[account(
+        seeds = [b"mint-denylist".as_ref(), registrar.key().as_ref()],
+        bump
+    )]
+    pub mint_denylist: UncheckedAccount<'info>,
+
+    /// NftDepositRecord of the voter
+    /// The account doesn't have to exist and only the NFTs provided in remaining accounts are quoted in such case
+    /// CHECK: Deserialized in instruction if it exists
+    #[account(
+        seeds = [
+            b"nft-deposit-record".as_ref(),
+            registrar.key().as_ref(),
+            governing_token_owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub nft_deposit_record: UncheckedAccount<'info>,
+}
+
+pub fn get_voter_weight(ctx: Context<GetVoterWeight>, nft_account_counts: Vec<u8>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let governing_token_owner = &ctx.accounts.governing_token_owner.key();
+
+    require!(
+        nft_account_counts.len() <= VoterWeightQuote::MAX_NFTS
+            && nft_account_counts
+                .iter()
+                .map(|c| *c as usize)
+                .sum::<usize>()
+                == ctx.remaining_accounts.len(),
+        NftVoterError::InvalidRemainingAccountsLayout
+    );
+
+    let mut voter_weight_quote = VoterWeightQuote::default();
+
+    // The NFTs locked in the NftDepositRecord are counted without providing their accounts
+    let mut nft_vote_infos = if ctx.accounts.nft_deposit_record.data_is_empty() {
+        vec![]
+    } else {
+        let nft_deposit_record =
+            Account::<NftDepositRecord>::try_from(&ctx.accounts.nft_deposit_record)?;
+
+        nft_deposit_record.get_nft_vote_infos(
+            registrar,
+            &ctx.accounts.mint_denylist,
+            &registrar.key(),
+            None,
+        )?
+    };
+
+    voter_weight_quote.deposited_nft_count = nft_vote_infos.len() as u32;
+    voter_weight_quote.deposited_nft_weight = nft_vote_infos
+        .iter()
+        .try_fold(0u64, |sum, info| sum.checked_add(info.weight))
+        .unwrap();
+
+    // Ensure all nfts are unique
+    let mut unique_nft_mints = vec![];
+
+    let mut remaining_accounts = ctx.remaining_accounts;
+
+    for nft_account_count in nft_account_counts {
+        let (nft_accounts, next_accounts) = remaining_accounts.split_at(nft_account_count as usize);
+        remaining_accounts = next_accounts;
+
+        let nft_vote_info = resolve_nft_vote_info(
+            registrar,
+            &ctx.accounts.mint_denylist,
+            governing_token_owner,
+            nft_accounts,
+            &mut unique_nft_mints,
+        );
+
+        let nft_voter_weight_quote = match nft_vote_info {
+            Ok(nft_vote_info) => {
+                let nft_voter_weight_quote = NftVoterWeightQuote {
+                    mint: nft_vote_info.mint,
+                    weight: nft_vote_info.weight,
+                    rejection_code: None,
+                };
+
+                nft_vote_infos.push(nft_vote_info);
+
+                nft_voter_weight_quote
+            }
+            Err(error) => {
+                let nft_info = nft_accounts.first();
+
+                NftVoterWeightQuote {
+                    mint: nft_info
+                        .map(|info| get_spl_token_mint(info).unwrap_or(*info.key))
+                        .unwrap_or_default(),
+                    weight: 0,
+                    rejection_code: Some(ProgramError::from(error).into()),
+                }
+            }
+        };
+
+        voter_weight_quote.nfts.push(nft_voter_weight_quote);
+    }
+
+    voter_weight_quote.voter_weight = get_capped_nft_voter_weight(registrar, &nft_vote_infos)?;
+
+    set_return_data(&voter_weight_quote.try_to_vec()?);
+
+    Ok(())
+}
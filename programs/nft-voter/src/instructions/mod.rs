@@ -1,9 +1,69 @@
 pub use configure_collection::*;
 mod configure_collection;
 
+pub use remove_collection::*;
+mod remove_collection;
+
+pub use create_collection_weight_overrides::*;
+mod create_collection_weight_overrides;
+
+pub use configure_collection_weight_overrides::*;
+mod configure_collection_weight_overrides;
+
+pub use add_collection_weight_overrides::*;
+mod add_collection_weight_overrides;
+
+pub use remove_collection_weight_overrides::*;
+mod remove_collection_weight_overrides;
+
+pub use add_escrow_program::*;
+mod add_escrow_program;
+
+pub use remove_escrow_program::*;
+mod remove_escrow_program;
+
+pub use create_conditional_weight_config::*;
+mod create_conditional_weight_config;
+
+pub use configure_conditional_weight_rules::*;
+mod configure_conditional_weight_rules;
+
+pub use create_mint_denylist::*;
+mod create_mint_denylist;
+
+pub use add_denied_mints::*;
+mod add_denied_mints;
+
+pub use remove_denied_mints::*;
+mod remove_denied_mints;
+
+pub use configure_programmable_nft_policy::*;
+mod configure_programmable_nft_policy;
+
+pub use configure_voter_weight_caps::*;
+mod configure_voter_weight_caps;
+
+pub use configure_voter_weighting_mode::*;
+mod configure_voter_weighting_mode;
+
+pub use configure_ownership_snapshot::*;
+mod configure_ownership_snapshot;
+
+pub use configure_vote_tracking_mode::*;
+mod configure_vote_tracking_mode;
+
+pub use create_nft_index_map::*;
+mod create_nft_index_map;
+
+pub use add_nft_index_map_mints::*;
+mod add_nft_index_map_mints;
+
 pub use create_registrar::*;
 mod create_registrar;
 
+pub use resize_registrar::*;
+mod resize_registrar;
+
 pub use create_voter_weight_record::*;
 mod create_voter_weight_record;
 
@@ -13,8 +73,50 @@ mod create_max_voter_weight_record;
 pub use update_voter_weight_record::*;
 mod update_voter_weight_record;
 
+pub use get_voter_weight::*;
+mod get_voter_weight;
+
+pub use create_voter_weight_tally::*;
+mod create_voter_weight_tally;
+
+pub use update_voter_weight_tally::*;
+mod update_voter_weight_tally;
+
+pub use close_voter_weight_tally::*;
+mod close_voter_weight_tally;
+
+pub use create_nft_deposit_record::*;
+mod create_nft_deposit_record;
+
+pub use deposit_nft::*;
+mod deposit_nft;
+
+pub use withdraw_nft::*;
+mod withdraw_nft;
+
+pub use create_nft_vote_delegation::*;
+mod create_nft_vote_delegation;
+
+pub use revoke_nft_vote_delegation::*;
+mod revoke_nft_vote_delegation;
+
+pub use register_nft_holders::*;
+mod register_nft_holders;
+
+pub use register_compressed_nft_holders::*;
+mod register_compressed_nft_holders;
+
 pub use relinquish_nft_vote::*;
 mod relinquish_nft_vote;
 
+pub use revoke_nft_vote::*;
+mod revoke_nft_vote;
+
+pub use close_nft_vote_records::*;
+mod close_nft_vote_records;
+
 pub use cast_nft_vote::*;
 mod cast_nft_vote;
+
+pub use cast_compressed_nft_vote::*;
+mod cast_compressed_nft_vote;
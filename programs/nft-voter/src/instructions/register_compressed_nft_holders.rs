@@ -0,0 +1,76 @@
+use crate::error::NftVoterError;
+use crate::instructions::RegisterNftHolders;
+use crate::state::*;
+use crate::tools::account_compression::get_merkle_tree_header;
+use anchor_lang::prelude::*;
+
+/// Registers the current holders of compressed NFTs (Bubblegum) using NftHolderRegistration accounts
+/// It uses the same accounts as RegisterNftHolders and for compressed NFTs NftHolderRegistration.nft_mint is the asset id of the NFT
+///
+/// For each compressed NFT the following remaining accounts must be provided:
+/// merkle_tree, nft_holder_registration and proof nodes from the leaf up to the root (max_depth of the tree)
+pub fn register_compressed_nft_holders<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, RegisterNftHolders<'info>>,
+    compressed_nfts: Vec<CompressedNftAsset>,
+) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let nft_holder = &ctx.accounts.nft_holder.key();
+
+    // Ensure all nfts are unique
+    let mut unique_nft_mints = vec![];
+
+    let rent = Rent::get()?;
+    let clock = Clock::get()?;
+
+    let mut remaining_accounts = ctx.remaining_accounts;
+
+    for compressed_nft in compressed_nfts.iter() {
+        require!(
+            remaining_accounts.len() >= 2,
+            NftVoterError::InvalidMerkleProof
+        );
+
+        let merkle_tree_info = &remaining_accounts[0];
+        let nft_holder_registration_info = &remaining_accounts[1];
+
+        let proof_len = get_merkle_tree_header(merkle_tree_info)?.max_depth as usize;
+
+        require!(
+            remaining_accounts.len() >= 2 + proof_len,
+            NftVoterError::InvalidMerkleProof
+        );
+
+        let proof_infos = &remaining_accounts[2..2 + proof_len];
+
+        // The compressed NFT must be currently held by nft_holder
+        let nft_vote_info = resolve_compressed_nft_vote_info(
+            registrar,
+            &ctx.accounts.mint_denylist,
+            nft_holder,
+            merkle_tree_info,
+            proof_infos,
+            compressed_nft,
+            &mut unique_nft_mints,
+        )?;
+
+        register_nft_holder(
+            &ctx.accounts.payer.to_account_info(),
+            nft_holder_registration_info,
+            &registrar.key(),
+            &nft_vote_info.mint,
+            nft_holder,
+            &ctx.accounts.system_program.to_account_info(),
+            &rent,
+            &clock,
+        )?;
+
+        remaining_accounts = &remaining_accounts[2 + proof_len..];
+    }
+
+    require!(
+        remaining_accounts.is_empty(),
+        NftVoterError::InvalidMerkleProof
+    );
+
+    Ok(())
+}
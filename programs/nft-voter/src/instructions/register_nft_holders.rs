@@ -0,0 +1,86 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Registers the current holders of NFTs using NftHolderRegistration accounts
+/// The registrations are used to enforce the ownership snapshot when it's enabled for the Registrar
+///
+/// The instruction is permissionless and can be invoked by the holder or by a crank
+/// It only records the current on-chain ownership and a registration is reset when the NFT changes hands
+///
+/// For each NFT the following remaining accounts must be provided: nft, nft_metadata,
+/// token_record (only for programmable NFTs), escrow_program_config and escrow_receipt (only for NFTs held by an escrow program),
+/// collection_weight_overrides (only for NFTs with weight override) and nft_holder_registration
+/// The number of the accounts of each NFT without nft_holder_registration is given by nft_account_counts
+#[derive(Accounts)]
+pub struct RegisterNftHolders<'info> {
+    /// The NFT voting Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    /// CHECK: The holder of the NFTs which is verified against the NFT token accounts
+    pub nft_holder: UncheckedAccount<'info>,
+
+    /// The account which pays for the registrations
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// MintDenylist of the Registrar
+    /// The account doesn't have to exist and no NFT mints are denied in such case
+    /// CHECK: Checked in instruction if it exists
+    #[account(
+        seeds = [b"mint-denylist".as_ref(), registrar.key().as_ref()],
+        bump
+    )]
+    pub mint_denylist: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers the holder of the given NFTs
+pub fn register_nft_holders<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, RegisterNftHolders<'info>>,
+    nft_account_counts: Vec<u8>,
+) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let nft_holder = &ctx.accounts.nft_holder.key();
+
+    // Ensure all nfts are unique
+    let mut unique_nft_mints = vec![];
+
+    let rent = Rent::get()?;
+    let clock = Clock::get()?;
+
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    for nft_account_count in nft_account_counts {
+        // The NFT must be currently held by nft_holder
+        let nft_vote_info = resolve_nft_vote_info(
+            registrar,
+            &ctx.accounts.mint_denylist,
+            nft_holder,
+            next_nft_accounts(account_info_iter, nft_account_count)?,
+            &mut unique_nft_mints,
+        )?;
+
+        let nft_holder_registration_info = next_account_info(account_info_iter)?;
+
+        register_nft_holder(
+            &ctx.accounts.payer.to_account_info(),
+            nft_holder_registration_info,
+            &registrar.key(),
+            &nft_vote_info.mint,
+            nft_holder,
+            &ctx.accounts.system_program.to_account_info(),
+            &rent,
+            &clock,
+        )?;
+    }
+
+    // All the remaining accounts must be consumed by the layout
+    require!(
+        account_info_iter.len() == 0,
+        NftVoterError::InvalidRemainingAccountsLayout
+    );
+
+    Ok(())
+}
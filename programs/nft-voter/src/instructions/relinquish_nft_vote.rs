@@ -1,21 +1,19 @@
 use crate::error::NftVoterError;
+use crate::events::NftVoteRelinquished;
+use crate::id;
 use crate::state::*;
 use crate::state::{get_nft_vote_record_data_for_proposal_and_token_owner, Registrar};
 use crate::tools::governance::get_vote_record_address;
 use anchor_lang::prelude::*;
+use gpl_voter_weight_plugin::voter_weight::EXPIRED_WEIGHT_EXPIRY;
 use spl_governance::state::{enums::ProposalState, governance, proposal};
 use spl_governance_tools::account::dispose_account;
 
 /// Disposes NftVoteRecord and recovers the rent from the accounts   
 /// It can only be executed when voting on the target Proposal ended or voter withdrew vote from the Proposal
 ///
-/// Note: If a voter votes with NFT and transfers the token then in the current version of the program the new owner can't withdraw the vote
-/// In order to support that scenario a change in spl-governance is needed
-/// It would have to support revoke_vote instruction which would take as input VoteWeightRecord with the following values:
-/// weight_action: RevokeVote, weight_action_target: VoteRecord, voter_weight: sum(previous owner NFT weight)
-/// The instruction would decrease the previous voter total VoteRecord.voter_weight by the provided VoteWeightRecord.voter_weight
-/// Once the spl-governance instruction is supported then nft-voter plugin should implement revoke_nft_vote instruction
-/// to supply the required VoteWeightRecord and delete relevant NftVoteRecords
+/// Note: If a voter votes with NFT and transfers the token then the new owner can't relinquish the vote
+/// In that scenario the new owner has to use RevokeNftVote together with spl-gov.RevokeVote instead
 #[derive(Accounts)]
 pub struct RelinquishNftVote<'info> {
     /// The NFT voting Registrar
@@ -55,6 +53,34 @@ pub struct RelinquishNftVote<'info> {
     /// CHECK: The beneficiary who receives lamports from the disposed NftVoterRecord accounts can be any account
     #[account(mut)]
     pub beneficiary: UncheckedAccount<'info>,
+
+    /// VoterWeightCapTally of the voter for the Proposal
+    /// The account only exists if the Registrar had voter_weight caps when the vote was cast
+    /// CHECK: Updated or disposed in instruction if it exists
+    #[account(
+        mut,
+        seeds = [
+            b"voter-weight-cap-tally".as_ref(),
+            proposal.key().as_ref(),
+            governing_token_owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub voter_weight_cap_tally: UncheckedAccount<'info>,
+
+    /// NftDepositRecord of the voter
+    /// The account doesn't have to exist and it's only updated when the NftVoteRecord of the deposit record is relinquished
+    /// CHECK: Deserialized and updated in instruction if it exists
+    #[account(
+        mut,
+        seeds = [
+            b"nft-deposit-record".as_ref(),
+            registrar.key().as_ref(),
+            governing_token_owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub nft_deposit_record: UncheckedAccount<'info>,
 }
 
 pub fn relinquish_nft_vote(ctx: Context<RelinquishNftVote>) -> Result<()> {
@@ -112,27 +138,75 @@ pub fn relinquish_nft_vote(ctx: Context<RelinquishNftVote>) -> Result<()> {
     // 2) voter-weight-plugin.cast_vote()
     // 3) nft-voter.relinquish_nft_vote()
     // 4) spl-gov.cast_vote() -> spl-gov uses VoterWeightRecord provided by voter-weight-plugin in step 2) while the nft vote is withdrawn and could be used to vote again
-    if voter_weight_record.voter_weight_expiry >= Some(Clock::get()?.slot) {
+    if voter_weight_record.is_voter_weight_active(Clock::get()?.slot) {
         return err!(NftVoterError::VoterWeightRecordMustBeExpired);
     }
 
+    let mut nft_mints = vec![];
+
     // Dispose all NftVoteRecords
     for nft_vote_record_info in ctx.remaining_accounts.iter() {
         // Ensure NftVoteRecord is for the given Proposal and TokenOwner
-        let _nft_vote_record = get_nft_vote_record_data_for_proposal_and_token_owner(
+        let nft_vote_record = get_nft_vote_record_data_for_proposal_and_token_owner(
             nft_vote_record_info,
             &ctx.accounts.proposal.key(),
             &ctx.accounts.governing_token_owner.key(),
         )?;
 
         dispose_account(nft_vote_record_info, &ctx.accounts.beneficiary);
+
+        // The NFTs locked in NftDepositRecord are recorded using the deposit record address as nft_mint
+        // and they can be withdrawn once all the NftVoteRecords of the deposit record are relinquished
+        if nft_vote_record.nft_mint == ctx.accounts.nft_deposit_record.key() {
+            let mut nft_deposit_record =
+                Account::<NftDepositRecord>::try_from(&ctx.accounts.nft_deposit_record)?;
+
+            nft_deposit_record.nft_vote_record_count = nft_deposit_record
+                .nft_vote_record_count
+                .checked_sub(1)
+                .unwrap();
+
+            nft_deposit_record.exit(&id())?;
+        }
+
+        nft_mints.push(nft_vote_record.nft_mint);
+    }
+
+    // VoterWeightCapTally is disposed once the last NftVoteRecord it tallied is relinquished
+    // and if the vote is cast again then the tally starts from scratch
+    // Until then the tally keeps the weight of all the NFTs cast on the Proposal and the caps are applied to it
+    //
+    // Once voting ended the tally is no longer needed and it's disposed regardless of the remaining records
+    // which can be closed by CloseNftVoteRecords without updating the tally
+    let voter_weight_cap_tally_info = &ctx.accounts.voter_weight_cap_tally;
+
+    if !voter_weight_cap_tally_info.data_is_empty() {
+        let mut voter_weight_cap_tally =
+            get_voter_weight_cap_tally_data(voter_weight_cap_tally_info)?;
+
+        if voter_weight_cap_tally.sub_nft_vote_records(nft_mints.len() as u32)
+            || proposal.state != ProposalState::Voting
+        {
+            dispose_account(voter_weight_cap_tally_info, &ctx.accounts.beneficiary);
+        } else {
+            voter_weight_cap_tally
+                .serialize(&mut *voter_weight_cap_tally_info.data.borrow_mut())?;
+        }
     }
 
     // Reset VoterWeightRecord and set expiry to expired to prevent it from being used
     voter_weight_record.voter_weight = 0;
-    voter_weight_record.voter_weight_expiry = Some(0);
+    voter_weight_record.voter_weight_expiry = EXPIRED_WEIGHT_EXPIRY;
 
     voter_weight_record.weight_action_target = None;
 
+    emit!(NftVoteRelinquished {
+        registrar: registrar.key(),
+        proposal: ctx.accounts.proposal.key(),
+        governing_token_owner: ctx.accounts.governing_token_owner.key(),
+        nft_mints,
+        beneficiary: ctx.accounts.beneficiary.key(),
+    });
+
     Ok(())
 }
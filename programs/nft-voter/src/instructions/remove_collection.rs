@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_lang::{
+    account,
+    prelude::{Context, Signer},
+    Accounts,
+};
+use gpl_voter_weight_plugin::realm::assert_no_voting_proposals;
+
+use crate::error::NftVoterError;
+use crate::state::{max_voter_weight_record::MaxVoterWeightRecord, Registrar};
+use crate::tools::governance::get_realm_data_for_realm_authority;
+
+/// Removes NFT voting collection from the Registrar
+/// Once removed NFTs from the collection can no longer be used for governance
+/// The instruction updates MaxVoterWeightRecord which is used by spl-gov to determine max voting power
+/// used to calculate voting quorum
+#[derive(Accounts)]
+pub struct RemoveCollection<'info> {
+    /// Registrar from which we remove the Collection
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+/// Removes the given collection from Registrar.collection_configs
+///
+/// Note: The collection is passed as an argument and not as an account because the collection mint
+/// could have been compromised or closed and it shouldn't be required to remove it from the Registrar
+pub fn remove_collection(ctx: Context<RemoveCollection>, collection: Pubkey) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let realm = get_realm_data_for_realm_authority(
+        registrar,
+        &ctx.accounts.realm,
+        &ctx.accounts.realm_authority.key(),
+    )?;
+
+    // Changes to the collections config can accidentally tip the scales for outstanding proposals and hence we disallow it
+    assert_no_voting_proposals(
+        realm.voting_proposal_count.into(),
+        NftVoterError::CannotConfigureCollectionWithVotingProposals,
+    )?;
+
+    let collection_idx = registrar
+        .collection_configs
+        .iter()
+        .position(|cc| cc.collection == collection)
+        .ok_or(NftVoterError::CollectionNotFound)?;
+
+    registrar.collection_configs.remove(collection_idx);
+
+    // Update MaxVoterWeightRecord based on max voting power of the remaining collections
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+    max_voter_weight_record.max_voter_weight = registrar.get_max_voter_weight();
+
+    // The weight never expires and only changes when collections are configured
+    max_voter_weight_record.max_voter_weight_expiry = None;
+
+    Ok(())
+}
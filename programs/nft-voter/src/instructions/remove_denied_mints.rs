@@ -0,0 +1,52 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use crate::tools::governance::get_realm_data_for_realm_authority;
+use anchor_lang::prelude::*;
+
+/// Removes NFT mints from MintDenylist
+/// The account is resized to fit the remaining mints and the payer receives the excess lamports
+#[derive(Accounts)]
+#[instruction(mints: Vec<Pubkey>)]
+pub struct RemoveDeniedMints<'info> {
+    /// Registrar the denylist belongs to
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = registrar @ NftVoterError::InvalidMintDenylist,
+        realloc = MintDenylist::get_space(mint_denylist.mints.len().saturating_sub(mints.len())),
+        realloc::payer = payer,
+        realloc::zero = false
+    )]
+    pub mint_denylist: Account<'info, MintDenylist>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Removes the given mints from MintDenylist
+///
+/// The mints must be on the denylist
+pub fn remove_denied_mints(ctx: Context<RemoveDeniedMints>, mints: Vec<Pubkey>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    get_realm_data_for_realm_authority(
+        registrar,
+        &ctx.accounts.realm,
+        &ctx.accounts.realm_authority.key(),
+    )?;
+
+    ctx.accounts.mint_denylist.remove_mints(&mints)
+}
@@ -0,0 +1,50 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use crate::tools::governance::get_realm_data_for_realm_authority;
+use anchor_lang::prelude::*;
+
+/// Removes escrow (staking) program from the allowlist of the Registrar
+/// NFTs held by the escrow program can no longer vote once it's removed
+#[derive(Accounts)]
+pub struct RemoveEscrowProgram<'info> {
+    /// Registrar for which we remove the escrow program
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// The allowlist entry of the escrow program
+    #[account(
+        mut,
+        has_one = registrar @ NftVoterError::InvalidEscrowProgramConfig,
+        close = beneficiary
+    )]
+    pub escrow_program_config: Account<'info, EscrowProgramConfig>,
+
+    /// Beneficiary which receives lamports from the disposed EscrowProgramConfig account
+    /// CHECK: Can be any account
+    #[account(mut)]
+    pub beneficiary: UncheckedAccount<'info>,
+}
+
+/// Removes escrow program from the allowlist
+///
+/// Note: NftVoteRecords created for escrowed NFTs are not affected and the votes already cast remain valid
+pub fn remove_escrow_program(ctx: Context<RemoveEscrowProgram>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    get_realm_data_for_realm_authority(
+        registrar,
+        &ctx.accounts.realm,
+        &ctx.accounts.realm_authority.key(),
+    )?;
+
+    Ok(())
+}
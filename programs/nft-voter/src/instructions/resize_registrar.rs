@@ -0,0 +1,54 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use crate::tools::governance::get_realm_data_for_realm_authority;
+use anchor_lang::prelude::*;
+
+/// Resizes Registrar to change the maximum number of NFT collections it can store
+/// The payer tops up the rent when the account grows and receives the excess lamports when it shrinks
+#[derive(Accounts)]
+#[instruction(max_collections: u8)]
+pub struct ResizeRegistrar<'info> {
+    /// The NFT voting Registrar to resize
+    #[account(
+        mut,
+        realloc = Registrar::get_space(max_collections),
+        realloc::payer = payer,
+        realloc::zero = false
+    )]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       address = registrar.realm @ NftVoterError::InvalidRealmForRegistrar,
+       owner = registrar.governance_program_id
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Resizes the Registrar to fit max_collections CollectionConfigs
+///
+/// max_collections can't be less than the number of collections already configured on the Registrar
+pub fn resize_registrar(ctx: Context<ResizeRegistrar>, max_collections: u8) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    get_realm_data_for_realm_authority(
+        registrar,
+        &ctx.accounts.realm,
+        &ctx.accounts.realm_authority.key(),
+    )?;
+
+    require!(
+        registrar.collection_configs.len() <= max_collections as usize,
+        NftVoterError::InvalidMaxCollections
+    );
+
+    Ok(())
+}
@@ -0,0 +1,236 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use crate::tools::governance::{assert_revoke_vote_instruction, get_vote_record_address};
+use anchor_lang::prelude::*;
+use solana_program::sysvar;
+use spl_governance::state::{enums::ProposalState, governance, proposal};
+use spl_governance_tools::account::dispose_account;
+
+/// Revokes votes cast with NFTs which were transferred to a new owner after voting
+/// The instruction supplies the VoterWeightRecord of the previous owner with RevokeVote action
+/// and the previous owner's VoteRecord as the target and disposes the NftVoteRecords of the transferred NFTs
+///
+/// The VoterWeightRecord is used by spl-gov RevokeVote instruction to decrease the previous owner's VoteRecord.voter_weight
+/// and hence the instruction must be bundled with spl-gov.RevokeVote as the last instruction in the same transaction
+/// Once the NftVoteRecords are disposed the new owner can vote with the NFTs
+///
+/// If the NFTs don't fit into a single transaction then each batch must be revoked with its own spl-gov.RevokeVote
+#[derive(Accounts)]
+pub struct RevokeNftVote<'info> {
+    /// The NFT voting Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    /// VoterWeightRecord of the previous owner who cast the vote with the NFTs
+    #[account(
+        mut,
+        constraint = voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidVoterWeightRecordMint,
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    /// Governance account the Proposal is for
+    #[account(owner = registrar.governance_program_id)]
+    pub governance: UncheckedAccount<'info>,
+
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    #[account(owner = registrar.governance_program_id)]
+    pub proposal: UncheckedAccount<'info>,
+
+    /// The current owner of the NFTs
+    pub governing_token_owner: Signer<'info>,
+
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    /// VoteRecord of the previous owner
+    /// The account can be already disposed if the previous owner withdrew the vote and hence the owner is checked in the instruction
+    pub vote_record: UncheckedAccount<'info>,
+
+    /// CHECK: The beneficiary who receives lamports from the disposed NftVoterRecord accounts can be any account
+    #[account(mut)]
+    pub beneficiary: UncheckedAccount<'info>,
+
+    /// MintDenylist of the Registrar
+    /// The account doesn't have to exist and no NFT mints are denied in such case
+    /// CHECK: Checked in instruction if it exists
+    #[account(
+        seeds = [b"mint-denylist".as_ref(), registrar.key().as_ref()],
+        bump
+    )]
+    pub mint_denylist: UncheckedAccount<'info>,
+
+    /// VoterWeightCapTally of the previous owner for the Proposal
+    /// The account only exists if the Registrar had voter_weight caps when the previous owner cast the vote
+    /// CHECK: Deserialized in instruction if it exists
+    #[account(
+        mut,
+        seeds = [
+            b"voter-weight-cap-tally".as_ref(),
+            proposal.key().as_ref(),
+            voter_weight_record.governing_token_owner.as_ref()
+        ],
+        bump
+    )]
+    pub voter_weight_cap_tally: UncheckedAccount<'info>,
+
+    /// CHECK: Accounts checked in instruction
+    #[account(address = sysvar::instructions::id())]
+    pub instruction_sysvar_account: UncheckedAccount<'info>,
+}
+
+/// Revokes NFT votes of the previous owner
+///
+/// For each NFT the following remaining accounts must be provided: nft, nft_metadata,
+/// token_record (only for programmable NFTs), escrow_program_config and escrow_receipt (only for NFTs held by an escrow program),
+/// collection_weight_overrides (only for NFTs with weight override) and nft_vote_record
+/// The number of the accounts of each NFT without nft_vote_record is given by nft_account_counts
+pub fn revoke_nft_vote<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, RevokeNftVote<'info>>,
+    nft_account_counts: Vec<u8>,
+) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let governing_token_owner = &ctx.accounts.governing_token_owner.key();
+    let previous_owner = ctx.accounts.voter_weight_record.governing_token_owner;
+
+    // The previous owner can relinquish the votes and revoke is only needed for transferred NFTs
+    require!(
+        previous_owner != *governing_token_owner,
+        NftVoterError::CannotRevokeOwnNftVote
+    );
+
+    // VoteBitmaps don't record the voter and the votes of the previous owner can't be identified
+    require!(
+        registrar.vote_tracking_mode == VoteTrackingMode::NftVoteRecords,
+        NftVoterError::CannotRevokeNftVoteWithVoteBitmaps
+    );
+
+    // Ensure the Governance belongs to Registrar.realm and is owned by Registrar.governance_program_id
+    let _governance = governance::get_governance_data_for_realm(
+        &registrar.governance_program_id,
+        &ctx.accounts.governance,
+        &registrar.realm,
+    )?;
+
+    // Ensure the Proposal belongs to Governance from Registrar.realm and Registrar.governing_token_mint and is owned by Registrar.governance_program_id
+    let proposal = proposal::get_proposal_data_for_governance_and_governing_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.proposal,
+        &ctx.accounts.governance.key(),
+        &registrar.governing_token_mint,
+    )?;
+
+    // Once voting ended the NftVoteRecords no longer restrict the NFTs and votes can't be revoked
+    require!(
+        proposal.state == ProposalState::Voting,
+        NftVoterError::InvalidProposalStateForRevoke
+    );
+
+    let vote_record_info = &ctx.accounts.vote_record.to_account_info();
+
+    // Ensure the given VoteRecord address matches the expected PDA of the previous owner
+    let vote_record_key = get_vote_record_address(
+        &registrar.governance_program_id,
+        &registrar.realm,
+        &registrar.governing_token_mint,
+        &previous_owner,
+        &ctx.accounts.proposal.key(),
+    );
+
+    require!(
+        vote_record_key == vote_record_info.key(),
+        NftVoterError::InvalidVoteRecordForNftVoteRecord
+    );
+
+    let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+
+    // Prevent overriding the previous owner's VoterWeightRecord while it's being used to cast vote
+    if voter_weight_record.weight_action == Some(VoterWeightAction::CastVote)
+        && voter_weight_record.is_voter_weight_active(Clock::get()?.slot)
+    {
+        return err!(NftVoterError::VoterWeightRecordMustBeExpired);
+    }
+
+    let mut nft_vote_infos = vec![];
+
+    // Ensure all revoked nfts in the batch are unique
+    let mut unique_nft_mints = vec![];
+
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    for nft_account_count in nft_account_counts {
+        // The current owner must own the NFT the same way as when casting vote with it
+        let nft_vote_info = resolve_nft_vote_info(
+            registrar,
+            &ctx.accounts.mint_denylist,
+            governing_token_owner,
+            next_nft_accounts(account_info_iter, nft_account_count)?,
+            &mut unique_nft_mints,
+        )?;
+
+        let nft_vote_record_info = next_account_info(account_info_iter)?;
+
+        // Ensure NftVoteRecord is for the given Proposal and the previous owner
+        let nft_vote_record = get_nft_vote_record_data_for_proposal_and_token_owner(
+            nft_vote_record_info,
+            &ctx.accounts.proposal.key(),
+            &previous_owner,
+        )?;
+
+        require!(
+            nft_vote_record.nft_mint == nft_vote_info.mint,
+            NftVoterError::InvalidNftMintForNftVoteRecord
+        );
+
+        dispose_account(nft_vote_record_info, &ctx.accounts.beneficiary);
+
+        nft_vote_infos.push(nft_vote_info);
+    }
+
+    // All the remaining accounts must be consumed by the layout
+    require!(
+        account_info_iter.len() == 0,
+        NftVoterError::InvalidRemainingAccountsLayout
+    );
+
+    // Note: The collection weights and voter_weight caps can't change while the Proposal is in Voting state
+    // and hence it's the same weight the NFTs voted with
+    let voter_weight = sub_capped_revoke_vote_weight(
+        registrar,
+        &ctx.accounts.voter_weight_cap_tally,
+        &nft_vote_infos,
+    )?;
+
+    // If the previous owner withdrew the vote then there is nothing to revoke in spl-gov
+    // and the stale NftVoteRecords are only disposed
+    if vote_record_info.data_is_empty() || vote_record_info.try_borrow_data()?[0] == 0 {
+        return Ok(());
+    }
+
+    require!(
+        *vote_record_info.owner == registrar.governance_program_id,
+        NftVoterError::InvalidVoteRecordForNftVoteRecord
+    );
+
+    // The NftVoteRecords are disposed and hence spl-gov.RevokeVote must decrease the VoteRecord in the same transaction
+    // Otherwise the NFTs could be used to vote again while their weight is still counted for the previous owner
+    assert_revoke_vote_instruction(
+        &ctx.accounts.instruction_sysvar_account,
+        &registrar.governance_program_id,
+        &vote_record_key,
+        &voter_weight_record.key(),
+    )?;
+
+    // The weight is only for the revoked NFTs because each batch is revoked with its own spl-gov.RevokeVote
+    voter_weight_record.voter_weight = voter_weight;
+
+    // The weight is only valid as of the current slot
+    voter_weight_record.voter_weight_expiry = Some(Clock::get()?.slot);
+
+    // Set the action and target to make the weight specific to revoking the previous owner's vote
+    voter_weight_record.weight_action = Some(VoterWeightAction::RevokeVote);
+    voter_weight_record.weight_action_target = Some(vote_record_key);
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Revokes NftVoteDelegation and recovers the rent from the account
+///
+/// Note: The votes already cast by the delegate are not affected
+#[derive(Accounts)]
+pub struct RevokeNftVoteDelegation<'info> {
+    /// The NFT voting Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"nft-vote-delegation".as_ref(),
+            registrar.key().as_ref(),
+            governing_token_owner.key().as_ref()
+        ],
+        bump,
+        close = beneficiary
+    )]
+    pub nft_vote_delegation: Account<'info, NftVoteDelegation>,
+
+    /// The owner of the NFTs who delegated the voting power
+    pub governing_token_owner: Signer<'info>,
+
+    /// CHECK: The beneficiary who receives lamports from the disposed NftVoteDelegation account can be any account
+    #[account(mut)]
+    pub beneficiary: UncheckedAccount<'info>,
+}
+
+pub fn revoke_nft_vote_delegation(_ctx: Context<RevokeNftVoteDelegation>) -> Result<()> {
+    Ok(())
+}
@@ -1,7 +1,6 @@
 use crate::error::NftVoterError;
 use crate::state::*;
 use anchor_lang::prelude::*;
-use itertools::Itertools;
 
 /// Updates VoterWeightRecord to evaluate governance power for non voting use cases: CreateProposal, CreateGovernance etc...
 /// This instruction updates VoterWeightRecord which is valid for the current Slot and the given target action only
@@ -9,8 +8,12 @@ use itertools::Itertools;
 ///
 /// Note: UpdateVoterWeight is not cumulative the same way as CastNftVote and hence voter_weight for non voting scenarios
 /// can only be used with max 5 NFTs due to Solana transaction size limit
-/// It could be supported in future version by introducing bookkeeping accounts to track the NFTs
-/// which were already used to calculate the total weight
+/// UpdateVoterWeightTally should be used instead if the voter owns more NFTs
+///
+/// The NFTs locked in the voter's NftDepositRecord are always counted and don't have to be provided
+///
+/// The instruction doesn't require the owner's signature and it can be used by the owner's delegates
+/// spl-gov checks the owner or TokenOwnerRecord.governance_delegate signed the corresponding instruction
 #[derive(Accounts)]
 #[instruction(voter_weight_action:VoterWeightAction)]
 pub struct UpdateVoterWeightRecord<'info> {
@@ -26,11 +29,34 @@ pub struct UpdateVoterWeightRecord<'info> {
         @ NftVoterError::InvalidVoterWeightRecordMint,
     )]
     pub voter_weight_record: Account<'info, VoterWeightRecord>,
+
+    /// MintDenylist of the Registrar
+    /// The account doesn't have to exist and no NFT mints are denied in such case
+    /// CHECK: Checked in instruction if it exists
+    #[account(
+        seeds = [b"mint-denylist".as_ref(), registrar.key().as_ref()],
+        bump
+    )]
+    pub mint_denylist: UncheckedAccount<'info>,
+
+    /// NftDepositRecord of the voter
+    /// The account doesn't have to exist and only the NFTs provided in remaining accounts are used in such case
+    /// CHECK: Deserialized in instruction if it exists
+    #[account(
+        seeds = [
+            b"nft-deposit-record".as_ref(),
+            registrar.key().as_ref(),
+            voter_weight_record.governing_token_owner.as_ref()
+        ],
+        bump
+    )]
+    pub nft_deposit_record: UncheckedAccount<'info>,
 }
 
 pub fn update_voter_weight_record(
     ctx: Context<UpdateVoterWeightRecord>,
     voter_weight_action: VoterWeightAction,
+    nft_account_counts: Vec<u8>,
 ) -> Result<()> {
     let registrar = &ctx.accounts.registrar;
     let governing_token_owner = &ctx.accounts.voter_weight_record.governing_token_owner;
@@ -38,32 +64,76 @@ pub fn update_voter_weight_record(
     match voter_weight_action {
         // voter_weight for CastVote action can't be evaluated using this instruction
         VoterWeightAction::CastVote => return err!(NftVoterError::CastVoteIsNotAllowed),
+        // voter_weight for RevokeVote action can only be evaluated using RevokeNftVote
+        VoterWeightAction::RevokeVote => return err!(NftVoterError::RevokeVoteIsNotAllowed),
         VoterWeightAction::CommentProposal
         | VoterWeightAction::CreateGovernance
         | VoterWeightAction::CreateProposal
         | VoterWeightAction::SignOffProposal => {}
     }
 
-    let mut voter_weight = 0u64;
+    // The NFT weights tallied using the Registrar voter_weighting_mode
+    let mut tally_weight = 0u64;
+
+    // The NFTs locked in the NftDepositRecord are counted without providing their accounts
+    if !ctx.accounts.nft_deposit_record.data_is_empty() {
+        let nft_deposit_record =
+            Account::<NftDepositRecord>::try_from(&ctx.accounts.nft_deposit_record)?;
+
+        for nft_vote_info in nft_deposit_record.get_nft_vote_infos(
+            registrar,
+            &ctx.accounts.mint_denylist,
+            &registrar.key(),
+            None,
+        )? {
+            tally_weight = tally_weight
+                .checked_add(
+                    registrar
+                        .voter_weighting_mode
+                        .get_nft_tally_weight(nft_vote_info.weight),
+                )
+                .unwrap();
+        }
+    }
 
     // Ensure all nfts are unique
     let mut unique_nft_mints = vec![];
 
-    for (nft_info, nft_metadata_info) in ctx.remaining_accounts.iter().tuples() {
-        let (nft_vote_weight, _) = resolve_nft_vote_weight_and_mint(
+    // For each NFT the remaining accounts are: nft, nft_metadata, token_record (only for programmable NFTs)
+    // escrow_program_config with escrow_receipt (only for NFTs held by an escrow program)
+    // and collection_weight_overrides (only for NFTs with weight override)
+    // The number of the accounts of each NFT is given by nft_account_counts
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    for nft_account_count in nft_account_counts {
+        let nft_vote_info = resolve_nft_vote_info(
             registrar,
+            &ctx.accounts.mint_denylist,
             governing_token_owner,
-            nft_info,
-            nft_metadata_info,
+            next_nft_accounts(account_info_iter, nft_account_count)?,
             &mut unique_nft_mints,
         )?;
 
-        voter_weight = voter_weight.checked_add(nft_vote_weight as u64).unwrap();
+        tally_weight = tally_weight
+            .checked_add(
+                registrar
+                    .voter_weighting_mode
+                    .get_nft_tally_weight(nft_vote_info.weight),
+            )
+            .unwrap();
     }
 
+    // All the remaining accounts must be consumed by the layout
+    require!(
+        account_info_iter.len() == 0,
+        NftVoterError::InvalidRemainingAccountsLayout
+    );
+
     let voter_weight_record = &mut ctx.accounts.voter_weight_record;
 
-    voter_weight_record.voter_weight = voter_weight;
+    voter_weight_record.voter_weight = registrar
+        .voter_weighting_mode
+        .get_voter_weight(tally_weight);
 
     // Record is only valid as of the current slot
     voter_weight_record.voter_weight_expiry = Some(Clock::get()?.slot);
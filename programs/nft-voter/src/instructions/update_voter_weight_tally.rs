@@ -0,0 +1,243 @@
+use crate::error::NftVoterError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accumulates voter_weight for non voting use cases: CreateProposal, CreateGovernance etc...
+/// and updates VoterWeightRecord with the total weight tallied so far
+/// This instruction updates VoterWeightRecord which is valid for the current Slot and the given target action only
+/// and hance only the last UpdateVoterWeightTally has to be executed inside the same transaction as the corresponding spl-gov instruction
+///
+/// UpdateVoterWeightTally is accumulative and can be invoked using several transactions if voter owns more NFTs
+/// than UpdateVoterWeightRecord can take in a single transaction
+/// The NFTs which were already counted are stored in VoterWeightTally to ensure the same NFT can't be counted twice
+/// and each NFT is recorded in its NftTallyRecord to ensure it can't be counted by another voter while the tally is active
+///
+/// The NFTs locked in the voter's NftDepositRecord are always counted and don't have to be provided
+///
+/// The tally can only be updated by the owner, the delegate from the owner's NftVoteDelegation
+/// or the governance_delegate from the owner's spl-gov TokenOwnerRecord
+#[derive(Accounts)]
+#[instruction(voter_weight_action:VoterWeightAction)]
+pub struct UpdateVoterWeightTally<'info> {
+    /// The NFT voting Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        constraint = voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidVoterWeightRecordMint,
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"voter-weight-tally".as_ref(), voter_weight_record.key().as_ref()],
+        bump,
+    )]
+    pub voter_weight_tally: Account<'info, VoterWeightTally>,
+
+    /// The token owner the tally is updated for
+    /// CHECK: The owner or its delegate must sign as voter_authority
+    #[account(
+        address = voter_weight_record.governing_token_owner @ NftVoterError::InvalidTokenOwnerForVoterWeightRecord
+    )]
+    pub governing_token_owner: UncheckedAccount<'info>,
+
+    /// The authority who updates the tally: governing_token_owner, the delegate from the owner's NftVoteDelegation
+    /// or the governance_delegate from the owner's spl-gov TokenOwnerRecord
+    pub voter_authority: Signer<'info>,
+
+    /// The account which pays for NftTallyRecords
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// MintDenylist of the Registrar
+    /// The account doesn't have to exist and no NFT mints are denied in such case
+    /// CHECK: Checked in instruction if it exists
+    #[account(
+        seeds = [b"mint-denylist".as_ref(), registrar.key().as_ref()],
+        bump
+    )]
+    pub mint_denylist: UncheckedAccount<'info>,
+
+    /// NftDepositRecord of the voter
+    /// The account doesn't have to exist and only the NFTs provided in remaining accounts are used in such case
+    /// CHECK: Deserialized in instruction if it exists
+    #[account(
+        seeds = [
+            b"nft-deposit-record".as_ref(),
+            registrar.key().as_ref(),
+            governing_token_owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub nft_deposit_record: UncheckedAccount<'info>,
+
+    /// NftVoteDelegation of the governing_token_owner
+    /// The account doesn't have to exist and it's only used when voter_authority is not the owner
+    /// CHECK: Deserialized in instruction if it exists
+    #[account(
+        seeds = [
+            b"nft-vote-delegation".as_ref(),
+            registrar.key().as_ref(),
+            governing_token_owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub nft_vote_delegation: UncheckedAccount<'info>,
+
+    /// spl-gov TokenOwnerRecord of the governing_token_owner
+    /// It's only used when voter_authority is not the owner to check TokenOwnerRecord.governance_delegate
+    /// CHECK: Checked in instruction if it's used
+    pub token_owner_record: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_voter_weight_tally<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, UpdateVoterWeightTally<'info>>,
+    voter_weight_action: VoterWeightAction,
+    nft_account_counts: Vec<u8>,
+) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let governing_token_owner = &ctx.accounts.governing_token_owner.key();
+
+    match voter_weight_action {
+        // voter_weight for CastVote action can't be evaluated using this instruction
+        VoterWeightAction::CastVote => return err!(NftVoterError::CastVoteIsNotAllowed),
+        // voter_weight for RevokeVote action can only be evaluated using RevokeNftVote
+        VoterWeightAction::RevokeVote => return err!(NftVoterError::RevokeVoteIsNotAllowed),
+        VoterWeightAction::CommentProposal
+        | VoterWeightAction::CreateGovernance
+        | VoterWeightAction::CreateProposal
+        | VoterWeightAction::SignOffProposal => {}
+    }
+
+    let delegated_collection = resolve_voter_authority_collection(
+        registrar,
+        governing_token_owner,
+        &ctx.accounts.voter_authority.key(),
+        &ctx.accounts.nft_vote_delegation,
+        &ctx.accounts.token_owner_record,
+    )?;
+
+    let current_slot = Clock::get()?.slot;
+    let rent = Rent::get()?;
+
+    let max_nfts = VoterWeightTally::get_max_nfts(
+        ctx.accounts.voter_weight_tally.to_account_info().data_len(),
+    );
+
+    let voter_weight_tally = &mut ctx.accounts.voter_weight_tally;
+
+    voter_weight_tally.reset_if_stale(voter_weight_action, current_slot);
+
+    let voter_weight_tally_key = voter_weight_tally.key();
+    let mut tally_weight = voter_weight_tally.voter_weight;
+    let mut nft_vote_infos = vec![];
+
+    // For each NFT the remaining accounts are: nft, nft_metadata, token_record (only for programmable NFTs)
+    // escrow_program_config with escrow_receipt (only for NFTs held by an escrow program),
+    // collection_weight_overrides (only for NFTs with weight override) and nft_tally_record
+    // The number of the accounts of each NFT without nft_tally_record is given by nft_account_counts
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    for nft_account_count in nft_account_counts {
+        // The NFTs already tallied are rejected as duplicates
+        let nft_vote_info = resolve_nft_vote_info(
+            registrar,
+            &ctx.accounts.mint_denylist,
+            governing_token_owner,
+            next_nft_accounts(account_info_iter, nft_account_count)?,
+            &mut voter_weight_tally.nft_mints,
+        )?;
+
+        let nft_tally_record_info = next_account_info(account_info_iter)?;
+
+        // Ensure the NFT isn't counted by another voter's tally while the tally is active
+        record_nft_tally(
+            &ctx.accounts.payer.to_account_info(),
+            nft_tally_record_info,
+            &registrar.key(),
+            &nft_vote_info.mint,
+            &voter_weight_tally_key,
+            voter_weight_tally.start_slot,
+            &ctx.accounts.system_program.to_account_info(),
+            &rent,
+            current_slot,
+        )?;
+
+        tally_weight = tally_weight
+            .checked_add(
+                registrar
+                    .voter_weighting_mode
+                    .get_nft_tally_weight(nft_vote_info.weight),
+            )
+            .unwrap();
+
+        nft_vote_infos.push(nft_vote_info);
+    }
+
+    // All the remaining accounts must be consumed by the layout
+    require!(
+        account_info_iter.len() == 0,
+        NftVoterError::InvalidRemainingAccountsLayout
+    );
+
+    require!(
+        voter_weight_tally.nft_mints.len() <= max_nfts,
+        NftVoterError::VoterWeightTallyIsFull
+    );
+
+    voter_weight_tally.voter_weight = tally_weight;
+
+    // The NFTs locked in the NftDepositRecord are counted without providing their accounts
+    // They are evaluated on every update and not stored in the tally because they can be withdrawn while the tally is active
+    // NFTs which were tallied before they were deposited are only counted once
+    if !ctx.accounts.nft_deposit_record.data_is_empty() {
+        let nft_deposit_record =
+            Account::<NftDepositRecord>::try_from(&ctx.accounts.nft_deposit_record)?;
+
+        for nft_vote_info in nft_deposit_record
+            .get_nft_vote_infos(
+                registrar,
+                &ctx.accounts.mint_denylist,
+                &registrar.key(),
+                None,
+            )?
+            .into_iter()
+            .filter(|nft_vote_info| !voter_weight_tally.nft_mints.contains(&nft_vote_info.mint))
+        {
+            tally_weight = tally_weight
+                .checked_add(
+                    registrar
+                        .voter_weighting_mode
+                        .get_nft_tally_weight(nft_vote_info.weight),
+                )
+                .unwrap();
+
+            nft_vote_infos.push(nft_vote_info);
+        }
+    }
+
+    assert_nft_vote_infos_delegated(delegated_collection, &nft_vote_infos)?;
+
+    let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+
+    // The weighting mode curve is applied to the total tallied weight and not to each batch of NFTs
+    voter_weight_record.voter_weight = registrar
+        .voter_weighting_mode
+        .get_voter_weight(tally_weight);
+
+    // Record is only valid as of the current slot
+    voter_weight_record.voter_weight_expiry = Some(current_slot);
+
+    // Set the action to make it specific and prevent being used for voting
+    voter_weight_record.weight_action = Some(voter_weight_action);
+    voter_weight_record.weight_action_target = None;
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+use crate::error::NftVoterError;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+use spl_governance::state::token_owner_record;
+
+/// Withdraws NFT from the program vault and unlocks it in the voter's NftDepositRecord
+///
+/// The NFT can't be withdrawn while the voter has active votes which are tracked by spl-gov
+/// using TokenOwnerRecord.unrelinquished_votes_count
+/// All the votes must be relinquished in spl-gov before the NFT can be withdrawn
+///
+/// The NftVoteRecords the locked NFTs are counted with must be relinquished using RelinquishNftVote as well
+/// Otherwise the NFT could be withdrawn and vote again on a Proposal it was already counted for
+#[derive(Accounts)]
+pub struct WithdrawNft<'info> {
+    /// The NFT voting Registrar
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"nft-deposit-record".as_ref(),
+            registrar.key().as_ref(),
+            governing_token_owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub nft_deposit_record: Account<'info, NftDepositRecord>,
+
+    /// The voter who deposited the NFT
+    pub governing_token_owner: Signer<'info>,
+
+    #[account(
+        constraint = voter_weight_record.realm == registrar.realm
+        @ NftVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ NftVoterError::InvalidVoterWeightRecordMint,
+
+        constraint = voter_weight_record.governing_token_owner == governing_token_owner.key()
+        @ NftVoterError::InvalidTokenOwnerForVoterWeightRecord,
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+
+    /// TokenOwnerRecord of the voter
+    /// The account doesn't have to exist if the voter never deposited to spl-gov or voted
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id and checked in instruction
+    pub token_owner_record: UncheckedAccount<'info>,
+
+    /// The token account the NFT is withdrawn to
+    #[account(
+        mut,
+        token::mint = nft_mint,
+    )]
+    pub nft_token_account: Account<'info, TokenAccount>,
+
+    pub nft_mint: Account<'info, Mint>,
+
+    /// The NFT locker authority which owns the vaults
+    /// CHECK: PDA without data
+    #[account(
+        seeds = [b"nft-locker-authority".as_ref(), registrar.key().as_ref()],
+        bump
+    )]
+    pub nft_locker_authority: UncheckedAccount<'info>,
+
+    /// The vault the NFT is locked in
+    /// The vault is closed once the NFT is withdrawn
+    #[account(
+        mut,
+        seeds = [b"nft-vault".as_ref(), registrar.key().as_ref(), nft_mint.key().as_ref()],
+        bump
+    )]
+    pub nft_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: The beneficiary who receives lamports from the closed vault can be any account
+    #[account(mut)]
+    pub beneficiary: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn withdraw_nft(ctx: Context<WithdrawNft>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let governing_token_owner = &ctx.accounts.governing_token_owner.key();
+
+    // Ensure the given TokenOwnerRecord address matches the expected PDA
+    let token_owner_record_key = token_owner_record::get_token_owner_record_address(
+        &registrar.governance_program_id,
+        &registrar.realm,
+        &registrar.governing_token_mint,
+        governing_token_owner,
+    );
+
+    require!(
+        token_owner_record_key == ctx.accounts.token_owner_record.key(),
+        NftVoterError::InvalidTokenOwnerRecordForNftDepositRecord
+    );
+
+    // The locked NFTs are counted in the votes the voter cast and can't be withdrawn until the votes are relinquished
+    if !ctx.accounts.token_owner_record.data_is_empty() {
+        let token_owner_record = token_owner_record::get_token_owner_record_data(
+            &registrar.governance_program_id,
+            &ctx.accounts.token_owner_record,
+        )?;
+
+        require!(
+            token_owner_record.unrelinquished_votes_count == 0,
+            NftVoterError::CannotWithdrawNftWithActiveVotes
+        );
+    }
+
+    // The locked NFTs are counted in NftVoteRecords even if the vote was never cast in spl-gov
+    // and they can't be withdrawn until the NftVoteRecords are relinquished
+    require!(
+        ctx.accounts.nft_deposit_record.nft_vote_record_count == 0,
+        NftVoterError::CannotWithdrawNftWithActiveVotes
+    );
+
+    // Prevent withdrawing the NFTs within the VoterWeightRecord expiration period
+    // Without the assertion the NFTs counted by CastNftVote could be withdrawn before spl-gov.CastVote is executed
+    if ctx
+        .accounts
+        .voter_weight_record
+        .is_voter_weight_active(Clock::get()?.slot)
+    {
+        return err!(NftVoterError::VoterWeightRecordMustBeExpired);
+    }
+
+    let nft_mint = ctx.accounts.nft_mint.key();
+
+    ctx.accounts
+        .nft_deposit_record
+        .remove_locked_nft(&nft_mint)?;
+
+    let registrar_key = registrar.key();
+    let (_, bump) =
+        Pubkey::find_program_address(&get_nft_locker_authority_seeds(&registrar_key), &id());
+    let nft_locker_authority_seeds: &[&[u8]] =
+        &[b"nft-locker-authority", registrar_key.as_ref(), &[bump]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.nft_vault.to_account_info(),
+                to: ctx.accounts.nft_token_account.to_account_info(),
+                authority: ctx.accounts.nft_locker_authority.to_account_info(),
+            },
+            &[nft_locker_authority_seeds],
+        ),
+        1,
+    )?;
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.nft_vault.to_account_info(),
+            destination: ctx.accounts.beneficiary.to_account_info(),
+            authority: ctx.accounts.nft_locker_authority.to_account_info(),
+        },
+        &[nft_locker_authority_seeds],
+    ))?;
+
+    Ok(())
+}
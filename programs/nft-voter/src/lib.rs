@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 
 pub mod error;
 
+pub mod events;
+
 mod instructions;
 use instructions::*;
 
@@ -23,6 +25,10 @@ pub mod nft_voter {
         log_version();
         instructions::create_registrar(ctx, max_collections)
     }
+    pub fn resize_registrar(ctx: Context<ResizeRegistrar>, max_collections: u8) -> Result<()> {
+        log_version();
+        instructions::resize_registrar(ctx, max_collections)
+    }
     pub fn create_voter_weight_record(
         ctx: Context<CreateVoterWeightRecord>,
         governing_token_owner: Pubkey,
@@ -37,14 +43,52 @@ pub mod nft_voter {
     pub fn update_voter_weight_record(
         ctx: Context<UpdateVoterWeightRecord>,
         voter_weight_action: VoterWeightAction,
+        nft_account_counts: Vec<u8>,
+    ) -> Result<()> {
+        log_version();
+        instructions::update_voter_weight_record(ctx, voter_weight_action, nft_account_counts)
+    }
+    pub fn get_voter_weight(
+        ctx: Context<GetVoterWeight>,
+        nft_account_counts: Vec<u8>,
+    ) -> Result<()> {
+        log_version();
+        instructions::get_voter_weight(ctx, nft_account_counts)
+    }
+    pub fn create_voter_weight_tally(
+        ctx: Context<CreateVoterWeightTally>,
+        max_nfts: u16,
+    ) -> Result<()> {
+        log_version();
+        instructions::create_voter_weight_tally(ctx, max_nfts)
+    }
+    pub fn update_voter_weight_tally<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, UpdateVoterWeightTally<'info>>,
+        voter_weight_action: VoterWeightAction,
+        nft_account_counts: Vec<u8>,
     ) -> Result<()> {
         log_version();
-        instructions::update_voter_weight_record(ctx, voter_weight_action)
+        instructions::update_voter_weight_tally(ctx, voter_weight_action, nft_account_counts)
+    }
+    pub fn close_voter_weight_tally(ctx: Context<CloseVoterWeightTally>) -> Result<()> {
+        log_version();
+        instructions::close_voter_weight_tally(ctx)
     }
     pub fn relinquish_nft_vote(ctx: Context<RelinquishNftVote>) -> Result<()> {
         log_version();
         instructions::relinquish_nft_vote(ctx)
     }
+    pub fn revoke_nft_vote<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, RevokeNftVote<'info>>,
+        nft_account_counts: Vec<u8>,
+    ) -> Result<()> {
+        log_version();
+        instructions::revoke_nft_vote(ctx, nft_account_counts)
+    }
+    pub fn close_nft_vote_records(ctx: Context<CloseNftVoteRecords>) -> Result<()> {
+        log_version();
+        instructions::close_nft_vote_records(ctx)
+    }
     pub fn configure_collection(
         ctx: Context<ConfigureCollection>,
         weight: u64,
@@ -53,12 +97,195 @@ pub mod nft_voter {
         log_version();
         instructions::configure_collection(ctx, weight, size)
     }
+    pub fn remove_collection(ctx: Context<RemoveCollection>, collection: Pubkey) -> Result<()> {
+        log_version();
+        instructions::remove_collection(ctx, collection)
+    }
+    pub fn create_collection_weight_overrides(
+        ctx: Context<CreateCollectionWeightOverrides>,
+        collection: Pubkey,
+        overrides: Vec<NftWeightOverride>,
+    ) -> Result<()> {
+        log_version();
+        instructions::create_collection_weight_overrides(ctx, collection, overrides)
+    }
+    pub fn configure_collection_weight_overrides(
+        ctx: Context<ConfigureCollectionWeightOverrides>,
+        overrides: Vec<NftWeightOverride>,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_collection_weight_overrides(ctx, overrides)
+    }
+    pub fn add_collection_weight_overrides(
+        ctx: Context<AddCollectionWeightOverrides>,
+        overrides: Vec<NftWeightOverride>,
+    ) -> Result<()> {
+        log_version();
+        instructions::add_collection_weight_overrides(ctx, overrides)
+    }
+    pub fn remove_collection_weight_overrides(
+        ctx: Context<RemoveCollectionWeightOverrides>,
+        nft_mints: Vec<Pubkey>,
+    ) -> Result<()> {
+        log_version();
+        instructions::remove_collection_weight_overrides(ctx, nft_mints)
+    }
+    pub fn configure_programmable_nft_policy(
+        ctx: Context<ConfigureProgrammableNftPolicy>,
+        programmable_nft_policy: ProgrammableNftPolicy,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_programmable_nft_policy(ctx, programmable_nft_policy)
+    }
+    pub fn configure_voter_weight_caps(
+        ctx: Context<ConfigureVoterWeightCaps>,
+        voter_weight_caps: VoterWeightCaps,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_voter_weight_caps(ctx, voter_weight_caps)
+    }
+    pub fn configure_voter_weighting_mode(
+        ctx: Context<ConfigureVoterWeightingMode>,
+        voter_weighting_mode: VoterWeightingMode,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_voter_weighting_mode(ctx, voter_weighting_mode)
+    }
+    pub fn configure_ownership_snapshot(
+        ctx: Context<ConfigureOwnershipSnapshot>,
+        ownership_snapshot_enabled: bool,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_ownership_snapshot(ctx, ownership_snapshot_enabled)
+    }
+    pub fn configure_vote_tracking_mode(
+        ctx: Context<ConfigureVoteTrackingMode>,
+        vote_tracking_mode: VoteTrackingMode,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_vote_tracking_mode(ctx, vote_tracking_mode)
+    }
+    pub fn create_nft_index_map(ctx: Context<CreateNftIndexMap>, collection: Pubkey) -> Result<()> {
+        log_version();
+        instructions::create_nft_index_map(ctx, collection)
+    }
+    pub fn add_nft_index_map_mints(
+        ctx: Context<AddNftIndexMapMints>,
+        collection: Pubkey,
+        mints: Vec<Pubkey>,
+    ) -> Result<()> {
+        log_version();
+        instructions::add_nft_index_map_mints(ctx, collection, mints)
+    }
+    pub fn add_escrow_program(
+        ctx: Context<AddEscrowProgram>,
+        program_id: Pubkey,
+        receipt_discriminator: [u8; 8],
+        beneficial_owner_offset: u32,
+        mint_offset: u32,
+    ) -> Result<()> {
+        log_version();
+        instructions::add_escrow_program(
+            ctx,
+            program_id,
+            receipt_discriminator,
+            beneficial_owner_offset,
+            mint_offset,
+        )
+    }
+    pub fn remove_escrow_program(ctx: Context<RemoveEscrowProgram>) -> Result<()> {
+        log_version();
+        instructions::remove_escrow_program(ctx)
+    }
+    pub fn create_conditional_weight_config(
+        ctx: Context<CreateConditionalWeightConfig>,
+        rules: Vec<ConditionalWeightRule>,
+    ) -> Result<()> {
+        log_version();
+        instructions::create_conditional_weight_config(ctx, rules)
+    }
+    pub fn configure_conditional_weight_rules(
+        ctx: Context<ConfigureConditionalWeightRules>,
+        rules: Vec<ConditionalWeightRule>,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_conditional_weight_rules(ctx, rules)
+    }
+    pub fn create_mint_denylist(
+        ctx: Context<CreateMintDenylist>,
+        mints: Vec<Pubkey>,
+    ) -> Result<()> {
+        log_version();
+        instructions::create_mint_denylist(ctx, mints)
+    }
+    pub fn add_denied_mints(ctx: Context<AddDeniedMints>, mints: Vec<Pubkey>) -> Result<()> {
+        log_version();
+        instructions::add_denied_mints(ctx, mints)
+    }
+    pub fn remove_denied_mints(ctx: Context<RemoveDeniedMints>, mints: Vec<Pubkey>) -> Result<()> {
+        log_version();
+        instructions::remove_denied_mints(ctx, mints)
+    }
+    pub fn create_nft_deposit_record(
+        ctx: Context<CreateNftDepositRecord>,
+        max_nfts: u16,
+    ) -> Result<()> {
+        log_version();
+        instructions::create_nft_deposit_record(ctx, max_nfts)
+    }
+    pub fn deposit_nft<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, DepositNft<'info>>,
+    ) -> Result<()> {
+        log_version();
+        instructions::deposit_nft(ctx)
+    }
+    pub fn withdraw_nft(ctx: Context<WithdrawNft>) -> Result<()> {
+        log_version();
+        instructions::withdraw_nft(ctx)
+    }
+    pub fn create_nft_vote_delegation(
+        ctx: Context<CreateNftVoteDelegation>,
+        delegate: Pubkey,
+        collection: Option<Pubkey>,
+        expiry: Option<i64>,
+    ) -> Result<()> {
+        log_version();
+        instructions::create_nft_vote_delegation(ctx, delegate, collection, expiry)
+    }
+    pub fn revoke_nft_vote_delegation(ctx: Context<RevokeNftVoteDelegation>) -> Result<()> {
+        log_version();
+        instructions::revoke_nft_vote_delegation(ctx)
+    }
+    pub fn register_nft_holders<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, RegisterNftHolders<'info>>,
+        nft_account_counts: Vec<u8>,
+    ) -> Result<()> {
+        log_version();
+        instructions::register_nft_holders(ctx, nft_account_counts)
+    }
+    pub fn register_compressed_nft_holders<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, RegisterNftHolders<'info>>,
+        compressed_nfts: Vec<CompressedNftAsset>,
+    ) -> Result<()> {
+        log_version();
+        instructions::register_compressed_nft_holders(ctx, compressed_nfts)
+    }
 
     pub fn cast_nft_vote<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, CastNftVote<'info>>,
+        layout: CastNftVoteLayout,
+    ) -> Result<()> {
+        log_version();
+        instructions::cast_nft_vote(ctx, layout)
+    }
+
+    pub fn cast_compressed_nft_vote<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, CastNftVote<'info>>,
+        compressed_nfts: Vec<CompressedNftAsset>,
+        with_proposal_transaction: bool,
     ) -> Result<()> {
         log_version();
-        instructions::cast_nft_vote(ctx)
+        instructions::cast_compressed_nft_vote(ctx, compressed_nfts, with_proposal_transaction)
     }
 }
 
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Layout of the remaining accounts provided to CastNftVote
+/// The layout is provided explicitly to ensure mis-shaped accounts are rejected instead of being parsed as NFT accounts
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq, Default)]
+pub struct CastNftVoteLayout {
+    /// The number of the accounts of each NFT provided in the remaining accounts
+    /// The accounts of the NFT are: nft, nft_metadata, token_record (only for programmable NFTs),
+    /// escrow_program_config and escrow_receipt (only for NFTs held by an escrow program)
+    /// and collection_weight_overrides (only for NFTs with weight override)
+    /// Note: The accounts used to track the vote of the NFT follow the accounts of the NFT and they aren't counted
+    pub nft_account_counts: Vec<u8>,
+
+    /// Indicates whether ProposalTransaction is provided as the last remaining account
+    /// It's required to cast vote on Proposals matching a conditional weight rule
+    pub with_proposal_transaction: bool,
+}
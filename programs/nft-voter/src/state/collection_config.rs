@@ -17,12 +17,17 @@ pub struct CollectionConfig {
     /// Ex: if the the mint has 2 decimal places then weight of 1 should be stored as 100
     pub weight: u64,
 
-    /// Reserved for future upgrades
-    pub reserved: [u8; 8],
+    /// Max governance power added on top of size * weight by the NFT weight overrides of the collection
+    /// Note: The value is computed from CollectionWeightOverrides when the collection or its overrides are configured
+    pub max_weight_bonus: u64,
 }
 
 impl CollectionConfig {
     pub fn get_max_weight(&self) -> u64 {
-        (self.size as u64).checked_mul(self.weight).unwrap()
+        (self.size as u64)
+            .checked_mul(self.weight)
+            .unwrap()
+            .checked_add(self.max_weight_bonus)
+            .unwrap()
     }
 }
@@ -0,0 +1,325 @@
+use crate::error::NftVoterError;
+use crate::id;
+use anchor_lang::prelude::*;
+use gpl_voter_weight_plugin::space::{DISCRIMINATOR_SIZE, PUBKEY_SIZE};
+
+/// Governance power weight of an individual NFT which overrides its collection weight
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct NftWeightOverride {
+    /// The mint of the NFT
+    pub nft_mint: Pubkey,
+
+    /// Governance power weight of the NFT
+    pub weight: u64,
+}
+
+/// Per NFT weight overrides (rarity table) for a collection configured on the Registrar
+/// The PDA of the overrides is ["collection-weight-overrides",registrar,collection]
+///
+/// Overrides can only boost governance power and override weights lower than the collection weight are rejected
+/// It ensures voters can't gain governance power by omitting the overrides account
+/// If the collection weight is raised above an override later then the collection weight is used for the NFT
+#[account]
+#[derive(Debug, PartialEq)]
+pub struct CollectionWeightOverrides {
+    /// Registrar the overrides belong to
+    pub registrar: Pubkey,
+
+    /// The collection of the NFTs
+    pub collection: Pubkey,
+
+    /// The weight overrides of the NFTs
+    pub overrides: Vec<NftWeightOverride>,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 32],
+}
+
+impl CollectionWeightOverrides {
+    pub fn get_space(overrides_count: usize) -> usize {
+        DISCRIMINATOR_SIZE + PUBKEY_SIZE * 2 + 4 + overrides_count * (PUBKEY_SIZE + 8) + 32
+    }
+
+    /// Returns the override weight of the given NFT mint
+    pub fn get_nft_weight(&self, nft_mint: &Pubkey) -> Option<u64> {
+        self.overrides
+            .iter()
+            .find(|o| o.nft_mint == *nft_mint)
+            .map(|o| o.weight)
+    }
+
+    /// Returns the max governance power added by the overrides on top of the collection weight
+    pub fn get_max_weight_bonus(&self, collection_weight: u64) -> u64 {
+        self.overrides
+            .iter()
+            .try_fold(0u64, |sum, o| {
+                sum.checked_add(o.weight.saturating_sub(collection_weight))
+            })
+            .unwrap()
+    }
+
+    /// Appends the given overrides to the existing ones
+    /// The NFT mints can't be already overridden
+    pub fn add_overrides(&mut self, overrides: &[NftWeightOverride]) -> Result<()> {
+        for nft_weight_override in overrides {
+            require!(
+                self.get_nft_weight(&nft_weight_override.nft_mint).is_none(),
+                NftVoterError::InvalidNftWeightOverride
+            );
+
+            self.overrides.push(*nft_weight_override);
+        }
+
+        Ok(())
+    }
+
+    /// Removes the overrides of the given NFT mints
+    /// The NFT mints must be overridden
+    pub fn remove_overrides(&mut self, nft_mints: &[Pubkey]) -> Result<()> {
+        for nft_mint in nft_mints {
+            let index = self
+                .overrides
+                .iter()
+                .position(|o| o.nft_mint == *nft_mint)
+                .ok_or(NftVoterError::InvalidNftWeightOverride)?;
+
+            self.overrides.remove(index);
+        }
+
+        Ok(())
+    }
+}
+
+/// Asserts the overrides are valid
+/// Each NFT mint can only be overridden once and the override weight can't be lower than the collection weight
+pub fn assert_valid_nft_weight_overrides(
+    overrides: &[NftWeightOverride],
+    collection_weight: u64,
+) -> Result<()> {
+    for (i, nft_weight_override) in overrides.iter().enumerate() {
+        require!(
+            !overrides[..i]
+                .iter()
+                .any(|o| o.nft_mint == nft_weight_override.nft_mint),
+            NftVoterError::InvalidNftWeightOverride
+        );
+
+        require!(
+            nft_weight_override.weight >= collection_weight,
+            NftVoterError::InvalidNftWeightOverride
+        );
+    }
+
+    Ok(())
+}
+
+/// Deserializes CollectionWeightOverrides and checks it belongs to the given Registrar and collection
+pub fn get_collection_weight_overrides_for_collection(
+    collection_weight_overrides_info: &AccountInfo,
+    registrar: &Pubkey,
+    collection: &Pubkey,
+) -> Result<CollectionWeightOverrides> {
+    let collection_weight_overrides: Account<CollectionWeightOverrides> =
+        Account::try_from(collection_weight_overrides_info)?;
+
+    require!(
+        collection_weight_overrides.registrar == *registrar
+            && collection_weight_overrides.collection == *collection,
+        NftVoterError::InvalidCollectionWeightOverrides
+    );
+
+    Ok(collection_weight_overrides.into_inner())
+}
+
+/// Returns the max governance power added by the overrides of the given collection
+/// 0 is returned if the CollectionWeightOverrides account doesn't exist
+pub fn get_collection_weight_overrides_bonus(
+    collection_weight_overrides_info: &AccountInfo,
+    registrar: &Pubkey,
+    collection: &Pubkey,
+    collection_weight: u64,
+) -> Result<u64> {
+    if collection_weight_overrides_info.data_is_empty() {
+        return Ok(0);
+    }
+
+    let collection_weight_overrides = get_collection_weight_overrides_for_collection(
+        collection_weight_overrides_info,
+        registrar,
+        collection,
+    )?;
+
+    Ok(collection_weight_overrides.get_max_weight_bonus(collection_weight))
+}
+
+/// Returns CollectionWeightOverrides PDA seeds
+pub fn get_collection_weight_overrides_seeds<'a>(
+    registrar: &'a Pubkey,
+    collection: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        b"collection-weight-overrides",
+        registrar.as_ref(),
+        collection.as_ref(),
+    ]
+}
+
+/// Returns CollectionWeightOverrides PDA address
+pub fn get_collection_weight_overrides_address(registrar: &Pubkey, collection: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_collection_weight_overrides_seeds(registrar, collection),
+        &id(),
+    )
+    .0
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = CollectionWeightOverrides::get_space(2);
+
+        let collection_weight_overrides = CollectionWeightOverrides {
+            registrar: Pubkey::default(),
+            collection: Pubkey::default(),
+            overrides: vec![
+                NftWeightOverride {
+                    nft_mint: Pubkey::default(),
+                    weight: 0,
+                },
+                NftWeightOverride {
+                    nft_mint: Pubkey::default(),
+                    weight: 0,
+                },
+            ],
+            reserved: [0; 32],
+        };
+
+        // Act
+        let actual_space =
+            DISCRIMINATOR_SIZE + collection_weight_overrides.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+
+    #[test]
+    fn test_get_max_weight_bonus() {
+        // Arrange
+        let collection_weight_overrides = CollectionWeightOverrides {
+            registrar: Pubkey::default(),
+            collection: Pubkey::default(),
+            overrides: vec![
+                NftWeightOverride {
+                    nft_mint: Pubkey::new_unique(),
+                    weight: 50,
+                },
+                NftWeightOverride {
+                    nft_mint: Pubkey::new_unique(),
+                    weight: 5,
+                },
+            ],
+            reserved: [0; 32],
+        };
+
+        // Act
+        let max_weight_bonus = collection_weight_overrides.get_max_weight_bonus(10);
+
+        // Assert
+        assert_eq!(max_weight_bonus, 40);
+    }
+
+    #[test]
+    fn test_assert_valid_nft_weight_overrides() {
+        // Arrange
+        let overrides = vec![
+            NftWeightOverride {
+                nft_mint: Pubkey::new_unique(),
+                weight: 50,
+            },
+            NftWeightOverride {
+                nft_mint: Pubkey::new_unique(),
+                weight: 10,
+            },
+        ];
+
+        // Act
+        let result = assert_valid_nft_weight_overrides(&overrides, 10);
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_assert_valid_nft_weight_overrides_with_weight_below_collection_weight_error() {
+        // Arrange
+        let overrides = vec![NftWeightOverride {
+            nft_mint: Pubkey::new_unique(),
+            weight: 5,
+        }];
+
+        // Act
+        let result = assert_valid_nft_weight_overrides(&overrides, 10);
+
+        // Assert
+        assert_eq!(result, Err(NftVoterError::InvalidNftWeightOverride.into()));
+    }
+
+    #[test]
+    fn test_add_overrides_with_overridden_nft_mint_error() {
+        // Arrange
+        let nft_weight_override = NftWeightOverride {
+            nft_mint: Pubkey::new_unique(),
+            weight: 50,
+        };
+
+        let mut collection_weight_overrides = CollectionWeightOverrides {
+            registrar: Pubkey::default(),
+            collection: Pubkey::default(),
+            overrides: vec![nft_weight_override],
+            reserved: [0; 32],
+        };
+
+        // Act
+        let result = collection_weight_overrides.add_overrides(&[NftWeightOverride {
+            nft_mint: nft_weight_override.nft_mint,
+            weight: 100,
+        }]);
+
+        // Assert
+        assert_eq!(result, Err(NftVoterError::InvalidNftWeightOverride.into()));
+    }
+
+    #[test]
+    fn test_remove_overrides() {
+        // Arrange
+        let overrides: Vec<NftWeightOverride> = (0..3)
+            .map(|_| NftWeightOverride {
+                nft_mint: Pubkey::new_unique(),
+                weight: 50,
+            })
+            .collect();
+
+        let mut collection_weight_overrides = CollectionWeightOverrides {
+            registrar: Pubkey::default(),
+            collection: Pubkey::default(),
+            overrides: overrides.clone(),
+            reserved: [0; 32],
+        };
+
+        // Act
+        collection_weight_overrides
+            .remove_overrides(&[overrides[1].nft_mint])
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            collection_weight_overrides.overrides,
+            vec![overrides[0], overrides[2]]
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::tools::bubblegum::MetadataArgs;
+
+/// Compressed NFT (Bubblegum) leaf data used to prove the voter owns the compressed NFT
+/// and the NFT belongs to a verified collection
+///
+/// The leaf is recomputed from the data and verified against the root of the Bubblegum Merkle tree
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq)]
+pub struct CompressedNftAsset {
+    /// The nonce of the leaf used to derive the asset id
+    pub nonce: u64,
+
+    /// The index of the leaf in the Merkle tree
+    pub index: u32,
+
+    /// The delegate of the leaf
+    /// If the compressed NFT has no delegate then it's the same as the owner
+    pub leaf_delegate: Pubkey,
+
+    /// The metadata of the compressed NFT
+    pub metadata: MetadataArgs,
+}
@@ -0,0 +1,286 @@
+use crate::error::NftVoterError;
+use crate::id;
+use crate::tools::phase_protocol::{PhaseProtocolProgram, PHASE_VOTE_DISCRIMATORS};
+use anchor_lang::prelude::*;
+use anchor_lang::Id;
+use gpl_voter_weight_plugin::space::{DISCRIMINATOR_SIZE, PUBKEY_SIZE};
+use spl_governance::state::vote_record::Vote;
+
+/// Policy applied to voter_weight when a vote is cast on a Proposal matching a ConditionalWeightRule
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum ConditionalWeightPolicy {
+    /// Only Approve votes carry voter_weight and any other vote gets 0 weight
+    ApproveOnly,
+
+    /// Only Deny votes carry voter_weight and any other vote gets 0 weight
+    DenyOnly,
+}
+
+impl ConditionalWeightPolicy {
+    /// Returns true if the vote carries voter_weight under the policy
+    pub fn is_weighted_vote(&self, vote: &Vote) -> bool {
+        match self {
+            ConditionalWeightPolicy::ApproveOnly => match vote {
+                Vote::Approve(choices) => choices.iter().any(|choice| choice.weight_percentage > 0),
+                _ => false,
+            },
+            ConditionalWeightPolicy::DenyOnly => *vote == Vote::Deny,
+        }
+    }
+}
+
+/// Rule which changes voter_weight for Proposals executing an instruction of the given program
+/// The rule matches Proposals with a single ProposalTransaction in the option labelled option_label
+/// and the ProposalTransaction must have a single instruction for program_id
+/// with instruction data starting with one of the discriminators
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq)]
+pub struct ConditionalWeightRule {
+    /// The program executed by the ProposalTransaction
+    pub program_id: Pubkey,
+
+    /// The 8 bytes instruction discriminators the rule applies to
+    pub discriminators: Vec<[u8; 8]>,
+
+    /// The policy applied to voter_weight when the rule matches
+    pub weight_policy: ConditionalWeightPolicy,
+
+    /// The label of the Proposal option with the ProposalTransaction
+    /// If None then the rule applies to the option with any label
+    pub option_label: Option<String>,
+}
+
+impl ConditionalWeightRule {
+    pub fn get_space(&self) -> usize {
+        PUBKEY_SIZE
+            + 4
+            + self.discriminators.len() * 8
+            + 1
+            + 1
+            + self
+                .option_label
+                .as_ref()
+                .map_or(0, |label| 4 + label.len())
+    }
+
+    /// Returns true if the rule applies to the Proposal option with the given label
+    pub fn is_option_match(&self, option_label: &str) -> bool {
+        self.option_label
+            .as_ref()
+            .map_or(true, |label| label == option_label)
+    }
+
+    /// Returns true if the rule applies to the given instruction
+    pub fn is_match(&self, program_id: &Pubkey, instruction_data: &[u8]) -> bool {
+        *program_id == self.program_id
+            && instruction_data.len() >= 8
+            && self
+                .discriminators
+                .iter()
+                .any(|discriminator| discriminator == &instruction_data[..8])
+    }
+}
+
+/// Conditional weight rules of the Registrar
+/// The PDA of the config is ["conditional-weight-config",registrar]
+///
+/// If the config doesn't exist then the default rules are used (phase protocol reverts are ApproveOnly)
+/// Once the config is created its rules replace the default rules
+#[account]
+#[derive(Debug, PartialEq)]
+pub struct ConditionalWeightConfig {
+    /// Registrar the config belongs to
+    pub registrar: Pubkey,
+
+    /// The configured rules
+    pub rules: Vec<ConditionalWeightRule>,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 32],
+}
+
+impl ConditionalWeightConfig {
+    pub fn get_space(rules: &[ConditionalWeightRule]) -> usize {
+        DISCRIMINATOR_SIZE
+            + PUBKEY_SIZE
+            + 4
+            + rules.iter().map(|rule| rule.get_space()).sum::<usize>()
+            + 32
+    }
+}
+
+/// Returns the rules used when ConditionalWeightConfig doesn't exist for the Registrar
+/// Phase protocol proposals have a single option labelled "Reject" which executes the phase vote
+pub fn get_default_conditional_weight_rules() -> Vec<ConditionalWeightRule> {
+    vec![ConditionalWeightRule {
+        program_id: PhaseProtocolProgram::id(),
+        discriminators: PHASE_VOTE_DISCRIMATORS.to_vec(),
+        weight_policy: ConditionalWeightPolicy::ApproveOnly,
+        option_label: Some("Reject".to_string()),
+    }]
+}
+
+/// Asserts the rules are valid
+/// Each rule must have at least one discriminator and a program can only have a single rule
+pub fn assert_valid_conditional_weight_rules(rules: &[ConditionalWeightRule]) -> Result<()> {
+    for (i, rule) in rules.iter().enumerate() {
+        require!(
+            !rule.discriminators.is_empty(),
+            NftVoterError::InvalidConditionalWeightRule
+        );
+
+        require!(
+            !rules[..i].iter().any(|r| r.program_id == rule.program_id),
+            NftVoterError::InvalidConditionalWeightRule
+        );
+    }
+
+    Ok(())
+}
+
+/// Returns the conditional weight rules of the Registrar
+/// The default rules are returned if the ConditionalWeightConfig account doesn't exist
+pub fn get_conditional_weight_rules(
+    conditional_weight_config_info: &AccountInfo,
+    registrar: &Pubkey,
+) -> Result<Vec<ConditionalWeightRule>> {
+    if conditional_weight_config_info.data_is_empty() {
+        return Ok(get_default_conditional_weight_rules());
+    }
+
+    let conditional_weight_config: Account<ConditionalWeightConfig> =
+        Account::try_from(conditional_weight_config_info)?;
+
+    require!(
+        conditional_weight_config.registrar == *registrar,
+        NftVoterError::InvalidConditionalWeightConfig
+    );
+
+    Ok(conditional_weight_config.rules.clone())
+}
+
+/// Returns ConditionalWeightConfig PDA seeds
+pub fn get_conditional_weight_config_seeds(registrar: &Pubkey) -> [&[u8]; 2] {
+    [b"conditional-weight-config", registrar.as_ref()]
+}
+
+/// Returns ConditionalWeightConfig PDA address
+pub fn get_conditional_weight_config_address(registrar: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&get_conditional_weight_config_seeds(registrar), &id()).0
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use spl_governance::state::vote_record::VoteChoice;
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let rules = get_default_conditional_weight_rules();
+        let expected_space = ConditionalWeightConfig::get_space(&rules);
+
+        let conditional_weight_config = ConditionalWeightConfig {
+            registrar: Pubkey::default(),
+            rules,
+            reserved: [0; 32],
+        };
+
+        // Act
+        let actual_space =
+            DISCRIMINATOR_SIZE + conditional_weight_config.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+
+    #[test]
+    fn test_get_space_with_rule_without_option_label() {
+        // Arrange
+        let rules = vec![ConditionalWeightRule {
+            program_id: Pubkey::default(),
+            discriminators: vec![[1; 8]],
+            weight_policy: ConditionalWeightPolicy::DenyOnly,
+            option_label: None,
+        }];
+        let expected_space = ConditionalWeightConfig::get_space(&rules);
+
+        let conditional_weight_config = ConditionalWeightConfig {
+            registrar: Pubkey::default(),
+            rules,
+            reserved: [0; 32],
+        };
+
+        // Act
+        let actual_space =
+            DISCRIMINATOR_SIZE + conditional_weight_config.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+
+    #[test]
+    fn test_is_option_match() {
+        // Arrange
+        let mut rule = get_default_conditional_weight_rules().remove(0);
+
+        // Act + Assert
+        assert!(rule.is_option_match("Reject"));
+        assert!(!rule.is_option_match("Approve"));
+
+        rule.option_label = None;
+        assert!(rule.is_option_match("Approve"));
+    }
+
+    #[test]
+    fn test_is_weighted_vote_with_approve_only_policy() {
+        // Arrange
+        let policy = ConditionalWeightPolicy::ApproveOnly;
+
+        let approve = Vote::Approve(vec![VoteChoice {
+            rank: 0,
+            weight_percentage: 100,
+        }]);
+
+        let approve_other_option = Vote::Approve(vec![
+            VoteChoice {
+                rank: 0,
+                weight_percentage: 0,
+            },
+            VoteChoice {
+                rank: 0,
+                weight_percentage: 50,
+            },
+        ]);
+
+        let approve_without_weight = Vote::Approve(vec![VoteChoice {
+            rank: 0,
+            weight_percentage: 0,
+        }]);
+
+        // Act + Assert
+        assert!(policy.is_weighted_vote(&approve));
+        assert!(policy.is_weighted_vote(&approve_other_option));
+        assert!(!policy.is_weighted_vote(&approve_without_weight));
+        assert!(!policy.is_weighted_vote(&Vote::Deny));
+        assert!(!policy.is_weighted_vote(&Vote::Abstain));
+        assert!(!policy.is_weighted_vote(&Vote::Veto));
+    }
+
+    #[test]
+    fn test_is_weighted_vote_with_deny_only_policy() {
+        // Arrange
+        let policy = ConditionalWeightPolicy::DenyOnly;
+
+        let approve = Vote::Approve(vec![VoteChoice {
+            rank: 0,
+            weight_percentage: 100,
+        }]);
+
+        // Act + Assert
+        assert!(policy.is_weighted_vote(&Vote::Deny));
+        assert!(!policy.is_weighted_vote(&approve));
+        assert!(!policy.is_weighted_vote(&Vote::Abstain));
+        assert!(!policy.is_weighted_vote(&Vote::Veto));
+    }
+}
@@ -0,0 +1,111 @@
+use crate::error::NftVoterError;
+use crate::id;
+use anchor_lang::prelude::*;
+use arrayref::array_ref;
+use gpl_voter_weight_plugin::space::{DISCRIMINATOR_SIZE, PUBKEY_SIZE};
+
+/// Escrow (staking) program trusted by the Registrar to hold NFTs on behalf of voters
+/// The PDA of the config is ["escrow-program-config",registrar,program_id]
+///
+/// NFTs held by an escrow program can vote if the escrow program issued a receipt account
+/// which names the voter as the beneficial owner of the NFT
+/// The layout of the receipt is described by the config and hence any escrow program can be plugged in
+#[account]
+#[derive(Debug, PartialEq)]
+pub struct EscrowProgramConfig {
+    /// Registrar the config belongs to
+    pub registrar: Pubkey,
+
+    /// The escrow program which owns the receipt accounts
+    pub program_id: Pubkey,
+
+    /// The account discriminator of the receipt accounts stored at the beginning of the receipt data
+    pub receipt_discriminator: [u8; 8],
+
+    /// The offset of the beneficial owner Pubkey in the receipt data
+    pub beneficial_owner_offset: u32,
+
+    /// The offset of the NFT mint Pubkey in the receipt data
+    pub mint_offset: u32,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 64],
+}
+
+impl EscrowProgramConfig {
+    pub fn get_space() -> usize {
+        DISCRIMINATOR_SIZE + PUBKEY_SIZE * 2 + 8 + 4 + 4 + 64
+    }
+}
+
+/// Returns EscrowProgramConfig PDA seeds
+pub fn get_escrow_program_config_seeds<'a>(
+    registrar: &'a Pubkey,
+    program_id: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        b"escrow-program-config",
+        registrar.as_ref(),
+        program_id.as_ref(),
+    ]
+}
+
+/// Returns EscrowProgramConfig PDA address
+pub fn get_escrow_program_config_address(registrar: &Pubkey, program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_escrow_program_config_seeds(registrar, program_id),
+        &id(),
+    )
+    .0
+}
+
+/// Asserts the escrow receipt proves the governing_token_owner is the beneficial owner of the NFT mint
+/// The escrow program must be allowlisted for the Registrar
+pub fn assert_escrow_ownership_proof(
+    registrar: &Pubkey,
+    escrow_program_config_info: &AccountInfo,
+    escrow_receipt_info: &AccountInfo,
+    governing_token_owner: &Pubkey,
+    nft_mint: &Pubkey,
+) -> Result<()> {
+    let escrow_program_config: Account<EscrowProgramConfig> =
+        Account::try_from(escrow_program_config_info)?;
+
+    require!(
+        escrow_program_config.registrar == *registrar,
+        NftVoterError::InvalidEscrowProgramConfig
+    );
+
+    // The receipt can only be trusted if it's owned by the allowlisted escrow program
+    require!(
+        *escrow_receipt_info.owner == escrow_program_config.program_id,
+        NftVoterError::EscrowProgramNotAllowed
+    );
+
+    let data = escrow_receipt_info.try_borrow_data()?;
+
+    let beneficial_owner_offset = escrow_program_config.beneficial_owner_offset as usize;
+    let mint_offset = escrow_program_config.mint_offset as usize;
+
+    require!(
+        data.len() >= DISCRIMINATOR_SIZE
+            && data.len() >= beneficial_owner_offset + PUBKEY_SIZE
+            && data.len() >= mint_offset + PUBKEY_SIZE
+            && *array_ref![data, 0, 8] == escrow_program_config.receipt_discriminator,
+        NftVoterError::InvalidEscrowReceipt
+    );
+
+    require!(
+        Pubkey::new_from_array(*array_ref![data, mint_offset, 32]) == *nft_mint,
+        NftVoterError::InvalidEscrowReceipt
+    );
+
+    // voter_weight_record.governing_token_owner must be the beneficial owner of the escrowed NFT
+    require!(
+        Pubkey::new_from_array(*array_ref![data, beneficial_owner_offset, 32])
+            == *governing_token_owner,
+        NftVoterError::VoterDoesNotOwnNft
+    );
+
+    Ok(())
+}
@@ -14,4 +14,102 @@ pub struct NftVoteRecord {
     /// The voter who casted this vote
     /// It's a Realm member pubkey corresponding to TokenOwnerRecord.governing_token_owner
     pub governing_token_owner: Pubkey,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 8],
+
+    /// The payer of the NftVoteRecord rent
+    pub payer: Pubkey,
+}
+
+/// VoteBitmap exported to IDL without account_discriminator
+#[account]
+pub struct VoteBitmap {
+    /// Proposal which was voted on
+    pub proposal: Pubkey,
+
+    /// The collection of the NFTs
+    pub collection: Pubkey,
+
+    /// The payer of the VoteBitmap rent
+    pub payer: Pubkey,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 8],
+
+    /// The bits of the NFTs which voted on the Proposal
+    pub bits: Vec<u8>,
+}
+
+/// NftHolderRegistration exported to IDL without account_discriminator
+#[account]
+pub struct NftHolderRegistration {
+    /// Registrar the registration belongs to
+    pub registrar: Pubkey,
+
+    /// The mint of the NFT
+    /// For compressed NFTs it's the asset id of the NFT
+    pub nft_mint: Pubkey,
+
+    /// The holder of the NFT
+    pub holder: Pubkey,
+
+    /// The time when the holder was first observed holding the NFT
+    pub registered_at: i64,
+
+    /// The slot when the holder was first observed holding the NFT
+    pub registered_at_slot: u64,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 8],
+}
+
+/// Weight of the NFTs from a collection cast by the voter before the caps are applied
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CollectionVoterWeight {
+    /// The collection of the NFTs
+    pub collection: Pubkey,
+
+    /// The total weight of the NFTs from the collection
+    pub weight: u64,
+}
+
+/// VoterWeightCapTally exported to IDL without account_discriminator
+#[account]
+pub struct VoterWeightCapTally {
+    /// Proposal which was voted on
+    pub proposal: Pubkey,
+
+    /// The voter who casted the votes
+    pub governing_token_owner: Pubkey,
+
+    /// Uncapped weight of the cast NFTs for each collection configured on the Registrar
+    /// For the non linear weighting modes it's the number of the cast NFTs
+    pub collection_weights: Vec<CollectionVoterWeight>,
+
+    /// The number of NftVoteRecords of the voter for the Proposal tallied by the tally
+    /// The tally is disposed when the last of the NftVoteRecords is relinquished
+    pub nft_vote_record_count: u32,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 8],
+}
+
+/// NftTallyRecord exported to IDL without account_discriminator
+#[account]
+pub struct NftTallyRecord {
+    /// Registrar the record belongs to
+    pub registrar: Pubkey,
+
+    /// The mint of the NFT
+    pub nft_mint: Pubkey,
+
+    /// The VoterWeightTally which counted the NFT
+    pub voter_weight_tally: Pubkey,
+
+    /// The start_slot of the VoterWeightTally when it counted the NFT
+    pub tally_start_slot: u64,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 8],
 }
@@ -1,7 +1,7 @@
 use crate::id;
-use crate::tools::anchor::{DISCRIMINATOR_SIZE, PUBKEY_SIZE};
 use anchor_lang::prelude::Pubkey;
 use anchor_lang::prelude::*;
+use gpl_voter_weight_plugin::voter_weight::{EXPIRED_WEIGHT_EXPIRY, MAX_VOTER_WEIGHT_RECORD_SPACE};
 
 /// MaxVoterWeightRecord account as defined in spl-governance-addin-api
 /// It's redefined here without account_discriminator for Anchor to treat it as native account
@@ -39,7 +39,7 @@ impl Default for MaxVoterWeightRecord {
             realm: Default::default(),
             governing_token_mint: Default::default(),
             max_voter_weight: Default::default(),
-            max_voter_weight_expiry: Some(0),
+            max_voter_weight_expiry: EXPIRED_WEIGHT_EXPIRY,
             reserved: Default::default(),
         }
     }
@@ -47,38 +47,29 @@ impl Default for MaxVoterWeightRecord {
 
 impl MaxVoterWeightRecord {
     pub fn get_space() -> usize {
-        DISCRIMINATOR_SIZE + PUBKEY_SIZE * 2 + 8 + 1 + 8 + 8
+        MAX_VOTER_WEIGHT_RECORD_SPACE
     }
 }
 
-/// Returns MaxVoterWeightRecord PDA seeds
-pub fn get_max_voter_weight_record_seeds<'a>(
-    realm: &'a Pubkey,
-    governing_token_mint: &'a Pubkey,
-) -> [&'a [u8]; 3] {
-    [
-        b"max-voter-weight-record",
-        realm.as_ref(),
-        governing_token_mint.as_ref(),
-    ]
-}
+pub use gpl_voter_weight_plugin::pda::get_max_voter_weight_record_seeds;
 
 /// Returns MaxVoterWeightRecord PDA address
 pub fn get_max_voter_weight_record_address(
     realm: &Pubkey,
     governing_token_mint: &Pubkey,
 ) -> Pubkey {
-    Pubkey::find_program_address(
-        &get_max_voter_weight_record_seeds(realm, governing_token_mint),
+    gpl_voter_weight_plugin::pda::get_max_voter_weight_record_address(
         &id(),
+        realm,
+        governing_token_mint,
     )
-    .0
 }
 
 #[cfg(test)]
 mod test {
 
     use super::*;
+    use gpl_voter_weight_plugin::space::DISCRIMINATOR_SIZE;
 
     #[test]
     fn test_get_space() {
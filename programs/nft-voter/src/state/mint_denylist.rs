@@ -0,0 +1,256 @@
+use crate::error::NftVoterError;
+use crate::id;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use arrayref::array_ref;
+use gpl_voter_weight_plugin::space::{DISCRIMINATOR_SIZE, PUBKEY_SIZE};
+use std::cmp::Ordering;
+
+/// NFT mints which are not allowed to vote, for example stolen or exploited NFTs
+/// The PDA of the denylist is ["mint-denylist",registrar]
+///
+/// The mints are kept sorted to binary search them in the account data without deserializing the whole list
+/// If the denylist doesn't exist then no mints are denied
+#[account]
+#[derive(Debug, PartialEq)]
+pub struct MintDenylist {
+    /// Registrar the denylist belongs to
+    pub registrar: Pubkey,
+
+    /// Sorted list of the denied mints
+    pub mints: Vec<Pubkey>,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 32],
+}
+
+impl MintDenylist {
+    pub fn get_space(mints_count: usize) -> usize {
+        DISCRIMINATOR_SIZE + PUBKEY_SIZE + 4 + mints_count * PUBKEY_SIZE + 32
+    }
+
+    /// Adds the given mints to the denylist and keeps the list sorted
+    /// The mints can't be already on the denylist
+    pub fn add_mints(&mut self, mints: &[Pubkey]) -> Result<()> {
+        for mint in mints {
+            match self.mints.binary_search(mint) {
+                Ok(_) => return err!(NftVoterError::InvalidDeniedMint),
+                Err(index) => self.mints.insert(index, *mint),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the given mints from the denylist
+    /// The mints must be on the denylist
+    pub fn remove_mints(&mut self, mints: &[Pubkey]) -> Result<()> {
+        for mint in mints {
+            let index = self
+                .mints
+                .binary_search(mint)
+                .map_err(|_| NftVoterError::InvalidDeniedMint)?;
+
+            self.mints.remove(index);
+        }
+
+        Ok(())
+    }
+}
+
+/// Offset of the mints Vec length in MintDenylist account data
+const MINTS_LEN_OFFSET: usize = DISCRIMINATOR_SIZE + PUBKEY_SIZE;
+
+/// Asserts the given NFT mint (or compressed NFT asset id) is not on the MintDenylist of the Registrar
+/// Nothing is denied if the MintDenylist account doesn't exist
+pub fn assert_nft_mint_not_denied(
+    mint_denylist_info: &AccountInfo,
+    registrar: &Pubkey,
+    nft_mint: &Pubkey,
+) -> Result<()> {
+    require!(
+        !is_nft_mint_denied(mint_denylist_info, registrar, nft_mint)?,
+        NftVoterError::NftMintDenied
+    );
+
+    Ok(())
+}
+
+/// Returns true if the given NFT mint (or compressed NFT asset id) is on the MintDenylist of the Registrar
+pub fn is_nft_mint_denied(
+    mint_denylist_info: &AccountInfo,
+    registrar: &Pubkey,
+    nft_mint: &Pubkey,
+) -> Result<bool> {
+    if mint_denylist_info.data_is_empty() {
+        return Ok(false);
+    }
+
+    require!(
+        *mint_denylist_info.owner == id(),
+        NftVoterError::InvalidMintDenylist
+    );
+
+    let data = mint_denylist_info.try_borrow_data()?;
+
+    require!(
+        data.len() >= MINTS_LEN_OFFSET + 4
+            && *array_ref![data, 0, 8] == MintDenylist::discriminator()
+            && array_ref![data, DISCRIMINATOR_SIZE, PUBKEY_SIZE] == registrar.as_ref(),
+        NftVoterError::InvalidMintDenylist
+    );
+
+    let mints_len = u32::from_le_bytes(*array_ref![data, MINTS_LEN_OFFSET, 4]) as usize;
+    let mints_data = &data[MINTS_LEN_OFFSET + 4..];
+
+    require!(
+        mints_data.len() >= mints_len * PUBKEY_SIZE,
+        NftVoterError::InvalidMintDenylist
+    );
+
+    // Binary search the sorted mints in place
+    let (mut low, mut high) = (0, mints_len);
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let mint = &mints_data[mid * PUBKEY_SIZE..(mid + 1) * PUBKEY_SIZE];
+
+        match mint.cmp(nft_mint.as_ref()) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return Ok(true),
+        }
+    }
+
+    Ok(false)
+}
+
+/// Returns MintDenylist PDA seeds
+pub fn get_mint_denylist_seeds(registrar: &Pubkey) -> [&[u8]; 2] {
+    [b"mint-denylist", registrar.as_ref()]
+}
+
+/// Returns MintDenylist PDA address
+pub fn get_mint_denylist_address(registrar: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&get_mint_denylist_seeds(registrar), &id()).0
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = MintDenylist::get_space(3);
+
+        let mint_denylist = MintDenylist {
+            registrar: Pubkey::default(),
+            mints: vec![Pubkey::default(), Pubkey::default(), Pubkey::default()],
+            reserved: [0; 32],
+        };
+
+        // Act
+        let actual_space = DISCRIMINATOR_SIZE + mint_denylist.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+
+    #[test]
+    fn test_add_mints_keeps_mints_sorted() {
+        // Arrange
+        let mut mint_denylist = MintDenylist {
+            registrar: Pubkey::default(),
+            mints: vec![],
+            reserved: [0; 32],
+        };
+
+        let mints = (0..10)
+            .map(|_| Pubkey::new_unique())
+            .rev()
+            .collect::<Vec<_>>();
+
+        // Act
+        mint_denylist.add_mints(&mints).unwrap();
+
+        // Assert
+        let mut sorted_mints = mints.clone();
+        sorted_mints.sort();
+
+        assert_eq!(mint_denylist.mints, sorted_mints);
+    }
+
+    #[test]
+    fn test_add_mints_with_duplicated_mint_error() {
+        // Arrange
+        let mint = Pubkey::new_unique();
+
+        let mut mint_denylist = MintDenylist {
+            registrar: Pubkey::default(),
+            mints: vec![mint],
+            reserved: [0; 32],
+        };
+
+        // Act
+        let result = mint_denylist.add_mints(&[mint]);
+
+        // Assert
+        assert_eq!(result, Err(NftVoterError::InvalidDeniedMint.into()));
+    }
+
+    #[test]
+    fn test_assert_nft_mint_not_denied() {
+        // Arrange
+        let registrar = Pubkey::new_unique();
+        let mint_denylist_address = Pubkey::new_unique();
+        let program_id = id();
+        let mut lamports = 0;
+
+        let mut mint_denylist = MintDenylist {
+            registrar,
+            mints: vec![],
+            reserved: [0; 32],
+        };
+
+        let denied_mints = (0..100).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+        mint_denylist.add_mints(&denied_mints).unwrap();
+
+        let mut data = vec![];
+        mint_denylist.try_serialize(&mut data).unwrap();
+
+        let mint_denylist_info = AccountInfo::new(
+            &mint_denylist_address,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+
+        // Act + Assert
+        for mint in denied_mints.iter() {
+            assert_eq!(
+                assert_nft_mint_not_denied(&mint_denylist_info, &registrar, mint),
+                Err(NftVoterError::NftMintDenied.into())
+            );
+        }
+
+        assert!(
+            assert_nft_mint_not_denied(&mint_denylist_info, &registrar, &Pubkey::new_unique())
+                .is_ok()
+        );
+
+        assert_eq!(
+            assert_nft_mint_not_denied(
+                &mint_denylist_info,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique()
+            ),
+            Err(NftVoterError::InvalidMintDenylist.into())
+        );
+    }
+}
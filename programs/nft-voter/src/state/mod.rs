@@ -4,12 +4,69 @@ pub mod registrar;
 pub use collection_config::*;
 pub mod collection_config;
 
+pub use collection_weight_overrides::*;
+pub mod collection_weight_overrides;
+
+pub use escrow_program_config::*;
+pub mod escrow_program_config;
+
+pub use conditional_weight_config::*;
+pub mod conditional_weight_config;
+
+pub use mint_denylist::*;
+pub mod mint_denylist;
+
+pub use nft_holder_registration::*;
+pub mod nft_holder_registration;
+
+pub use nft_tally_record::*;
+pub mod nft_tally_record;
+
+pub use programmable_nft_policy::*;
+pub mod programmable_nft_policy;
+
+pub use cast_nft_vote_layout::*;
+pub mod cast_nft_vote_layout;
+
+pub use compressed_nft_asset::*;
+pub mod compressed_nft_asset;
+
 pub use nft_vote_record::*;
 pub mod nft_vote_record;
 
+pub use vote_tracking_mode::*;
+pub mod vote_tracking_mode;
+
+pub use nft_index_map::*;
+pub mod nft_index_map;
+
+pub use vote_bitmap::*;
+pub mod vote_bitmap;
+
+pub use nft_deposit_record::*;
+pub mod nft_deposit_record;
+
+pub use nft_vote_delegation::*;
+pub mod nft_vote_delegation;
+
 pub mod max_voter_weight_record;
 
 pub use voter_weight_record::*;
 pub mod voter_weight_record;
 
+pub use voter_weight_tally::*;
+pub mod voter_weight_tally;
+
+pub use voter_weight_caps::*;
+pub mod voter_weight_caps;
+
+pub use voter_weighting_mode::*;
+pub mod voter_weighting_mode;
+
+pub use voter_weight_cap_tally::*;
+pub mod voter_weight_cap_tally;
+
+pub use voter_weight_quote::*;
+pub mod voter_weight_quote;
+
 pub mod idl_types;
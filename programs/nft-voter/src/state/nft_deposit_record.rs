@@ -0,0 +1,328 @@
+use crate::error::NftVoterError;
+use crate::id;
+use crate::state::{create_nft_vote_record, is_nft_mint_denied, NftVoteInfo, Registrar};
+use anchor_lang::prelude::*;
+use gpl_voter_weight_plugin::space::{DISCRIMINATOR_SIZE, PUBKEY_SIZE};
+use solana_program::clock::UnixTimestamp;
+use spl_governance::state::proposal::get_proposal_data;
+
+/// NFT locked in the NftDepositRecord
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq)]
+pub struct LockedNft {
+    /// The mint of the NFT
+    pub mint: Pubkey,
+
+    /// The verified collection of the NFT
+    pub collection: Pubkey,
+
+    /// Governance power weight of the NFT when it was deposited
+    pub weight: u64,
+
+    /// The time when the NFT was deposited
+    // Note: UnixTimestamp alias is not resolved by Anchor IDL and i64 is used instead
+    pub deposited_at: i64,
+}
+
+impl LockedNft {
+    pub const SPACE: usize = PUBKEY_SIZE + PUBKEY_SIZE + 8 + 8;
+}
+
+/// Deposit record of the NFTs the voter locked in the program vaults
+/// The PDA of the record is ["nft-deposit-record",registrar,governing_token_owner]
+///
+/// The locked NFTs give the voter persistent governance power and they don't have to be supplied
+/// with their token and metadata accounts when the voter casts vote or updates voter_weight
+/// Every NFT is held in its own vault token account with PDA ["nft-vault",registrar,nft_mint]
+/// owned by the locker authority PDA ["nft-locker-authority",registrar]
+#[account]
+#[derive(Debug, PartialEq)]
+pub struct NftDepositRecord {
+    /// Registrar the deposit record belongs to
+    pub registrar: Pubkey,
+
+    /// The voter who deposited the NFTs
+    pub governing_token_owner: Pubkey,
+
+    /// The total weight of the locked NFTs
+    pub total_weight: u64,
+
+    /// The NFTs locked in the vaults
+    pub locked_nfts: Vec<LockedNft>,
+
+    /// The number of the Proposals the locked NFTs are counted for with NftVoteRecord of the deposit record
+    /// The NFTs can't be withdrawn until the NftVoteRecords are relinquished
+    pub nft_vote_record_count: u32,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 28],
+}
+
+impl NftDepositRecord {
+    pub fn get_space(max_nfts: u16) -> usize {
+        DISCRIMINATOR_SIZE
+            + PUBKEY_SIZE
+            + PUBKEY_SIZE
+            + 8
+            + 4
+            + max_nfts as usize * LockedNft::SPACE
+            + 4
+            + 28
+    }
+
+    /// Returns the max number of NFTs the deposit record account can store
+    pub fn get_max_nfts(account_size: usize) -> usize {
+        account_size.saturating_sub(Self::get_space(0)) / LockedNft::SPACE
+    }
+
+    /// Adds the NFT to the locked NFTs
+    pub fn add_locked_nft(&mut self, locked_nft: LockedNft, account_size: usize) -> Result<()> {
+        require!(
+            self.locked_nfts.len() < Self::get_max_nfts(account_size),
+            NftVoterError::NftDepositRecordIsFull
+        );
+
+        self.total_weight = self.total_weight.checked_add(locked_nft.weight).unwrap();
+        self.locked_nfts.push(locked_nft);
+
+        Ok(())
+    }
+
+    /// Removes the NFT with the given mint from the locked NFTs
+    pub fn remove_locked_nft(&mut self, nft_mint: &Pubkey) -> Result<LockedNft> {
+        let index = self
+            .locked_nfts
+            .iter()
+            .position(|locked_nft| locked_nft.mint == *nft_mint)
+            .ok_or(NftVoterError::NftNotDeposited)?;
+
+        let locked_nft = self.locked_nfts.remove(index);
+        self.total_weight = self.total_weight.checked_sub(locked_nft.weight).unwrap();
+
+        Ok(locked_nft)
+    }
+
+    /// Returns vote info of the locked NFTs which can be used to vote
+    ///
+    /// If deposited_before is provided then only the NFTs deposited before that time are returned
+    /// NFTs from collections no longer configured on the Registrar and NFTs on the MintDenylist are skipped
+    pub fn get_nft_vote_infos(
+        &self,
+        registrar: &Registrar,
+        mint_denylist_info: &AccountInfo,
+        registrar_key: &Pubkey,
+        deposited_before: Option<UnixTimestamp>,
+    ) -> Result<Vec<NftVoteInfo>> {
+        let mut nft_vote_infos = vec![];
+
+        for locked_nft in self.locked_nfts.iter() {
+            if deposited_before.map_or(false, |voting_at| locked_nft.deposited_at >= voting_at)
+                || registrar
+                    .get_collection_config(locked_nft.collection)
+                    .is_err()
+                || is_nft_mint_denied(mint_denylist_info, registrar_key, &locked_nft.mint)?
+            {
+                continue;
+            }
+
+            nft_vote_infos.push(NftVoteInfo {
+                weight: locked_nft.weight,
+                mint: locked_nft.mint,
+                collection: locked_nft.collection,
+                token_standard: None,
+            });
+        }
+
+        Ok(nft_vote_infos)
+    }
+}
+
+/// Returns NftDepositRecord PDA seeds
+pub fn get_nft_deposit_record_seeds<'a>(
+    registrar: &'a Pubkey,
+    governing_token_owner: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        b"nft-deposit-record",
+        registrar.as_ref(),
+        governing_token_owner.as_ref(),
+    ]
+}
+
+/// Returns NftDepositRecord PDA address
+pub fn get_nft_deposit_record_address(
+    registrar: &Pubkey,
+    governing_token_owner: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_nft_deposit_record_seeds(registrar, governing_token_owner),
+        &id(),
+    )
+    .0
+}
+
+/// Returns the NFT locker authority PDA seeds
+pub fn get_nft_locker_authority_seeds(registrar: &Pubkey) -> [&[u8]; 2] {
+    [b"nft-locker-authority", registrar.as_ref()]
+}
+
+/// Returns the NFT locker authority PDA address
+pub fn get_nft_locker_authority_address(registrar: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&get_nft_locker_authority_seeds(registrar), &id()).0
+}
+
+/// Returns the NFT vault PDA seeds
+pub fn get_nft_vault_seeds<'a>(registrar: &'a Pubkey, nft_mint: &'a Pubkey) -> [&'a [u8]; 3] {
+    [b"nft-vault", registrar.as_ref(), nft_mint.as_ref()]
+}
+
+/// Returns the NFT vault PDA address
+pub fn get_nft_vault_address(registrar: &Pubkey, nft_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&get_nft_vault_seeds(registrar, nft_mint), &id()).0
+}
+
+/// Resolves vote info of the NFTs locked in the voter's NftDepositRecord when the vote is cast
+///
+/// The locked NFTs are counted once per Proposal and the NftVoteRecord with PDA ["nft-vote-record",proposal,nft_deposit_record]
+/// is created to record it. If the record already exists then the NFTs were counted by a previous CastNftVote
+/// The created record is counted in NftDepositRecord.nft_vote_record_count and the NFTs can't be withdrawn until it's relinquished
+///
+/// Only the NFTs deposited before the Proposal voting started are counted
+/// It prevents NFTs which voted directly on the Proposal from being deposited and voting again
+#[allow(clippy::too_many_arguments)]
+pub fn resolve_nft_deposit_vote_infos<'info>(
+    registrar: &Account<'info, Registrar>,
+    nft_deposit_record_info: &AccountInfo<'info>,
+    nft_deposit_vote_record_info: &AccountInfo<'info>,
+    mint_denylist_info: &AccountInfo<'info>,
+    proposal_info: &AccountInfo<'info>,
+    governing_token_owner: &Pubkey,
+    payer_info: &AccountInfo<'info>,
+    system_info: &AccountInfo<'info>,
+    rent: &Rent,
+) -> Result<Vec<NftVoteInfo>> {
+    if nft_deposit_record_info.data_is_empty() || !nft_deposit_vote_record_info.data_is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut nft_deposit_record = Account::<NftDepositRecord>::try_from(nft_deposit_record_info)?;
+
+    let voting_at = get_proposal_data(&registrar.governance_program_id, proposal_info)?
+        .voting_at
+        .ok_or(NftVoterError::ProposalVotingNotStarted)?;
+
+    create_nft_vote_record(
+        payer_info,
+        nft_deposit_vote_record_info,
+        proposal_info.key,
+        nft_deposit_record_info.key,
+        governing_token_owner,
+        system_info,
+        rent,
+    )?;
+
+    nft_deposit_record.nft_vote_record_count = nft_deposit_record
+        .nft_vote_record_count
+        .checked_add(1)
+        .unwrap();
+
+    nft_deposit_record.exit(&id())?;
+
+    nft_deposit_record.get_nft_vote_infos(
+        registrar,
+        mint_denylist_info,
+        &registrar.key(),
+        Some(voting_at),
+    )
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    fn create_test_nft_deposit_record() -> NftDepositRecord {
+        NftDepositRecord {
+            registrar: Pubkey::new_unique(),
+            governing_token_owner: Pubkey::new_unique(),
+            total_weight: 0,
+            locked_nfts: vec![],
+            nft_vote_record_count: 0,
+            reserved: [0; 28],
+        }
+    }
+
+    fn create_test_locked_nft(weight: u64) -> LockedNft {
+        LockedNft {
+            mint: Pubkey::new_unique(),
+            collection: Pubkey::new_unique(),
+            weight,
+            deposited_at: 10,
+        }
+    }
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = NftDepositRecord::get_space(3);
+
+        let nft_deposit_record = NftDepositRecord {
+            locked_nfts: vec![
+                create_test_locked_nft(1),
+                create_test_locked_nft(1),
+                create_test_locked_nft(1),
+            ],
+            ..create_test_nft_deposit_record()
+        };
+
+        // Act
+        let actual_space = DISCRIMINATOR_SIZE + nft_deposit_record.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+        assert_eq!(NftDepositRecord::get_max_nfts(actual_space), 3);
+    }
+
+    #[test]
+    fn test_add_and_remove_locked_nft() {
+        // Arrange
+        let mut nft_deposit_record = create_test_nft_deposit_record();
+        let account_size = NftDepositRecord::get_space(2);
+
+        let locked_nft1 = create_test_locked_nft(10);
+        let locked_nft2 = create_test_locked_nft(5);
+
+        // Act
+        nft_deposit_record
+            .add_locked_nft(locked_nft1.clone(), account_size)
+            .unwrap();
+        nft_deposit_record
+            .add_locked_nft(locked_nft2, account_size)
+            .unwrap();
+
+        let removed_nft = nft_deposit_record
+            .remove_locked_nft(&locked_nft1.mint)
+            .unwrap();
+
+        // Assert
+        assert_eq!(removed_nft, locked_nft1);
+        assert_eq!(nft_deposit_record.total_weight, 5);
+        assert_eq!(nft_deposit_record.locked_nfts.len(), 1);
+    }
+
+    #[test]
+    fn test_add_locked_nft_with_full_record_error() {
+        // Arrange
+        let mut nft_deposit_record = create_test_nft_deposit_record();
+        let account_size = NftDepositRecord::get_space(1);
+
+        nft_deposit_record
+            .add_locked_nft(create_test_locked_nft(1), account_size)
+            .unwrap();
+
+        // Act
+        let result = nft_deposit_record.add_locked_nft(create_test_locked_nft(1), account_size);
+
+        // Assert
+        assert_eq!(result, Err(NftVoterError::NftDepositRecordIsFull.into()));
+    }
+}
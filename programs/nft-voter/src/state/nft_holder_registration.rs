@@ -0,0 +1,214 @@
+use anchor_lang::prelude::*;
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use solana_program::{clock::UnixTimestamp, program_pack::IsInitialized};
+
+use spl_governance::state::proposal::get_proposal_data;
+use spl_governance_tools::account::{
+    create_and_serialize_account_signed, get_account_data, AccountMaxSize,
+};
+
+use crate::{error::NftVoterError, id, state::Registrar};
+
+/// Registration of the holder of an NFT used to enforce the ownership snapshot
+/// The PDA of the registration is ["nft-holder-registration",registrar,nft_mint]
+///
+/// The registration records when the current holder of the NFT was first observed holding it
+/// It can be refreshed by the holder or by a permissionless crank and it's reset whenever the NFT changes hands
+/// When the Registrar has the ownership snapshot enabled only NFTs registered to the voter
+/// before the Proposal voting started can vote on the Proposal
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct NftHolderRegistration {
+    /// NftHolderRegistration discriminator sha256("account:NftHolderRegistration")[..8]
+    /// Note: The discriminator is used explicitly because the registrations
+    /// are created dynamically using remaining_accounts the same way as NftVoteRecord
+    pub account_discriminator: [u8; 8],
+
+    /// Registrar the registration belongs to
+    pub registrar: Pubkey,
+
+    /// The mint of the NFT
+    /// For compressed NFTs it's the asset id of the NFT
+    pub nft_mint: Pubkey,
+
+    /// The holder of the NFT
+    pub holder: Pubkey,
+
+    /// The time when the holder was first observed holding the NFT
+    pub registered_at: UnixTimestamp,
+
+    /// The slot when the holder was first observed holding the NFT
+    pub registered_at_slot: u64,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 8],
+}
+
+impl NftHolderRegistration {
+    /// sha256("account:NftHolderRegistration")[..8]
+    pub const ACCOUNT_DISCRIMINATOR: [u8; 8] = [16, 81, 74, 167, 83, 99, 57, 138];
+}
+
+impl AccountMaxSize for NftHolderRegistration {}
+
+impl IsInitialized for NftHolderRegistration {
+    fn is_initialized(&self) -> bool {
+        self.account_discriminator == NftHolderRegistration::ACCOUNT_DISCRIMINATOR
+    }
+}
+
+/// Returns NftHolderRegistration PDA seeds
+pub fn get_nft_holder_registration_seeds<'a>(
+    registrar: &'a Pubkey,
+    nft_mint: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        b"nft-holder-registration",
+        registrar.as_ref(),
+        nft_mint.as_ref(),
+    ]
+}
+
+/// Returns NftHolderRegistration PDA address
+pub fn get_nft_holder_registration_address(registrar: &Pubkey, nft_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_nft_holder_registration_seeds(registrar, nft_mint),
+        &id(),
+    )
+    .0
+}
+
+/// Deserializes account and checks owner program
+pub fn get_nft_holder_registration_data(
+    nft_holder_registration_info: &AccountInfo,
+) -> Result<NftHolderRegistration> {
+    Ok(get_account_data::<NftHolderRegistration>(
+        &id(),
+        nft_holder_registration_info,
+    )?)
+}
+
+/// Registers the current holder of the NFT
+/// The registration is created if it doesn't exist and it's reset if the NFT changed hands since it was registered
+/// If the NFT is still held by the registered holder then the registration is left unchanged
+///
+/// Note: The caller must verify the holder currently owns the NFT
+#[allow(clippy::too_many_arguments)]
+pub fn register_nft_holder<'a>(
+    payer_info: &AccountInfo<'a>,
+    nft_holder_registration_info: &AccountInfo<'a>,
+    registrar: &Pubkey,
+    nft_mint: &Pubkey,
+    holder: &Pubkey,
+    system_info: &AccountInfo<'a>,
+    rent: &Rent,
+    clock: &Clock,
+) -> Result<()> {
+    if nft_holder_registration_info.data_is_empty() {
+        let nft_holder_registration = NftHolderRegistration {
+            account_discriminator: NftHolderRegistration::ACCOUNT_DISCRIMINATOR,
+            registrar: *registrar,
+            nft_mint: *nft_mint,
+            holder: *holder,
+            registered_at: clock.unix_timestamp,
+            registered_at_slot: clock.slot,
+            reserved: [0; 8],
+        };
+
+        // Note: The correct PDA of the registration is validated in create_and_serialize_account_signed
+        create_and_serialize_account_signed(
+            payer_info,
+            nft_holder_registration_info,
+            &nft_holder_registration,
+            &get_nft_holder_registration_seeds(registrar, nft_mint),
+            &id(),
+            system_info,
+            rent,
+        )?;
+
+        return Ok(());
+    }
+
+    let mut nft_holder_registration = get_nft_holder_registration_data_for_nft_mint(
+        nft_holder_registration_info,
+        registrar,
+        nft_mint,
+    )?;
+
+    if nft_holder_registration.holder != *holder {
+        nft_holder_registration.holder = *holder;
+        nft_holder_registration.registered_at = clock.unix_timestamp;
+        nft_holder_registration.registered_at_slot = clock.slot;
+
+        nft_holder_registration.serialize(&mut *nft_holder_registration_info.data.borrow_mut())?;
+    }
+
+    Ok(())
+}
+
+/// Deserializes the registration and checks it's the registration of the given NFT
+pub fn get_nft_holder_registration_data_for_nft_mint(
+    nft_holder_registration_info: &AccountInfo,
+    registrar: &Pubkey,
+    nft_mint: &Pubkey,
+) -> Result<NftHolderRegistration> {
+    let nft_holder_registration = get_nft_holder_registration_data(nft_holder_registration_info)?;
+
+    require!(
+        nft_holder_registration.registrar == *registrar
+            && nft_holder_registration.nft_mint == *nft_mint,
+        NftVoterError::InvalidNftHolderRegistration
+    );
+
+    Ok(nft_holder_registration)
+}
+
+/// Returns the time the Proposal voting started if the Registrar has the ownership snapshot enabled
+/// The NFTs used to vote on the Proposal must be registered to the voter before that time
+pub fn get_ownership_snapshot_voting_at(
+    registrar: &Registrar,
+    proposal_info: &AccountInfo,
+) -> Result<Option<UnixTimestamp>> {
+    if !registrar.ownership_snapshot_enabled {
+        return Ok(None);
+    }
+
+    let proposal = get_proposal_data(&registrar.governance_program_id, proposal_info)?;
+
+    let voting_at = proposal
+        .voting_at
+        .ok_or(NftVoterError::ProposalVotingNotStarted)?;
+
+    Ok(Some(voting_at))
+}
+
+/// Asserts the NFT was registered to the voter before the Proposal voting started
+pub fn assert_nft_held_before_voting(
+    nft_holder_registration_info: &AccountInfo,
+    registrar: &Pubkey,
+    nft_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+    voting_at: UnixTimestamp,
+) -> Result<()> {
+    require!(
+        !nft_holder_registration_info.data_is_empty(),
+        NftVoterError::NftHolderNotRegistered
+    );
+
+    let nft_holder_registration = get_nft_holder_registration_data_for_nft_mint(
+        nft_holder_registration_info,
+        registrar,
+        nft_mint,
+    )?;
+
+    require!(
+        nft_holder_registration.holder == *governing_token_owner,
+        NftVoterError::NftHolderNotRegistered
+    );
+
+    require!(
+        nft_holder_registration.registered_at < voting_at,
+        NftVoterError::NftAcquiredAfterVotingStarted
+    );
+
+    Ok(())
+}
@@ -0,0 +1,410 @@
+use crate::error::NftVoterError;
+use crate::id;
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, Discriminator};
+use arrayref::array_ref;
+use gpl_voter_weight_plugin::space::{DISCRIMINATOR_SIZE, PUBKEY_SIZE};
+use std::cmp::Ordering;
+
+/// Index of an NFT in NftIndexMap
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct NftIndex {
+    /// The mint of the NFT
+    /// For compressed NFTs it's the asset id of the NFT
+    pub mint: Pubkey,
+
+    /// The index of the NFT bit in VoteBitmaps
+    pub index: u32,
+}
+
+impl NftIndex {
+    pub const SPACE: usize = PUBKEY_SIZE + 4;
+}
+
+/// Index map of the NFTs from a collection used to track votes with VoteBitmaps
+/// The PDA of the map is ["nft-index-map",registrar,collection]
+///
+/// Every NFT is assigned the next index when it's added to the map, usually its position in the collection
+/// The indexes are stable because they address the NFT bits in VoteBitmaps and NFTs can't be removed from the map
+///
+/// The NFTs are kept sorted by mint to binary search them in the account data without deserializing the whole map
+/// Large collections don't fit into the heap and the map is only read and extended in place
+#[account]
+#[derive(Debug, PartialEq)]
+pub struct NftIndexMap {
+    /// Registrar the map belongs to
+    pub registrar: Pubkey,
+
+    /// The collection of the NFTs
+    pub collection: Pubkey,
+
+    /// Reserved for future upgrades
+    /// Note: The field precedes nfts to extend the account in place when NFTs are added
+    pub reserved: [u8; 32],
+
+    /// NFT indexes sorted by mint
+    pub nfts: Vec<NftIndex>,
+}
+
+impl NftIndexMap {
+    pub fn get_space(nfts_count: usize) -> usize {
+        DISCRIMINATOR_SIZE + PUBKEY_SIZE * 2 + 32 + 4 + nfts_count * NftIndex::SPACE
+    }
+}
+
+/// Offset of the nfts Vec length in NftIndexMap account data
+const NFTS_LEN_OFFSET: usize = DISCRIMINATOR_SIZE + PUBKEY_SIZE * 2 + 32;
+
+/// Offset of the first NftIndex in NftIndexMap account data
+const NFTS_OFFSET: usize = NFTS_LEN_OFFSET + 4;
+
+/// Returns the number of NFTs in NftIndexMap and checks the map belongs to the given Registrar and collection
+pub fn get_nft_index_map_len(
+    nft_index_map_info: &AccountInfo,
+    registrar: &Pubkey,
+    collection: &Pubkey,
+) -> Result<usize> {
+    require!(
+        *nft_index_map_info.owner == id(),
+        NftVoterError::InvalidNftIndexMap
+    );
+
+    let data = nft_index_map_info.try_borrow_data()?;
+
+    require!(
+        data.len() >= NFTS_OFFSET
+            && *array_ref![data, 0, 8] == NftIndexMap::discriminator()
+            && array_ref![data, DISCRIMINATOR_SIZE, PUBKEY_SIZE] == registrar.as_ref()
+            && array_ref![data, DISCRIMINATOR_SIZE + PUBKEY_SIZE, PUBKEY_SIZE]
+                == collection.as_ref(),
+        NftVoterError::InvalidNftIndexMap
+    );
+
+    let nfts_len = u32::from_le_bytes(*array_ref![data, NFTS_LEN_OFFSET, 4]) as usize;
+
+    require!(
+        data.len() >= NFTS_OFFSET + nfts_len * NftIndex::SPACE,
+        NftVoterError::InvalidNftIndexMap
+    );
+
+    Ok(nfts_len)
+}
+
+/// Binary searches the sorted NFT indexes in place
+/// Returns the position of the NFT if it's found or the position where it should be inserted otherwise
+fn search_nft_index(
+    nfts_data: &[u8],
+    nfts_len: usize,
+    nft_mint: &Pubkey,
+) -> std::result::Result<usize, usize> {
+    let (mut low, mut high) = (0, nfts_len);
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let mint = &nfts_data[mid * NftIndex::SPACE..mid * NftIndex::SPACE + PUBKEY_SIZE];
+
+        match mint.cmp(nft_mint.as_ref()) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return Ok(mid),
+        }
+    }
+
+    Err(low)
+}
+
+/// Returns the index of the given NFT mint (or compressed NFT asset id) from the NftIndexMap of its collection
+pub fn get_nft_index(
+    nft_index_map_info: &AccountInfo,
+    registrar: &Pubkey,
+    collection: &Pubkey,
+    nft_mint: &Pubkey,
+) -> Result<u32> {
+    let nfts_len = get_nft_index_map_len(nft_index_map_info, registrar, collection)?;
+
+    let data = nft_index_map_info.try_borrow_data()?;
+    let nfts_data = &data[NFTS_OFFSET..];
+
+    let position = search_nft_index(nfts_data, nfts_len, nft_mint)
+        .map_err(|_| NftVoterError::NftNotInIndexMap)?;
+
+    Ok(u32::from_le_bytes(*array_ref![
+        nfts_data,
+        position * NftIndex::SPACE + PUBKEY_SIZE,
+        4
+    ]))
+}
+
+/// Extends NftIndexMap account data to fit the given number of new NFTs
+/// The payer tops up the rent of the extended account
+///
+/// Note: The account is resized in place because Anchor realloc constraint requires the map to be deserialized
+pub fn extend_nft_index_map<'a>(
+    nft_index_map_info: &AccountInfo<'a>,
+    registrar: &Pubkey,
+    collection: &Pubkey,
+    new_nfts_count: usize,
+    payer_info: &AccountInfo<'a>,
+    system_info: &AccountInfo<'a>,
+    rent: &Rent,
+) -> Result<()> {
+    get_nft_index_map_len(nft_index_map_info, registrar, collection)?;
+
+    let new_space = nft_index_map_info.data_len() + new_nfts_count * NftIndex::SPACE;
+    let rent_minimum = rent.minimum_balance(new_space);
+
+    if rent_minimum > nft_index_map_info.lamports() {
+        system_program::transfer(
+            CpiContext::new(
+                system_info.clone(),
+                system_program::Transfer {
+                    from: payer_info.clone(),
+                    to: nft_index_map_info.clone(),
+                },
+            ),
+            rent_minimum - nft_index_map_info.lamports(),
+        )?;
+    }
+
+    nft_index_map_info.realloc(new_space, true)?;
+
+    Ok(())
+}
+
+/// Adds the given mints to NftIndexMap and assigns them the next indexes in the given order
+/// The mints are inserted in place into the sorted account data and the account must be already resized to fit them
+///
+/// The number of NFTs in the map can't exceed max_nfts which is the size of the collection
+pub fn insert_nft_index_map_mints(
+    nft_index_map_info: &AccountInfo,
+    registrar: &Pubkey,
+    collection: &Pubkey,
+    mints: &[Pubkey],
+    max_nfts: u64,
+) -> Result<()> {
+    let mut nfts_len = get_nft_index_map_len(nft_index_map_info, registrar, collection)?;
+
+    let mut data = nft_index_map_info.try_borrow_mut_data()?;
+
+    require!(
+        data.len() >= NFTS_OFFSET + (nfts_len + mints.len()) * NftIndex::SPACE,
+        NftVoterError::InvalidNftIndexMap
+    );
+
+    for mint in mints {
+        require!(
+            (nfts_len as u64) < max_nfts,
+            NftVoterError::NftIndexMapIsFull
+        );
+
+        let nfts_data = &mut data[NFTS_OFFSET..];
+
+        let position = match search_nft_index(nfts_data, nfts_len, mint) {
+            Ok(_) => return err!(NftVoterError::NftAlreadyInIndexMap),
+            Err(position) => position,
+        };
+
+        // Shift the NFTs following the position to make space for the new NFT
+        nfts_data.copy_within(
+            position * NftIndex::SPACE..nfts_len * NftIndex::SPACE,
+            (position + 1) * NftIndex::SPACE,
+        );
+
+        let nft_index = NftIndex {
+            mint: *mint,
+            index: nfts_len as u32,
+        };
+
+        nfts_data[position * NftIndex::SPACE..(position + 1) * NftIndex::SPACE]
+            .copy_from_slice(&nft_index.try_to_vec()?);
+
+        nfts_len += 1;
+    }
+
+    data[NFTS_LEN_OFFSET..NFTS_OFFSET].copy_from_slice(&(nfts_len as u32).to_le_bytes());
+
+    Ok(())
+}
+
+/// Returns NftIndexMap PDA seeds
+pub fn get_nft_index_map_seeds<'a>(registrar: &'a Pubkey, collection: &'a Pubkey) -> [&'a [u8]; 3] {
+    [b"nft-index-map", registrar.as_ref(), collection.as_ref()]
+}
+
+/// Returns NftIndexMap PDA address
+pub fn get_nft_index_map_address(registrar: &Pubkey, collection: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&get_nft_index_map_seeds(registrar, collection), &id()).0
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    fn create_test_nft_index_map_data(
+        registrar: &Pubkey,
+        collection: &Pubkey,
+        nfts_count: usize,
+    ) -> Vec<u8> {
+        let nft_index_map = NftIndexMap {
+            registrar: *registrar,
+            collection: *collection,
+            reserved: [0; 32],
+            nfts: vec![],
+        };
+
+        let mut data = vec![];
+        nft_index_map.try_serialize(&mut data).unwrap();
+        data.resize(NftIndexMap::get_space(nfts_count), 0);
+
+        data
+    }
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = NftIndexMap::get_space(2);
+
+        let nft_index_map = NftIndexMap {
+            registrar: Pubkey::default(),
+            collection: Pubkey::default(),
+            reserved: [0; 32],
+            nfts: vec![
+                NftIndex {
+                    mint: Pubkey::default(),
+                    index: 0,
+                },
+                NftIndex {
+                    mint: Pubkey::default(),
+                    index: 1,
+                },
+            ],
+        };
+
+        // Act
+        let actual_space = DISCRIMINATOR_SIZE + nft_index_map.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+
+    #[test]
+    fn test_insert_nft_index_map_mints() {
+        // Arrange
+        let registrar = Pubkey::new_unique();
+        let collection = Pubkey::new_unique();
+        let program_id = id();
+
+        let mints = (0..10)
+            .map(|_| Pubkey::new_unique())
+            .rev()
+            .collect::<Vec<_>>();
+
+        let mut data = create_test_nft_index_map_data(&registrar, &collection, mints.len());
+        let mut lamports = 0;
+        let key = Pubkey::new_unique();
+
+        let nft_index_map_info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+
+        // Act
+        insert_nft_index_map_mints(&nft_index_map_info, &registrar, &collection, &mints, 10)
+            .unwrap();
+
+        // Assert
+        for (index, mint) in mints.iter().enumerate() {
+            assert_eq!(
+                get_nft_index(&nft_index_map_info, &registrar, &collection, mint).unwrap(),
+                index as u32
+            );
+        }
+
+        let nft_index_map =
+            NftIndexMap::try_deserialize(&mut &nft_index_map_info.data.borrow()[..]).unwrap();
+
+        assert!(nft_index_map
+            .nfts
+            .windows(2)
+            .all(|nfts| nfts[0].mint < nfts[1].mint));
+    }
+
+    #[test]
+    fn test_insert_nft_index_map_mints_with_existing_mint_error() {
+        // Arrange
+        let registrar = Pubkey::new_unique();
+        let collection = Pubkey::new_unique();
+        let program_id = id();
+
+        let mint = Pubkey::new_unique();
+
+        let mut data = create_test_nft_index_map_data(&registrar, &collection, 2);
+        let mut lamports = 0;
+        let key = Pubkey::new_unique();
+
+        let nft_index_map_info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+
+        // Act
+        let result = insert_nft_index_map_mints(
+            &nft_index_map_info,
+            &registrar,
+            &collection,
+            &[mint, mint],
+            10,
+        );
+
+        // Assert
+        assert_eq!(result, Err(NftVoterError::NftAlreadyInIndexMap.into()));
+    }
+
+    #[test]
+    fn test_insert_nft_index_map_mints_with_full_map_error() {
+        // Arrange
+        let registrar = Pubkey::new_unique();
+        let collection = Pubkey::new_unique();
+        let program_id = id();
+
+        let mut data = create_test_nft_index_map_data(&registrar, &collection, 2);
+        let mut lamports = 0;
+        let key = Pubkey::new_unique();
+
+        let nft_index_map_info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+
+        // Act
+        let result = insert_nft_index_map_mints(
+            &nft_index_map_info,
+            &registrar,
+            &collection,
+            &[Pubkey::new_unique(), Pubkey::new_unique()],
+            1,
+        );
+
+        // Assert
+        assert_eq!(result, Err(NftVoterError::NftIndexMapIsFull.into()));
+    }
+}
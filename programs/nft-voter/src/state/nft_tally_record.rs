@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use solana_program::program_pack::IsInitialized;
+
+use spl_governance_tools::account::{
+    create_and_serialize_account_signed, get_account_data, AccountMaxSize,
+};
+
+use crate::{error::NftVoterError, id, state::VOTER_WEIGHT_TALLY_MAX_SLOTS};
+
+/// Record of the VoterWeightTally which counted the NFT
+/// The PDA of the record is ["nft-tally-record",registrar,nft_mint]
+///
+/// It ensures an NFT counted by a VoterWeightTally can't be counted by another voter's VoterWeightTally
+/// until the VoterWeightTally which counted it expires
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct NftTallyRecord {
+    /// NftTallyRecord discriminator sha256("account:NftTallyRecord")[..8]
+    /// Note: The discriminator is used explicitly because the records
+    /// are created dynamically using remaining_accounts the same way as NftVoteRecord
+    pub account_discriminator: [u8; 8],
+
+    /// Registrar the record belongs to
+    pub registrar: Pubkey,
+
+    /// The mint of the NFT
+    pub nft_mint: Pubkey,
+
+    /// The VoterWeightTally which counted the NFT
+    pub voter_weight_tally: Pubkey,
+
+    /// The start_slot of the VoterWeightTally when it counted the NFT
+    pub tally_start_slot: u64,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 8],
+}
+
+impl NftTallyRecord {
+    /// sha256("account:NftTallyRecord")[..8]
+    pub const ACCOUNT_DISCRIMINATOR: [u8; 8] = [204, 119, 217, 240, 75, 179, 220, 131];
+
+    /// Returns true if the NFT is counted by a VoterWeightTally which hasn't expired yet
+    pub fn is_tally_active(&self, current_slot: u64) -> bool {
+        current_slot
+            <= self
+                .tally_start_slot
+                .saturating_add(VOTER_WEIGHT_TALLY_MAX_SLOTS)
+    }
+}
+
+impl AccountMaxSize for NftTallyRecord {}
+
+impl IsInitialized for NftTallyRecord {
+    fn is_initialized(&self) -> bool {
+        self.account_discriminator == NftTallyRecord::ACCOUNT_DISCRIMINATOR
+    }
+}
+
+/// Returns NftTallyRecord PDA seeds
+pub fn get_nft_tally_record_seeds<'a>(
+    registrar: &'a Pubkey,
+    nft_mint: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [b"nft-tally-record", registrar.as_ref(), nft_mint.as_ref()]
+}
+
+/// Returns NftTallyRecord PDA address
+pub fn get_nft_tally_record_address(registrar: &Pubkey, nft_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&get_nft_tally_record_seeds(registrar, nft_mint), &id()).0
+}
+
+/// Deserializes account and checks owner program
+pub fn get_nft_tally_record_data(nft_tally_record_info: &AccountInfo) -> Result<NftTallyRecord> {
+    Ok(get_account_data::<NftTallyRecord>(
+        &id(),
+        nft_tally_record_info,
+    )?)
+}
+
+/// Records the NFT is counted by the given VoterWeightTally
+/// The record is created if it doesn't exist and it's taken over if the VoterWeightTally which counted the NFT expired
+/// NFTs counted by another VoterWeightTally which is still active are rejected
+#[allow(clippy::too_many_arguments)]
+pub fn record_nft_tally<'a>(
+    payer_info: &AccountInfo<'a>,
+    nft_tally_record_info: &AccountInfo<'a>,
+    registrar: &Pubkey,
+    nft_mint: &Pubkey,
+    voter_weight_tally: &Pubkey,
+    tally_start_slot: u64,
+    system_info: &AccountInfo<'a>,
+    rent: &Rent,
+    current_slot: u64,
+) -> Result<()> {
+    if nft_tally_record_info.data_is_empty() {
+        let nft_tally_record = NftTallyRecord {
+            account_discriminator: NftTallyRecord::ACCOUNT_DISCRIMINATOR,
+            registrar: *registrar,
+            nft_mint: *nft_mint,
+            voter_weight_tally: *voter_weight_tally,
+            tally_start_slot,
+            reserved: [0; 8],
+        };
+
+        // Note: The correct PDA of the record is validated in create_and_serialize_account_signed
+        create_and_serialize_account_signed(
+            payer_info,
+            nft_tally_record_info,
+            &nft_tally_record,
+            &get_nft_tally_record_seeds(registrar, nft_mint),
+            &id(),
+            system_info,
+            rent,
+        )?;
+
+        return Ok(());
+    }
+
+    let mut nft_tally_record = get_nft_tally_record_data(nft_tally_record_info)?;
+
+    require!(
+        nft_tally_record.registrar == *registrar && nft_tally_record.nft_mint == *nft_mint,
+        NftVoterError::InvalidNftTallyRecord
+    );
+
+    require!(
+        nft_tally_record.voter_weight_tally == *voter_weight_tally
+            || !nft_tally_record.is_tally_active(current_slot),
+        NftVoterError::NftAlreadyTallied
+    );
+
+    nft_tally_record.voter_weight_tally = *voter_weight_tally;
+    nft_tally_record.tally_start_slot = tally_start_slot;
+
+    nft_tally_record.serialize(&mut *nft_tally_record_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_is_tally_active() {
+        // Arrange
+        let nft_tally_record = NftTallyRecord {
+            account_discriminator: NftTallyRecord::ACCOUNT_DISCRIMINATOR,
+            registrar: Pubkey::default(),
+            nft_mint: Pubkey::default(),
+            voter_weight_tally: Pubkey::default(),
+            tally_start_slot: 10,
+            reserved: [0; 8],
+        };
+
+        // Act + Assert
+        assert!(nft_tally_record.is_tally_active(10));
+        assert!(nft_tally_record.is_tally_active(10 + VOTER_WEIGHT_TALLY_MAX_SLOTS));
+        assert!(!nft_tally_record.is_tally_active(11 + VOTER_WEIGHT_TALLY_MAX_SLOTS));
+    }
+}
@@ -0,0 +1,202 @@
+use crate::error::NftVoterError;
+use crate::id;
+use crate::state::{NftVoteInfo, Registrar};
+use anchor_lang::prelude::*;
+use gpl_voter_weight_plugin::space::{DISCRIMINATOR_SIZE, PUBKEY_SIZE};
+use solana_program::clock::UnixTimestamp;
+use spl_governance::state::token_owner_record;
+
+/// Delegation of the NFT voting power of the governing_token_owner to the delegate
+/// The PDA of the delegation is ["nft-vote-delegation",registrar,governing_token_owner]
+///
+/// The delegate can sign CastNftVote on behalf of the owner and the NftVoteRecords still record the owner as the voter
+/// The delegation can be scoped to the NFTs from a single collection and it can expire
+///
+/// Note: The delegation only applies to the nft-voter instructions and spl-gov CastVote must still be signed
+/// by the owner or TokenOwnerRecord.governance_delegate
+/// For the delegate to vote without the owner's signature the owner has to set the delegate as the governance_delegate as well
+#[account]
+#[derive(Debug, PartialEq)]
+pub struct NftVoteDelegation {
+    /// Registrar the delegation belongs to
+    pub registrar: Pubkey,
+
+    /// The owner of the NFTs who delegated the voting power
+    pub governing_token_owner: Pubkey,
+
+    /// The delegate who can vote with the owner's NFTs
+    pub delegate: Pubkey,
+
+    /// If set then the delegate can only vote with the NFTs from the collection
+    pub collection: Option<Pubkey>,
+
+    /// If set then the delegation expires at the given time
+    // Note: UnixTimestamp alias is not resolved by Anchor IDL and i64 is used instead
+    pub expiry: Option<i64>,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 32],
+}
+
+impl NftVoteDelegation {
+    pub fn get_space() -> usize {
+        DISCRIMINATOR_SIZE + PUBKEY_SIZE * 3 + 1 + PUBKEY_SIZE + 1 + 8 + 32
+    }
+
+    /// Returns true if the delegation expired at the given time
+    pub fn is_expired(&self, unix_timestamp: UnixTimestamp) -> bool {
+        self.expiry.map_or(false, |expiry| unix_timestamp >= expiry)
+    }
+}
+
+/// Returns NftVoteDelegation PDA seeds
+pub fn get_nft_vote_delegation_seeds<'a>(
+    registrar: &'a Pubkey,
+    governing_token_owner: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        b"nft-vote-delegation",
+        registrar.as_ref(),
+        governing_token_owner.as_ref(),
+    ]
+}
+
+/// Returns NftVoteDelegation PDA address
+pub fn get_nft_vote_delegation_address(
+    registrar: &Pubkey,
+    governing_token_owner: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_nft_vote_delegation_seeds(registrar, governing_token_owner),
+        &id(),
+    )
+    .0
+}
+
+/// Resolves the collection the voter_authority can vote with on behalf of the governing_token_owner
+/// None is returned if the voter_authority can vote with all the owner's NFTs
+///
+/// The voter_authority must be the governing_token_owner, the delegate from the owner's NftVoteDelegation
+/// or the governance_delegate from the owner's spl-gov TokenOwnerRecord
+pub fn resolve_voter_authority_collection(
+    registrar: &Registrar,
+    governing_token_owner: &Pubkey,
+    voter_authority: &Pubkey,
+    nft_vote_delegation_info: &AccountInfo,
+    token_owner_record_info: &AccountInfo,
+) -> Result<Option<Pubkey>> {
+    if voter_authority == governing_token_owner {
+        return Ok(None);
+    }
+
+    if !nft_vote_delegation_info.data_is_empty() {
+        let nft_vote_delegation = Account::<NftVoteDelegation>::try_from(nft_vote_delegation_info)?;
+
+        if nft_vote_delegation.delegate == *voter_authority {
+            require!(
+                !nft_vote_delegation.is_expired(Clock::get()?.unix_timestamp),
+                NftVoterError::NftVoteDelegationExpired
+            );
+
+            return Ok(nft_vote_delegation.collection);
+        }
+    }
+
+    // The governance_delegate set in spl-gov can vote with all the owner's NFTs
+    let token_owner_record_key = token_owner_record::get_token_owner_record_address(
+        &registrar.governance_program_id,
+        &registrar.realm,
+        &registrar.governing_token_mint,
+        governing_token_owner,
+    );
+
+    if token_owner_record_info.key() == token_owner_record_key
+        && !token_owner_record_info.data_is_empty()
+    {
+        let token_owner_record = token_owner_record::get_token_owner_record_data(
+            &registrar.governance_program_id,
+            token_owner_record_info,
+        )?;
+
+        if token_owner_record.governance_delegate == Some(*voter_authority) {
+            return Ok(None);
+        }
+    }
+
+    err!(NftVoterError::InvalidVoterAuthority)
+}
+
+/// Asserts the NFTs are from the collection the voter_authority can vote with
+pub fn assert_nft_vote_infos_delegated(
+    delegated_collection: Option<Pubkey>,
+    nft_vote_infos: &[NftVoteInfo],
+) -> Result<()> {
+    if let Some(collection) = delegated_collection {
+        require!(
+            nft_vote_infos
+                .iter()
+                .all(|nft_vote_info| nft_vote_info.collection == collection),
+            NftVoterError::NftCollectionNotDelegated
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = NftVoteDelegation::get_space();
+
+        let nft_vote_delegation = NftVoteDelegation {
+            registrar: Pubkey::default(),
+            governing_token_owner: Pubkey::default(),
+            delegate: Pubkey::default(),
+            collection: Some(Pubkey::default()),
+            expiry: Some(0),
+            reserved: [0; 32],
+        };
+
+        // Act
+        let actual_space = DISCRIMINATOR_SIZE + nft_vote_delegation.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+
+    #[test]
+    fn test_assert_nft_vote_infos_delegated() {
+        // Arrange
+        let collection = Pubkey::new_unique();
+
+        let nft_vote_info = NftVoteInfo {
+            weight: 1,
+            mint: Pubkey::new_unique(),
+            collection,
+            token_standard: None,
+        };
+
+        let other_nft_vote_info = NftVoteInfo {
+            collection: Pubkey::new_unique(),
+            ..nft_vote_info.clone()
+        };
+
+        // Act + Assert
+        assert!(assert_nft_vote_infos_delegated(None, &[other_nft_vote_info.clone()]).is_ok());
+        assert!(
+            assert_nft_vote_infos_delegated(Some(collection), &[nft_vote_info.clone()]).is_ok()
+        );
+        assert_eq!(
+            assert_nft_vote_infos_delegated(
+                Some(collection),
+                &[nft_vote_info, other_nft_vote_info]
+            ),
+            Err(NftVoterError::NftCollectionNotDelegated.into())
+        );
+    }
+}
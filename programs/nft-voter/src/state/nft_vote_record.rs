@@ -2,7 +2,9 @@ use anchor_lang::prelude::*;
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use solana_program::program_pack::IsInitialized;
 
-use spl_governance_tools::account::{get_account_data, AccountMaxSize};
+use spl_governance_tools::account::{
+    create_and_serialize_account_signed, get_account_data, AccountMaxSize,
+};
 
 use crate::{error::NftVoterError, id};
 
@@ -22,6 +24,7 @@ pub struct NftVoteRecord {
     pub proposal: Pubkey,
 
     /// The mint of the NFT which was used for the vote
+    /// For compressed NFTs it's the asset id of the NFT
     pub nft_mint: Pubkey,
 
     /// The voter who casted this vote
@@ -30,11 +33,19 @@ pub struct NftVoteRecord {
 
     /// Reserved for future upgrades
     pub reserved: [u8; 8],
+
+    /// The payer of the NftVoteRecord rent who is refunded when the record is closed by CloseNftVoteRecords
+    /// Note: NftVoteRecords created before the payer was recorded don't have the field
+    /// and the governing_token_owner is used as the payer for them
+    pub payer: Pubkey,
 }
 
 impl NftVoteRecord {
     /// sha256("account:NftVoteRecord")[..8]
     pub const ACCOUNT_DISCRIMINATOR: [u8; 8] = [137, 6, 55, 139, 251, 126, 254, 99];
+
+    /// The size of NftVoteRecords created before the payer was recorded
+    pub const LEGACY_SPACE: usize = 8 + 32 * 3 + 8;
 }
 
 impl AccountMaxSize for NftVoteRecord {}
@@ -45,6 +56,24 @@ impl IsInitialized for NftVoteRecord {
     }
 }
 
+/// NftVoteRecord layout before the payer was recorded
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+struct NftVoteRecordV1 {
+    pub account_discriminator: [u8; 8],
+    pub proposal: Pubkey,
+    pub nft_mint: Pubkey,
+    pub governing_token_owner: Pubkey,
+    pub reserved: [u8; 8],
+}
+
+impl AccountMaxSize for NftVoteRecordV1 {}
+
+impl IsInitialized for NftVoteRecordV1 {
+    fn is_initialized(&self) -> bool {
+        self.account_discriminator == NftVoteRecord::ACCOUNT_DISCRIMINATOR
+    }
+}
+
 /// Returns NftVoteRecord PDA seeds
 pub fn get_nft_vote_record_seeds<'a>(proposal: &'a Pubkey, nft_mint: &'a Pubkey) -> [&'a [u8]; 3] {
     [b"nft-vote-record", proposal.as_ref(), nft_mint.as_ref()]
@@ -55,8 +84,62 @@ pub fn get_nft_vote_record_address(proposal: &Pubkey, nft_mint: &Pubkey) -> Pubk
     Pubkey::find_program_address(&get_nft_vote_record_seeds(proposal, nft_mint), &id()).0
 }
 
+/// Creates NftVoteRecord to ensure the same NFT can't be used to vote on the Proposal twice
+/// Note: The correct PDA of the NftVoteRecord is validated in create_and_serialize_account_signed
+/// It ensures the NftVoteRecord is for ('nft-vote-record',proposal,nft_mint) seeds
+pub fn create_nft_vote_record<'a>(
+    payer_info: &AccountInfo<'a>,
+    nft_vote_record_info: &AccountInfo<'a>,
+    proposal: &Pubkey,
+    nft_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+    system_info: &AccountInfo<'a>,
+    rent: &Rent,
+) -> Result<()> {
+    require!(
+        nft_vote_record_info.data_is_empty(),
+        NftVoterError::NftAlreadyVoted
+    );
+
+    let nft_vote_record = NftVoteRecord {
+        account_discriminator: NftVoteRecord::ACCOUNT_DISCRIMINATOR,
+        proposal: *proposal,
+        nft_mint: *nft_mint,
+        governing_token_owner: *governing_token_owner,
+        reserved: [0; 8],
+        payer: *payer_info.key,
+    };
+
+    // Anchor doesn't natively support dynamic account creation using remaining_accounts
+    // and we have to take it on the manual drive
+    create_and_serialize_account_signed(
+        payer_info,
+        nft_vote_record_info,
+        &nft_vote_record,
+        &get_nft_vote_record_seeds(proposal, nft_mint),
+        &id(),
+        system_info,
+        rent,
+    )?;
+
+    Ok(())
+}
+
 /// Deserializes account and checks owner program
 pub fn get_nft_vote_record_data(nft_vote_record_info: &AccountInfo) -> Result<NftVoteRecord> {
+    if nft_vote_record_info.data_len() == NftVoteRecord::LEGACY_SPACE {
+        let nft_vote_record_v1 = get_account_data::<NftVoteRecordV1>(&id(), nft_vote_record_info)?;
+
+        return Ok(NftVoteRecord {
+            account_discriminator: nft_vote_record_v1.account_discriminator,
+            proposal: nft_vote_record_v1.proposal,
+            nft_mint: nft_vote_record_v1.nft_mint,
+            governing_token_owner: nft_vote_record_v1.governing_token_owner,
+            reserved: nft_vote_record_v1.reserved,
+            payer: nft_vote_record_v1.governing_token_owner,
+        });
+    }
+
     Ok(get_account_data::<NftVoteRecord>(
         &id(),
         nft_vote_record_info,
@@ -68,16 +151,26 @@ pub fn get_nft_vote_record_data_for_proposal_and_token_owner(
     proposal: &Pubkey,
     governing_token_owner: &Pubkey,
 ) -> Result<NftVoteRecord> {
-    let nft_vote_record = get_nft_vote_record_data(nft_vote_record_info)?;
+    let nft_vote_record = get_nft_vote_record_data_for_proposal(nft_vote_record_info, proposal)?;
 
     require!(
-        nft_vote_record.proposal == *proposal,
-        NftVoterError::InvalidProposalForNftVoteRecord
+        nft_vote_record.governing_token_owner == *governing_token_owner,
+        NftVoterError::InvalidTokenOwnerForNftVoteRecord
     );
 
+    Ok(nft_vote_record)
+}
+
+/// Deserializes NftVoteRecord and checks it's a record for the given Proposal
+pub fn get_nft_vote_record_data_for_proposal(
+    nft_vote_record_info: &AccountInfo,
+    proposal: &Pubkey,
+) -> Result<NftVoteRecord> {
+    let nft_vote_record = get_nft_vote_record_data(nft_vote_record_info)?;
+
     require!(
-        nft_vote_record.governing_token_owner == *governing_token_owner,
-        NftVoterError::InvalidTokenOwnerForNftVoteRecord
+        nft_vote_record.proposal == *proposal,
+        NftVoterError::InvalidProposalForNftVoteRecord
     );
 
     Ok(nft_vote_record)
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+/// Policy applied to programmable NFTs (pNFTs) used for voting
+/// The policy is evaluated using the TokenRecord of the pNFT token account
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum ProgrammableNftPolicy {
+    /// pNFTs can vote regardless of their TokenRecord state and delegate
+    AllowAll,
+
+    /// pNFTs which are locked or have a delegate with transfer authority can't vote
+    RejectLockedOrDelegated,
+}
+
+impl Default for ProgrammableNftPolicy {
+    fn default() -> Self {
+        ProgrammableNftPolicy::AllowAll
+    }
+}
@@ -1,14 +1,24 @@
 use crate::{
     error::NftVoterError,
     id,
-    state::CollectionConfig,
+    state::{
+        assert_escrow_ownership_proof, assert_nft_mint_not_denied,
+        get_collection_weight_overrides_for_collection, CollectionConfig,
+        CollectionWeightOverrides, CompressedNftAsset, ProgrammableNftPolicy, VoteTrackingMode,
+        VoterWeightCaps, VoterWeightingMode,
+    },
     tools::{
-        anchor::{DISCRIMINATOR_SIZE, PUBKEY_SIZE},
+        account_compression::{assert_valid_merkle_proof, get_merkle_tree_header},
+        bubblegum::{get_asset_id, get_tree_authority, hash_creators, hash_leaf, hash_metadata},
         spl_token::get_spl_token_amount,
-        token_metadata::get_token_metadata_for_mint,
+        token_metadata::{
+            get_nft_metadata_for_mint, get_token_record_data_for_token_account, TokenStandard,
+            TokenState,
+        },
     },
 };
 use anchor_lang::prelude::*;
+use gpl_voter_weight_plugin::space::{DISCRIMINATOR_SIZE, PUBKEY_SIZE};
 use spl_governance::tools::spl_token::{get_spl_token_mint, get_spl_token_owner};
 
 /// Registrar which stores NFT voting configuration for the given Realm
@@ -30,8 +40,25 @@ pub struct Registrar {
     /// MPL Collection used for voting
     pub collection_configs: Vec<CollectionConfig>,
 
+    /// Policy applied to programmable NFTs used for voting
+    pub programmable_nft_policy: ProgrammableNftPolicy,
+
+    /// Caps applied to voter_weight cast on a Proposal by a single voter
+    pub voter_weight_caps: VoterWeightCaps,
+
+    /// Weighting mode used to calculate voter_weight of the NFTs owned by a voter
+    pub voter_weighting_mode: VoterWeightingMode,
+
+    /// Indicates whether NFTs must be registered to the voter using NftHolderRegistration
+    /// before the Proposal voting started in order to vote on the Proposal
+    /// It prevents acquiring NFTs in the middle of the vote to swing the outcome
+    pub ownership_snapshot_enabled: bool,
+
+    /// Mode used to track the NFTs which voted on a Proposal
+    pub vote_tracking_mode: VoteTrackingMode,
+
     /// Reserved for future upgrades
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 112],
 }
 
 impl Registrar {
@@ -40,21 +67,20 @@ impl Registrar {
             + PUBKEY_SIZE * 3
             + 4
             + max_collections as usize * (PUBKEY_SIZE + 8 + 8 + 8)
-            + 128
+            + 1
+            + (1 + 8 + 1 + 2)
+            + 1
+            + 1
+            + 1
+            + 112
     }
 }
 
-/// Returns Registrar PDA seeds
-pub fn get_registrar_seeds<'a>(
-    realm: &'a Pubkey,
-    governing_token_mint: &'a Pubkey,
-) -> [&'a [u8]; 3] {
-    [b"registrar", realm.as_ref(), governing_token_mint.as_ref()]
-}
+pub use gpl_voter_weight_plugin::pda::get_registrar_seeds;
 
 /// Returns Registrar PDA address
 pub fn get_registrar_address(realm: &Pubkey, governing_token_mint: &Pubkey) -> Pubkey {
-    Pubkey::find_program_address(&get_registrar_seeds(realm, governing_token_mint), &id()).0
+    gpl_voter_weight_plugin::pda::get_registrar_address(&id(), realm, governing_token_mint)
 }
 
 impl Registrar {
@@ -65,24 +91,131 @@ impl Registrar {
             .find(|cc| cc.collection == collection)
             .ok_or_else(|| NftVoterError::CollectionNotFound.into());
     }
+
+    /// Updates max_weight_bonus of the given collection using its NFT weight overrides
+    pub fn set_collection_max_weight_bonus(
+        &mut self,
+        collection: &Pubkey,
+        collection_weight_overrides: &CollectionWeightOverrides,
+    ) -> Result<()> {
+        let collection_config = self
+            .collection_configs
+            .iter_mut()
+            .find(|cc| cc.collection == *collection)
+            .ok_or(NftVoterError::CollectionNotFound)?;
+
+        collection_config.max_weight_bonus =
+            collection_weight_overrides.get_max_weight_bonus(collection_config.weight);
+
+        Ok(())
+    }
+
+    /// Returns the max voting power of all the configured collections
+    ///
+    /// For the non linear weighting modes it's the number of NFTs in the collections with governance power
+    /// times voter_weight of a single NFT which is the voting power when every NFT is held by a different voter
+    /// The curves are subadditive and hence the total voter_weight of all the voters can never exceed it
+    pub fn get_max_voter_weight(&self) -> u64 {
+        if self.voter_weighting_mode.is_linear() {
+            return self.get_max_tally_weight();
+        }
+
+        self.get_max_tally_weight()
+            .checked_mul(self.voter_weighting_mode.get_voter_weight(1))
+            .unwrap()
+    }
+
+    /// Returns the max weight of all the NFTs tallied using the Registrar voter_weighting_mode
+    /// For the linear mode it's the max weight of all the collections
+    /// and for the non linear modes it's the number of NFTs in the collections with governance power
+    pub fn get_max_tally_weight(&self) -> u64 {
+        self.collection_configs
+            .iter()
+            .map(|cc| {
+                if self.voter_weighting_mode.is_linear() {
+                    cc.get_max_weight()
+                } else if cc.get_max_weight() > 0 {
+                    cc.size
+                } else {
+                    0
+                }
+            })
+            .try_fold(0u64, |sum, weight| sum.checked_add(weight))
+            .unwrap()
+    }
+
+    /// Returns true if votes must be tallied using VoterWeightCapTally
+    /// to apply the voter_weight caps or the non linear weighting mode across several transactions
+    pub fn is_voter_weight_cap_tally_required(&self) -> bool {
+        self.voter_weight_caps.is_enabled() || !self.voter_weighting_mode.is_linear()
+    }
+
+    /// Returns voter_weight of the given NFTs using the Registrar voter_weighting_mode
+    pub fn get_nft_voter_weight(&self, nft_vote_infos: &[NftVoteInfo]) -> u64 {
+        let tally_weight = nft_vote_infos
+            .iter()
+            .map(|info| self.voter_weighting_mode.get_nft_tally_weight(info.weight))
+            .try_fold(0u64, |sum, weight| sum.checked_add(weight))
+            .unwrap();
+
+        self.voter_weighting_mode.get_voter_weight(tally_weight)
+    }
 }
 
-/// Resolves vote weight and voting mint for the given NFT
-pub fn resolve_nft_vote_weight_and_mint(
-    registrar: &Registrar,
-    governing_token_owner: &Pubkey,
-    nft_info: &AccountInfo,
-    nft_metadata_info: &AccountInfo,
-    unique_nft_mints: &mut Vec<Pubkey>,
-) -> Result<(u64, Pubkey)> {
-    let nft_owner = get_spl_token_owner(nft_info)?;
+/// Vote info resolved for the given NFT
+#[derive(Debug, Clone, PartialEq)]
+pub struct NftVoteInfo {
+    /// Governance power weight of the NFT
+    pub weight: u64,
+
+    /// Mint of the NFT
+    /// For compressed NFTs it's the asset id of the NFT
+    pub mint: Pubkey,
+
+    /// Collection of the NFT
+    pub collection: Pubkey,
+
+    /// Token standard of the NFT as stored in its Metadata
+    pub token_standard: Option<TokenStandard>,
+}
+
+/// Returns the accounts of the next NFT from the given accounts iterator
+/// The number of the accounts of the NFT is provided explicitly by the instruction layout
+pub fn next_nft_accounts<'a, 'info>(
+    account_info_iter: &mut std::slice::Iter<'a, AccountInfo<'info>>,
+    nft_account_count: u8,
+) -> Result<&'a [AccountInfo<'info>]> {
+    let accounts = account_info_iter.as_slice();
 
-    // voter_weight_record.governing_token_owner must be the owner of the NFT
     require!(
-        nft_owner == *governing_token_owner,
-        NftVoterError::VoterDoesNotOwnNft
+        accounts.len() >= nft_account_count as usize,
+        NftVoterError::InvalidRemainingAccountsLayout
     );
 
+    let (nft_accounts, next_accounts) = accounts.split_at(nft_account_count as usize);
+    *account_info_iter = next_accounts.iter();
+
+    Ok(nft_accounts)
+}
+
+/// Resolves vote info for the NFT using all the given accounts of the NFT
+/// The accounts are: nft (token account), nft_metadata, for programmable NFTs its TokenRecord,
+/// for NFTs held by an escrow program its EscrowProgramConfig and the escrow receipt
+/// and for NFTs with weight override the CollectionWeightOverrides of the collection as the last account
+/// NFTs on the MintDenylist of the Registrar are rejected
+pub fn resolve_nft_vote_info(
+    registrar: &Account<Registrar>,
+    mint_denylist_info: &AccountInfo,
+    governing_token_owner: &Pubkey,
+    nft_accounts: &[AccountInfo],
+    unique_nft_mints: &mut Vec<Pubkey>,
+) -> Result<NftVoteInfo> {
+    let account_info_iter = &mut nft_accounts.iter();
+
+    let nft_info = next_account_info(account_info_iter)?;
+    let nft_metadata_info = next_account_info(account_info_iter)?;
+
+    let nft_owner = get_spl_token_owner(nft_info)?;
     let nft_mint = get_spl_token_mint(nft_info)?;
 
     // Ensure the same NFT was not provided more than once
@@ -91,12 +224,61 @@ pub fn resolve_nft_vote_weight_and_mint(
     }
     unique_nft_mints.push(nft_mint);
 
+    // Stolen or exploited NFTs can be denied by the realm authority
+    assert_nft_mint_not_denied(mint_denylist_info, &registrar.key(), &nft_mint)?;
+
     // Ensure the token amount is exactly 1
     let nft_amount = get_spl_token_amount(nft_info)?;
 
     require!(nft_amount == 1, NftVoterError::InvalidNftAmount);
 
-    let nft_metadata = get_token_metadata_for_mint(nft_metadata_info, &nft_mint)?;
+    let nft_metadata = get_nft_metadata_for_mint(nft_metadata_info, &nft_mint)?;
+
+    // Programmable NFTs must be provided with the TokenRecord of the token account
+    if nft_metadata
+        .token_standard
+        .map_or(false, |ts| ts.is_programmable())
+    {
+        let token_record_info = next_account_info(account_info_iter)?;
+
+        let token_record =
+            get_token_record_data_for_token_account(token_record_info, &nft_mint, nft_info.key)?;
+
+        if registrar.programmable_nft_policy == ProgrammableNftPolicy::RejectLockedOrDelegated {
+            require!(
+                token_record.state == TokenState::Unlocked,
+                NftVoterError::ProgrammableNftLocked
+            );
+
+            require!(
+                !token_record
+                    .delegate_role
+                    .map_or(false, |role| role.can_transfer()),
+                NftVoterError::ProgrammableNftDelegated
+            );
+        }
+    }
+
+    // voter_weight_record.governing_token_owner must be the owner of the NFT
+    // or the beneficial owner of the NFT held by an escrow program allowlisted for the Registrar
+    if nft_owner != *governing_token_owner {
+        // The escrow accounts are required to prove the beneficial ownership of the NFT
+        require!(
+            account_info_iter.len() >= 2,
+            NftVoterError::VoterDoesNotOwnNft
+        );
+
+        let escrow_program_config_info = next_account_info(account_info_iter)?;
+        let escrow_receipt_info = next_account_info(account_info_iter)?;
+
+        assert_escrow_ownership_proof(
+            &registrar.key(),
+            escrow_program_config_info,
+            escrow_receipt_info,
+            governing_token_owner,
+            &nft_mint,
+        )?;
+    }
 
     // The NFT must have a collection and the collection must be verified
     let collection = nft_metadata
@@ -107,7 +289,102 @@ pub fn resolve_nft_vote_weight_and_mint(
 
     let collection_config = registrar.get_collection_config(collection.key)?;
 
-    Ok((collection_config.weight, nft_mint))
+    // The weight of the NFT can be boosted by the weight override of the collection
+    // The overrides account is optional because omitting it can only reduce the governance power of the NFT
+    let mut weight = collection_config.weight;
+
+    if let Some(collection_weight_overrides_info) = account_info_iter.next() {
+        let collection_weight_overrides = get_collection_weight_overrides_for_collection(
+            collection_weight_overrides_info,
+            &registrar.key(),
+            &collection.key,
+        )?;
+
+        // Overrides lower than the collection weight are rejected when they are set
+        // and the collection weight is used if it was raised above the override later
+        if let Some(override_weight) = collection_weight_overrides.get_nft_weight(&nft_mint) {
+            weight = weight.max(override_weight);
+        }
+    }
+
+    // All the accounts of the NFT must be consumed
+    require!(
+        account_info_iter.len() == 0,
+        NftVoterError::InvalidRemainingAccountsLayout
+    );
+
+    Ok(NftVoteInfo {
+        weight,
+        mint: nft_mint,
+        collection: collection.key,
+        token_standard: nft_metadata.token_standard,
+    })
+}
+
+/// Resolves vote info for the given compressed NFT
+/// The asset id is used the same way as NFT mint to ensure the compressed NFT can't vote twice
+pub fn resolve_compressed_nft_vote_info(
+    registrar: &Account<Registrar>,
+    mint_denylist_info: &AccountInfo,
+    governing_token_owner: &Pubkey,
+    merkle_tree_info: &AccountInfo,
+    proof_infos: &[AccountInfo],
+    compressed_nft: &CompressedNftAsset,
+    unique_nft_mints: &mut Vec<Pubkey>,
+) -> Result<NftVoteInfo> {
+    let merkle_tree_header = get_merkle_tree_header(merkle_tree_info)?;
+
+    // Only trees managed by Bubblegum can hold compressed NFTs with verified collections
+    require!(
+        merkle_tree_header.authority == get_tree_authority(merkle_tree_info.key),
+        NftVoterError::InvalidMerkleTreeAuthority
+    );
+
+    let asset_id = get_asset_id(merkle_tree_info.key, compressed_nft.nonce);
+
+    // Ensure the same compressed NFT was not provided more than once
+    if unique_nft_mints.contains(&asset_id) {
+        return Err(NftVoterError::DuplicatedNftDetected.into());
+    }
+    unique_nft_mints.push(asset_id);
+
+    assert_nft_mint_not_denied(mint_denylist_info, &registrar.key(), &asset_id)?;
+
+    // The compressed NFT must have a collection and the collection must be verified
+    let collection = compressed_nft
+        .metadata
+        .collection
+        .ok_or(NftVoterError::MissingMetadataCollection)?;
+
+    require!(collection.verified, NftVoterError::CollectionMustBeVerified);
+
+    let collection_config = registrar.get_collection_config(collection.key)?;
+
+    // voter_weight_record.governing_token_owner must be the owner of the compressed NFT
+    // The leaf is recomputed with governing_token_owner as the owner and it can only be verified if the voter owns the NFT
+    let leaf = hash_leaf(
+        &asset_id,
+        governing_token_owner,
+        &compressed_nft.leaf_delegate,
+        compressed_nft.nonce,
+        &hash_metadata(&compressed_nft.metadata)?,
+        &hash_creators(&compressed_nft.metadata.creators),
+    );
+
+    assert_valid_merkle_proof(
+        merkle_tree_info,
+        &merkle_tree_header,
+        &leaf,
+        proof_infos,
+        compressed_nft.index,
+    )?;
+
+    Ok(NftVoteInfo {
+        weight: collection_config.weight,
+        mint: asset_id,
+        collection: collection.key,
+        token_standard: None,
+    })
 }
 
 #[cfg(test)]
@@ -129,7 +406,15 @@ mod test {
                 CollectionConfig::default(),
                 CollectionConfig::default(),
             ],
-            reserved: [0; 128],
+            programmable_nft_policy: ProgrammableNftPolicy::AllowAll,
+            voter_weight_caps: VoterWeightCaps {
+                max_voter_weight: Some(10),
+                max_collection_weight_share_bps: Some(5_000),
+            },
+            voter_weighting_mode: VoterWeightingMode::Linear,
+            ownership_snapshot_enabled: false,
+            vote_tracking_mode: VoteTrackingMode::NftVoteRecords,
+            reserved: [0; 112],
         };
 
         // Act
@@ -0,0 +1,361 @@
+use anchor_lang::prelude::*;
+use arrayref::array_ref;
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use gpl_voter_weight_plugin::space::{DISCRIMINATOR_SIZE, PUBKEY_SIZE};
+use solana_program::program_pack::IsInitialized;
+
+use spl_governance_tools::account::{
+    create_and_serialize_account_signed, get_account_data, AccountMaxSize,
+};
+
+use crate::{
+    error::NftVoterError,
+    id,
+    state::{create_nft_vote_record, get_nft_index, NftVoteInfo, Registrar, VoteTrackingMode},
+};
+
+/// Bitmap of the NFTs from a collection which voted on the Proposal
+/// The PDA of the bitmap is ["vote-bitmap",proposal,collection]
+///
+/// Every NFT is tracked by a single bit given by its index in the NftIndexMap of the collection
+/// It's used instead of NftVoteRecords when the Registrar uses VoteTrackingMode::VoteBitmaps
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct VoteBitmap {
+    /// VoteBitmap discriminator sha256("account:VoteBitmap")[..8]
+    /// Note: The discriminator is used explicitly because VoteBitmaps
+    /// are created dynamically using remaining_accounts the same way as NftVoteRecord
+    pub account_discriminator: [u8; 8],
+
+    /// Proposal which was voted on
+    pub proposal: Pubkey,
+
+    /// The collection of the NFTs
+    pub collection: Pubkey,
+
+    /// The payer of the VoteBitmap rent who is refunded when the bitmap is closed by CloseNftVoteRecords
+    pub payer: Pubkey,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 8],
+
+    /// The bits of the NFTs which voted on the Proposal
+    pub bits: Vec<u8>,
+}
+
+impl VoteBitmap {
+    /// sha256("account:VoteBitmap")[..8]
+    pub const ACCOUNT_DISCRIMINATOR: [u8; 8] = [178, 110, 89, 253, 221, 143, 104, 110];
+
+    /// Returns the number of bytes needed to store the bits of a collection with the given size
+    pub fn get_bits_len(collection_size: u64) -> usize {
+        ((collection_size + 7) / 8) as usize
+    }
+}
+
+impl AccountMaxSize for VoteBitmap {}
+
+impl IsInitialized for VoteBitmap {
+    fn is_initialized(&self) -> bool {
+        self.account_discriminator == VoteBitmap::ACCOUNT_DISCRIMINATOR
+    }
+}
+
+/// Offset of the bits Vec length in VoteBitmap account data
+const BITS_LEN_OFFSET: usize = DISCRIMINATOR_SIZE + PUBKEY_SIZE * 3 + 8;
+
+/// Offset of the bits in VoteBitmap account data
+const BITS_OFFSET: usize = BITS_LEN_OFFSET + 4;
+
+/// Returns VoteBitmap PDA seeds
+pub fn get_vote_bitmap_seeds<'a>(proposal: &'a Pubkey, collection: &'a Pubkey) -> [&'a [u8]; 3] {
+    [b"vote-bitmap", proposal.as_ref(), collection.as_ref()]
+}
+
+/// Returns VoteBitmap PDA address
+pub fn get_vote_bitmap_address(proposal: &Pubkey, collection: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&get_vote_bitmap_seeds(proposal, collection), &id()).0
+}
+
+/// Deserializes account and checks owner program
+pub fn get_vote_bitmap_data(vote_bitmap_info: &AccountInfo) -> Result<VoteBitmap> {
+    Ok(get_account_data::<VoteBitmap>(&id(), vote_bitmap_info)?)
+}
+
+/// Deserializes VoteBitmap and checks it's a bitmap for the given Proposal
+pub fn get_vote_bitmap_data_for_proposal(
+    vote_bitmap_info: &AccountInfo,
+    proposal: &Pubkey,
+) -> Result<VoteBitmap> {
+    let vote_bitmap = get_vote_bitmap_data(vote_bitmap_info)?;
+
+    require!(
+        vote_bitmap.proposal == *proposal,
+        NftVoterError::InvalidVoteBitmap
+    );
+
+    Ok(vote_bitmap)
+}
+
+/// Returns true if the given account is VoteBitmap
+pub fn is_vote_bitmap(account_info: &AccountInfo) -> Result<bool> {
+    let data = account_info.try_borrow_data()?;
+
+    Ok(*account_info.owner == id()
+        && data.len() >= DISCRIMINATOR_SIZE
+        && *array_ref![data, 0, 8] == VoteBitmap::ACCOUNT_DISCRIMINATOR)
+}
+
+/// Sets the bit of the NFT with the given index in the VoteBitmap of the Proposal and the collection
+/// The VoteBitmap is created if it doesn't exist and the NFT can't vote if its bit is already set
+///
+/// Note: The bit is set in place to avoid deserializing bitmaps of large collections
+#[allow(clippy::too_many_arguments)]
+pub fn set_vote_bitmap_bit<'a>(
+    payer_info: &AccountInfo<'a>,
+    vote_bitmap_info: &AccountInfo<'a>,
+    proposal: &Pubkey,
+    collection: &Pubkey,
+    collection_size: u64,
+    nft_index: u32,
+    system_info: &AccountInfo<'a>,
+    rent: &Rent,
+) -> Result<()> {
+    let (byte_index, bit_mask) = ((nft_index / 8) as usize, 1u8 << (nft_index % 8));
+
+    if vote_bitmap_info.data_is_empty() {
+        let mut vote_bitmap = VoteBitmap {
+            account_discriminator: VoteBitmap::ACCOUNT_DISCRIMINATOR,
+            proposal: *proposal,
+            collection: *collection,
+            payer: *payer_info.key,
+            reserved: [0; 8],
+            bits: vec![0; VoteBitmap::get_bits_len(collection_size)],
+        };
+
+        require!(
+            byte_index < vote_bitmap.bits.len(),
+            NftVoterError::NftIndexOutOfRange
+        );
+
+        vote_bitmap.bits[byte_index] |= bit_mask;
+
+        // Note: The correct PDA of the VoteBitmap is validated in create_and_serialize_account_signed
+        // It ensures the VoteBitmap is for ('vote-bitmap',proposal,collection) seeds
+        create_and_serialize_account_signed(
+            payer_info,
+            vote_bitmap_info,
+            &vote_bitmap,
+            &get_vote_bitmap_seeds(proposal, collection),
+            &id(),
+            system_info,
+            rent,
+        )?;
+
+        return Ok(());
+    }
+
+    require!(
+        *vote_bitmap_info.owner == id(),
+        NftVoterError::InvalidVoteBitmap
+    );
+
+    let mut data = vote_bitmap_info.try_borrow_mut_data()?;
+
+    require!(
+        data.len() >= BITS_OFFSET
+            && *array_ref![data, 0, 8] == VoteBitmap::ACCOUNT_DISCRIMINATOR
+            && array_ref![data, DISCRIMINATOR_SIZE, PUBKEY_SIZE] == proposal.as_ref()
+            && array_ref![data, DISCRIMINATOR_SIZE + PUBKEY_SIZE, PUBKEY_SIZE]
+                == collection.as_ref(),
+        NftVoterError::InvalidVoteBitmap
+    );
+
+    let bits_len = u32::from_le_bytes(*array_ref![data, BITS_LEN_OFFSET, 4]) as usize;
+
+    require!(
+        byte_index < bits_len && BITS_OFFSET + bits_len <= data.len(),
+        NftVoterError::NftIndexOutOfRange
+    );
+
+    let byte = &mut data[BITS_OFFSET + byte_index];
+
+    require!(*byte & bit_mask == 0, NftVoterError::NftAlreadyVoted);
+
+    *byte |= bit_mask;
+
+    Ok(())
+}
+
+/// Records the NFT voted on the Proposal using the VoteTrackingMode of the Registrar
+///
+/// For VoteTrackingMode::NftVoteRecords nft_vote_record_info is the NftVoteRecord of the NFT
+/// For VoteTrackingMode::VoteBitmaps nft_vote_record_info is the VoteBitmap of the NFT collection
+/// and nft_index_map_info is the NftIndexMap of the collection
+#[allow(clippy::too_many_arguments)]
+pub fn record_nft_vote<'info>(
+    registrar: &Account<'info, Registrar>,
+    payer_info: &AccountInfo<'info>,
+    nft_vote_record_info: &AccountInfo<'info>,
+    nft_index_map_info: Option<&AccountInfo<'info>>,
+    proposal: &Pubkey,
+    nft_vote_info: &NftVoteInfo,
+    governing_token_owner: &Pubkey,
+    system_info: &AccountInfo<'info>,
+    rent: &Rent,
+) -> Result<()> {
+    match registrar.vote_tracking_mode {
+        VoteTrackingMode::NftVoteRecords => create_nft_vote_record(
+            payer_info,
+            nft_vote_record_info,
+            proposal,
+            &nft_vote_info.mint,
+            governing_token_owner,
+            system_info,
+            rent,
+        ),
+        VoteTrackingMode::VoteBitmaps => {
+            let nft_index_map_info =
+                nft_index_map_info.ok_or(NftVoterError::InvalidRemainingAccountsLayout)?;
+
+            let nft_index = get_nft_index(
+                nft_index_map_info,
+                &registrar.key(),
+                &nft_vote_info.collection,
+                &nft_vote_info.mint,
+            )?;
+
+            let collection_config = registrar.get_collection_config(nft_vote_info.collection)?;
+
+            set_vote_bitmap_bit(
+                payer_info,
+                nft_vote_record_info,
+                proposal,
+                &nft_vote_info.collection,
+                collection_config.size,
+                nft_index,
+                system_info,
+                rent,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_bits_offset() {
+        // Arrange
+        let vote_bitmap = VoteBitmap {
+            account_discriminator: VoteBitmap::ACCOUNT_DISCRIMINATOR,
+            proposal: Pubkey::new_unique(),
+            collection: Pubkey::new_unique(),
+            payer: Pubkey::new_unique(),
+            reserved: [0; 8],
+            bits: vec![255; 3],
+        };
+
+        // Act
+        let data = vote_bitmap.try_to_vec().unwrap();
+
+        // Assert
+        assert_eq!(data.len(), BITS_OFFSET + 3);
+        assert_eq!(u32::from_le_bytes(*array_ref![data, BITS_LEN_OFFSET, 4]), 3);
+        assert_eq!(&data[BITS_OFFSET..], &[255; 3]);
+    }
+
+    #[test]
+    fn test_set_vote_bitmap_bit() {
+        // Arrange
+        let proposal = Pubkey::new_unique();
+        let collection = Pubkey::new_unique();
+        let program_id = id();
+
+        let vote_bitmap = VoteBitmap {
+            account_discriminator: VoteBitmap::ACCOUNT_DISCRIMINATOR,
+            proposal,
+            collection,
+            payer: Pubkey::new_unique(),
+            reserved: [0; 8],
+            bits: vec![0; VoteBitmap::get_bits_len(20)],
+        };
+
+        let mut data = vote_bitmap.try_to_vec().unwrap();
+        let mut lamports = 0;
+        let key = Pubkey::new_unique();
+
+        let vote_bitmap_info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+
+        let mut payer_lamports = 0;
+        let mut payer_data = vec![];
+        let payer_key = Pubkey::new_unique();
+        let payer_info = AccountInfo::new(
+            &payer_key,
+            true,
+            true,
+            &mut payer_lamports,
+            &mut payer_data,
+            &program_id,
+            false,
+            0,
+        );
+
+        let rent = Rent::default();
+
+        // Act
+        set_vote_bitmap_bit(
+            &payer_info,
+            &vote_bitmap_info,
+            &proposal,
+            &collection,
+            20,
+            10,
+            &payer_info,
+            &rent,
+        )
+        .unwrap();
+
+        // Assert
+        let vote_bitmap = get_vote_bitmap_data_for_proposal(&vote_bitmap_info, &proposal).unwrap();
+        assert_eq!(vote_bitmap.bits, vec![0, 0b100, 0]);
+
+        assert_eq!(
+            set_vote_bitmap_bit(
+                &payer_info,
+                &vote_bitmap_info,
+                &proposal,
+                &collection,
+                20,
+                10,
+                &payer_info,
+                &rent,
+            ),
+            Err(NftVoterError::NftAlreadyVoted.into())
+        );
+
+        assert_eq!(
+            set_vote_bitmap_bit(
+                &payer_info,
+                &vote_bitmap_info,
+                &proposal,
+                &collection,
+                20,
+                24,
+                &payer_info,
+                &rent,
+            ),
+            Err(NftVoterError::NftIndexOutOfRange.into())
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Mode used to track the NFTs which voted on a Proposal and prevent them from voting twice
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum VoteTrackingMode {
+    /// Every NFT which voted on the Proposal is tracked using its own NftVoteRecord account
+    NftVoteRecords,
+
+    /// The NFTs which voted on the Proposal are tracked using a single bit in the VoteBitmap of the Proposal and the NFT collection
+    /// The bit of an NFT is given by its index in the NftIndexMap of the collection and only the NFTs in the map can vote
+    ///
+    /// The VoteBitmaps cut the rent of the votes by orders of magnitude but they don't record the voter
+    /// and hence votes of transferred NFTs can't be revoked and relinquished NFTs can't vote again on the same Proposal
+    VoteBitmaps,
+}
+
+impl Default for VoteTrackingMode {
+    fn default() -> Self {
+        VoteTrackingMode::NftVoteRecords
+    }
+}
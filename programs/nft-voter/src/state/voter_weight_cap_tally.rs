@@ -0,0 +1,442 @@
+use anchor_lang::prelude::*;
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use solana_program::program_pack::IsInitialized;
+
+use spl_governance_tools::account::{
+    create_and_serialize_account_signed, get_account_data, AccountMaxSize,
+};
+
+use crate::{
+    error::NftVoterError,
+    id,
+    state::{NftVoteInfo, Registrar},
+};
+
+/// Weight of the NFTs from a collection cast by the voter before the caps are applied
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct CollectionVoterWeight {
+    /// The collection of the NFTs
+    pub collection: Pubkey,
+
+    /// The total weight of the NFTs from the collection
+    pub weight: u64,
+}
+
+/// Bookkeeping account used to apply the Registrar voter_weight caps and non linear voter_weighting_mode
+/// to votes cast using several transactions
+/// The PDA of the tally is ["voter-weight-cap-tally",proposal,governing_token_owner]
+///
+/// The tally stores the uncapped weight of the NFTs the voter cast vote with for each collection
+/// (the number of the NFTs for the non linear weighting modes)
+/// and each transaction only adds to voter_weight the increase of the capped weight
+/// The tally is only used when the Registrar has voter_weight caps or a non linear weighting mode
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct VoterWeightCapTally {
+    /// VoterWeightCapTally discriminator sha256("account:VoterWeightCapTally")[..8]
+    /// Note: The discriminator is used explicitly because the tally is created dynamically
+    /// when the first NFT vote is cast the same way as NftVoteRecord
+    pub account_discriminator: [u8; 8],
+
+    /// Proposal which was voted on
+    pub proposal: Pubkey,
+
+    /// The voter who casted the votes
+    pub governing_token_owner: Pubkey,
+
+    /// Uncapped weight of the cast NFTs for each collection configured on the Registrar
+    /// For the non linear weighting modes it's the number of the cast NFTs
+    pub collection_weights: Vec<CollectionVoterWeight>,
+
+    /// The number of NftVoteRecords of the voter for the Proposal tallied by the tally
+    /// The tally is disposed when the last of the NftVoteRecords is relinquished
+    pub nft_vote_record_count: u32,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 8],
+}
+
+impl VoterWeightCapTally {
+    /// sha256("account:VoterWeightCapTally")[..8]
+    pub const ACCOUNT_DISCRIMINATOR: [u8; 8] = [247, 8, 232, 210, 165, 75, 37, 248];
+
+    /// Returns empty tally for the collections configured on the Registrar
+    pub fn new(registrar: &Registrar, proposal: &Pubkey, governing_token_owner: &Pubkey) -> Self {
+        VoterWeightCapTally {
+            account_discriminator: VoterWeightCapTally::ACCOUNT_DISCRIMINATOR,
+            proposal: *proposal,
+            governing_token_owner: *governing_token_owner,
+            collection_weights: registrar
+                .collection_configs
+                .iter()
+                .map(|cc| CollectionVoterWeight {
+                    collection: cc.collection,
+                    weight: 0,
+                })
+                .collect(),
+            nft_vote_record_count: 0,
+            reserved: [0; 8],
+        }
+    }
+
+    /// Returns voter_weight of the cast NFTs with the Registrar caps and voter_weighting_mode applied
+    ///
+    /// The collection share cap is applied to the tallied weights and the max voter_weight cap
+    /// is applied to the final voter_weight calculated using the weighting mode curve
+    pub fn get_capped_voter_weight(&self, registrar: &Registrar) -> u64 {
+        let voter_weight_caps = &registrar.voter_weight_caps;
+
+        let tally_weight = voter_weight_caps.get_collection_weight_share_capped(
+            self.collection_weights.iter().map(|cw| cw.weight),
+            registrar.get_max_tally_weight(),
+        );
+
+        voter_weight_caps.get_max_voter_weight_capped(
+            registrar
+                .voter_weighting_mode
+                .get_voter_weight(tally_weight),
+        )
+    }
+
+    fn get_collection_weight_mut(
+        &mut self,
+        collection: &Pubkey,
+    ) -> Result<&mut CollectionVoterWeight> {
+        self.collection_weights
+            .iter_mut()
+            .find(|cw| cw.collection == *collection)
+            .ok_or_else(|| NftVoterError::CollectionNotFound.into())
+    }
+
+    /// Adds the weight of a cast NFT to the tally
+    pub fn add_nft_weight(&mut self, collection: &Pubkey, weight: u64) -> Result<()> {
+        let collection_weight = self.get_collection_weight_mut(collection)?;
+        collection_weight.weight = collection_weight.weight.checked_add(weight).unwrap();
+
+        Ok(())
+    }
+
+    /// Adds the created NftVoteRecords to the tally
+    pub fn add_nft_vote_records(&mut self, nft_vote_record_count: u32) {
+        self.nft_vote_record_count = self
+            .nft_vote_record_count
+            .checked_add(nft_vote_record_count)
+            .unwrap();
+    }
+
+    /// Removes the disposed NftVoteRecords from the tally
+    /// Returns true if none of the tallied NftVoteRecords are left
+    pub fn sub_nft_vote_records(&mut self, nft_vote_record_count: u32) -> bool {
+        self.nft_vote_record_count = self
+            .nft_vote_record_count
+            .saturating_sub(nft_vote_record_count);
+
+        self.nft_vote_record_count == 0
+    }
+
+    /// Removes the weight of a revoked NFT from the tally
+    pub fn sub_nft_weight(&mut self, collection: &Pubkey, weight: u64) -> Result<()> {
+        let collection_weight = self.get_collection_weight_mut(collection)?;
+        collection_weight.weight = collection_weight.weight.checked_sub(weight).unwrap();
+
+        Ok(())
+    }
+}
+
+impl AccountMaxSize for VoterWeightCapTally {}
+
+impl IsInitialized for VoterWeightCapTally {
+    fn is_initialized(&self) -> bool {
+        self.account_discriminator == VoterWeightCapTally::ACCOUNT_DISCRIMINATOR
+    }
+}
+
+/// Returns VoterWeightCapTally PDA seeds
+pub fn get_voter_weight_cap_tally_seeds<'a>(
+    proposal: &'a Pubkey,
+    governing_token_owner: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        b"voter-weight-cap-tally",
+        proposal.as_ref(),
+        governing_token_owner.as_ref(),
+    ]
+}
+
+/// Returns VoterWeightCapTally PDA address
+pub fn get_voter_weight_cap_tally_address(
+    proposal: &Pubkey,
+    governing_token_owner: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_voter_weight_cap_tally_seeds(proposal, governing_token_owner),
+        &id(),
+    )
+    .0
+}
+
+/// Deserializes account and checks owner program
+pub fn get_voter_weight_cap_tally_data(
+    voter_weight_cap_tally_info: &AccountInfo,
+) -> Result<VoterWeightCapTally> {
+    Ok(get_account_data::<VoterWeightCapTally>(
+        &id(),
+        voter_weight_cap_tally_info,
+    )?)
+}
+
+/// Returns voter_weight of the cast NFTs with the Registrar caps applied
+/// The NFTs are accumulated in VoterWeightCapTally of the voter for the Proposal
+/// and the returned weight is the increase of the capped voter_weight of all the votes cast so far
+/// The tally is created when the first NFT vote is cast
+/// and it counts the NftVoteRecords created by the instruction (nft_vote_record_count)
+///
+/// If the Registrar has no voter_weight caps and uses the linear weighting mode
+/// then the total weight of the NFTs is returned and the tally is not used
+#[allow(clippy::too_many_arguments)]
+pub fn add_capped_cast_vote_weight<'a>(
+    registrar: &Registrar,
+    voter_weight_cap_tally_info: &AccountInfo<'a>,
+    payer_info: &AccountInfo<'a>,
+    proposal: &Pubkey,
+    governing_token_owner: &Pubkey,
+    system_info: &AccountInfo<'a>,
+    rent: &Rent,
+    nft_vote_infos: &[NftVoteInfo],
+    nft_vote_record_count: u32,
+) -> Result<u64> {
+    if !registrar.is_voter_weight_cap_tally_required() {
+        return Ok(registrar.get_nft_voter_weight(nft_vote_infos));
+    }
+
+    let mut voter_weight_cap_tally = if voter_weight_cap_tally_info.data_is_empty() {
+        VoterWeightCapTally::new(registrar, proposal, governing_token_owner)
+    } else {
+        get_voter_weight_cap_tally_data(voter_weight_cap_tally_info)?
+    };
+
+    voter_weight_cap_tally.add_nft_vote_records(nft_vote_record_count);
+
+    let capped_voter_weight_before = voter_weight_cap_tally.get_capped_voter_weight(registrar);
+
+    for nft_vote_info in nft_vote_infos {
+        voter_weight_cap_tally.add_nft_weight(
+            &nft_vote_info.collection,
+            registrar
+                .voter_weighting_mode
+                .get_nft_tally_weight(nft_vote_info.weight),
+        )?;
+    }
+
+    let capped_voter_weight_after = voter_weight_cap_tally.get_capped_voter_weight(registrar);
+
+    if voter_weight_cap_tally_info.data_is_empty() {
+        // Note: The correct PDA of the tally is validated in create_and_serialize_account_signed
+        create_and_serialize_account_signed(
+            payer_info,
+            voter_weight_cap_tally_info,
+            &voter_weight_cap_tally,
+            &get_voter_weight_cap_tally_seeds(proposal, governing_token_owner),
+            &id(),
+            system_info,
+            rent,
+        )?;
+    } else {
+        voter_weight_cap_tally.serialize(&mut *voter_weight_cap_tally_info.data.borrow_mut())?;
+    }
+
+    Ok(capped_voter_weight_after - capped_voter_weight_before)
+}
+
+/// Returns voter_weight of the revoked NFTs with the Registrar caps applied
+/// The NFTs are removed from VoterWeightCapTally of the previous owner for the Proposal
+/// and the returned weight is the decrease of the capped voter_weight the previous owner voted with
+///
+/// If the previous owner voted without the tally (no voter_weight caps and the linear weighting mode)
+/// then the total weight of the NFTs is returned
+pub fn sub_capped_revoke_vote_weight(
+    registrar: &Registrar,
+    voter_weight_cap_tally_info: &AccountInfo,
+    nft_vote_infos: &[NftVoteInfo],
+) -> Result<u64> {
+    if voter_weight_cap_tally_info.data_is_empty() {
+        return Ok(registrar.get_nft_voter_weight(nft_vote_infos));
+    }
+
+    let mut voter_weight_cap_tally = get_voter_weight_cap_tally_data(voter_weight_cap_tally_info)?;
+
+    // Every revoked NFT has its own NftVoteRecord disposed by RevokeNftVote
+    voter_weight_cap_tally.sub_nft_vote_records(nft_vote_infos.len() as u32);
+
+    let capped_voter_weight_before = voter_weight_cap_tally.get_capped_voter_weight(registrar);
+
+    for nft_vote_info in nft_vote_infos {
+        voter_weight_cap_tally.sub_nft_weight(
+            &nft_vote_info.collection,
+            registrar
+                .voter_weighting_mode
+                .get_nft_tally_weight(nft_vote_info.weight),
+        )?;
+    }
+
+    let capped_voter_weight_after = voter_weight_cap_tally.get_capped_voter_weight(registrar);
+
+    voter_weight_cap_tally.serialize(&mut *voter_weight_cap_tally_info.data.borrow_mut())?;
+
+    Ok(capped_voter_weight_before - capped_voter_weight_after)
+}
+
+/// Returns voter_weight of the given NFTs with the Registrar caps applied
+/// as if they were the only NFTs the voter cast vote with on a Proposal
+pub fn get_capped_nft_voter_weight(
+    registrar: &Registrar,
+    nft_vote_infos: &[NftVoteInfo],
+) -> Result<u64> {
+    if !registrar.is_voter_weight_cap_tally_required() {
+        return Ok(registrar.get_nft_voter_weight(nft_vote_infos));
+    }
+
+    let mut voter_weight_cap_tally =
+        VoterWeightCapTally::new(registrar, &Pubkey::default(), &Pubkey::default());
+
+    for nft_vote_info in nft_vote_infos {
+        voter_weight_cap_tally.add_nft_weight(
+            &nft_vote_info.collection,
+            registrar
+                .voter_weighting_mode
+                .get_nft_tally_weight(nft_vote_info.weight),
+        )?;
+    }
+
+    Ok(voter_weight_cap_tally.get_capped_voter_weight(registrar))
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::state::{
+        CollectionConfig, ProgrammableNftPolicy, VoteTrackingMode, VoterWeightCaps,
+        VoterWeightingMode,
+    };
+
+    fn create_registrar(voter_weight_caps: VoterWeightCaps) -> Registrar {
+        Registrar {
+            governance_program_id: Pubkey::default(),
+            realm: Pubkey::default(),
+            governing_token_mint: Pubkey::default(),
+            collection_configs: vec![CollectionConfig {
+                collection: Pubkey::default(),
+                size: 100,
+                weight: 10,
+                max_weight_bonus: 0,
+            }],
+            programmable_nft_policy: ProgrammableNftPolicy::AllowAll,
+            voter_weight_caps,
+            voter_weighting_mode: VoterWeightingMode::Linear,
+            ownership_snapshot_enabled: false,
+            vote_tracking_mode: VoteTrackingMode::NftVoteRecords,
+            reserved: [0; 112],
+        }
+    }
+
+    #[test]
+    fn test_get_capped_voter_weight_accumulated_in_steps() {
+        // Arrange
+        let registrar = create_registrar(VoterWeightCaps {
+            max_voter_weight: Some(25),
+            max_collection_weight_share_bps: None,
+        });
+
+        let mut voter_weight_cap_tally = VoterWeightCapTally {
+            account_discriminator: VoterWeightCapTally::ACCOUNT_DISCRIMINATOR,
+            proposal: Pubkey::default(),
+            governing_token_owner: Pubkey::default(),
+            collection_weights: vec![CollectionVoterWeight {
+                collection: Pubkey::default(),
+                weight: 0,
+            }],
+            nft_vote_record_count: 0,
+            reserved: [0; 8],
+        };
+
+        // Act
+        let mut voter_weight_steps = vec![];
+
+        for _ in 0..4 {
+            let before = voter_weight_cap_tally.get_capped_voter_weight(&registrar);
+            voter_weight_cap_tally
+                .add_nft_weight(&Pubkey::default(), 10)
+                .unwrap();
+            let after = voter_weight_cap_tally.get_capped_voter_weight(&registrar);
+
+            voter_weight_steps.push(after - before);
+        }
+
+        // Assert
+        assert_eq!(voter_weight_steps, vec![10, 10, 5, 0]);
+    }
+
+    #[test]
+    fn test_get_capped_voter_weight_with_quadratic_mode_accumulated_in_steps() {
+        // Arrange
+        let mut registrar = create_registrar(VoterWeightCaps::default());
+        registrar.voter_weighting_mode = VoterWeightingMode::Quadratic;
+
+        let mut voter_weight_cap_tally = VoterWeightCapTally {
+            account_discriminator: VoterWeightCapTally::ACCOUNT_DISCRIMINATOR,
+            proposal: Pubkey::default(),
+            governing_token_owner: Pubkey::default(),
+            collection_weights: vec![CollectionVoterWeight {
+                collection: Pubkey::default(),
+                weight: 0,
+            }],
+            nft_vote_record_count: 0,
+            reserved: [0; 8],
+        };
+
+        // Act
+        let mut voter_weight = 0;
+
+        for _ in 0..4 {
+            let before = voter_weight_cap_tally.get_capped_voter_weight(&registrar);
+            voter_weight_cap_tally
+                .add_nft_weight(
+                    &Pubkey::default(),
+                    registrar.voter_weighting_mode.get_nft_tally_weight(10),
+                )
+                .unwrap();
+            let after = voter_weight_cap_tally.get_capped_voter_weight(&registrar);
+
+            voter_weight += after - before;
+        }
+
+        // Assert
+        // sqrt(4) NFTs
+        assert_eq!(voter_weight, 2_000_000);
+        assert_eq!(registrar.get_max_voter_weight(), 100 * 1_000_000);
+    }
+
+    #[test]
+    fn test_get_capped_nft_voter_weight() {
+        // Arrange
+        let registrar = create_registrar(VoterWeightCaps {
+            max_voter_weight: Some(25),
+            max_collection_weight_share_bps: None,
+        });
+
+        let nft_vote_infos = (0..4)
+            .map(|_| NftVoteInfo {
+                weight: 10,
+                mint: Pubkey::new_unique(),
+                collection: Pubkey::default(),
+                token_standard: None,
+            })
+            .collect::<Vec<_>>();
+
+        // Act
+        let voter_weight = get_capped_nft_voter_weight(&registrar, &nft_vote_infos).unwrap();
+
+        // Assert
+        assert_eq!(voter_weight, 25);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::error::NftVoterError;
+use anchor_lang::prelude::*;
+
+/// Basis points representing 100% share
+pub const MAX_BPS: u16 = 10_000;
+
+/// Caps applied to voter_weight cast on a Proposal by a single governing_token_owner
+/// The caps limit the governance power of whales holding large numbers of NFTs
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct VoterWeightCaps {
+    /// Max voter_weight a single governing_token_owner can cast on a Proposal
+    pub max_voter_weight: Option<u64>,
+
+    /// Max share of max_voter_weight a single governing_token_owner can cast on a Proposal
+    /// with NFTs from any one collection
+    /// The share is expressed in basis points (1/100 of a percent)
+    pub max_collection_weight_share_bps: Option<u16>,
+}
+
+impl VoterWeightCaps {
+    /// Returns true if any of the caps is set
+    pub fn is_enabled(&self) -> bool {
+        self.max_voter_weight.is_some() || self.max_collection_weight_share_bps.is_some()
+    }
+
+    /// Returns the max voter_weight which can be cast with NFTs from a single collection
+    pub fn get_max_collection_weight(&self, max_voter_weight: u64) -> Option<u64> {
+        self.max_collection_weight_share_bps.map(|share_bps| {
+            (max_voter_weight as u128 * share_bps as u128 / MAX_BPS as u128) as u64
+        })
+    }
+
+    /// Returns voter_weight with the caps applied for the given weights of each collection
+    pub fn get_capped_voter_weight<I: IntoIterator<Item = u64>>(
+        &self,
+        collection_weights: I,
+        max_voter_weight: u64,
+    ) -> u64 {
+        self.get_max_voter_weight_capped(
+            self.get_collection_weight_share_capped(collection_weights, max_voter_weight),
+        )
+    }
+
+    /// Returns the total weight of the collections with max_collection_weight_share cap applied to each collection
+    /// The share is calculated from the given max_weight
+    pub fn get_collection_weight_share_capped<I: IntoIterator<Item = u64>>(
+        &self,
+        collection_weights: I,
+        max_weight: u64,
+    ) -> u64 {
+        let max_collection_weight = self.get_max_collection_weight(max_weight);
+
+        collection_weights
+            .into_iter()
+            .map(|weight| max_collection_weight.map_or(weight, |max| weight.min(max)))
+            .try_fold(0u64, |sum, weight| sum.checked_add(weight))
+            .unwrap()
+    }
+
+    /// Returns voter_weight with max_voter_weight cap applied
+    pub fn get_max_voter_weight_capped(&self, voter_weight: u64) -> u64 {
+        self.max_voter_weight
+            .map_or(voter_weight, |max| voter_weight.min(max))
+    }
+}
+
+/// Asserts the caps are valid
+/// The caps must be positive and the collection share can't exceed 100%
+pub fn assert_valid_voter_weight_caps(voter_weight_caps: &VoterWeightCaps) -> Result<()> {
+    require!(
+        voter_weight_caps.max_voter_weight != Some(0),
+        NftVoterError::InvalidVoterWeightCaps
+    );
+
+    require!(
+        voter_weight_caps
+            .max_collection_weight_share_bps
+            .map_or(true, |share_bps| share_bps > 0 && share_bps <= MAX_BPS),
+        NftVoterError::InvalidVoterWeightCaps
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_get_capped_voter_weight_without_caps() {
+        // Arrange
+        let voter_weight_caps = VoterWeightCaps::default();
+
+        // Act
+        let voter_weight = voter_weight_caps.get_capped_voter_weight(vec![100, 200], 1000);
+
+        // Assert
+        assert_eq!(voter_weight, 300);
+    }
+
+    #[test]
+    fn test_get_capped_voter_weight_with_max_voter_weight() {
+        // Arrange
+        let voter_weight_caps = VoterWeightCaps {
+            max_voter_weight: Some(250),
+            max_collection_weight_share_bps: None,
+        };
+
+        // Act
+        let voter_weight = voter_weight_caps.get_capped_voter_weight(vec![100, 200], 1000);
+
+        // Assert
+        assert_eq!(voter_weight, 250);
+    }
+
+    #[test]
+    fn test_get_capped_voter_weight_with_max_collection_weight_share() {
+        // Arrange
+        let voter_weight_caps = VoterWeightCaps {
+            max_voter_weight: None,
+            max_collection_weight_share_bps: Some(1500),
+        };
+
+        // Act
+        let voter_weight = voter_weight_caps.get_capped_voter_weight(vec![100, 200], 1000);
+
+        // Assert
+        assert_eq!(voter_weight, 100 + 150);
+    }
+
+    #[test]
+    fn test_get_capped_voter_weight_with_both_caps() {
+        // Arrange
+        let voter_weight_caps = VoterWeightCaps {
+            max_voter_weight: Some(200),
+            max_collection_weight_share_bps: Some(1500),
+        };
+
+        // Act
+        let voter_weight = voter_weight_caps.get_capped_voter_weight(vec![100, 200], 1000);
+
+        // Assert
+        assert_eq!(voter_weight, 200);
+    }
+
+    #[test]
+    fn test_assert_valid_voter_weight_caps() {
+        // Arrange
+        let invalid_caps = [
+            VoterWeightCaps {
+                max_voter_weight: Some(0),
+                max_collection_weight_share_bps: None,
+            },
+            VoterWeightCaps {
+                max_voter_weight: None,
+                max_collection_weight_share_bps: Some(0),
+            },
+            VoterWeightCaps {
+                max_voter_weight: None,
+                max_collection_weight_share_bps: Some(MAX_BPS + 1),
+            },
+        ];
+
+        // Act + Assert
+        for caps in invalid_caps.iter() {
+            assert!(assert_valid_voter_weight_caps(caps).is_err());
+        }
+
+        assert!(assert_valid_voter_weight_caps(&VoterWeightCaps::default()).is_ok());
+        assert!(assert_valid_voter_weight_caps(&VoterWeightCaps {
+            max_voter_weight: Some(1),
+            max_collection_weight_share_bps: Some(MAX_BPS),
+        })
+        .is_ok());
+    }
+}
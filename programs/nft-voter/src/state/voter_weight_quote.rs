@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use solana_program::program::MAX_RETURN_DATA;
+
+use gpl_voter_weight_plugin::space::PUBKEY_SIZE;
+
+/// Voter weight quote of an NFT provided to GetVoterWeight
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct NftVoterWeightQuote {
+    /// The mint of the NFT
+    /// If the NFT token account can't be read then it's the address of the token account
+    pub mint: Pubkey,
+
+    /// Governance power weight of the NFT
+    /// It's 0 if the NFT was rejected
+    pub weight: u64,
+
+    /// The error the NFT was rejected with encoded as ProgramError
+    /// For NftVoterError and Anchor errors it's the error code
+    /// None if the NFT can be used to vote
+    pub rejection_code: Option<u64>,
+}
+
+impl NftVoterWeightQuote {
+    pub const SPACE: usize = PUBKEY_SIZE + 8 + 1 + 8;
+}
+
+/// Voter weight quote returned by GetVoterWeight using the instruction return data
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq, Default)]
+pub struct VoterWeightQuote {
+    /// The voter_weight of the voter with the Registrar voter_weighting_mode and voter_weight caps applied
+    pub voter_weight: u64,
+
+    /// The number of NFTs locked in the voter's NftDepositRecord counted in voter_weight
+    pub deposited_nft_count: u32,
+
+    /// The total weight of the NFTs locked in the voter's NftDepositRecord
+    pub deposited_nft_weight: u64,
+
+    /// Quotes of the NFTs provided in the remaining accounts in the same order
+    pub nfts: Vec<NftVoterWeightQuote>,
+}
+
+impl VoterWeightQuote {
+    /// The max number of NFTs which can be quoted within the return data size limit
+    pub const MAX_NFTS: usize =
+        (MAX_RETURN_DATA - VoterWeightQuote::get_space(0)) / NftVoterWeightQuote::SPACE;
+
+    pub const fn get_space(nfts_count: usize) -> usize {
+        8 + 4 + 8 + 4 + nfts_count * NftVoterWeightQuote::SPACE
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = VoterWeightQuote::get_space(2);
+
+        let voter_weight_quote = VoterWeightQuote {
+            voter_weight: 10,
+            deposited_nft_count: 1,
+            deposited_nft_weight: 5,
+            nfts: vec![
+                NftVoterWeightQuote {
+                    mint: Pubkey::new_unique(),
+                    weight: 5,
+                    rejection_code: None,
+                },
+                NftVoterWeightQuote {
+                    mint: Pubkey::new_unique(),
+                    weight: 0,
+                    rejection_code: Some(6010),
+                },
+            ],
+        };
+
+        // Act
+        let actual_space = voter_weight_quote.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+
+    #[test]
+    fn test_max_nfts_fit_into_return_data() {
+        // Arrange
+        let voter_weight_quote = VoterWeightQuote {
+            nfts: vec![
+                NftVoterWeightQuote {
+                    mint: Pubkey::new_unique(),
+                    weight: u64::MAX,
+                    rejection_code: Some(u64::MAX),
+                };
+                VoterWeightQuote::MAX_NFTS
+            ],
+            ..Default::default()
+        };
+
+        // Act
+        let data = voter_weight_quote.try_to_vec().unwrap();
+
+        // Assert
+        assert!(data.len() <= MAX_RETURN_DATA);
+    }
+}
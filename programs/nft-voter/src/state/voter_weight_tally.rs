@@ -0,0 +1,100 @@
+use crate::id;
+use crate::state::VoterWeightAction;
+use anchor_lang::prelude::*;
+use gpl_voter_weight_plugin::space::{DISCRIMINATOR_SIZE, PUBKEY_SIZE};
+
+/// The number of slots the VoterWeightTally can be accumulated for before it's reset
+/// It gives time to submit all the transactions required to tally the NFTs of the voter
+/// and it's the time an NFT counted by the tally can't be counted by another voter's tally (see NftTallyRecord)
+pub const VOTER_WEIGHT_TALLY_MAX_SLOTS: u64 = 150;
+
+/// Bookkeeping account used to accumulate voter_weight for non voting actions (CreateProposal, CreateGovernance etc...)
+/// using several transactions
+/// The PDA of the tally is ["voter-weight-tally",voter_weight_record]
+///
+/// The tally stores the NFTs which were already counted to ensure the same NFT can't be counted twice
+/// It's reset when it's used for a different action or when it's older than VOTER_WEIGHT_TALLY_MAX_SLOTS
+#[account]
+#[derive(Debug, PartialEq)]
+pub struct VoterWeightTally {
+    /// VoterWeightRecord the tally accumulates voter_weight for
+    pub voter_weight_record: Pubkey,
+
+    /// The governance action the tally accumulates voter_weight for
+    pub weight_action: Option<VoterWeightAction>,
+
+    /// The slot when the tally was started
+    pub start_slot: u64,
+
+    /// The accumulated voter_weight
+    /// For the non linear weighting modes it's the number of the counted NFTs
+    /// and the weighting mode curve is applied to it when VoterWeightRecord is updated
+    pub voter_weight: u64,
+
+    /// The NFTs which were already counted
+    pub nft_mints: Vec<Pubkey>,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 32],
+}
+
+impl VoterWeightTally {
+    pub fn get_space(max_nfts: u16) -> usize {
+        DISCRIMINATOR_SIZE + PUBKEY_SIZE + 1 + 1 + 8 + 8 + 4 + max_nfts as usize * PUBKEY_SIZE + 32
+    }
+
+    /// Returns the max number of NFTs the tally account can store
+    pub fn get_max_nfts(account_size: usize) -> usize {
+        account_size.saturating_sub(Self::get_space(0)) / PUBKEY_SIZE
+    }
+
+    /// Resets the tally if it's for a different action or expired
+    pub fn reset_if_stale(&mut self, weight_action: VoterWeightAction, current_slot: u64) {
+        if self.weight_action != Some(weight_action)
+            || current_slot > self.start_slot.saturating_add(VOTER_WEIGHT_TALLY_MAX_SLOTS)
+        {
+            self.weight_action = Some(weight_action);
+            self.start_slot = current_slot;
+            self.voter_weight = 0;
+            self.nft_mints = vec![];
+        }
+    }
+}
+
+/// Returns VoterWeightTally PDA seeds
+pub fn get_voter_weight_tally_seeds(voter_weight_record: &Pubkey) -> [&[u8]; 2] {
+    [b"voter-weight-tally", voter_weight_record.as_ref()]
+}
+
+/// Returns VoterWeightTally PDA address
+pub fn get_voter_weight_tally_address(voter_weight_record: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&get_voter_weight_tally_seeds(voter_weight_record), &id()).0
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = VoterWeightTally::get_space(3);
+
+        let voter_weight_tally = VoterWeightTally {
+            voter_weight_record: Pubkey::default(),
+            weight_action: Some(VoterWeightAction::CreateProposal),
+            start_slot: 0,
+            voter_weight: 0,
+            nft_mints: vec![Pubkey::default(), Pubkey::default(), Pubkey::default()],
+            reserved: [0; 32],
+        };
+
+        // Act
+        let actual_space = DISCRIMINATOR_SIZE + voter_weight_tally.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+        assert_eq!(VoterWeightTally::get_max_nfts(actual_space), 3);
+    }
+}
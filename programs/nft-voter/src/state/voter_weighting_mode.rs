@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+
+/// voter_weight of a single NFT for the non linear weighting modes
+/// The curves are scaled to preserve precision of the fractional weights
+pub const VOTER_WEIGHT_CURVE_SCALE: u64 = 1_000_000;
+
+/// Number of fractional bits used to calculate the logarithmic curve
+const LOG2_FRACTION_BITS: u32 = 32;
+
+/// Weighting mode used to calculate voter_weight of the NFTs owned by a voter
+///
+/// For the non linear modes voter_weight is a function of the number of NFTs the voter owns
+/// and the collection weights are only used to determine whether an NFT has governance power
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum VoterWeightingMode {
+    /// voter_weight is the sum of the NFT weights
+    Linear,
+
+    /// voter_weight is the square root of the number of NFTs
+    Quadratic,
+
+    /// voter_weight is the base 2 logarithm of the number of NFTs plus one
+    Logarithmic,
+}
+
+impl Default for VoterWeightingMode {
+    fn default() -> Self {
+        VoterWeightingMode::Linear
+    }
+}
+
+impl VoterWeightingMode {
+    /// Returns true if voter_weight is the sum of the NFT weights
+    pub fn is_linear(&self) -> bool {
+        *self == VoterWeightingMode::Linear
+    }
+
+    /// Returns the weight the NFT is tallied with
+    /// For the non linear modes every NFT with governance power is tallied as a single NFT
+    pub fn get_nft_tally_weight(&self, nft_weight: u64) -> u64 {
+        match self {
+            VoterWeightingMode::Linear => nft_weight,
+            VoterWeightingMode::Quadratic | VoterWeightingMode::Logarithmic => {
+                (nft_weight > 0) as u64
+            }
+        }
+    }
+
+    /// Returns voter_weight for the tallied NFT weights
+    /// For the non linear modes the tallied weight is the number of NFTs and the curve is applied to it
+    pub fn get_voter_weight(&self, tally_weight: u64) -> u64 {
+        match self {
+            VoterWeightingMode::Linear => tally_weight,
+            VoterWeightingMode::Quadratic => {
+                let scale = VOTER_WEIGHT_CURVE_SCALE as u128;
+                isqrt(tally_weight as u128 * scale * scale) as u64
+            }
+            VoterWeightingMode::Logarithmic => {
+                let log2 = log2_fixed(tally_weight as u128 + 1);
+                ((log2 * VOTER_WEIGHT_CURVE_SCALE as u128) >> LOG2_FRACTION_BITS) as u64
+            }
+        }
+    }
+}
+
+/// Returns the integer square root of the given value
+fn isqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    // Newton's method starting from a value greater than the root
+    let mut x = 1u128 << ((128 - value.leading_zeros() + 1) / 2);
+
+    loop {
+        let next = (x + value / x) / 2;
+
+        if next >= x {
+            return x;
+        }
+
+        x = next;
+    }
+}
+
+/// Returns base 2 logarithm of the given value as a fixed point number with LOG2_FRACTION_BITS fractional bits
+fn log2_fixed(value: u128) -> u128 {
+    let integer_part = (127 - value.leading_zeros()) as u128;
+
+    // Normalize the value to [1, 2) and calculate the fractional bits by repeated squaring
+    let one = 1u128 << LOG2_FRACTION_BITS;
+    let mut y = (value << LOG2_FRACTION_BITS) >> integer_part;
+    let mut fraction = 0u128;
+
+    for bit in (0..LOG2_FRACTION_BITS).rev() {
+        y = (y * y) >> LOG2_FRACTION_BITS;
+
+        if y >= 2 * one {
+            y >>= 1;
+            fraction |= 1 << bit;
+        }
+    }
+
+    (integer_part << LOG2_FRACTION_BITS) | fraction
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_get_voter_weight_for_single_nft_is_scale() {
+        // Act + Assert
+        assert_eq!(
+            VoterWeightingMode::Quadratic.get_voter_weight(1),
+            VOTER_WEIGHT_CURVE_SCALE
+        );
+        assert_eq!(
+            VoterWeightingMode::Logarithmic.get_voter_weight(1),
+            VOTER_WEIGHT_CURVE_SCALE
+        );
+    }
+
+    #[test]
+    fn test_get_voter_weight_with_quadratic_mode() {
+        // Arrange
+        let mode = VoterWeightingMode::Quadratic;
+
+        // Act + Assert
+        assert_eq!(mode.get_voter_weight(0), 0);
+        assert_eq!(mode.get_voter_weight(4), 2 * VOTER_WEIGHT_CURVE_SCALE);
+        assert_eq!(mode.get_voter_weight(100), 10 * VOTER_WEIGHT_CURVE_SCALE);
+        assert_eq!(mode.get_voter_weight(2), 1_414_213);
+        assert_eq!(mode.get_voter_weight(u64::MAX), 4_294_967_295_999_999);
+    }
+
+    #[test]
+    fn test_get_voter_weight_with_logarithmic_mode() {
+        // Arrange
+        let mode = VoterWeightingMode::Logarithmic;
+
+        // Act + Assert
+        assert_eq!(mode.get_voter_weight(0), 0);
+        assert_eq!(mode.get_voter_weight(3), 2 * VOTER_WEIGHT_CURVE_SCALE);
+        assert_eq!(mode.get_voter_weight(1023), 10 * VOTER_WEIGHT_CURVE_SCALE);
+        assert_eq!(mode.get_voter_weight(2), 1_584_962);
+        assert_eq!(
+            mode.get_voter_weight(u64::MAX),
+            64 * VOTER_WEIGHT_CURVE_SCALE
+        );
+    }
+
+    #[test]
+    fn test_get_voter_weight_with_linear_mode() {
+        // Arrange
+        let mode = VoterWeightingMode::Linear;
+
+        // Act + Assert
+        assert_eq!(mode.get_nft_tally_weight(10), 10);
+        assert_eq!(mode.get_voter_weight(30), 30);
+    }
+
+    #[test]
+    fn test_get_nft_tally_weight_with_non_linear_modes() {
+        // Act + Assert
+        for mode in [
+            VoterWeightingMode::Quadratic,
+            VoterWeightingMode::Logarithmic,
+        ]
+        .iter()
+        {
+            assert_eq!(mode.get_nft_tally_weight(10), 1);
+            assert_eq!(mode.get_nft_tally_weight(0), 0);
+        }
+    }
+
+    #[test]
+    fn test_non_linear_curves_are_subadditive() {
+        // Splitting NFTs between several voters can't exceed voter_weight of the NFTs held by individual voters
+        // and hence max_voter_weight is the number of NFTs times voter_weight of a single NFT
+        for mode in [
+            VoterWeightingMode::Quadratic,
+            VoterWeightingMode::Logarithmic,
+        ]
+        .iter()
+        {
+            for a in 0..50u64 {
+                for b in 0..50u64 {
+                    assert!(
+                        mode.get_voter_weight(a + b)
+                            <= mode.get_voter_weight(a) + mode.get_voter_weight(b)
+                    );
+                }
+            }
+        }
+    }
+}
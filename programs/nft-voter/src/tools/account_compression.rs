@@ -0,0 +1,136 @@
+use std::str::FromStr;
+
+use anchor_lang::prelude::*;
+use arrayref::array_ref;
+use solana_program::keccak;
+
+use crate::error::NftVoterError;
+
+#[derive(Debug, Clone)]
+pub struct SplAccountCompressionProgram;
+
+impl anchor_lang::Id for SplAccountCompressionProgram {
+    fn id() -> Pubkey {
+        Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap()
+    }
+}
+
+/// CompressionAccountType::ConcurrentMerkleTree
+pub const CONCURRENT_MERKLE_TREE_ACCOUNT_TYPE: u8 = 1;
+
+/// ConcurrentMerkleTreeHeaderData::V1
+pub const CONCURRENT_MERKLE_TREE_HEADER_VERSION_V1: u8 = 0;
+
+/// account_type(1), version(1), max_buffer_size(4), max_depth(4), authority(32), creation_slot(8), padding(6)
+pub const CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1: usize = 56;
+
+/// ConcurrentMerkleTree layout: sequence_number(8), active_index(8), buffer_size(8), change_logs, rightmost_proof
+pub const CONCURRENT_MERKLE_TREE_CHANGE_LOGS_OFFSET: usize = 24;
+
+/// ConcurrentMerkleTreeHeader V1 fields required to verify leaves
+#[derive(Debug, Clone, PartialEq)]
+pub struct MerkleTreeHeader {
+    pub max_buffer_size: u32,
+    pub max_depth: u32,
+    pub authority: Pubkey,
+}
+
+/// Computationally cheap method to get the header of spl-account-compression ConcurrentMerkleTree account
+/// It reads the header fields without deserializing the tree
+pub fn get_merkle_tree_header(merkle_tree_info: &AccountInfo) -> Result<MerkleTreeHeader> {
+    if *merkle_tree_info.owner != SplAccountCompressionProgram::id() {
+        return Err(NftVoterError::InvalidAccountOwner.into());
+    }
+
+    let data = merkle_tree_info.try_borrow_data()?;
+
+    if data.len() < CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1
+        || data[0] != CONCURRENT_MERKLE_TREE_ACCOUNT_TYPE
+        || data[1] != CONCURRENT_MERKLE_TREE_HEADER_VERSION_V1
+    {
+        return Err(NftVoterError::InvalidMerkleTreeAccount.into());
+    }
+
+    Ok(MerkleTreeHeader {
+        max_buffer_size: u32::from_le_bytes(*array_ref![data, 2, 4]),
+        max_depth: u32::from_le_bytes(*array_ref![data, 6, 4]),
+        authority: Pubkey::new_from_array(*array_ref![data, 10, 32]),
+    })
+}
+
+/// Returns the current root of the ConcurrentMerkleTree which is the root of the active change log
+pub fn get_merkle_tree_root(
+    merkle_tree_info: &AccountInfo,
+    header: &MerkleTreeHeader,
+) -> Result<[u8; 32]> {
+    let data = merkle_tree_info.try_borrow_data()?;
+
+    let tree_offset = CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1;
+    let change_log_size = 32 + 32 * header.max_depth as usize + 8;
+
+    if data.len() < tree_offset + CONCURRENT_MERKLE_TREE_CHANGE_LOGS_OFFSET {
+        return Err(NftVoterError::InvalidMerkleTreeAccount.into());
+    }
+
+    let active_index = u64::from_le_bytes(*array_ref![data, tree_offset + 8, 8]);
+
+    if active_index >= header.max_buffer_size as u64 {
+        return Err(NftVoterError::InvalidMerkleTreeAccount.into());
+    }
+
+    let root_offset = tree_offset
+        + CONCURRENT_MERKLE_TREE_CHANGE_LOGS_OFFSET
+        + active_index as usize * change_log_size;
+
+    if data.len() < root_offset + 32 {
+        return Err(NftVoterError::InvalidMerkleTreeAccount.into());
+    }
+
+    Ok(*array_ref![data, root_offset, 32])
+}
+
+/// Recomputes Merkle root from the given leaf and its proof the same way as spl-concurrent-merkle-tree
+pub fn recompute_merkle_root(leaf: &[u8; 32], proof: &[[u8; 32]], index: u32) -> [u8; 32] {
+    let mut node = *leaf;
+
+    for (depth, sibling) in proof.iter().enumerate() {
+        node = if (index >> depth) & 1 == 0 {
+            keccak::hashv(&[&node, sibling]).to_bytes()
+        } else {
+            keccak::hashv(&[sibling, &node]).to_bytes()
+        };
+    }
+
+    node
+}
+
+/// Asserts the given leaf is stored in the ConcurrentMerkleTree at the given index
+///
+/// Note: The leaf is verified against the current root of the tree and hence the proof must be
+/// up to date with the latest tree change and include all the nodes up to the root (canopy is not used)
+pub fn assert_valid_merkle_proof(
+    merkle_tree_info: &AccountInfo,
+    header: &MerkleTreeHeader,
+    leaf: &[u8; 32],
+    proof_infos: &[AccountInfo],
+    index: u32,
+) -> Result<()> {
+    require!(
+        proof_infos.len() == header.max_depth as usize,
+        NftVoterError::InvalidMerkleProof
+    );
+
+    let proof = proof_infos
+        .iter()
+        .map(|node| node.key.to_bytes())
+        .collect::<Vec<[u8; 32]>>();
+
+    let root = get_merkle_tree_root(merkle_tree_info, header)?;
+
+    require!(
+        recompute_merkle_root(leaf, &proof, index) == root,
+        NftVoterError::InvalidMerkleProof
+    );
+
+    Ok(())
+}
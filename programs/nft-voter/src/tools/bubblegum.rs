@@ -0,0 +1,138 @@
+use std::str::FromStr;
+
+use anchor_lang::prelude::*;
+use solana_program::keccak;
+
+use crate::tools::token_metadata::TokenStandard;
+
+#[derive(Debug, Clone)]
+pub struct BubblegumProgram;
+
+impl anchor_lang::Id for BubblegumProgram {
+    fn id() -> Pubkey {
+        Pubkey::from_str("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY").unwrap()
+    }
+}
+
+/// Bubblegum LeafSchema::V1 version byte used in the leaf hash
+pub const LEAF_SCHEMA_V1_VERSION: u8 = 1;
+
+/// TokenProgramVersion as defined in Bubblegum
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum TokenProgramVersion {
+    Original,
+    Token2022,
+}
+
+/// UseMethod as defined in Bubblegum
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum UseMethod {
+    Burn,
+    Multiple,
+    Single,
+}
+
+/// Uses as defined in Bubblegum
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Uses {
+    pub use_method: UseMethod,
+    pub remaining: u64,
+    pub total: u64,
+}
+
+/// Collection as defined in Bubblegum
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Collection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+/// Creator as defined in Bubblegum
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Creator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+/// MetadataArgs as defined in Bubblegum
+/// The layout must match Bubblegum exactly because the leaf data_hash is computed from its serialized form
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq)]
+pub struct MetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    pub edition_nonce: Option<u8>,
+    pub token_standard: Option<TokenStandard>,
+    pub collection: Option<Collection>,
+    pub uses: Option<Uses>,
+    pub token_program_version: TokenProgramVersion,
+    pub creators: Vec<Creator>,
+}
+
+/// Returns the asset id of the compressed NFT with the given nonce in the given tree
+/// Note: The asset id is the same as the mint of the NFT once it's decompressed
+pub fn get_asset_id(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"asset", merkle_tree.as_ref(), &nonce.to_le_bytes()],
+        &BubblegumProgram::id(),
+    )
+    .0
+}
+
+/// Returns the Bubblegum TreeConfig PDA which is the authority of Bubblegum trees
+pub fn get_tree_authority(merkle_tree: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[merkle_tree.as_ref()], &BubblegumProgram::id()).0
+}
+
+/// Computes the leaf data_hash the same way as Bubblegum
+pub fn hash_metadata(metadata: &MetadataArgs) -> Result<[u8; 32]> {
+    let metadata_args_hash = keccak::hashv(&[metadata.try_to_vec()?.as_slice()]);
+
+    Ok(keccak::hashv(&[
+        &metadata_args_hash.to_bytes(),
+        &metadata.seller_fee_basis_points.to_le_bytes(),
+    ])
+    .to_bytes())
+}
+
+/// Computes the leaf creator_hash the same way as Bubblegum
+pub fn hash_creators(creators: &[Creator]) -> [u8; 32] {
+    let creator_data = creators
+        .iter()
+        .map(|c| [c.address.as_ref(), &[c.verified as u8], &[c.share]].concat())
+        .collect::<Vec<_>>();
+
+    keccak::hashv(
+        creator_data
+            .iter()
+            .map(|c| c.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_ref(),
+    )
+    .to_bytes()
+}
+
+/// Computes Bubblegum LeafSchema::V1 hash
+pub fn hash_leaf(
+    asset_id: &Pubkey,
+    owner: &Pubkey,
+    delegate: &Pubkey,
+    nonce: u64,
+    data_hash: &[u8; 32],
+    creator_hash: &[u8; 32],
+) -> [u8; 32] {
+    keccak::hashv(&[
+        &[LEAF_SCHEMA_V1_VERSION],
+        asset_id.as_ref(),
+        owner.as_ref(),
+        delegate.as_ref(),
+        nonce.to_le_bytes().as_ref(),
+        data_hash.as_ref(),
+        creator_hash.as_ref(),
+    ])
+    .to_bytes()
+}
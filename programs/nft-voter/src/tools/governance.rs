@@ -1,16 +1,31 @@
-use crate::{error::NftVoterError, state::voter_weight_record::VoterWeightRecord};
-use anchor_lang::{prelude::*, Id, Key};
-use solana_program::{instruction::Instruction, msg};
-use spl_governance::state::{
-    proposal::{get_proposal_data, ProposalV2, VoteType},
-    proposal_transaction::get_proposal_transaction_data_for_proposal,
-    token_owner_record, vote_record,
+use crate::{
+    error::NftVoterError,
+    state::{
+        conditional_weight_config::{ConditionalWeightPolicy, ConditionalWeightRule},
+        voter_weight_record::{VoterWeightAction, VoterWeightRecord},
+        Registrar,
+    },
+};
+use anchor_lang::{prelude::*, Key};
+use gpl_voter_weight_plugin::{
+    realm::assert_realm_authority, voter_weight::is_weight_action_target,
+};
+use solana_program::{
+    instruction::Instruction, msg, sysvar::instructions::get_instruction_relative,
+};
+use spl_governance::{
+    instruction::GovernanceInstruction,
+    state::{
+        proposal::{get_proposal_data, ProposalOption, ProposalV2},
+        proposal_transaction::get_proposal_transaction_data_for_proposal,
+        realm::{get_realm_data_for_governing_token_mint, RealmV2},
+        token_owner_record,
+        vote_record::{self, Vote},
+    },
 };
 
 use std::str::FromStr;
 
-use super::phase_protocol::{PhaseProtocolProgram, PHASE_VOTE_DISCRIMATORS};
-
 #[derive(Debug, Clone)]
 pub struct DedSplGovernanceProgram;
 
@@ -37,49 +52,248 @@ pub fn get_vote_record_address(
     vote_record::get_vote_record_address(program_id, proposal, &token_owner_record_key)
 }
 
-pub fn is_phase_option(proposal: &ProposalV2) -> bool {
-    if proposal.options.len() != 1
-        || proposal.options[0].transactions_count != 1
-        || proposal.options[0].label != "Reject"
-        || proposal.vote_type != VoteType::SingleChoice
-    {
-        return false;
+/// Returns the Realm of the Registrar and asserts realm_authority is the current authority of the Realm
+/// The governing_token_mint of the Registrar must be one of the Realm mints
+pub fn get_realm_data_for_realm_authority(
+    registrar: &Registrar,
+    realm_info: &AccountInfo,
+    realm_authority: &Pubkey,
+) -> Result<RealmV2> {
+    let realm = get_realm_data_for_governing_token_mint(
+        &registrar.governance_program_id,
+        realm_info,
+        &registrar.governing_token_mint,
+    )?;
+
+    assert_realm_authority(
+        realm.authority,
+        realm_authority,
+        NftVoterError::InvalidRealmAuthority,
+    )?;
+
+    Ok(realm)
+}
+
+/// Returns spl-gov instruction if it's bundled right after the current instruction
+/// The instruction must be the last instruction in the transaction
+/// and it must be executed by the given spl-governance instance
+fn get_next_governance_instruction(
+    instruction_sysvar_account: &AccountInfo,
+    governance_program_id: &Pubkey,
+) -> Result<Option<Instruction>> {
+    let spl_ix_result = get_instruction_relative(1, instruction_sysvar_account);
+
+    let spl_ix = if let Ok(ix) = spl_ix_result {
+        //Check there are no more instructions after the spl-gov instruction
+        get_instruction_relative(2, instruction_sysvar_account).unwrap_err();
+        require_keys_eq!(
+            ix.program_id,
+            *governance_program_id,
+            NftVoterError::InvalidGovernanceProgram
+        );
+        Some(ix)
+    } else {
+        None
+    };
+
+    Ok(spl_ix)
+}
+
+/// Returns spl-gov CastVote instruction if it's bundled right after the current instruction
+/// The CastVote instruction must be the last instruction in the transaction
+/// and it must be executed by the given spl-governance instance
+pub fn get_cast_vote_instruction(
+    instruction_sysvar_account: &AccountInfo,
+    governance_program_id: &Pubkey,
+) -> Result<Option<Instruction>> {
+    get_next_governance_instruction(instruction_sysvar_account, governance_program_id)
+}
+
+/// Asserts spl-gov RevokeVote instruction is bundled right after the current instruction
+/// The RevokeVote instruction must be the last instruction in the transaction
+/// and it must revoke the vote of the given VoteRecord using the given VoterWeightRecord
+pub fn assert_revoke_vote_instruction(
+    instruction_sysvar_account: &AccountInfo,
+    governance_program_id: &Pubkey,
+    vote_record: &Pubkey,
+    voter_weight_record: &Pubkey,
+) -> Result<()> {
+    let revoke_vote_ix =
+        get_next_governance_instruction(instruction_sysvar_account, governance_program_id)?
+            .ok_or(NftVoterError::MustIncludeRevokeVoteInstruction)?;
+
+    let accounts = &revoke_vote_ix.accounts;
+
+    require!(
+        matches!(
+            GovernanceInstruction::try_from_slice(&revoke_vote_ix.data),
+            Ok(GovernanceInstruction::RevokeVote { .. })
+        ) && accounts
+            .iter()
+            .any(|account| account.pubkey == *vote_record && account.is_writable)
+            && accounts
+                .iter()
+                .any(|account| account.pubkey == *voter_weight_record),
+        NftVoterError::InvalidRevokeVoteInstruction
+    );
+
+    Ok(())
+}
+
+/// Index of the Proposal account in spl-gov CastVote instruction accounts
+pub const CAST_VOTE_PROPOSAL_INDEX: usize = 2;
+
+/// Index of the voter's TokenOwnerRecord account in spl-gov CastVote instruction accounts
+pub const CAST_VOTE_VOTER_TOKEN_OWNER_RECORD_INDEX: usize = 4;
+
+/// Decodes spl-gov CastVote instruction and returns its Vote
+/// The instruction must cast vote on the given Proposal for the owner of the VoterWeightRecord
+/// and it must use the VoterWeightRecord
+pub fn get_cast_vote(
+    cast_vote_ix: &Instruction,
+    governance_program_id: &Pubkey,
+    proposal: &Pubkey,
+    voter_weight_record: &Account<VoterWeightRecord>,
+) -> Result<Vote> {
+    let vote = match GovernanceInstruction::try_from_slice(&cast_vote_ix.data) {
+        Ok(GovernanceInstruction::CastVote { vote }) => vote,
+        _ => return Err(NftVoterError::InvalidCastVoteInstruction.into()),
+    };
+
+    let voter_token_owner_record = token_owner_record::get_token_owner_record_address(
+        governance_program_id,
+        &voter_weight_record.realm,
+        &voter_weight_record.governing_token_mint,
+        &voter_weight_record.governing_token_owner,
+    );
+
+    let accounts = &cast_vote_ix.accounts;
+
+    require!(
+        accounts.len() > CAST_VOTE_VOTER_TOKEN_OWNER_RECORD_INDEX
+            && accounts[CAST_VOTE_PROPOSAL_INDEX].pubkey == *proposal
+            && accounts[CAST_VOTE_VOTER_TOKEN_OWNER_RECORD_INDEX].pubkey
+                == voter_token_owner_record
+            && accounts
+                .iter()
+                .any(|account| account.pubkey == voter_weight_record.key()),
+        NftVoterError::InvalidCastVoteInstruction
+    );
+
+    Ok(vote)
+}
+
+/// Updates VoterWeightRecord with the voter_weight to cast vote on the given Proposal in the current slot
+/// Returns the voter weight added to the VoterWeightRecord
+pub fn add_cast_vote_voter_weight(
+    proposal_info: &AccountInfo,
+    governance_program_id: &Pubkey,
+    proposal_transaction_info: Option<&AccountInfo>,
+    cast_vote_ix: Option<Instruction>,
+    conditional_weight_rules: &[ConditionalWeightRule],
+    voter_weight: u64,
+    voter_weight_record: &mut Account<VoterWeightRecord>,
+) -> Result<u64> {
+    // If cast vote is called for the same proposal then we keep accumulating the weight
+    // this way cast vote can be called multiple times in different transactions to allow voting with any number of NFTs
+    if !is_weight_action_target(
+        voter_weight_record.weight_action,
+        voter_weight_record.weight_action_target,
+        VoterWeightAction::CastVote,
+        proposal_info.key,
+    ) {
+        voter_weight_record.voter_weight = 0;
     }
 
-    true
+    let previous_voter_weight = voter_weight_record.voter_weight;
+
+    let cast_vote = cast_vote_ix
+        .map(|ix| {
+            get_cast_vote(
+                &ix,
+                governance_program_id,
+                &proposal_info.key(),
+                voter_weight_record,
+            )
+        })
+        .transpose()?;
+
+    add_voter_weight(
+        proposal_info,
+        governance_program_id,
+        proposal_transaction_info,
+        cast_vote,
+        conditional_weight_rules,
+        voter_weight,
+        voter_weight_record,
+    )?;
+
+    // The record is only valid as of the current slot
+    voter_weight_record.voter_weight_expiry = Some(Clock::get()?.slot);
+
+    // The record is only valid for casting vote on the given Proposal
+    voter_weight_record.weight_action = Some(VoterWeightAction::CastVote);
+    voter_weight_record.weight_action_target = Some(proposal_info.key());
+
+    // Note: The weight is reset to 0 when the vote is not allowed by conditional weight rules
+    Ok(voter_weight_record
+        .voter_weight
+        .saturating_sub(previous_voter_weight))
+}
+
+/// Returns the option of the Proposal the conditional weight rules are checked for
+/// The rules are only checked for Proposals with a single ProposalTransaction
+/// which unambiguously determines the instruction the Proposal executes
+pub fn get_conditional_weight_option(proposal: &ProposalV2) -> Option<&ProposalOption> {
+    let mut options = proposal
+        .options
+        .iter()
+        .filter(|option| option.transactions_count > 0);
+
+    match (options.next(), options.next()) {
+        (Some(option), None) if option.transactions_count == 1 => Some(option),
+        _ => None,
+    }
 }
 
 pub fn add_voter_weight(
     proposal_info: &AccountInfo,
     governance_program_id: &Pubkey,
     proposal_transaction_info: Option<&AccountInfo>,
-    cast_vote_ix: Option<Instruction>,
+    cast_vote: Option<Vote>,
+    conditional_weight_rules: &[ConditionalWeightRule],
     voter_weight: u64,
     voter_weight_record: &mut Account<VoterWeightRecord>,
 ) -> Result<()> {
     let proposal = get_proposal_data(governance_program_id, proposal_info)?;
-    if let Some(vote_ix) = cast_vote_ix {
-        if is_phase_option(&proposal) {
 
-            if !proposal_transaction_info.is_some() {
-                return Err(NftVoterError::MustIncludeProposalTransactionForPhaseVotes.into());
-            }
-
-            if let Some(prop_info) = proposal_transaction_info {
+    if let Some(vote) = cast_vote {
+        if let Some(option) = get_conditional_weight_option(&proposal) {
+            // ProposalTransaction must be provided for Proposals which can match any of the rules
+            if conditional_weight_rules
+                .iter()
+                .any(|rule| rule.is_option_match(&option.label))
+            {
+                let proposal_transaction_info = proposal_transaction_info
+                    .ok_or(NftVoterError::MustIncludeProposalTransactionForPhaseVotes)?;
 
-                let is_phase =
-                    is_phase_transaction(&prop_info, &governance_program_id, &proposal_info.key())?;
+                let weight_policy = get_conditional_weight_policy(
+                    proposal_transaction_info,
+                    governance_program_id,
+                    proposal_info.key,
+                    &option.label,
+                    conditional_weight_rules,
+                )?;
 
-                if is_phase {
-                    if vote_ix.data.as_slice() != [13, 0, 1, 0, 0, 0, 0, 100] {
-                        msg!("Not an approve option for vote setting weight to 0");
-                        voter_weight_record.voter_weight = 0;
-                        return Ok(())
-                    }
+                if !weight_policy.map_or(true, |policy| policy.is_weighted_vote(&vote)) {
+                    msg!("Vote not allowed by conditional weight rule setting weight to 0");
+                    voter_weight_record.voter_weight = 0;
+                    return Ok(());
                 }
             }
         }
     }
+
     voter_weight_record.voter_weight = voter_weight_record
         .voter_weight
         .checked_add(voter_weight)
@@ -88,33 +302,32 @@ pub fn add_voter_weight(
     Ok(())
 }
 
-pub fn is_phase_transaction(
+/// Returns the policy of the conditional weight rule matching the ProposalTransaction
+/// None is returned if no rule matches the ProposalTransaction
+pub fn get_conditional_weight_policy(
     proposal_transaction_info: &AccountInfo,
     governance_program_id: &Pubkey,
     proposal_key: &Pubkey,
-) -> Result<bool> {
+    option_label: &str,
+    conditional_weight_rules: &[ConditionalWeightRule],
+) -> Result<Option<ConditionalWeightPolicy>> {
     let proposal_transaction = get_proposal_transaction_data_for_proposal(
-        &governance_program_id,
-        &proposal_transaction_info,
-        &proposal_key,
+        governance_program_id,
+        proposal_transaction_info,
+        proposal_key,
     )?;
 
     if proposal_transaction.instructions.len() != 1 {
-        return Ok(false);
+        return Ok(None);
     }
 
     let instruction = &proposal_transaction.instructions[0];
 
-    let mut discrimanator: [u8; 8] = Default::default();
-    discrimanator.copy_from_slice(&instruction.data[0..8]);
-
-    if !PHASE_VOTE_DISCRIMATORS.contains(&discrimanator) {
-        return Ok(false);
-    }
-
-    if proposal_transaction.instructions[0].program_id != PhaseProtocolProgram::id() {
-        return Ok(false);
-    }
-
-    Ok(true)
+    Ok(conditional_weight_rules
+        .iter()
+        .find(|rule| {
+            rule.is_option_match(option_label)
+                && rule.is_match(&instruction.program_id, &instruction.data)
+        })
+        .map(|rule| rule.weight_policy))
 }
@@ -1,4 +1,5 @@
-pub mod anchor;
+pub mod account_compression;
+pub mod bubblegum;
 pub mod governance;
 pub mod phase_protocol;
 pub mod spl_token;
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+use mpl_token_metadata::state::{Collection, Creator, Key, Metadata, TokenMetadataAccount};
 
 use crate::error::NftVoterError;
 
@@ -29,3 +29,161 @@ pub fn get_token_metadata_for_mint(account_info: &AccountInfo, mint: &Pubkey) ->
 
     Ok(token_metadata)
 }
+
+/// TokenStandard as defined in mpl-token-metadata
+/// It's redefined here because the programmable standards are not supported by the mpl-token-metadata version used by the program
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum TokenStandard {
+    NonFungible,
+    FungibleAsset,
+    Fungible,
+    NonFungibleEdition,
+    ProgrammableNonFungible,
+    ProgrammableNonFungibleEdition,
+}
+
+impl TokenStandard {
+    pub fn is_programmable(&self) -> bool {
+        matches!(
+            self,
+            TokenStandard::ProgrammableNonFungible | TokenStandard::ProgrammableNonFungibleEdition
+        )
+    }
+}
+
+/// Metadata fields used to resolve NFT vote weight
+#[derive(Debug, Clone, PartialEq)]
+pub struct NftMetadata {
+    pub mint: Pubkey,
+    pub token_standard: Option<TokenStandard>,
+    pub collection: Option<Collection>,
+}
+
+/// Reads the Metadata fields up to and including the collection without deserializing the full account
+/// The full Metadata can't be deserialized using the mpl-token-metadata version used by the program
+/// when the NFT uses one of the programmable token standards
+pub fn get_nft_metadata_for_mint(account_info: &AccountInfo, mint: &Pubkey) -> Result<NftMetadata> {
+    if *account_info.owner != mpl_token_metadata::ID {
+        return Err(NftVoterError::InvalidAccountOwner.into());
+    }
+
+    let data = account_info.try_borrow_data()?;
+
+    if data.is_empty() || data[0] != Key::MetadataV1 as u8 {
+        return Err(NftVoterError::InvalidTokenMetadataAccount.into());
+    }
+
+    let buf = &mut &data[1..];
+
+    // Metadata layout: key, update_authority, mint, data(name, symbol, uri, seller_fee_basis_points, creators),
+    // primary_sale_happened, is_mutable, edition_nonce, token_standard, collection, ...
+    let _update_authority = Pubkey::deserialize(buf)?;
+    let metadata_mint = Pubkey::deserialize(buf)?;
+    let _name = String::deserialize(buf)?;
+    let _symbol = String::deserialize(buf)?;
+    let _uri = String::deserialize(buf)?;
+    let _seller_fee_basis_points = u16::deserialize(buf)?;
+    let _creators = Option::<Vec<Creator>>::deserialize(buf)?;
+    let _primary_sale_happened = bool::deserialize(buf)?;
+    let _is_mutable = bool::deserialize(buf)?;
+    let _edition_nonce = Option::<u8>::deserialize(buf)?;
+    let token_standard = Option::<TokenStandard>::deserialize(buf)?;
+    let collection = Option::<Collection>::deserialize(buf)?;
+
+    if metadata_mint != *mint {
+        return Err(NftVoterError::TokenMetadataDoesNotMatch.into());
+    }
+
+    Ok(NftMetadata {
+        mint: metadata_mint,
+        token_standard,
+        collection,
+    })
+}
+
+/// TokenState as defined in mpl-token-metadata
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum TokenState {
+    Unlocked,
+    Locked,
+    Listed,
+}
+
+/// TokenDelegateRole as defined in mpl-token-metadata
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum TokenDelegateRole {
+    Sale,
+    Transfer,
+    Utility,
+    Staking,
+    Standard,
+    LockedTransfer,
+    Migration,
+}
+
+impl TokenDelegateRole {
+    /// Returns true if the delegate can transfer the token on behalf of the owner
+    pub fn can_transfer(&self) -> bool {
+        matches!(
+            self,
+            TokenDelegateRole::Sale
+                | TokenDelegateRole::Transfer
+                | TokenDelegateRole::LockedTransfer
+                | TokenDelegateRole::Standard
+        )
+    }
+}
+
+/// TokenRecord account as defined in mpl-token-metadata
+/// Only the fields used by the program are defined
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq)]
+pub struct TokenRecord {
+    pub key: u8,
+    pub bump: u8,
+    pub state: TokenState,
+    pub rule_set_revision: Option<u64>,
+    pub delegate: Option<Pubkey>,
+    pub delegate_role: Option<TokenDelegateRole>,
+}
+
+/// mpl-token-metadata Key::TokenRecord
+pub const TOKEN_RECORD_KEY: u8 = 11;
+
+/// Returns TokenRecord PDA address for the given mint and token account
+pub fn get_token_record_address(mint: &Pubkey, token_account: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"metadata",
+            mpl_token_metadata::ID.as_ref(),
+            mint.as_ref(),
+            b"token_record",
+            token_account.as_ref(),
+        ],
+        &mpl_token_metadata::ID,
+    )
+    .0
+}
+
+/// Deserializes TokenRecord account and checks it's the TokenRecord for the given mint and token account
+pub fn get_token_record_data_for_token_account(
+    token_record_info: &AccountInfo,
+    mint: &Pubkey,
+    token_account: &Pubkey,
+) -> Result<TokenRecord> {
+    if *token_record_info.owner != mpl_token_metadata::ID {
+        return Err(NftVoterError::InvalidAccountOwner.into());
+    }
+
+    if *token_record_info.key != get_token_record_address(mint, token_account) {
+        return Err(NftVoterError::InvalidTokenRecord.into());
+    }
+
+    let data = token_record_info.try_borrow_data()?;
+    let token_record = TokenRecord::deserialize(&mut &data[..])?;
+
+    if token_record.key != TOKEN_RECORD_KEY {
+        return Err(NftVoterError::InvalidTokenRecord.into());
+    }
+
+    Ok(token_record)
+}
@@ -0,0 +1,90 @@
+use gpl_nft_voter::error::NftVoterError;
+use program_test::{
+    nft_voter_test::NftVoterTest,
+    tools::{assert_anchor_err, assert_nft_voter_err},
+};
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_add_escrow_program() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    let escrow_program_config_cookie = nft_voter_test
+        .with_escrow_program(&registrar_cookie)
+        .await?;
+
+    // Assert
+    let escrow_program_config = nft_voter_test
+        .get_escrow_program_config_account(&escrow_program_config_cookie.address)
+        .await;
+
+    assert_eq!(escrow_program_config_cookie.account, escrow_program_config);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_add_escrow_program_with_realm_authority_must_sign_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    let err = nft_voter_test
+        .with_escrow_program_using_ix(
+            &registrar_cookie,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_add_escrow_program_with_invalid_realm_authority_error() -> Result<(), BanksClientError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = nft_voter_test
+        .with_escrow_program_using_ix(
+            &registrar_cookie,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
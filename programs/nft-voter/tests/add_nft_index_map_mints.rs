@@ -0,0 +1,251 @@
+use crate::program_test::nft_voter_test::ConfigureCollectionArgs;
+use gpl_nft_voter::error::NftVoterError;
+use program_test::{nft_voter_test::NftVoterTest, tools::assert_nft_voter_err};
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_add_nft_index_map_mints() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 1,
+                size: 10,
+            }),
+        )
+        .await?;
+
+    let mut nft_index_map_cookie = nft_voter_test
+        .with_nft_index_map(&registrar_cookie, &nft_collection_cookie)
+        .await?;
+
+    let mints = (0..5).map(|_| Keypair::new().pubkey()).collect();
+
+    // Act
+    nft_voter_test
+        .add_nft_index_map_mints(&registrar_cookie, &mut nft_index_map_cookie, mints)
+        .await?;
+
+    // Assert
+    let nft_index_map = nft_voter_test
+        .get_nft_index_map_account(&nft_index_map_cookie.address)
+        .await;
+
+    assert_eq!(nft_index_map_cookie.account, nft_index_map);
+    assert_eq!(nft_index_map.nfts.len(), 5);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_add_nft_index_map_mints_using_multiple_instructions() -> Result<(), BanksClientError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 1,
+                size: 250,
+            }),
+        )
+        .await?;
+
+    let mut nft_index_map_cookie = nft_voter_test
+        .with_nft_index_map(&registrar_cookie, &nft_collection_cookie)
+        .await?;
+
+    // Act
+    for _ in 0..10 {
+        let mints = (0..25).map(|_| Keypair::new().pubkey()).collect();
+
+        nft_voter_test
+            .add_nft_index_map_mints(&registrar_cookie, &mut nft_index_map_cookie, mints)
+            .await?;
+    }
+
+    // Assert
+    let nft_index_map = nft_voter_test
+        .get_nft_index_map_account(&nft_index_map_cookie.address)
+        .await;
+
+    assert_eq!(nft_index_map_cookie.account, nft_index_map);
+    assert_eq!(nft_index_map.nfts.len(), 250);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_add_nft_index_map_mints_with_nft_already_in_index_map_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let mut nft_index_map_cookie = nft_voter_test
+        .with_nft_index_map(&registrar_cookie, &nft_collection_cookie)
+        .await?;
+
+    let mint = Keypair::new().pubkey();
+
+    nft_voter_test
+        .add_nft_index_map_mints(&registrar_cookie, &mut nft_index_map_cookie, vec![mint])
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .add_nft_index_map_mints(&registrar_cookie, &mut nft_index_map_cookie, vec![mint])
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftAlreadyInIndexMap);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_add_nft_index_map_mints_with_nft_index_map_is_full_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { weight: 1, size: 3 }),
+        )
+        .await?;
+
+    let mut nft_index_map_cookie = nft_voter_test
+        .with_nft_index_map(&registrar_cookie, &nft_collection_cookie)
+        .await?;
+
+    let mints = (0..4).map(|_| Keypair::new().pubkey()).collect();
+
+    // Act
+    let err = nft_voter_test
+        .add_nft_index_map_mints(&registrar_cookie, &mut nft_index_map_cookie, mints)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftIndexMapIsFull);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_add_nft_index_map_mints_with_invalid_realm_authority_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let mut nft_index_map_cookie = nft_voter_test
+        .with_nft_index_map(&registrar_cookie, &nft_collection_cookie)
+        .await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = nft_voter_test
+        .add_nft_index_map_mints_using_ix(
+            &registrar_cookie,
+            &mut nft_index_map_cookie,
+            vec![Keypair::new().pubkey()],
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
@@ -0,0 +1,1039 @@
+use crate::program_test::compression_test::CreateCompressedNftArgs;
+use crate::program_test::nft_voter_test::ConfigureCollectionArgs;
+use gpl_nft_voter::error::NftVoterError;
+use gpl_nft_voter::state::*;
+use program_test::{nft_voter_test::*, tools::assert_nft_voter_err};
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use spl_governance::state::vote_record::Vote;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_cast_compressed_nft_vote() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let mut merkle_tree_cookie = nft_voter_test.compression.with_merkle_tree(3).await;
+
+    let compressed_nft_cookie1 = nft_voter_test
+        .compression
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+            None,
+        )
+        .await;
+
+    nft_voter_test.bench.advance_clock().await;
+    let clock = nft_voter_test.bench.get_clock().await;
+
+    // Act
+    let nft_vote_record_cookies = nft_voter_test
+        .cast_compressed_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1],
+            None,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let nft_vote_record = nft_voter_test
+        .get_nf_vote_record_account(&nft_vote_record_cookies[0].address)
+        .await;
+
+    assert_eq!(nft_vote_record_cookies[0].account, nft_vote_record);
+    assert_eq!(nft_vote_record.nft_mint, compressed_nft_cookie1.asset_id);
+
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+    assert_eq!(
+        voter_weight_record.weight_action,
+        Some(VoterWeightAction::CastVote.into())
+    );
+    assert_eq!(
+        voter_weight_record.weight_action_target,
+        Some(proposal_cookie.address)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_compressed_nft_vote_with_multiple_nfts() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let mut merkle_tree_cookie = nft_voter_test.compression.with_merkle_tree(3).await;
+
+    let compressed_nft_cookie1 = nft_voter_test
+        .compression
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+            None,
+        )
+        .await;
+
+    let compressed_nft_cookie2 = nft_voter_test
+        .compression
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+            None,
+        )
+        .await;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let nft_vote_record_cookies = nft_voter_test
+        .cast_compressed_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1, &compressed_nft_cookie2],
+            None,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let nft_vote_record1 = nft_voter_test
+        .get_nf_vote_record_account(&nft_vote_record_cookies[0].address)
+        .await;
+
+    assert_eq!(nft_vote_record_cookies[0].account, nft_vote_record1);
+
+    let nft_vote_record2 = nft_voter_test
+        .get_nf_vote_record_account(&nft_vote_record_cookies[1].address)
+        .await;
+
+    assert_eq!(nft_vote_record_cookies[1].account, nft_vote_record2);
+
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 20);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_compressed_nft_vote_with_nft_already_voted_error() -> Result<(), BanksClientError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let mut merkle_tree_cookie = nft_voter_test.compression.with_merkle_tree(3).await;
+
+    let compressed_nft_cookie1 = nft_voter_test
+        .compression
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+            None,
+        )
+        .await;
+
+    nft_voter_test
+        .cast_compressed_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1],
+            None,
+            Some(CastNftVoteArgs {
+                cast_spl_gov_vote: false,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .cast_compressed_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1],
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftAlreadyVoted);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_compressed_nft_vote_with_invalid_owner_error() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let mut merkle_tree_cookie = nft_voter_test.compression.with_merkle_tree(3).await;
+
+    let voter_cookie2 = nft_voter_test.bench.with_wallet().await;
+
+    let compressed_nft_cookie1 = nft_voter_test
+        .compression
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &voter_cookie2,
+            None,
+        )
+        .await;
+
+    // Act
+    let err = nft_voter_test
+        .cast_compressed_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1],
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidMerkleProof);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_compressed_nft_vote_with_unverified_collection_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let mut merkle_tree_cookie = nft_voter_test.compression.with_merkle_tree(3).await;
+
+    let compressed_nft_cookie1 = nft_voter_test
+        .compression
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+            Some(CreateCompressedNftArgs {
+                verify_collection: false,
+            }),
+        )
+        .await;
+
+    // Act
+    let err = nft_voter_test
+        .cast_compressed_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1],
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::CollectionMustBeVerified);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_compressed_nft_vote_with_invalid_tree_authority_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let mut merkle_tree_cookie = nft_voter_test.compression.with_merkle_tree(3).await;
+
+    let compressed_nft_cookie1 = nft_voter_test
+        .compression
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+            None,
+        )
+        .await;
+
+    // Replace the tree authority with an authority which is not Bubblegum TreeConfig
+    nft_voter_test
+        .compression
+        .set_merkle_tree_account(&merkle_tree_cookie, &Pubkey::new_unique())
+        .await;
+
+    // Act
+    let err = nft_voter_test
+        .cast_compressed_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1],
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidMerkleTreeAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_compressed_nft_vote_with_stale_proof_error() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let mut merkle_tree_cookie = nft_voter_test.compression.with_merkle_tree(3).await;
+
+    let compressed_nft_cookie1 = nft_voter_test
+        .compression
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+            None,
+        )
+        .await;
+
+    // Change the tree root without changing the leaves used to generate the proof
+    let leaves = merkle_tree_cookie.leaves.clone();
+    merkle_tree_cookie.leaves.push([1; 32]);
+
+    nft_voter_test
+        .compression
+        .set_merkle_tree_account(
+            &merkle_tree_cookie,
+            &gpl_nft_voter::tools::bubblegum::get_tree_authority(&merkle_tree_cookie.address),
+        )
+        .await;
+
+    merkle_tree_cookie.leaves = leaves;
+
+    // Act
+    let err = nft_voter_test
+        .cast_compressed_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1],
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidMerkleProof);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_compressed_nft_vote_with_ownership_snapshot() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    nft_voter_test
+        .configure_ownership_snapshot(&mut registrar_cookie, true)
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let mut merkle_tree_cookie = nft_voter_test.compression.with_merkle_tree(3).await;
+
+    let compressed_nft_cookie1 = nft_voter_test
+        .compression
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+            None,
+        )
+        .await;
+
+    nft_voter_test
+        .register_compressed_nft_holders(
+            &registrar_cookie,
+            &voter_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1],
+        )
+        .await?;
+
+    let clock = nft_voter_test.bench.get_clock().await;
+    nft_voter_test
+        .bench
+        .advance_clock_past_timestamp(clock.unix_timestamp)
+        .await;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    // Act
+    nft_voter_test
+        .cast_compressed_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1],
+            None,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_compressed_nft_vote_with_phase_spl_deny_weight_should_be_0(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, Some(vec!["Reject".to_string()]))
+        .await?;
+
+    let transaction_cookie = nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let mut merkle_tree_cookie = nft_voter_test.compression.with_merkle_tree(3).await;
+
+    let compressed_nft_cookie1 = nft_voter_test
+        .compression
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+            None,
+        )
+        .await;
+
+    nft_voter_test.bench.advance_clock().await;
+    let clock = nft_voter_test.bench.get_clock().await;
+
+    // Act
+    let nft_vote_record_cookies = nft_voter_test
+        .cast_compressed_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1],
+            Some(transaction_cookie),
+            Some(CastNftVoteArgs {
+                cast_spl_gov_vote: true,
+                vote_type: Vote::Deny,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    // Assert
+    let nft_vote_record = nft_voter_test
+        .get_nf_vote_record_account(&nft_vote_record_cookies[0].address)
+        .await;
+
+    assert_eq!(nft_vote_record_cookies[0].account, nft_vote_record);
+
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 0);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+    assert_eq!(
+        voter_weight_record.weight_action_target,
+        Some(proposal_cookie.address)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_compressed_nft_vote_with_phase_spl_deny_weight_without_prop_tx_should_fail(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, Some(vec!["Reject".to_string()]))
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let mut merkle_tree_cookie = nft_voter_test.compression.with_merkle_tree(3).await;
+
+    let compressed_nft_cookie1 = nft_voter_test
+        .compression
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+            None,
+        )
+        .await;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .cast_compressed_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1],
+            None,
+            Some(CastNftVoteArgs {
+                cast_spl_gov_vote: true,
+                vote_type: Vote::Deny,
+                ..Default::default()
+            }),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(
+        err,
+        NftVoterError::MustIncludeProposalTransactionForPhaseVotes,
+    );
+
+    Ok(())
+}
@@ -1,11 +1,15 @@
-
 use crate::program_test::nft_voter_test::ConfigureCollectionArgs;
 use gpl_nft_voter::error::NftVoterError;
 use gpl_nft_voter::state::*;
-use program_test::token_metadata_test::CreateNftArgs;
-use program_test::{nft_voter_test::*, tools::assert_nft_voter_err};
+use gpl_nft_voter::tools::token_metadata::{TokenDelegateRole, TokenState};
+use program_test::token_metadata_test::{CreateNftArgs, CreateProgrammableNftArgs};
+use program_test::{
+    nft_voter_test::*,
+    tools::{assert_nft_voter_err, clone_keypair, NopOverride},
+};
 
 use solana_program_test::*;
+use solana_sdk::{instruction::AccountMeta, signature::Keypair, signer::Signer};
 use spl_governance::state::vote_record::{Vote, VoteChoice};
 
 mod program_test;
@@ -86,6 +90,7 @@ async fn test_cast_nft_vote_with_phase_spl_deny_weight_should_be_0() -> Result<(
             Some(CastNftVoteArgs {
                 cast_spl_gov_vote: true,
                 vote_type: Vote::Deny,
+                ..Default::default()
             }),
         )
         .await?;
@@ -296,6 +301,7 @@ async fn test_cast_nft_vote_with_multiple_nfts_with_phase_deny_should_be_0(
             Some(CastNftVoteArgs {
                 cast_spl_gov_vote: true,
                 vote_type: Vote::Deny,
+                ..Default::default()
             }),
         )
         .await?;
@@ -511,6 +517,7 @@ async fn test_cast_nft_vote_with_phase_spl_deny_weight_without_prop_tx_should_fa
             Some(CastNftVoteArgs {
                 cast_spl_gov_vote: true,
                 vote_type: Vote::Deny,
+                ..Default::default()
             }),
         )
         .await
@@ -1236,6 +1243,7 @@ async fn test_cast_nft_vote_with_max_5_nfts_with_deny_phase_vote() -> Result<(),
             Some(CastNftVoteArgs {
                 cast_spl_gov_vote: true,
                 vote_type: Vote::Deny,
+                ..Default::default()
             }),
         )
         .await?;
@@ -1450,6 +1458,7 @@ async fn test_cast_nft_vote_using_multiple_instructions() -> Result<(), BanksCli
             rank: 0,
             weight_percentage: 100,
         }]),
+        ..Default::default()
     };
 
     nft_voter_test
@@ -1571,6 +1580,7 @@ async fn test_cast_nft_vote_using_multiple_instructions_with_nft_already_voted_e
             rank: 0,
             weight_percentage: 100,
         }]),
+        ..Default::default()
     };
 
     nft_voter_test
@@ -1675,6 +1685,7 @@ async fn test_cast_nft_vote_using_multiple_instructions_with_attempted_sandwiche
             rank: 0,
             weight_percentage: 100,
         }]),
+        ..Default::default()
     };
 
     // Cast vote with NFT
@@ -1732,3 +1743,3678 @@ async fn test_cast_nft_vote_using_multiple_instructions_with_attempted_sandwiche
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_programmable_nft() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_programmable_nft(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+    let clock = nft_voter_test.bench.get_clock().await;
+
+    // Act
+    let nft_vote_record_cookies = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let nft_vote_record = nft_voter_test
+        .get_nf_vote_record_account(&nft_vote_record_cookies[0].address)
+        .await;
+
+    assert_eq!(nft_vote_record_cookies[0].account, nft_vote_record);
+
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_locked_programmable_nft_and_allow_all_policy(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_programmable_nft(
+            &nft_collection_cookie,
+            &voter_cookie,
+            Some(CreateProgrammableNftArgs {
+                token_state: TokenState::Locked,
+                delegate_role: Some(TokenDelegateRole::Staking),
+            }),
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+    let clock = nft_voter_test.bench.get_clock().await;
+
+    // Act
+    let nft_vote_record_cookies = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let nft_vote_record = nft_voter_test
+        .get_nf_vote_record_account(&nft_vote_record_cookies[0].address)
+        .await;
+
+    assert_eq!(nft_vote_record_cookies[0].account, nft_vote_record);
+
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_locked_programmable_nft_error() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    nft_voter_test
+        .configure_programmable_nft_policy(
+            &mut registrar_cookie,
+            ProgrammableNftPolicy::RejectLockedOrDelegated,
+        )
+        .await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_programmable_nft(
+            &nft_collection_cookie,
+            &voter_cookie,
+            Some(CreateProgrammableNftArgs {
+                token_state: TokenState::Locked,
+                delegate_role: Some(TokenDelegateRole::Staking),
+            }),
+        )
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::ProgrammableNftLocked);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_delegated_programmable_nft_error() -> Result<(), BanksClientError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    nft_voter_test
+        .configure_programmable_nft_policy(
+            &mut registrar_cookie,
+            ProgrammableNftPolicy::RejectLockedOrDelegated,
+        )
+        .await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_programmable_nft(
+            &nft_collection_cookie,
+            &voter_cookie,
+            Some(CreateProgrammableNftArgs {
+                token_state: TokenState::Unlocked,
+                delegate_role: Some(TokenDelegateRole::Transfer),
+            }),
+        )
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::ProgrammableNftDelegated);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_invalid_token_record_error() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let mut nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_programmable_nft(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_programmable_nft(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // Try to use TokenRecord of another pNFT
+    nft_cookie1.token_record = nft_cookie2.token_record;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidTokenRecord);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_escrowed_nft() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let escrow_program_config_cookie = nft_voter_test
+        .with_escrow_program(&registrar_cookie)
+        .await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .with_escrowed_nft(
+            &escrow_program_config_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+        )
+        .await?;
+
+    // Act
+    let nft_vote_record_cookies = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let nft_vote_record = nft_voter_test
+        .get_nf_vote_record_account(&nft_vote_record_cookies[0].address)
+        .await;
+
+    assert_eq!(nft_vote_record_cookies[0].account, nft_vote_record);
+
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_escrowed_nft_and_invalid_beneficial_owner_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let escrow_program_config_cookie = nft_voter_test
+        .with_escrow_program(&registrar_cookie)
+        .await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let beneficial_owner_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let nft_cookie1 = nft_voter_test
+        .with_escrowed_nft(
+            &escrow_program_config_cookie,
+            &nft_collection_cookie,
+            &beneficial_owner_cookie,
+        )
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::VoterDoesNotOwnNft);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_escrowed_nft_and_escrow_program_not_allowed_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let escrow_program_config_cookie = nft_voter_test
+        .with_escrow_program(&registrar_cookie)
+        .await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .with_escrowed_nft(
+            &escrow_program_config_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+        )
+        .await?;
+
+    // Try to use receipt with the same layout issued by another escrow program
+    let escrow_receipt = nft_cookie1.escrow.as_ref().unwrap().escrow_receipt;
+    let mut escrow_receipt_account = nft_voter_test
+        .bench
+        .get_account(&escrow_receipt)
+        .await
+        .unwrap();
+    escrow_receipt_account.owner = Keypair::new().pubkey();
+
+    nft_voter_test
+        .bench
+        .set_account(&escrow_receipt, &escrow_receipt_account)
+        .await;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::EscrowProgramNotAllowed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_escrowed_nft_and_removed_escrow_program_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let escrow_program_config_cookie = nft_voter_test
+        .with_escrow_program(&registrar_cookie)
+        .await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .with_escrowed_nft(
+            &escrow_program_config_cookie,
+            &nft_collection_cookie,
+            &voter_cookie,
+        )
+        .await?;
+
+    nft_voter_test
+        .remove_escrow_program(&registrar_cookie, &escrow_program_config_cookie)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::VoterDoesNotOwnNft);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_registrar_for_other_governance_program_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    let nft_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // Move the Registrar and the Proposal to another spl-governance instance
+    // while the default spl-governance program is still passed as governance_program
+    let other_governance_program_id = Keypair::new().pubkey();
+
+    let mut registrar_account = nft_voter_test
+        .bench
+        .get_account(&registrar_cookie.address)
+        .await
+        .unwrap();
+    registrar_account.data[8..40].copy_from_slice(other_governance_program_id.as_ref());
+
+    nft_voter_test
+        .bench
+        .set_account(&registrar_cookie.address, &registrar_account)
+        .await;
+
+    let mut proposal_account = nft_voter_test
+        .bench
+        .get_account(&proposal_cookie.address)
+        .await
+        .unwrap();
+    proposal_account.owner = other_governance_program_id;
+
+    nft_voter_test
+        .bench
+        .set_account(&proposal_cookie.address, &proposal_account)
+        .await;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie],
+            None,
+            Some(CastNftVoteArgs {
+                cast_spl_gov_vote: false,
+                ..Default::default()
+            }),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidGovernanceProgram);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_phase_spl_deny_and_no_conditional_weight_rules(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    // Replace the default phase protocol rule with no rules
+    nft_voter_test
+        .with_conditional_weight_config(&registrar_cookie, vec![])
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, Some(vec!["Reject".to_string()]))
+        .await?;
+
+    let transaction_cookie = nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+    let clock = nft_voter_test.bench.get_clock().await;
+
+    // Act
+    let nft_vote_record_cookies = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            Some(transaction_cookie),
+            Some(CastNftVoteArgs {
+                cast_spl_gov_vote: true,
+                vote_type: Vote::Deny,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    // Assert
+    let nft_vote_record = nft_voter_test
+        .get_nf_vote_record_account(&nft_vote_record_cookies[0].address)
+        .await;
+
+    assert_eq!(nft_vote_record_cookies[0].account, nft_vote_record);
+
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+    assert_eq!(
+        voter_weight_record.weight_action,
+        Some(VoterWeightAction::CastVote.into())
+    );
+    assert_eq!(
+        voter_weight_record.weight_action_target,
+        Some(proposal_cookie.address)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_nft_account_counts_exceeding_remaining_accounts_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    let nft_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote_using_ix(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie],
+            None,
+            None,
+            |i| {
+                i.data =
+                    anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::CastNftVote {
+                        layout: CastNftVoteLayout {
+                            nft_account_counts: vec![2, 2],
+                            with_proposal_transaction: false,
+                        },
+                    })
+            },
+            NopOverride,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRemainingAccountsLayout);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_unexpected_remaining_account_error() -> Result<(), BanksClientError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    let nft_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // Act
+    // The extra account is not declared in the layout
+    let err = nft_voter_test
+        .cast_nft_vote_using_ix(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie],
+            None,
+            None,
+            |i| {
+                i.accounts
+                    .push(AccountMeta::new_readonly(Keypair::new().pubkey(), false))
+            },
+            NopOverride,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRemainingAccountsLayout);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_nft_weight_override() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let mut nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let collection_weight_overrides_cookie = nft_voter_test
+        .with_collection_weight_overrides(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            vec![NftWeightOverride {
+                nft_mint: nft_cookie1.mint_cookie.address,
+                weight: 50,
+            }],
+        )
+        .await?;
+
+    nft_cookie1.collection_weight_overrides = Some(collection_weight_overrides_cookie.address);
+
+    // Act
+    nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1, &nft_cookie2],
+            None,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    // nft_cookie2 has no override and carries the collection weight
+    assert_eq!(voter_weight_record.voter_weight, 50 + 10);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_nft_weight_override_for_other_collection_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let nft_collection_cookie2 = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie2,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let mut nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // Overrides of the other collection
+    let collection_weight_overrides_cookie = nft_voter_test
+        .with_collection_weight_overrides(
+            &registrar_cookie,
+            &nft_collection_cookie2,
+            &max_voter_weight_record_cookie,
+            vec![NftWeightOverride {
+                nft_mint: nft_cookie1.mint_cookie.address,
+                weight: 50,
+            }],
+        )
+        .await?;
+
+    nft_cookie1.collection_weight_overrides = Some(collection_weight_overrides_cookie.address);
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidCollectionWeightOverrides);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_denied_nft_mint_error() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // Fill the denylist with other mints to exercise the search over a larger list
+    let mut denied_mints: Vec<_> = (0..20).map(|_| Keypair::new().pubkey()).collect();
+    denied_mints.push(nft_cookie1.mint_cookie.address);
+
+    nft_voter_test
+        .with_mint_denylist(&registrar_cookie, denied_mints)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftMintDenied);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_using_multiple_instructions_with_max_voter_weight_cap(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    nft_voter_test
+        .configure_voter_weight_caps(
+            &mut registrar_cookie,
+            VoterWeightCaps {
+                max_voter_weight: Some(15),
+                max_collection_weight_share_bps: None,
+            },
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let args = CastNftVoteArgs {
+        cast_spl_gov_vote: false,
+        vote_type: Vote::Approve(vec![VoteChoice {
+            rank: 0,
+            weight_percentage: 100,
+        }]),
+        ..Default::default()
+    };
+
+    nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            Some(args),
+        )
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie2],
+            None,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 15);
+
+    let voter_weight_cap_tally = nft_voter_test
+        .get_voter_weight_cap_tally(&proposal_cookie.address, &voter_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_cap_tally.collection_weights[0].weight, 20);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_max_collection_weight_share_cap() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // max_voter_weight = 10 * 20 = 200
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    // 7.5% of max_voter_weight = 15
+    nft_voter_test
+        .configure_voter_weight_caps(
+            &mut registrar_cookie,
+            VoterWeightCaps {
+                max_voter_weight: None,
+                max_collection_weight_share_bps: Some(750),
+            },
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // Act
+    nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1, &nft_cookie2],
+            None,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 15);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_using_multiple_instructions_with_quadratic_weighting_mode(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    nft_voter_test
+        .configure_voter_weighting_mode(
+            &mut registrar_cookie,
+            &max_voter_weight_record_cookie,
+            VoterWeightingMode::Quadratic,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie3 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie4 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let args = CastNftVoteArgs {
+        cast_spl_gov_vote: false,
+        vote_type: Vote::Approve(vec![VoteChoice {
+            rank: 0,
+            weight_percentage: 100,
+        }]),
+        ..Default::default()
+    };
+
+    nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            Some(args),
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie2, &nft_cookie3, &nft_cookie4],
+            None,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    // sqrt(4) NFTs
+    assert_eq!(
+        voter_weight_record.voter_weight,
+        2 * VOTER_WEIGHT_CURVE_SCALE
+    );
+
+    let voter_weight_cap_tally = nft_voter_test
+        .get_voter_weight_cap_tally(&proposal_cookie.address, &voter_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_cap_tally.collection_weights[0].weight, 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_ownership_snapshot() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    nft_voter_test
+        .configure_ownership_snapshot(&mut registrar_cookie, true)
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .register_nft_holders(&registrar_cookie, &voter_cookie, &[&nft_cookie1])
+        .await?;
+
+    let clock = nft_voter_test.bench.get_clock().await;
+    nft_voter_test
+        .bench
+        .advance_clock_past_timestamp(clock.unix_timestamp)
+        .await;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    // Act
+    nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_ownership_snapshot_and_nft_holder_not_registered_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    nft_voter_test
+        .configure_ownership_snapshot(&mut registrar_cookie, true)
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftHolderNotRegistered);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_ownership_snapshot_and_nft_acquired_after_voting_started_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    nft_voter_test
+        .configure_ownership_snapshot(&mut registrar_cookie, true)
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    nft_voter_test
+        .register_nft_holders(&registrar_cookie, &voter_cookie, &[&nft_cookie1])
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftAcquiredAfterVotingStarted);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_nft_deposit() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_deposit_record_cookie = nft_voter_test
+        .with_nft_deposit_record(&registrar_cookie, &voter_cookie, 5)
+        .await?;
+
+    nft_voter_test
+        .deposit_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_cookie,
+            &nft_cookie1,
+        )
+        .await?;
+
+    let clock = nft_voter_test.bench.get_clock().await;
+    nft_voter_test
+        .bench
+        .advance_clock_past_timestamp(clock.unix_timestamp)
+        .await;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    // Act
+    nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie2],
+            None,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 20);
+
+    let nft_deposit_vote_record = nft_voter_test
+        .get_nf_vote_record_account(&get_nft_vote_record_address(
+            &proposal_cookie.address,
+            &nft_deposit_record_cookie.address,
+        ))
+        .await;
+
+    assert_eq!(
+        nft_deposit_vote_record.governing_token_owner,
+        voter_cookie.address
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_nft_deposited_after_voting_started() -> Result<(), BanksClientError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_deposit_record_cookie = nft_voter_test
+        .with_nft_deposit_record(&registrar_cookie, &voter_cookie, 5)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    // The NFT is deposited after the Proposal voting started
+    nft_voter_test
+        .deposit_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_cookie,
+            &nft_cookie1,
+        )
+        .await?;
+
+    // Act
+    nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[],
+            None,
+            Some(CastNftVoteArgs {
+                cast_spl_gov_vote: false,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_nft_vote_delegation() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let delegate_cookie = nft_voter_test.bench.with_wallet().await;
+
+    nft_voter_test
+        .with_nft_vote_delegation(
+            &registrar_cookie,
+            &voter_cookie,
+            &delegate_cookie.address,
+            None,
+            None,
+        )
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // Act
+    let nft_vote_record_cookies = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            Some(CastNftVoteArgs {
+                cast_spl_gov_vote: false,
+                voter_authority: Some(clone_keypair(&delegate_cookie.signer)),
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    // Assert
+    let nft_vote_record = nft_voter_test
+        .get_nf_vote_record_account(&nft_vote_record_cookies[0].address)
+        .await;
+
+    // The NFT vote is recorded for the owner and not the delegate
+    assert_eq!(nft_vote_record.governing_token_owner, voter_cookie.address);
+
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_nft_vote_delegation_and_spl_gov_vote(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let mut voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let delegate_cookie = nft_voter_test.bench.with_wallet().await;
+
+    nft_voter_test
+        .with_nft_vote_delegation(
+            &registrar_cookie,
+            &voter_cookie,
+            &delegate_cookie.address,
+            None,
+            None,
+        )
+        .await?;
+
+    // spl-gov CastVote must be signed by the owner or TokenOwnerRecord.governance_delegate
+    nft_voter_test
+        .governance
+        .set_governance_delegate(
+            &realm_cookie,
+            &voter_cookie,
+            &mut voter_token_owner_record_cookie,
+            &Some(delegate_cookie.address),
+        )
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // Act
+    let nft_vote_record_cookies = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            Some(CastNftVoteArgs {
+                cast_spl_gov_vote: true,
+                voter_authority: Some(clone_keypair(&delegate_cookie.signer)),
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    // Assert
+    let nft_vote_record = nft_voter_test
+        .get_nf_vote_record_account(&nft_vote_record_cookies[0].address)
+        .await;
+
+    // The NFT vote is recorded for the owner and not the delegate
+    assert_eq!(nft_vote_record.governing_token_owner, voter_cookie.address);
+
+    let proposal = nft_voter_test
+        .governance
+        .get_proposal(&proposal_cookie.address)
+        .await;
+
+    assert_eq!(proposal.options[0].vote_weight, 10);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_governance_delegate() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let mut voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let delegate_cookie = nft_voter_test.bench.with_wallet().await;
+
+    nft_voter_test
+        .governance
+        .set_governance_delegate(
+            &realm_cookie,
+            &voter_cookie,
+            &mut voter_token_owner_record_cookie,
+            &Some(delegate_cookie.address),
+        )
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // Act
+    let nft_vote_record_cookies = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            Some(CastNftVoteArgs {
+                voter_authority: Some(clone_keypair(&delegate_cookie.signer)),
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    // Assert
+    let nft_vote_record = nft_voter_test
+        .get_nf_vote_record_account(&nft_vote_record_cookies[0].address)
+        .await;
+
+    // The NFT vote is recorded for the owner and not the delegate
+    assert_eq!(nft_vote_record.governing_token_owner, voter_cookie.address);
+
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_invalid_voter_authority_error() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let delegate_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            Some(CastNftVoteArgs {
+                cast_spl_gov_vote: false,
+                voter_authority: Some(clone_keypair(&delegate_cookie.signer)),
+                ..Default::default()
+            }),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidVoterAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_nft_collection_not_delegated_error() -> Result<(), BanksClientError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let nft_collection_cookie2 = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie2,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let delegate_cookie = nft_voter_test.bench.with_wallet().await;
+
+    // The delegation is scoped to the first collection
+    nft_voter_test
+        .with_nft_vote_delegation(
+            &registrar_cookie,
+            &voter_cookie,
+            &delegate_cookie.address,
+            Some(nft_collection_cookie.mint),
+            None,
+        )
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie2, &voter_cookie, None)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            Some(CastNftVoteArgs {
+                cast_spl_gov_vote: false,
+                voter_authority: Some(clone_keypair(&delegate_cookie.signer)),
+                ..Default::default()
+            }),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftCollectionNotDelegated);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_expired_nft_vote_delegation_error() -> Result<(), BanksClientError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let delegate_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let clock = nft_voter_test.bench.get_clock().await;
+    let expiry = clock.unix_timestamp + 10;
+
+    nft_voter_test
+        .with_nft_vote_delegation(
+            &registrar_cookie,
+            &voter_cookie,
+            &delegate_cookie.address,
+            None,
+            Some(expiry),
+        )
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .bench
+        .advance_clock_past_timestamp(expiry)
+        .await;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            Some(CastNftVoteArgs {
+                cast_spl_gov_vote: false,
+                voter_authority: Some(clone_keypair(&delegate_cookie.signer)),
+                ..Default::default()
+            }),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftVoteDelegationExpired);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_revoked_nft_vote_delegation_error() -> Result<(), BanksClientError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let delegate_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let nft_vote_delegation_cookie = nft_voter_test
+        .with_nft_vote_delegation(
+            &registrar_cookie,
+            &voter_cookie,
+            &delegate_cookie.address,
+            None,
+            None,
+        )
+        .await?;
+
+    nft_voter_test
+        .revoke_nft_vote_delegation(
+            &registrar_cookie,
+            &nft_vote_delegation_cookie,
+            &voter_cookie,
+        )
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            Some(CastNftVoteArgs {
+                cast_spl_gov_vote: false,
+                voter_authority: Some(clone_keypair(&delegate_cookie.signer)),
+                ..Default::default()
+            }),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidVoterAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_vote_bitmaps() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    nft_voter_test
+        .configure_vote_tracking_mode(&mut registrar_cookie, VoteTrackingMode::VoteBitmaps)
+        .await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let mut nft_index_map_cookie = nft_voter_test
+        .with_nft_index_map(&registrar_cookie, &nft_collection_cookie)
+        .await?;
+
+    nft_voter_test
+        .add_nft_index_map_mints(
+            &registrar_cookie,
+            &mut nft_index_map_cookie,
+            vec![
+                Keypair::new().pubkey(),
+                nft_cookie1.mint_cookie.address,
+                nft_cookie2.mint_cookie.address,
+            ],
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+    let clock = nft_voter_test.bench.get_clock().await;
+
+    // Act
+    let nft_vote_record_cookies = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1, &nft_cookie2],
+            None,
+            None,
+        )
+        .await?;
+
+    // Assert
+    assert!(nft_vote_record_cookies.is_empty());
+
+    let vote_bitmap = nft_voter_test
+        .get_vote_bitmap_account(&proposal_cookie.address, &nft_collection_cookie.mint)
+        .await;
+
+    assert_eq!(vote_bitmap.proposal, proposal_cookie.address);
+    assert_eq!(vote_bitmap.collection, nft_collection_cookie.mint);
+    assert_eq!(vote_bitmap.payer, nft_voter_test.bench.payer.pubkey());
+    assert_eq!(vote_bitmap.bits, vec![0b110, 0, 0]);
+
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 20);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_vote_bitmaps_and_nft_already_voted_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    nft_voter_test
+        .configure_vote_tracking_mode(&mut registrar_cookie, VoteTrackingMode::VoteBitmaps)
+        .await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let mut nft_index_map_cookie = nft_voter_test
+        .with_nft_index_map(&registrar_cookie, &nft_collection_cookie)
+        .await?;
+
+    nft_voter_test
+        .add_nft_index_map_mints(
+            &registrar_cookie,
+            &mut nft_index_map_cookie,
+            vec![nft_cookie1.mint_cookie.address],
+        )
+        .await?;
+
+    nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftAlreadyVoted);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_vote_bitmaps_and_nft_not_in_index_map_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    nft_voter_test
+        .configure_vote_tracking_mode(&mut registrar_cookie, VoteTrackingMode::VoteBitmaps)
+        .await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let mut nft_index_map_cookie = nft_voter_test
+        .with_nft_index_map(&registrar_cookie, &nft_collection_cookie)
+        .await?;
+
+    nft_voter_test
+        .add_nft_index_map_mints(
+            &registrar_cookie,
+            &mut nft_index_map_cookie,
+            vec![Keypair::new().pubkey()],
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftNotInIndexMap);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_cast_vote_for_another_proposal_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let proposal_cookie2 = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie2, &realm_cookie)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote_using_ix(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+            NopOverride,
+            |i| i.accounts[2].pubkey = proposal_cookie2.address, // proposal
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidCastVoteInstruction);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_cast_vote_for_another_voter_error() -> Result<(), BanksClientError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let voter_cookie2 = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie2 = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie2)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote_using_ix(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+            NopOverride,
+            |i| i.accounts[4].pubkey = voter_token_owner_record_cookie2.address, // voter_token_owner_record
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidCastVoteInstruction);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cast_nft_vote_with_cast_vote_without_voter_weight_record_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .cast_nft_vote_using_ix(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+            NopOverride,
+            |i| {
+                i.accounts
+                    .retain(|a| a.pubkey != voter_weight_record_cookie.address)
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidCastVoteInstruction);
+
+    Ok(())
+}
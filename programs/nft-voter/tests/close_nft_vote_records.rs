@@ -0,0 +1,590 @@
+use crate::program_test::nft_voter_test::ConfigureCollectionArgs;
+use gpl_nft_voter::error::NftVoterError;
+use gpl_nft_voter::state::get_nft_vote_record_address;
+use program_test::{
+    nft_voter_test::{NftVoteRecordCookie, NftVoterTest},
+    tools::assert_nft_voter_err,
+};
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_close_nft_vote_records() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { weight: 1, size: 2 }), // Set Size == 2 to complete voting with the two NFT votes
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_vote_record_cookies = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1, &nft_cookie2],
+            None,
+            None,
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    nft_voter_test
+        .close_nft_vote_records(
+            &registrar_cookie,
+            &proposal_cookie,
+            &nft_vote_record_cookies,
+        )
+        .await?;
+
+    // Assert
+    for nft_vote_record_cookie in nft_vote_record_cookies.iter() {
+        let nft_vote_record = nft_voter_test
+            .bench
+            .get_account(&nft_vote_record_cookie.address)
+            .await;
+
+        assert_eq!(None, nft_vote_record);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_nft_vote_records_in_batches() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { weight: 1, size: 2 }), // Set Size == 2 to complete voting with the two NFT votes
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_vote_record_cookies = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1, &nft_cookie2],
+            None,
+            None,
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    nft_voter_test
+        .close_nft_vote_records(
+            &registrar_cookie,
+            &proposal_cookie,
+            &nft_vote_record_cookies[..1],
+        )
+        .await?;
+
+    // Act
+    nft_voter_test
+        .close_nft_vote_records(
+            &registrar_cookie,
+            &proposal_cookie,
+            &nft_vote_record_cookies[1..],
+        )
+        .await?;
+
+    // Assert
+    let nft_vote_record = nft_voter_test
+        .bench
+        .get_account(&nft_vote_record_cookies[1].address)
+        .await;
+
+    assert_eq!(None, nft_vote_record);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_nft_vote_records_for_proposal_in_voting_state_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 1,
+                size: 10,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_vote_record_cookies = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1, &nft_cookie2],
+            None,
+            None,
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .close_nft_vote_records(
+            &registrar_cookie,
+            &proposal_cookie,
+            &nft_vote_record_cookies,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::ProposalVotingNotFinalized);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_nft_vote_records_with_invalid_payer_error() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { weight: 1, size: 2 }), // Set Size == 2 to complete voting with the two NFT votes
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_vote_record_cookies = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1, &nft_cookie2],
+            None,
+            None,
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    let fake_payer = Keypair::new();
+
+    // Act
+    let err = nft_voter_test
+        .close_nft_vote_records_using_ix(
+            &registrar_cookie,
+            &proposal_cookie,
+            &nft_vote_record_cookies,
+            |i| i.accounts[4].pubkey = fake_payer.pubkey(), // payer of the first NftVoteRecord
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidPayerForNftVoteRecord);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_nft_vote_records_with_missing_payer_error() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { weight: 1, size: 2 }), // Set Size == 2 to complete voting with the two NFT votes
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_vote_record_cookies = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1, &nft_cookie2],
+            None,
+            None,
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .close_nft_vote_records_using_ix(
+            &registrar_cookie,
+            &proposal_cookie,
+            &nft_vote_record_cookies,
+            |i| {
+                i.accounts.pop(); // payer of the second NftVoteRecord
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRemainingAccountsLayout);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_nft_vote_records_with_nft_deposit_vote_record_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs { weight: 1, size: 1 }), // Set Size == 1 to complete voting with the locked NFT vote
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_deposit_record_cookie = nft_voter_test
+        .with_nft_deposit_record(&registrar_cookie, &voter_cookie, 5)
+        .await?;
+
+    nft_voter_test
+        .deposit_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_cookie,
+            &nft_cookie1,
+        )
+        .await?;
+
+    let clock = nft_voter_test.bench.get_clock().await;
+    nft_voter_test
+        .bench
+        .advance_clock_past_timestamp(clock.unix_timestamp)
+        .await;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[],
+            None,
+            None,
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    let nft_deposit_vote_record_address =
+        get_nft_vote_record_address(&proposal_cookie.address, &nft_deposit_record_cookie.address);
+
+    let nft_deposit_vote_record_cookie = NftVoteRecordCookie {
+        address: nft_deposit_vote_record_address,
+        account: nft_voter_test
+            .get_nf_vote_record_account(&nft_deposit_vote_record_address)
+            .await,
+    };
+
+    // Act
+    let err = nft_voter_test
+        .close_nft_vote_records(
+            &registrar_cookie,
+            &proposal_cookie,
+            &[nft_deposit_vote_record_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::CannotCloseNftDepositVoteRecord);
+
+    Ok(())
+}
@@ -334,8 +334,6 @@ async fn test_configure_existing_collection() -> Result<(), BanksClientError> {
     Ok(())
 }
 
-// TODO: Remove collection test
-
 #[tokio::test]
 async fn test_configure_collection_with_invalid_realm_error() -> Result<(), BanksClientError> {
     // Arrange
@@ -0,0 +1,275 @@
+use gpl_nft_voter::error::NftVoterError;
+use gpl_nft_voter::state::*;
+use program_test::{nft_voter_test::NftVoterTest, tools::assert_nft_voter_err};
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::program_test::nft_voter_test::ConfigureCollectionArgs;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_configure_collection_weight_overrides() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let mut collection_weight_overrides_cookie = nft_voter_test
+        .with_collection_weight_overrides(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            vec![NftWeightOverride {
+                nft_mint: Keypair::new().pubkey(),
+                weight: 50,
+            }],
+        )
+        .await?;
+
+    let overrides = vec![
+        NftWeightOverride {
+            nft_mint: Keypair::new().pubkey(),
+            weight: 100,
+        },
+        NftWeightOverride {
+            nft_mint: Keypair::new().pubkey(),
+            weight: 30,
+        },
+    ];
+
+    // Act
+    nft_voter_test
+        .configure_collection_weight_overrides(
+            &registrar_cookie,
+            &mut collection_weight_overrides_cookie,
+            &max_voter_weight_record_cookie,
+            overrides,
+        )
+        .await?;
+
+    // Assert
+    let collection_weight_overrides = nft_voter_test
+        .get_collection_weight_overrides_account(&collection_weight_overrides_cookie.address)
+        .await;
+
+    assert_eq!(
+        collection_weight_overrides_cookie.account,
+        collection_weight_overrides
+    );
+
+    let max_voter_weight_record = nft_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 10 * 20 + 90 + 20);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_collection_with_weight_overrides() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    nft_voter_test
+        .with_collection_weight_overrides(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            vec![NftWeightOverride {
+                nft_mint: Keypair::new().pubkey(),
+                weight: 50,
+            }],
+        )
+        .await?;
+
+    // Act
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 20,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    // Assert
+    let registrar = nft_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    // The bonus is recomputed for the new collection weight
+    assert_eq!(registrar.collection_configs[0].max_weight_bonus, 30);
+
+    let max_voter_weight_record = nft_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 20 * 20 + 30);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_collection_weight_overrides_with_invalid_realm_authority_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let mut collection_weight_overrides_cookie = nft_voter_test
+        .with_collection_weight_overrides(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            vec![],
+        )
+        .await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = nft_voter_test
+        .configure_collection_weight_overrides_using_ix(
+            &registrar_cookie,
+            &mut collection_weight_overrides_cookie,
+            &max_voter_weight_record_cookie,
+            vec![],
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_collection_weight_overrides_with_weight_below_collection_weight_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let mut collection_weight_overrides_cookie = nft_voter_test
+        .with_collection_weight_overrides(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            vec![NftWeightOverride {
+                nft_mint: Keypair::new().pubkey(),
+                weight: 50,
+            }],
+        )
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .configure_collection_weight_overrides(
+            &registrar_cookie,
+            &mut collection_weight_overrides_cookie,
+            &max_voter_weight_record_cookie,
+            vec![NftWeightOverride {
+                nft_mint: Keypair::new().pubkey(),
+                weight: 5,
+            }],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidNftWeightOverride);
+
+    Ok(())
+}
@@ -0,0 +1,231 @@
+use gpl_nft_voter::error::NftVoterError;
+use gpl_nft_voter::state::*;
+use program_test::{
+    nft_voter_test::NftVoterTest,
+    tools::{assert_anchor_err, assert_nft_voter_err},
+};
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_configure_conditional_weight_rules() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut conditional_weight_config_cookie = nft_voter_test
+        .with_conditional_weight_config(&registrar_cookie, get_default_conditional_weight_rules())
+        .await?;
+
+    let rules = vec![ConditionalWeightRule {
+        program_id: Keypair::new().pubkey(),
+        discriminators: vec![[1, 2, 3, 4, 5, 6, 7, 8], [8, 7, 6, 5, 4, 3, 2, 1]],
+        weight_policy: ConditionalWeightPolicy::DenyOnly,
+        option_label: None,
+    }];
+
+    // Act
+    nft_voter_test
+        .configure_conditional_weight_rules(
+            &registrar_cookie,
+            &mut conditional_weight_config_cookie,
+            rules,
+        )
+        .await?;
+
+    // Assert
+    let conditional_weight_config = nft_voter_test
+        .get_conditional_weight_config_account(&conditional_weight_config_cookie.address)
+        .await;
+
+    assert_eq!(
+        conditional_weight_config_cookie.account,
+        conditional_weight_config
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_conditional_weight_rules_with_no_rules() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut conditional_weight_config_cookie = nft_voter_test
+        .with_conditional_weight_config(&registrar_cookie, get_default_conditional_weight_rules())
+        .await?;
+
+    // Act
+    nft_voter_test
+        .configure_conditional_weight_rules(
+            &registrar_cookie,
+            &mut conditional_weight_config_cookie,
+            vec![],
+        )
+        .await?;
+
+    // Assert
+    let conditional_weight_config = nft_voter_test
+        .get_conditional_weight_config_account(&conditional_weight_config_cookie.address)
+        .await;
+
+    assert_eq!(conditional_weight_config.rules, vec![]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_conditional_weight_rules_with_realm_authority_must_sign_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut conditional_weight_config_cookie = nft_voter_test
+        .with_conditional_weight_config(&registrar_cookie, get_default_conditional_weight_rules())
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .configure_conditional_weight_rules_using_ix(
+            &registrar_cookie,
+            &mut conditional_weight_config_cookie,
+            vec![],
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_conditional_weight_rules_with_invalid_realm_authority_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut conditional_weight_config_cookie = nft_voter_test
+        .with_conditional_weight_config(&registrar_cookie, get_default_conditional_weight_rules())
+        .await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = nft_voter_test
+        .configure_conditional_weight_rules_using_ix(
+            &registrar_cookie,
+            &mut conditional_weight_config_cookie,
+            vec![],
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_conditional_weight_rules_with_duplicated_program_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut conditional_weight_config_cookie = nft_voter_test
+        .with_conditional_weight_config(&registrar_cookie, get_default_conditional_weight_rules())
+        .await?;
+
+    let mut rules = get_default_conditional_weight_rules();
+    rules.extend(get_default_conditional_weight_rules());
+
+    // Act
+    let err = nft_voter_test
+        .configure_conditional_weight_rules(
+            &registrar_cookie,
+            &mut conditional_weight_config_cookie,
+            rules,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidConditionalWeightRule);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_conditional_weight_rules_with_voting_proposals_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut conditional_weight_config_cookie = nft_voter_test
+        .with_conditional_weight_config(&registrar_cookie, get_default_conditional_weight_rules())
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .configure_conditional_weight_rules(
+            &registrar_cookie,
+            &mut conditional_weight_config_cookie,
+            vec![],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(
+        err,
+        NftVoterError::CannotConfigureConditionalWeightRulesWithVotingProposals,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use gpl_nft_voter::error::NftVoterError;
+use program_test::{
+    nft_voter_test::NftVoterTest,
+    tools::{assert_anchor_err, assert_nft_voter_err},
+};
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_configure_ownership_snapshot() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    nft_voter_test
+        .configure_ownership_snapshot(&mut registrar_cookie, true)
+        .await?;
+
+    // Assert
+    let registrar = nft_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert!(registrar.ownership_snapshot_enabled);
+    assert_eq!(registrar, registrar_cookie.account);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_ownership_snapshot_with_realm_authority_must_sign_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    let err = nft_voter_test
+        .configure_ownership_snapshot_using_ix(
+            &mut registrar_cookie,
+            true,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_ownership_snapshot_with_invalid_realm_authority_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = nft_voter_test
+        .configure_ownership_snapshot_using_ix(
+            &mut registrar_cookie,
+            true,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_ownership_snapshot_with_voting_proposals_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .configure_ownership_snapshot(&mut registrar_cookie, true)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(
+        err,
+        NftVoterError::CannotConfigureOwnershipSnapshotWithVotingProposals,
+    );
+
+    Ok(())
+}
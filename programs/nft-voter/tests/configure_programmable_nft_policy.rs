@@ -0,0 +1,100 @@
+use gpl_nft_voter::error::NftVoterError;
+use gpl_nft_voter::state::ProgrammableNftPolicy;
+use program_test::{
+    nft_voter_test::NftVoterTest,
+    tools::{assert_anchor_err, assert_nft_voter_err},
+};
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_configure_programmable_nft_policy() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    nft_voter_test
+        .configure_programmable_nft_policy(
+            &mut registrar_cookie,
+            ProgrammableNftPolicy::RejectLockedOrDelegated,
+        )
+        .await?;
+
+    // Assert
+    let registrar = nft_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.programmable_nft_policy,
+        ProgrammableNftPolicy::RejectLockedOrDelegated
+    );
+    assert_eq!(registrar, registrar_cookie.account);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_programmable_nft_policy_with_realm_authority_must_sign_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    let err = nft_voter_test
+        .configure_programmable_nft_policy_using_ix(
+            &mut registrar_cookie,
+            ProgrammableNftPolicy::RejectLockedOrDelegated,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_programmable_nft_policy_with_invalid_realm_authority_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = nft_voter_test
+        .configure_programmable_nft_policy_using_ix(
+            &mut registrar_cookie,
+            ProgrammableNftPolicy::RejectLockedOrDelegated,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
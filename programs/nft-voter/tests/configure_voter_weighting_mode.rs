@@ -0,0 +1,239 @@
+use gpl_nft_voter::error::NftVoterError;
+use gpl_nft_voter::state::{VoterWeightingMode, VOTER_WEIGHT_CURVE_SCALE};
+use program_test::{
+    nft_voter_test::NftVoterTest,
+    tools::{assert_anchor_err, assert_nft_voter_err},
+};
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::program_test::nft_voter_test::ConfigureCollectionArgs;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_configure_voter_weighting_mode() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    // Act
+    nft_voter_test
+        .configure_voter_weighting_mode(
+            &mut registrar_cookie,
+            &max_voter_weight_record_cookie,
+            VoterWeightingMode::Quadratic,
+        )
+        .await?;
+
+    // Assert
+    let registrar = nft_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.voter_weighting_mode,
+        VoterWeightingMode::Quadratic
+    );
+
+    let max_voter_weight_record = nft_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    // Every NFT of the collection held by a different voter
+    assert_eq!(
+        max_voter_weight_record.max_voter_weight,
+        20 * VOTER_WEIGHT_CURVE_SCALE
+    );
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_voter_weighting_mode_back_to_linear() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    nft_voter_test
+        .configure_voter_weighting_mode(
+            &mut registrar_cookie,
+            &max_voter_weight_record_cookie,
+            VoterWeightingMode::Logarithmic,
+        )
+        .await?;
+
+    // Act
+    nft_voter_test
+        .configure_voter_weighting_mode(
+            &mut registrar_cookie,
+            &max_voter_weight_record_cookie,
+            VoterWeightingMode::Linear,
+        )
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = nft_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 200);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_voter_weighting_mode_with_realm_authority_must_sign_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .configure_voter_weighting_mode_using_ix(
+            &mut registrar_cookie,
+            &max_voter_weight_record_cookie,
+            VoterWeightingMode::Quadratic,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_voter_weighting_mode_with_invalid_realm_authority_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = nft_voter_test
+        .configure_voter_weighting_mode_using_ix(
+            &mut registrar_cookie,
+            &max_voter_weight_record_cookie,
+            VoterWeightingMode::Quadratic,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_voter_weighting_mode_with_voting_proposals_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .configure_voter_weighting_mode(
+            &mut registrar_cookie,
+            &max_voter_weight_record_cookie,
+            VoterWeightingMode::Quadratic,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(
+        err,
+        NftVoterError::CannotConfigureVoterWeightingModeWithVotingProposals,
+    );
+
+    Ok(())
+}
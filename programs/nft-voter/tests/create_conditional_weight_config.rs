@@ -0,0 +1,170 @@
+use gpl_nft_voter::error::NftVoterError;
+use gpl_nft_voter::state::*;
+use program_test::{
+    nft_voter_test::NftVoterTest,
+    tools::{assert_anchor_err, assert_nft_voter_err},
+};
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_create_conditional_weight_config() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut rules = get_default_conditional_weight_rules();
+    rules.push(ConditionalWeightRule {
+        program_id: Keypair::new().pubkey(),
+        discriminators: vec![[1, 2, 3, 4, 5, 6, 7, 8]],
+        weight_policy: ConditionalWeightPolicy::DenyOnly,
+        option_label: None,
+    });
+
+    // Act
+    let conditional_weight_config_cookie = nft_voter_test
+        .with_conditional_weight_config(&registrar_cookie, rules)
+        .await?;
+
+    // Assert
+    let conditional_weight_config = nft_voter_test
+        .get_conditional_weight_config_account(&conditional_weight_config_cookie.address)
+        .await;
+
+    assert_eq!(
+        conditional_weight_config_cookie.account,
+        conditional_weight_config
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_conditional_weight_config_with_realm_authority_must_sign_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    let err = nft_voter_test
+        .with_conditional_weight_config_using_ix(
+            &registrar_cookie,
+            get_default_conditional_weight_rules(),
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_conditional_weight_config_with_invalid_realm_authority_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = nft_voter_test
+        .with_conditional_weight_config_using_ix(
+            &registrar_cookie,
+            get_default_conditional_weight_rules(),
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_conditional_weight_config_with_rule_without_discriminators_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let rules = vec![ConditionalWeightRule {
+        program_id: Keypair::new().pubkey(),
+        discriminators: vec![],
+        weight_policy: ConditionalWeightPolicy::ApproveOnly,
+        option_label: None,
+    }];
+
+    // Act
+    let err = nft_voter_test
+        .with_conditional_weight_config(&registrar_cookie, rules)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidConditionalWeightRule);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_conditional_weight_config_with_voting_proposals_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .with_conditional_weight_config(&registrar_cookie, get_default_conditional_weight_rules())
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(
+        err,
+        NftVoterError::CannotConfigureConditionalWeightRulesWithVotingProposals,
+    );
+
+    Ok(())
+}
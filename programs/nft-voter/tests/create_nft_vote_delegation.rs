@@ -0,0 +1,164 @@
+use gpl_nft_voter::error::NftVoterError;
+use program_test::{
+    nft_voter_test::NftVoterTest,
+    tools::{assert_anchor_err, assert_nft_voter_err},
+};
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_create_nft_vote_delegation() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let owner_cookie = nft_voter_test.bench.with_wallet().await;
+    let delegate_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let clock = nft_voter_test.bench.get_clock().await;
+
+    // Act
+    let nft_vote_delegation_cookie = nft_voter_test
+        .with_nft_vote_delegation(
+            &registrar_cookie,
+            &owner_cookie,
+            &delegate_cookie.address,
+            Some(nft_collection_cookie.mint),
+            Some(clock.unix_timestamp + 3600),
+        )
+        .await?;
+
+    // Assert
+    let nft_vote_delegation = nft_voter_test
+        .get_nft_vote_delegation_account(&nft_vote_delegation_cookie.address)
+        .await;
+
+    assert_eq!(nft_vote_delegation_cookie.account, nft_vote_delegation);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_nft_vote_delegation_with_collection_not_found_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let owner_cookie = nft_voter_test.bench.with_wallet().await;
+    let delegate_cookie = nft_voter_test.bench.with_wallet().await;
+
+    // Act
+    let err = nft_voter_test
+        .with_nft_vote_delegation(
+            &registrar_cookie,
+            &owner_cookie,
+            &delegate_cookie.address,
+            Some(nft_collection_cookie.mint),
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::CollectionNotFound);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_nft_vote_delegation_with_expiry_in_past_error() -> Result<(), BanksClientError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let owner_cookie = nft_voter_test.bench.with_wallet().await;
+    let delegate_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let clock = nft_voter_test.bench.get_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .with_nft_vote_delegation(
+            &registrar_cookie,
+            &owner_cookie,
+            &delegate_cookie.address,
+            None,
+            Some(clock.unix_timestamp),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidNftVoteDelegationExpiry);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_nft_vote_delegation_with_owner_must_sign_error() -> Result<(), BanksClientError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let owner_cookie = nft_voter_test.bench.with_wallet().await;
+    let delegate_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let fake_owner = Keypair::new();
+
+    // Act
+    let err = nft_voter_test
+        .with_nft_vote_delegation_using_ix(
+            &registrar_cookie,
+            &owner_cookie,
+            &delegate_cookie.address,
+            None,
+            None,
+            |i| i.accounts[2].is_signer = false, // governing_token_owner
+            Some(&[&fake_owner]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
@@ -0,0 +1,272 @@
+use anchor_spl::token::TokenAccount;
+use gpl_nft_voter::error::NftVoterError;
+use gpl_nft_voter::state::*;
+use program_test::nft_voter_test::{ConfigureCollectionArgs, NftVoterTest};
+use program_test::tools::{assert_anchor_err, assert_nft_voter_err};
+use solana_program_test::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_deposit_nft() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_deposit_record_cookie = nft_voter_test
+        .with_nft_deposit_record(&registrar_cookie, &voter_cookie, 5)
+        .await?;
+
+    // Act
+    nft_voter_test
+        .deposit_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_cookie,
+            &nft_cookie1,
+        )
+        .await?;
+
+    // Assert
+    let nft_deposit_record = nft_voter_test
+        .get_nft_deposit_record_account(&nft_deposit_record_cookie.address)
+        .await;
+
+    assert_eq!(nft_deposit_record.total_weight, 10);
+    assert_eq!(nft_deposit_record.locked_nfts.len(), 1);
+    assert_eq!(
+        nft_deposit_record.locked_nfts[0].mint,
+        nft_cookie1.mint_cookie.address
+    );
+    assert_eq!(
+        nft_deposit_record.locked_nfts[0].collection,
+        nft_collection_cookie.mint
+    );
+
+    let nft_vault = nft_voter_test
+        .bench
+        .get_anchor_account::<TokenAccount>(get_nft_vault_address(
+            &registrar_cookie.address,
+            &nft_cookie1.mint_cookie.address,
+        ))
+        .await;
+
+    assert_eq!(nft_vault.amount, 1);
+    assert_eq!(
+        nft_vault.owner,
+        get_nft_locker_authority_address(&registrar_cookie.address)
+    );
+
+    let nft_token_account = nft_voter_test
+        .bench
+        .get_anchor_account::<TokenAccount>(nft_cookie1.address)
+        .await;
+
+    assert_eq!(nft_token_account.amount, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deposit_nft_with_full_deposit_record_error() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_deposit_record_cookie = nft_voter_test
+        .with_nft_deposit_record(&registrar_cookie, &voter_cookie, 1)
+        .await?;
+
+    nft_voter_test
+        .deposit_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_cookie,
+            &nft_cookie1,
+        )
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .deposit_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_cookie,
+            &nft_cookie2,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftDepositRecordIsFull);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deposit_nft_with_nft_not_owned_by_voter_error() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+    let voter_cookie2 = nft_voter_test.bench.with_wallet().await;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie2, None)
+        .await?;
+
+    let nft_deposit_record_cookie = nft_voter_test
+        .with_nft_deposit_record(&registrar_cookie, &voter_cookie, 5)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .deposit_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_cookie,
+            &nft_cookie1,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::VoterDoesNotOwnNft);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deposit_nft_with_voter_must_sign_error() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_deposit_record_cookie = nft_voter_test
+        .with_nft_deposit_record(&registrar_cookie, &voter_cookie, 5)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .deposit_nft_using_ix(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_cookie,
+            &nft_cookie1,
+            |i| i.accounts[2].is_signer = false, // governing_token_owner
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
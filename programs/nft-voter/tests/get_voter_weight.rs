@@ -0,0 +1,214 @@
+use crate::program_test::nft_voter_test::ConfigureCollectionArgs;
+use anchor_lang::prelude::ERROR_CODE_OFFSET;
+use gpl_nft_voter::error::NftVoterError;
+use program_test::{nft_voter_test::NftVoterTest, tools::assert_nft_voter_err};
+
+use solana_program_test::*;
+use solana_sdk::{instruction::AccountMeta, signature::Keypair, signer::Signer};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_get_voter_weight() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // Act
+    let voter_weight_quote = nft_voter_test
+        .get_voter_weight(
+            &registrar_cookie,
+            &voter_cookie,
+            &[&nft_cookie1, &nft_cookie2],
+        )
+        .await?;
+
+    // Assert
+    assert_eq!(voter_weight_quote.voter_weight, 20);
+    assert_eq!(voter_weight_quote.deposited_nft_count, 0);
+
+    assert_eq!(voter_weight_quote.nfts.len(), 2);
+    assert_eq!(
+        voter_weight_quote.nfts[0].mint,
+        nft_cookie1.mint_cookie.address
+    );
+    assert_eq!(voter_weight_quote.nfts[0].weight, 10);
+    assert_eq!(voter_weight_quote.nfts[0].rejection_code, None);
+    assert_eq!(
+        voter_weight_quote.nfts[1].mint,
+        nft_cookie2.mint_cookie.address
+    );
+    assert_eq!(voter_weight_quote.nfts[1].weight, 10);
+    assert_eq!(voter_weight_quote.nfts[1].rejection_code, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_voter_weight_with_rejected_nfts() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+    let voter_cookie2 = nft_voter_test.bench.with_wallet().await;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // NFT owned by another voter
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie2, None)
+        .await?;
+
+    // NFT from a collection which is not configured for the Registrar
+    let nft_collection_cookie2 = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let nft_cookie3 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie2, &voter_cookie, None)
+        .await?;
+
+    // Act
+    let voter_weight_quote = nft_voter_test
+        .get_voter_weight(
+            &registrar_cookie,
+            &voter_cookie,
+            &[&nft_cookie1, &nft_cookie2, &nft_cookie3],
+        )
+        .await?;
+
+    // Assert
+    assert_eq!(voter_weight_quote.voter_weight, 10);
+
+    assert_eq!(voter_weight_quote.nfts.len(), 3);
+    assert_eq!(voter_weight_quote.nfts[0].weight, 10);
+    assert_eq!(voter_weight_quote.nfts[0].rejection_code, None);
+
+    assert_eq!(
+        voter_weight_quote.nfts[1].mint,
+        nft_cookie2.mint_cookie.address
+    );
+    assert_eq!(voter_weight_quote.nfts[1].weight, 0);
+    assert_eq!(
+        voter_weight_quote.nfts[1].rejection_code,
+        Some((NftVoterError::VoterDoesNotOwnNft as u32 + ERROR_CODE_OFFSET) as u64)
+    );
+
+    assert_eq!(
+        voter_weight_quote.nfts[2].mint,
+        nft_cookie3.mint_cookie.address
+    );
+    assert_eq!(voter_weight_quote.nfts[2].weight, 0);
+    assert_eq!(
+        voter_weight_quote.nfts[2].rejection_code,
+        Some((NftVoterError::CollectionNotFound as u32 + ERROR_CODE_OFFSET) as u64)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_voter_weight_with_invalid_remaining_accounts_layout_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .get_voter_weight_using_ix(&registrar_cookie, &voter_cookie, &[&nft_cookie1], |i| {
+            i.accounts
+                .push(AccountMeta::new_readonly(Keypair::new().pubkey(), false))
+        })
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRemainingAccountsLayout);
+
+    Ok(())
+}
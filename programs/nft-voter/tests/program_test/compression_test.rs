@@ -0,0 +1,220 @@
+use std::sync::Arc;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Id;
+use gpl_nft_voter::state::CompressedNftAsset;
+use gpl_nft_voter::tools::account_compression::{
+    SplAccountCompressionProgram, CONCURRENT_MERKLE_TREE_ACCOUNT_TYPE,
+    CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1, CONCURRENT_MERKLE_TREE_HEADER_VERSION_V1,
+};
+use gpl_nft_voter::tools::bubblegum::{
+    get_asset_id, get_tree_authority, hash_creators, hash_leaf, hash_metadata, Collection,
+    MetadataArgs, TokenProgramVersion,
+};
+use gpl_nft_voter::tools::token_metadata::TokenStandard;
+use solana_program::keccak;
+use solana_sdk::account::Account;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+use crate::program_test::program_test_bench::{ProgramTestBench, WalletCookie};
+use crate::program_test::token_metadata_test::NftCollectionCookie;
+
+/// In-test Bubblegum Merkle tree
+/// The tree is built by the bench and written directly to the spl-account-compression tree account
+pub struct MerkleTreeCookie {
+    pub address: Pubkey,
+    pub max_depth: u32,
+    pub leaves: Vec<[u8; 32]>,
+}
+
+impl MerkleTreeCookie {
+    /// Returns all the nodes of the tree level by level starting from the leaves
+    fn get_levels(&self) -> Vec<Vec<[u8; 32]>> {
+        let mut level = self.leaves.clone();
+        level.resize(1 << self.max_depth, [0; 32]);
+
+        let mut levels = vec![level];
+
+        for _ in 0..self.max_depth {
+            let parent_level = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|nodes| keccak::hashv(&[&nodes[0], &nodes[1]]).to_bytes())
+                .collect::<Vec<[u8; 32]>>();
+
+            levels.push(parent_level);
+        }
+
+        levels
+    }
+
+    pub fn get_root(&self) -> [u8; 32] {
+        self.get_levels().last().unwrap()[0]
+    }
+
+    pub fn get_proof(&self, index: u32) -> Vec<Pubkey> {
+        let levels = self.get_levels();
+
+        (0..self.max_depth as usize)
+            .map(|depth| {
+                let sibling_index = (index as usize >> depth) ^ 1;
+                Pubkey::new_from_array(levels[depth][sibling_index])
+            })
+            .collect()
+    }
+}
+
+pub struct CompressedNftCookie {
+    pub asset_id: Pubkey,
+    pub compressed_nft: CompressedNftAsset,
+}
+
+pub struct CreateCompressedNftArgs {
+    pub verify_collection: bool,
+}
+
+impl Default for CreateCompressedNftArgs {
+    fn default() -> Self {
+        Self {
+            verify_collection: true,
+        }
+    }
+}
+
+pub struct CompressionTest {
+    pub bench: Arc<ProgramTestBench>,
+}
+
+impl CompressionTest {
+    pub const MAX_BUFFER_SIZE: u32 = 8;
+
+    #[allow(dead_code)]
+    pub fn new(bench: Arc<ProgramTestBench>) -> Self {
+        CompressionTest { bench }
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_merkle_tree(&self, max_depth: u32) -> MerkleTreeCookie {
+        let merkle_tree_cookie = MerkleTreeCookie {
+            address: Keypair::new().pubkey(),
+            max_depth,
+            leaves: vec![],
+        };
+
+        let authority = get_tree_authority(&merkle_tree_cookie.address);
+        self.set_merkle_tree_account(&merkle_tree_cookie, &authority)
+            .await;
+
+        merkle_tree_cookie
+    }
+
+    /// Mints compressed NFT to the in-test Merkle tree and updates the tree account
+    #[allow(dead_code)]
+    pub async fn with_compressed_nft(
+        &self,
+        merkle_tree_cookie: &mut MerkleTreeCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        nft_owner_cookie: &WalletCookie,
+        args: Option<CreateCompressedNftArgs>,
+    ) -> CompressedNftCookie {
+        let CreateCompressedNftArgs { verify_collection } = args.unwrap_or_default();
+
+        let nonce = merkle_tree_cookie.leaves.len() as u64;
+        let asset_id = get_asset_id(&merkle_tree_cookie.address, nonce);
+
+        let metadata = MetadataArgs {
+            name: "TestCompressedNFT".to_string(),
+            symbol: "cNFT".to_string(),
+            uri: "URI".to_string(),
+            seller_fee_basis_points: 10,
+            primary_sale_happened: false,
+            is_mutable: false,
+            edition_nonce: None,
+            token_standard: Some(TokenStandard::NonFungible),
+            collection: Some(Collection {
+                verified: verify_collection,
+                key: nft_collection_cookie.mint,
+            }),
+            uses: None,
+            token_program_version: TokenProgramVersion::Original,
+            creators: vec![],
+        };
+
+        let leaf = hash_leaf(
+            &asset_id,
+            &nft_owner_cookie.address,
+            &nft_owner_cookie.address,
+            nonce,
+            &hash_metadata(&metadata).unwrap(),
+            &hash_creators(&metadata.creators),
+        );
+
+        merkle_tree_cookie.leaves.push(leaf);
+
+        let authority = get_tree_authority(&merkle_tree_cookie.address);
+        self.set_merkle_tree_account(merkle_tree_cookie, &authority)
+            .await;
+
+        CompressedNftCookie {
+            asset_id,
+            compressed_nft: CompressedNftAsset {
+                nonce,
+                index: nonce as u32,
+                leaf_delegate: nft_owner_cookie.address,
+                metadata,
+            },
+        }
+    }
+
+    /// Writes ConcurrentMerkleTree account with the current root of the in-test tree
+    #[allow(dead_code)]
+    pub async fn set_merkle_tree_account(
+        &self,
+        merkle_tree_cookie: &MerkleTreeCookie,
+        authority: &Pubkey,
+    ) {
+        let max_depth = merkle_tree_cookie.max_depth as usize;
+        let max_buffer_size = Self::MAX_BUFFER_SIZE as usize;
+
+        let change_log_size = 32 + 32 * max_depth + 8;
+        let rightmost_proof_size = 32 * max_depth + 32 + 8;
+
+        let mut data = vec![
+            0;
+            CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1
+                + 24
+                + max_buffer_size * change_log_size
+                + rightmost_proof_size
+        ];
+
+        // Header
+        data[0] = CONCURRENT_MERKLE_TREE_ACCOUNT_TYPE;
+        data[1] = CONCURRENT_MERKLE_TREE_HEADER_VERSION_V1;
+        data[2..6].copy_from_slice(&Self::MAX_BUFFER_SIZE.to_le_bytes());
+        data[6..10].copy_from_slice(&merkle_tree_cookie.max_depth.to_le_bytes());
+        data[10..42].copy_from_slice(authority.as_ref());
+
+        // Tree: sequence_number, active_index, buffer_size and the root of the active change log
+        let tree_offset = CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1;
+        let sequence_number = merkle_tree_cookie.leaves.len() as u64;
+
+        data[tree_offset..tree_offset + 8].copy_from_slice(&sequence_number.to_le_bytes());
+        data[tree_offset + 8..tree_offset + 16].copy_from_slice(&0u64.to_le_bytes());
+        data[tree_offset + 16..tree_offset + 24].copy_from_slice(&1u64.to_le_bytes());
+        data[tree_offset + 24..tree_offset + 56].copy_from_slice(&merkle_tree_cookie.get_root());
+
+        let account = Account {
+            lamports: self.bench.rent.minimum_balance(data.len()),
+            data,
+            owner: SplAccountCompressionProgram::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        self.bench
+            .set_account(&merkle_tree_cookie.address, &account)
+            .await;
+    }
+}
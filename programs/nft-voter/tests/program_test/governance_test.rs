@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use anchor_lang::prelude::{AccountMeta, Pubkey};
 use anchor_lang::Id;
+use borsh::BorshSerialize;
 use gpl_nft_voter::tools::phase_protocol::REVERT_STAGED_APPROVE_PHASE_DATA;
 use gpl_nft_voter::tools::{
     governance::DedSplGovernanceProgram, phase_protocol::PhaseProtocolProgram,
@@ -15,14 +16,17 @@ use spl_governance::state::proposal_transaction::{
 use spl_governance::{
     instruction::{
         create_governance, create_proposal, create_realm, create_token_owner_record,
-        deposit_governing_tokens, insert_transaction, relinquish_vote, sign_off_proposal,
+        deposit_governing_tokens, insert_transaction, relinquish_vote, set_governance_delegate,
+        sign_off_proposal, GovernanceInstruction,
     },
     state::{
         enums::{GovernanceAccountType, MintMaxVoteWeightSource, ProposalState, VoteTipping},
         governance::get_governance_address,
         proposal::{get_proposal_address, ProposalV2},
         realm::{get_realm_address, RealmConfig, RealmV2},
+        realm_config::get_realm_config_address,
         token_owner_record::{get_token_owner_record_address, TokenOwnerRecordV2},
+        vote_record::{get_vote_record_address, VoteRecordV2},
     },
 };
 
@@ -325,7 +329,7 @@ impl GovernanceTest {
             .await?;
         Ok(())
     }
-    
+
     #[allow(dead_code)]
     pub async fn with_add_tx(
         &self,
@@ -450,6 +454,76 @@ impl GovernanceTest {
         Ok(())
     }
 
+    /// Returns spl-gov RevokeVote instruction which decreases the VoteRecord of the previous_owner
+    /// by the voter_weight of the given VoterWeightRecord
+    #[allow(dead_code)]
+    pub fn revoke_vote_instruction(
+        &self,
+        proposal_cookie: &ProposalCookie,
+        realm: &Pubkey,
+        previous_owner: &Pubkey,
+        voter_weight_record: &Pubkey,
+        governing_token_owner: &Pubkey,
+    ) -> Instruction {
+        let token_owner_record_key = get_token_owner_record_address(
+            &self.program_id,
+            realm,
+            &proposal_cookie.account.governing_token_mint,
+            previous_owner,
+        );
+
+        let vote_record_key = get_vote_record_address(
+            &self.program_id,
+            &proposal_cookie.address,
+            &token_owner_record_key,
+        );
+
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(*realm, false),
+                AccountMeta::new_readonly(proposal_cookie.account.governance, false),
+                AccountMeta::new(proposal_cookie.address, false),
+                AccountMeta::new(token_owner_record_key, false),
+                AccountMeta::new(vote_record_key, false),
+                AccountMeta::new_readonly(proposal_cookie.account.governing_token_mint, false),
+                AccountMeta::new_readonly(get_realm_config_address(&self.program_id, realm), false),
+                AccountMeta::new_readonly(*voter_weight_record, false),
+                AccountMeta::new_readonly(*governing_token_owner, true),
+            ],
+            data: GovernanceInstruction::RevokeVote {}.try_to_vec().unwrap(),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub async fn set_governance_delegate(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        token_owner_cookie: &WalletCookie,
+        token_owner_record_cookie: &mut TokenOwnerRecordCookie,
+        new_governance_delegate: &Option<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        let set_governance_delegate_ix = set_governance_delegate(
+            &self.program_id,
+            &token_owner_cookie.address,
+            &realm_cookie.address,
+            &token_owner_record_cookie.account.governing_token_mint,
+            &token_owner_cookie.address,
+            new_governance_delegate,
+        );
+
+        self.bench
+            .process_transaction(
+                &[set_governance_delegate_ix],
+                Some(&[&token_owner_cookie.signer]),
+            )
+            .await?;
+
+        token_owner_record_cookie.account.governance_delegate = *new_governance_delegate;
+
+        Ok(())
+    }
+
     #[allow(dead_code)]
     pub async fn get_proposal(&mut self, proposal_key: &Pubkey) -> ProposalV2 {
         self.bench
@@ -457,6 +531,13 @@ impl GovernanceTest {
             .await
     }
 
+    #[allow(dead_code)]
+    pub async fn get_vote_record(&mut self, vote_record_key: &Pubkey) -> VoteRecordV2 {
+        self.bench
+            .get_borsh_account::<VoteRecordV2>(vote_record_key)
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn get_token_owner_record(
         &mut self,
@@ -1,15 +1,18 @@
 use std::sync::Arc;
 
 use anchor_lang::prelude::{AccountMeta, Pubkey};
+use borsh::BorshDeserialize;
 
 use gpl_nft_voter::state::max_voter_weight_record::{
     get_max_voter_weight_record_address, MaxVoterWeightRecord,
 };
 use gpl_nft_voter::state::*;
+use gpl_nft_voter::tools::governance::get_vote_record_address;
 
 use solana_program::sysvar;
 use spl_governance::instruction::cast_vote;
 use spl_governance::state::proposal_transaction::ProposalTransactionV2;
+use spl_governance::state::token_owner_record::get_token_owner_record_address;
 use spl_governance::state::vote_record::{self, Vote, VoteChoice};
 
 use gpl_nft_voter::state::{
@@ -17,16 +20,22 @@ use gpl_nft_voter::state::{
 };
 
 use solana_program_test::{BanksClientError, ProgramTest};
+use solana_sdk::account::Account;
 use solana_sdk::instruction::Instruction;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 
+use crate::program_test::compression_test::{
+    CompressedNftCookie, CompressionTest, MerkleTreeCookie,
+};
 use crate::program_test::governance_test::GovernanceTest;
 use crate::program_test::program_test_bench::ProgramTestBench;
 
 use crate::program_test::governance_test::{ProposalCookie, RealmCookie, TokenOwnerRecordCookie};
 use crate::program_test::program_test_bench::WalletCookie;
-use crate::program_test::token_metadata_test::{NftCollectionCookie, NftCookie, TokenMetadataTest};
+use crate::program_test::token_metadata_test::{
+    NftCollectionCookie, NftCookie, NftEscrowCookie, TokenMetadataTest,
+};
 use crate::program_test::tools::NopOverride;
 
 #[derive(Debug, PartialEq)]
@@ -43,6 +52,23 @@ pub struct VoterWeightRecordCookie {
     pub account: VoterWeightRecord,
 }
 
+pub struct VoterWeightTallyCookie {
+    pub address: Pubkey,
+    pub account: VoterWeightTally,
+    pub max_nfts: u16,
+}
+
+pub struct NftDepositRecordCookie {
+    pub address: Pubkey,
+    pub account: NftDepositRecord,
+    pub max_nfts: u16,
+}
+
+pub struct NftVoteDelegationCookie {
+    pub address: Pubkey,
+    pub account: NftVoteDelegation,
+}
+
 pub struct MaxVoterWeightRecordCookie {
     pub address: Pubkey,
     pub account: MaxVoterWeightRecord,
@@ -53,6 +79,31 @@ pub struct ProposalTransactionCookie {
     pub account: ProposalTransactionV2,
 }
 
+pub struct EscrowProgramConfigCookie {
+    pub address: Pubkey,
+    pub account: EscrowProgramConfig,
+}
+
+pub struct ConditionalWeightConfigCookie {
+    pub address: Pubkey,
+    pub account: ConditionalWeightConfig,
+}
+
+pub struct CollectionWeightOverridesCookie {
+    pub address: Pubkey,
+    pub account: CollectionWeightOverrides,
+}
+
+pub struct MintDenylistCookie {
+    pub address: Pubkey,
+    pub account: MintDenylist,
+}
+
+pub struct NftIndexMapCookie {
+    pub address: Pubkey,
+    pub account: NftIndexMap,
+}
+
 pub struct CollectionConfigCookie {
     pub collection_config: CollectionConfig,
 }
@@ -77,6 +128,21 @@ pub struct NftVoteRecordCookie {
 pub struct CastNftVoteArgs {
     pub cast_spl_gov_vote: bool,
     pub vote_type: Vote,
+
+    /// The delegate who casts the vote on behalf of the NFT owner
+    pub voter_authority: Option<Keypair>,
+}
+
+pub struct RevokeNftVoteArgs {
+    pub revoke_spl_gov_vote: bool,
+}
+
+impl Default for RevokeNftVoteArgs {
+    fn default() -> Self {
+        Self {
+            revoke_spl_gov_vote: true,
+        }
+    }
 }
 
 impl Default for CastNftVoteArgs {
@@ -87,6 +153,7 @@ impl Default for CastNftVoteArgs {
                 rank: 0,
                 weight_percentage: 100,
             }]),
+            voter_authority: None,
         }
     }
 }
@@ -96,6 +163,7 @@ pub struct NftVoterTest {
     pub bench: Arc<ProgramTestBench>,
     pub governance: GovernanceTest,
     pub token_metadata: TokenMetadataTest,
+    pub compression: CompressionTest,
 }
 
 impl NftVoterTest {
@@ -120,12 +188,14 @@ impl NftVoterTest {
         let governance_bench =
             GovernanceTest::new(bench_rc.clone(), Some(program_id), Some(program_id));
         let token_metadata_bench = TokenMetadataTest::new(bench_rc.clone());
+        let compression_bench = CompressionTest::new(bench_rc.clone());
 
         Self {
             program_id,
             bench: bench_rc,
             governance: governance_bench,
             token_metadata: token_metadata_bench,
+            compression: compression_bench,
         }
     }
 
@@ -188,7 +258,12 @@ impl NftVoterTest {
             realm: realm_cookie.address,
             governing_token_mint: realm_cookie.account.community_mint,
             collection_configs: vec![],
-            reserved: [0; 128],
+            programmable_nft_policy: ProgrammableNftPolicy::AllowAll,
+            voter_weight_caps: VoterWeightCaps::default(),
+            voter_weighting_mode: VoterWeightingMode::Linear,
+            ownership_snapshot_enabled: false,
+            vote_tracking_mode: VoteTrackingMode::NftVoteRecords,
+            reserved: [0; 112],
         };
 
         Ok(RegistrarCookie {
@@ -199,6 +274,57 @@ impl NftVoterTest {
         })
     }
 
+    #[allow(dead_code)]
+    pub async fn resize_registrar(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        max_collections: u8,
+    ) -> Result<(), BanksClientError> {
+        self.resize_registrar_using_ix(registrar_cookie, max_collections, NopOverride, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn resize_registrar_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        max_collections: u8,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::ResizeRegistrar {
+                max_collections,
+            });
+
+        let accounts = gpl_nft_voter::accounts::ResizeRegistrar {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut resize_registrar_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut resize_registrar_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[resize_registrar_ix], Some(signers))
+            .await?;
+
+        registrar_cookie.max_collections = max_collections;
+
+        Ok(())
+    }
+
     #[allow(dead_code)]
     pub async fn with_voter_weight_record(
         &self,
@@ -339,31 +465,329 @@ impl NftVoterTest {
         voter_weight_action: VoterWeightAction,
         nft_cookies: &[&NftCookie],
     ) -> Result<(), BanksClientError> {
+        let accounts = gpl_nft_voter::accounts::UpdateVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+            mint_denylist: get_mint_denylist_address(&registrar_cookie.address),
+            nft_deposit_record: get_nft_deposit_record_address(
+                &registrar_cookie.address,
+                &voter_weight_record_cookie.account.governing_token_owner,
+            ),
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+        let mut nft_account_counts = vec![];
+
+        for nft_cookie in nft_cookies {
+            let nft_accounts_start = account_metas.len();
+
+            account_metas.push(AccountMeta::new_readonly(nft_cookie.address, false));
+            account_metas.push(AccountMeta::new_readonly(nft_cookie.metadata, false));
+
+            if let Some(token_record) = nft_cookie.token_record {
+                account_metas.push(AccountMeta::new_readonly(token_record, false));
+            }
+
+            if let Some(ref escrow) = nft_cookie.escrow {
+                account_metas.push(AccountMeta::new_readonly(
+                    escrow.escrow_program_config,
+                    false,
+                ));
+                account_metas.push(AccountMeta::new_readonly(escrow.escrow_receipt, false));
+            }
+
+            if let Some(collection_weight_overrides) = nft_cookie.collection_weight_overrides {
+                account_metas.push(AccountMeta::new_readonly(
+                    collection_weight_overrides,
+                    false,
+                ));
+            }
+
+            nft_account_counts.push((account_metas.len() - nft_accounts_start) as u8);
+        }
+
         let data = anchor_lang::InstructionData::data(
             &gpl_nft_voter::instruction::UpdateVoterWeightRecord {
                 voter_weight_action,
+                nft_account_counts,
             },
         );
 
-        let accounts = gpl_nft_voter::accounts::UpdateVoterWeightRecord {
+        let instructions = vec![Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: account_metas,
+            data,
+        }];
+
+        self.bench.process_transaction(&instructions, None).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_voter_weight(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &WalletCookie,
+        nft_cookies: &[&NftCookie],
+    ) -> Result<VoterWeightQuote, BanksClientError> {
+        self.get_voter_weight_using_ix(registrar_cookie, voter_cookie, nft_cookies, NopOverride)
+            .await
+    }
+
+    /// Simulates GetVoterWeight and returns the VoterWeightQuote from the instruction return data
+    #[allow(dead_code)]
+    pub async fn get_voter_weight_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &WalletCookie,
+        nft_cookies: &[&NftCookie],
+        instruction_override: F,
+    ) -> Result<VoterWeightQuote, BanksClientError> {
+        let mut account_metas = vec![];
+        let mut nft_account_counts = vec![];
+
+        for nft_cookie in nft_cookies {
+            let nft_accounts_start = account_metas.len();
+
+            account_metas.push(AccountMeta::new_readonly(nft_cookie.address, false));
+            account_metas.push(AccountMeta::new_readonly(nft_cookie.metadata, false));
+
+            if let Some(token_record) = nft_cookie.token_record {
+                account_metas.push(AccountMeta::new_readonly(token_record, false));
+            }
+
+            if let Some(ref escrow) = nft_cookie.escrow {
+                account_metas.push(AccountMeta::new_readonly(
+                    escrow.escrow_program_config,
+                    false,
+                ));
+                account_metas.push(AccountMeta::new_readonly(escrow.escrow_receipt, false));
+            }
+
+            if let Some(collection_weight_overrides) = nft_cookie.collection_weight_overrides {
+                account_metas.push(AccountMeta::new_readonly(
+                    collection_weight_overrides,
+                    false,
+                ));
+            }
+
+            nft_account_counts.push((account_metas.len() - nft_accounts_start) as u8);
+        }
+
+        let data =
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::GetVoterWeight {
+                nft_account_counts,
+            });
+
+        let accounts = gpl_nft_voter::accounts::GetVoterWeight {
+            registrar: registrar_cookie.address,
+            governing_token_owner: voter_cookie.address,
+            mint_denylist: get_mint_denylist_address(&registrar_cookie.address),
+            nft_deposit_record: get_nft_deposit_record_address(
+                &registrar_cookie.address,
+                &voter_cookie.address,
+            ),
+        };
+
+        let mut get_voter_weight_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        get_voter_weight_ix.accounts.extend(account_metas);
+
+        instruction_override(&mut get_voter_weight_ix);
+
+        let return_data = self
+            .bench
+            .simulate_transaction_return_data(&[get_voter_weight_ix], None, &gpl_nft_voter::id())
+            .await?
+            .unwrap();
+
+        Ok(VoterWeightQuote::try_from_slice(&return_data).unwrap())
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_voter_weight_tally(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        max_nfts: u16,
+    ) -> Result<VoterWeightTallyCookie, BanksClientError> {
+        let voter_weight_tally_key =
+            get_voter_weight_tally_address(&voter_weight_record_cookie.address);
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::CreateVoterWeightTally { max_nfts },
+        );
+
+        let accounts = gpl_nft_voter::accounts::CreateVoterWeightTally {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+            voter_weight_tally: voter_weight_tally_key,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let create_voter_weight_tally_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        self.bench
+            .process_transaction(&[create_voter_weight_tally_ix], None)
+            .await?;
+
+        let account = VoterWeightTally {
+            voter_weight_record: voter_weight_record_cookie.address,
+            weight_action: None,
+            start_slot: 0,
+            voter_weight: 0,
+            nft_mints: vec![],
+            reserved: [0; 32],
+        };
+
+        Ok(VoterWeightTallyCookie {
+            address: voter_weight_tally_key,
+            account,
+            max_nfts,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_tally(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        voter_weight_tally_cookie: &VoterWeightTallyCookie,
+        voter_cookie: &WalletCookie,
+        voter_weight_action: VoterWeightAction,
+        nft_cookies: &[&NftCookie],
+    ) -> Result<(), BanksClientError> {
+        self.update_voter_weight_tally_using_ix(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            voter_weight_tally_cookie,
+            &voter_cookie.signer,
+            voter_weight_action,
+            nft_cookies,
+            NopOverride,
+        )
+        .await
+    }
+
+    /// Updates VoterWeightTally signed by the given voter_authority
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_tally_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        voter_weight_tally_cookie: &VoterWeightTallyCookie,
+        voter_authority: &Keypair,
+        voter_weight_action: VoterWeightAction,
+        nft_cookies: &[&NftCookie],
+        instruction_override: F,
+    ) -> Result<(), BanksClientError> {
+        let governing_token_owner = voter_weight_record_cookie.account.governing_token_owner;
+
+        let accounts = gpl_nft_voter::accounts::UpdateVoterWeightTally {
             registrar: registrar_cookie.address,
             voter_weight_record: voter_weight_record_cookie.address,
+            voter_weight_tally: voter_weight_tally_cookie.address,
+            governing_token_owner,
+            voter_authority: voter_authority.pubkey(),
+            payer: self.bench.payer.pubkey(),
+            mint_denylist: get_mint_denylist_address(&registrar_cookie.address),
+            nft_deposit_record: get_nft_deposit_record_address(
+                &registrar_cookie.address,
+                &governing_token_owner,
+            ),
+            nft_vote_delegation: get_nft_vote_delegation_address(
+                &registrar_cookie.address,
+                &governing_token_owner,
+            ),
+            token_owner_record: get_token_owner_record_address(
+                &self.governance.program_id,
+                &registrar_cookie.account.realm,
+                &registrar_cookie.account.governing_token_mint,
+                &governing_token_owner,
+            ),
+            system_program: solana_sdk::system_program::id(),
         };
 
         let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+        let mut nft_account_counts = vec![];
 
         for nft_cookie in nft_cookies {
             account_metas.push(AccountMeta::new_readonly(nft_cookie.address, false));
             account_metas.push(AccountMeta::new_readonly(nft_cookie.metadata, false));
+            nft_account_counts.push(2);
+
+            account_metas.push(AccountMeta::new(
+                get_nft_tally_record_address(
+                    &registrar_cookie.address,
+                    &nft_cookie.mint_cookie.address,
+                ),
+                false,
+            ));
         }
 
-        let instructions = vec![Instruction {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::UpdateVoterWeightTally {
+                voter_weight_action,
+                nft_account_counts,
+            },
+        );
+
+        let mut update_voter_weight_tally_ix = Instruction {
             program_id: gpl_nft_voter::id(),
             accounts: account_metas,
             data,
-        }];
+        };
 
-        self.bench.process_transaction(&instructions, None).await
+        instruction_override(&mut update_voter_weight_tally_ix);
+
+        self.bench
+            .process_transaction(&[update_voter_weight_tally_ix], Some(&[voter_authority]))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn close_voter_weight_tally(
+        &self,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        voter_weight_tally_cookie: &VoterWeightTallyCookie,
+        voter_cookie: &WalletCookie,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::CloseVoterWeightTally {},
+        );
+
+        let accounts = gpl_nft_voter::accounts::CloseVoterWeightTally {
+            voter_weight_record: voter_weight_record_cookie.address,
+            voter_weight_tally: voter_weight_tally_cookie.address,
+            governing_token_owner: voter_cookie.address,
+            beneficiary: self.bench.payer.pubkey(),
+        };
+
+        let close_voter_weight_tally_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        self.bench
+            .process_transaction(
+                &[close_voter_weight_tally_ix],
+                Some(&[&voter_cookie.signer]),
+            )
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_voter_weight_tally(&self, voter_weight_tally: &Pubkey) -> VoterWeightTally {
+        self.bench.get_anchor_account(*voter_weight_tally).await
     }
 
     #[allow(dead_code)]
@@ -393,6 +817,14 @@ impl NftVoterTest {
             governing_token_owner: voter_cookie.address,
             vote_record: vote_record_key,
             beneficiary: self.bench.payer.pubkey(),
+            voter_weight_cap_tally: get_voter_weight_cap_tally_address(
+                &proposal_cookie.address,
+                &voter_cookie.address,
+            ),
+            nft_deposit_record: get_nft_deposit_record_address(
+                &registrar_cookie.address,
+                &voter_cookie.address,
+            ),
         };
 
         let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
@@ -415,74 +847,2009 @@ impl NftVoterTest {
     }
 
     #[allow(dead_code)]
-    pub async fn with_collection(
+    pub async fn close_nft_vote_records(
         &mut self,
         registrar_cookie: &RegistrarCookie,
-        nft_collection_cookie: &NftCollectionCookie,
-        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
-        args: Option<ConfigureCollectionArgs>,
-    ) -> Result<CollectionConfigCookie, BanksClientError> {
-        self.with_collection_using_ix(
+        proposal_cookie: &ProposalCookie,
+        nft_vote_record_cookies: &[NftVoteRecordCookie],
+    ) -> Result<(), BanksClientError> {
+        self.close_nft_vote_records_using_ix(
             registrar_cookie,
-            nft_collection_cookie,
-            max_voter_weight_record_cookie,
-            args,
+            proposal_cookie,
+            nft_vote_record_cookies,
             NopOverride,
-            None,
         )
         .await
     }
 
     #[allow(dead_code)]
-    pub async fn with_collection_using_ix<F: Fn(&mut Instruction)>(
+    pub async fn close_nft_vote_records_using_ix<F: Fn(&mut Instruction)>(
         &mut self,
         registrar_cookie: &RegistrarCookie,
-        nft_collection_cookie: &NftCollectionCookie,
-        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
-        args: Option<ConfigureCollectionArgs>,
+        proposal_cookie: &ProposalCookie,
+        nft_vote_record_cookies: &[NftVoteRecordCookie],
         instruction_override: F,
-        signers_override: Option<&[&Keypair]>,
-    ) -> Result<CollectionConfigCookie, BanksClientError> {
-        let args = args.unwrap_or_default();
-
+    ) -> Result<(), BanksClientError> {
         let data =
-            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::ConfigureCollection {
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::CloseNftVoteRecords {});
+
+        let accounts = gpl_nft_voter::accounts::CloseNftVoteRecords {
+            registrar: registrar_cookie.address,
+            governance: proposal_cookie.account.governance,
+            proposal: proposal_cookie.address,
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        for nft_vote_record_cookie in nft_vote_record_cookies {
+            account_metas.push(AccountMeta::new(nft_vote_record_cookie.address, false));
+            account_metas.push(AccountMeta::new(
+                nft_vote_record_cookie.account.payer,
+                false,
+            ));
+        }
+
+        let mut close_nft_vote_records_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: account_metas,
+            data,
+        };
+
+        instruction_override(&mut close_nft_vote_records_ix);
+
+        self.bench
+            .process_transaction(&[close_nft_vote_records_ix], None)
+            .await
+    }
+
+    /// Revokes NFT votes of the previous owner with NFTs owned by the new owner
+    #[allow(dead_code)]
+    pub async fn revoke_nft_vote(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        proposal_cookie: &ProposalCookie,
+        new_owner_cookie: &WalletCookie,
+        nft_cookies: &[&NftCookie],
+    ) -> Result<(), BanksClientError> {
+        self.revoke_nft_vote_using_ix(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            proposal_cookie,
+            new_owner_cookie,
+            nft_cookies,
+            None,
+            NopOverride,
+            NopOverride,
+        )
+        .await
+    }
+
+    /// Revokes NFT votes of the previous owner and the previous owner's spl-gov vote
+    /// revoke_vote_instruction_override is applied to the bundled spl-gov RevokeVote instruction
+    #[allow(dead_code)]
+    pub async fn revoke_nft_vote_using_ix<F: Fn(&mut Instruction), G: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        proposal_cookie: &ProposalCookie,
+        new_owner_cookie: &WalletCookie,
+        nft_cookies: &[&NftCookie],
+        args: Option<RevokeNftVoteArgs>,
+        instruction_override: F,
+        revoke_vote_instruction_override: G,
+    ) -> Result<(), BanksClientError> {
+        let args = args.unwrap_or_default();
+
+        let vote_record_key = get_vote_record_address(
+            &self.governance.program_id,
+            &registrar_cookie.account.realm,
+            &registrar_cookie.account.governing_token_mint,
+            &voter_weight_record_cookie.account.governing_token_owner,
+            &proposal_cookie.address,
+        );
+
+        let accounts = gpl_nft_voter::accounts::RevokeNftVote {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+            governance: proposal_cookie.account.governance,
+            proposal: proposal_cookie.address,
+            governing_token_owner: new_owner_cookie.address,
+            vote_record: vote_record_key,
+            beneficiary: self.bench.payer.pubkey(),
+            mint_denylist: get_mint_denylist_address(&registrar_cookie.address),
+            voter_weight_cap_tally: get_voter_weight_cap_tally_address(
+                &proposal_cookie.address,
+                &voter_weight_record_cookie.account.governing_token_owner,
+            ),
+            instruction_sysvar_account: sysvar::instructions::id(),
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+        let mut nft_account_counts = vec![];
+
+        for nft_cookie in nft_cookies {
+            let nft_accounts_start = account_metas.len();
+
+            account_metas.push(AccountMeta::new_readonly(nft_cookie.address, false));
+            account_metas.push(AccountMeta::new_readonly(nft_cookie.metadata, false));
+
+            if let Some(token_record) = nft_cookie.token_record {
+                account_metas.push(AccountMeta::new_readonly(token_record, false));
+            }
+
+            if let Some(ref escrow) = nft_cookie.escrow {
+                account_metas.push(AccountMeta::new_readonly(
+                    escrow.escrow_program_config,
+                    false,
+                ));
+                account_metas.push(AccountMeta::new_readonly(escrow.escrow_receipt, false));
+            }
+
+            if let Some(collection_weight_overrides) = nft_cookie.collection_weight_overrides {
+                account_metas.push(AccountMeta::new_readonly(
+                    collection_weight_overrides,
+                    false,
+                ));
+            }
+
+            nft_account_counts.push((account_metas.len() - nft_accounts_start) as u8);
+
+            let nft_vote_record_key = get_nft_vote_record_address(
+                &proposal_cookie.address,
+                &nft_cookie.mint_cookie.address,
+            );
+            account_metas.push(AccountMeta::new(nft_vote_record_key, false));
+        }
+
+        let data = anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::RevokeNftVote {
+            nft_account_counts,
+        });
+
+        let mut revoke_nft_vote_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: account_metas,
+            data,
+        };
+
+        instruction_override(&mut revoke_nft_vote_ix);
+
+        let mut instructions = vec![revoke_nft_vote_ix];
+
+        if args.revoke_spl_gov_vote {
+            let mut revoke_vote_ix = self.governance.revoke_vote_instruction(
+                proposal_cookie,
+                &registrar_cookie.account.realm,
+                &voter_weight_record_cookie.account.governing_token_owner,
+                &voter_weight_record_cookie.address,
+                &new_owner_cookie.address,
+            );
+
+            revoke_vote_instruction_override(&mut revoke_vote_ix);
+
+            instructions.push(revoke_vote_ix);
+        }
+
+        self.bench
+            .process_transaction(&instructions, Some(&[&new_owner_cookie.signer]))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_collection(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        args: Option<ConfigureCollectionArgs>,
+    ) -> Result<CollectionConfigCookie, BanksClientError> {
+        self.with_collection_using_ix(
+            registrar_cookie,
+            nft_collection_cookie,
+            max_voter_weight_record_cookie,
+            args,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_collection_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        args: Option<ConfigureCollectionArgs>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<CollectionConfigCookie, BanksClientError> {
+        let args = args.unwrap_or_default();
+
+        let data =
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::ConfigureCollection {
                 weight: args.weight,
                 size: args.size,
             });
 
-        let accounts = gpl_nft_voter::accounts::ConfigureCollection {
-            metadata: nft_collection_cookie.metadata,
+        let accounts = gpl_nft_voter::accounts::ConfigureCollection {
+            metadata: nft_collection_cookie.metadata,
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            collection: nft_collection_cookie.mint,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            collection_weight_overrides: get_collection_weight_overrides_address(
+                &registrar_cookie.address,
+                &nft_collection_cookie.mint,
+            ),
+        };
+
+        let mut configure_collection_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_collection_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_collection_ix], Some(signers))
+            .await?;
+
+        let collection_config = CollectionConfig {
+            collection: nft_collection_cookie.mint,
+            size: args.size,
+            weight: args.weight,
+            max_weight_bonus: 0,
+        };
+
+        Ok(CollectionConfigCookie { collection_config })
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_collection_weight_overrides(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        overrides: Vec<NftWeightOverride>,
+    ) -> Result<CollectionWeightOverridesCookie, BanksClientError> {
+        self.with_collection_weight_overrides_using_ix(
+            registrar_cookie,
+            nft_collection_cookie,
+            max_voter_weight_record_cookie,
+            overrides,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_collection_weight_overrides_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        overrides: Vec<NftWeightOverride>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<CollectionWeightOverridesCookie, BanksClientError> {
+        let collection_weight_overrides_key = get_collection_weight_overrides_address(
+            &registrar_cookie.address,
+            &nft_collection_cookie.mint,
+        );
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::CreateCollectionWeightOverrides {
+                collection: nft_collection_cookie.mint,
+                overrides: overrides.clone(),
+            },
+        );
+
+        let accounts = gpl_nft_voter::accounts::CreateCollectionWeightOverrides {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            collection_weight_overrides: collection_weight_overrides_key,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut create_collection_weight_overrides_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut create_collection_weight_overrides_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[create_collection_weight_overrides_ix], Some(signers))
+            .await?;
+
+        let account = CollectionWeightOverrides {
+            registrar: registrar_cookie.address,
+            collection: nft_collection_cookie.mint,
+            overrides,
+            reserved: [0; 32],
+        };
+
+        Ok(CollectionWeightOverridesCookie {
+            address: collection_weight_overrides_key,
+            account,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_collection_weight_overrides(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        collection_weight_overrides_cookie: &mut CollectionWeightOverridesCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        overrides: Vec<NftWeightOverride>,
+    ) -> Result<(), BanksClientError> {
+        self.configure_collection_weight_overrides_using_ix(
+            registrar_cookie,
+            collection_weight_overrides_cookie,
+            max_voter_weight_record_cookie,
+            overrides,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_collection_weight_overrides_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        collection_weight_overrides_cookie: &mut CollectionWeightOverridesCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        overrides: Vec<NftWeightOverride>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::ConfigureCollectionWeightOverrides {
+                overrides: overrides.clone(),
+            },
+        );
+
+        let accounts = gpl_nft_voter::accounts::ConfigureCollectionWeightOverrides {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            collection_weight_overrides: collection_weight_overrides_cookie.address,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut configure_collection_weight_overrides_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_collection_weight_overrides_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_collection_weight_overrides_ix], Some(signers))
+            .await?;
+
+        collection_weight_overrides_cookie.account.overrides = overrides;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn add_collection_weight_overrides(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        collection_weight_overrides_cookie: &mut CollectionWeightOverridesCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        overrides: Vec<NftWeightOverride>,
+    ) -> Result<(), BanksClientError> {
+        self.add_collection_weight_overrides_using_ix(
+            registrar_cookie,
+            collection_weight_overrides_cookie,
+            max_voter_weight_record_cookie,
+            overrides,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn add_collection_weight_overrides_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        collection_weight_overrides_cookie: &mut CollectionWeightOverridesCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        overrides: Vec<NftWeightOverride>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::AddCollectionWeightOverrides {
+                overrides: overrides.clone(),
+            },
+        );
+
+        let accounts = gpl_nft_voter::accounts::AddCollectionWeightOverrides {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            collection_weight_overrides: collection_weight_overrides_cookie.address,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut add_collection_weight_overrides_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut add_collection_weight_overrides_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[add_collection_weight_overrides_ix], Some(signers))
+            .await?;
+
+        collection_weight_overrides_cookie
+            .account
+            .overrides
+            .extend(overrides);
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_collection_weight_overrides(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        collection_weight_overrides_cookie: &mut CollectionWeightOverridesCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        nft_mints: Vec<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        self.remove_collection_weight_overrides_using_ix(
+            registrar_cookie,
+            collection_weight_overrides_cookie,
+            max_voter_weight_record_cookie,
+            nft_mints,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_collection_weight_overrides_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        collection_weight_overrides_cookie: &mut CollectionWeightOverridesCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        nft_mints: Vec<Pubkey>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::RemoveCollectionWeightOverrides {
+                nft_mints: nft_mints.clone(),
+            },
+        );
+
+        let accounts = gpl_nft_voter::accounts::RemoveCollectionWeightOverrides {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            collection_weight_overrides: collection_weight_overrides_cookie.address,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut remove_collection_weight_overrides_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut remove_collection_weight_overrides_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[remove_collection_weight_overrides_ix], Some(signers))
+            .await?;
+
+        collection_weight_overrides_cookie
+            .account
+            .overrides
+            .retain(|o| !nft_mints.contains(&o.nft_mint));
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_collection_weight_overrides_account(
+        &mut self,
+        collection_weight_overrides: &Pubkey,
+    ) -> CollectionWeightOverrides {
+        self.bench
+            .get_anchor_account::<CollectionWeightOverrides>(*collection_weight_overrides)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_collection(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+    ) -> Result<(), BanksClientError> {
+        self.remove_collection_using_ix(
+            registrar_cookie,
+            nft_collection_cookie,
+            max_voter_weight_record_cookie,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_collection_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::RemoveCollection {
+                collection: nft_collection_cookie.mint,
+            });
+
+        let accounts = gpl_nft_voter::accounts::RemoveCollection {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+        };
+
+        let mut remove_collection_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut remove_collection_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[remove_collection_ix], Some(signers))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_programmable_nft_policy(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        programmable_nft_policy: ProgrammableNftPolicy,
+    ) -> Result<(), BanksClientError> {
+        self.configure_programmable_nft_policy_using_ix(
+            registrar_cookie,
+            programmable_nft_policy,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_programmable_nft_policy_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        programmable_nft_policy: ProgrammableNftPolicy,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::ConfigureProgrammableNftPolicy {
+                programmable_nft_policy,
+            },
+        );
+
+        let accounts = gpl_nft_voter::accounts::ConfigureProgrammableNftPolicy {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+        };
+
+        let mut configure_programmable_nft_policy_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_programmable_nft_policy_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_programmable_nft_policy_ix], Some(signers))
+            .await?;
+
+        registrar_cookie.account.programmable_nft_policy = programmable_nft_policy;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_voter_weight_caps(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        voter_weight_caps: VoterWeightCaps,
+    ) -> Result<(), BanksClientError> {
+        self.configure_voter_weight_caps_using_ix(
+            registrar_cookie,
+            voter_weight_caps,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_voter_weight_caps_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        voter_weight_caps: VoterWeightCaps,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::ConfigureVoterWeightCaps { voter_weight_caps },
+        );
+
+        let accounts = gpl_nft_voter::accounts::ConfigureVoterWeightCaps {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+        };
+
+        let mut configure_voter_weight_caps_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_voter_weight_caps_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_voter_weight_caps_ix], Some(signers))
+            .await?;
+
+        registrar_cookie.account.voter_weight_caps = voter_weight_caps;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_voter_weighting_mode(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        voter_weighting_mode: VoterWeightingMode,
+    ) -> Result<(), BanksClientError> {
+        self.configure_voter_weighting_mode_using_ix(
+            registrar_cookie,
+            max_voter_weight_record_cookie,
+            voter_weighting_mode,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_voter_weighting_mode_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        voter_weighting_mode: VoterWeightingMode,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::ConfigureVoterWeightingMode {
+                voter_weighting_mode,
+            },
+        );
+
+        let accounts = gpl_nft_voter::accounts::ConfigureVoterWeightingMode {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+        };
+
+        let mut configure_voter_weighting_mode_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_voter_weighting_mode_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_voter_weighting_mode_ix], Some(signers))
+            .await?;
+
+        registrar_cookie.account.voter_weighting_mode = voter_weighting_mode;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_ownership_snapshot(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        ownership_snapshot_enabled: bool,
+    ) -> Result<(), BanksClientError> {
+        self.configure_ownership_snapshot_using_ix(
+            registrar_cookie,
+            ownership_snapshot_enabled,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_ownership_snapshot_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        ownership_snapshot_enabled: bool,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::ConfigureOwnershipSnapshot {
+                ownership_snapshot_enabled,
+            },
+        );
+
+        let accounts = gpl_nft_voter::accounts::ConfigureOwnershipSnapshot {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+        };
+
+        let mut configure_ownership_snapshot_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_ownership_snapshot_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_ownership_snapshot_ix], Some(signers))
+            .await?;
+
+        registrar_cookie.account.ownership_snapshot_enabled = ownership_snapshot_enabled;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_vote_tracking_mode(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        vote_tracking_mode: VoteTrackingMode,
+    ) -> Result<(), BanksClientError> {
+        self.configure_vote_tracking_mode_using_ix(
+            registrar_cookie,
+            vote_tracking_mode,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_vote_tracking_mode_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &mut RegistrarCookie,
+        vote_tracking_mode: VoteTrackingMode,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::ConfigureVoteTrackingMode { vote_tracking_mode },
+        );
+
+        let accounts = gpl_nft_voter::accounts::ConfigureVoteTrackingMode {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+        };
+
+        let mut configure_vote_tracking_mode_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_vote_tracking_mode_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_vote_tracking_mode_ix], Some(signers))
+            .await?;
+
+        registrar_cookie.account.vote_tracking_mode = vote_tracking_mode;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_nft_index_map(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+    ) -> Result<NftIndexMapCookie, BanksClientError> {
+        self.with_nft_index_map_using_ix(registrar_cookie, nft_collection_cookie, NopOverride, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_nft_index_map_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<NftIndexMapCookie, BanksClientError> {
+        let nft_index_map_key =
+            get_nft_index_map_address(&registrar_cookie.address, &nft_collection_cookie.mint);
+
+        let data =
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::CreateNftIndexMap {
+                collection: nft_collection_cookie.mint,
+            });
+
+        let accounts = gpl_nft_voter::accounts::CreateNftIndexMap {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            nft_index_map: nft_index_map_key,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut create_nft_index_map_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut create_nft_index_map_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[create_nft_index_map_ix], Some(signers))
+            .await?;
+
+        let account = NftIndexMap {
+            registrar: registrar_cookie.address,
+            collection: nft_collection_cookie.mint,
+            reserved: [0; 32],
+            nfts: vec![],
+        };
+
+        Ok(NftIndexMapCookie {
+            address: nft_index_map_key,
+            account,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn add_nft_index_map_mints(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_index_map_cookie: &mut NftIndexMapCookie,
+        mints: Vec<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        self.add_nft_index_map_mints_using_ix(
+            registrar_cookie,
+            nft_index_map_cookie,
+            mints,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn add_nft_index_map_mints_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_index_map_cookie: &mut NftIndexMapCookie,
+        mints: Vec<Pubkey>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::AddNftIndexMapMints {
+                collection: nft_index_map_cookie.account.collection,
+                mints: mints.clone(),
+            });
+
+        let accounts = gpl_nft_voter::accounts::AddNftIndexMapMints {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            nft_index_map: nft_index_map_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut add_nft_index_map_mints_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut add_nft_index_map_mints_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[add_nft_index_map_mints_ix], Some(signers))
+            .await?;
+
+        let nfts = &mut nft_index_map_cookie.account.nfts;
+
+        for mint in mints {
+            let index = nfts.len() as u32;
+            nfts.push(NftIndex { mint, index });
+        }
+
+        nfts.sort_by(|a, b| a.mint.cmp(&b.mint));
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_nft_index_map_account(&mut self, nft_index_map: &Pubkey) -> NftIndexMap {
+        self.bench
+            .get_anchor_account::<NftIndexMap>(*nft_index_map)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_nft_deposit_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &WalletCookie,
+        max_nfts: u16,
+    ) -> Result<NftDepositRecordCookie, BanksClientError> {
+        let nft_deposit_record_key =
+            get_nft_deposit_record_address(&registrar_cookie.address, &voter_cookie.address);
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::CreateNftDepositRecord { max_nfts },
+        );
+
+        let accounts = gpl_nft_voter::accounts::CreateNftDepositRecord {
+            registrar: registrar_cookie.address,
+            nft_deposit_record: nft_deposit_record_key,
+            governing_token_owner: voter_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let create_nft_deposit_record_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        self.bench
+            .process_transaction(
+                &[create_nft_deposit_record_ix],
+                Some(&[&voter_cookie.signer]),
+            )
+            .await?;
+
+        let account = NftDepositRecord {
+            registrar: registrar_cookie.address,
+            governing_token_owner: voter_cookie.address,
+            total_weight: 0,
+            locked_nfts: vec![],
+            nft_vote_record_count: 0,
+            reserved: [0; 28],
+        };
+
+        Ok(NftDepositRecordCookie {
+            address: nft_deposit_record_key,
+            account,
+            max_nfts,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn deposit_nft(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        nft_deposit_record_cookie: &NftDepositRecordCookie,
+        voter_cookie: &WalletCookie,
+        nft_cookie: &NftCookie,
+    ) -> Result<(), BanksClientError> {
+        self.deposit_nft_using_ix(
+            registrar_cookie,
+            nft_deposit_record_cookie,
+            voter_cookie,
+            nft_cookie,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn deposit_nft_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        nft_deposit_record_cookie: &NftDepositRecordCookie,
+        voter_cookie: &WalletCookie,
+        nft_cookie: &NftCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::DepositNft {});
+
+        let accounts = gpl_nft_voter::accounts::DepositNft {
+            registrar: registrar_cookie.address,
+            nft_deposit_record: nft_deposit_record_cookie.address,
+            governing_token_owner: voter_cookie.address,
+            nft_token_account: nft_cookie.address,
+            nft_metadata: nft_cookie.metadata,
+            nft_mint: nft_cookie.mint_cookie.address,
+            nft_locker_authority: get_nft_locker_authority_address(&registrar_cookie.address),
+            nft_vault: get_nft_vault_address(
+                &registrar_cookie.address,
+                &nft_cookie.mint_cookie.address,
+            ),
+            mint_denylist: get_mint_denylist_address(&registrar_cookie.address),
+            payer: self.bench.payer.pubkey(),
+            token_program: spl_token::id(),
+            system_program: solana_sdk::system_program::id(),
+            rent: sysvar::rent::id(),
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        if let Some(collection_weight_overrides) = nft_cookie.collection_weight_overrides {
+            account_metas.push(AccountMeta::new_readonly(
+                collection_weight_overrides,
+                false,
+            ));
+        }
+
+        let mut deposit_nft_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: account_metas,
+            data,
+        };
+
+        instruction_override(&mut deposit_nft_ix);
+
+        let default_signers = &[&voter_cookie.signer];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[deposit_nft_ix], Some(signers))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn withdraw_nft(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        nft_deposit_record_cookie: &NftDepositRecordCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        voter_token_owner_record_cookie: &TokenOwnerRecordCookie,
+        voter_cookie: &WalletCookie,
+        nft_cookie: &NftCookie,
+    ) -> Result<(), BanksClientError> {
+        self.withdraw_nft_using_ix(
+            registrar_cookie,
+            nft_deposit_record_cookie,
+            voter_weight_record_cookie,
+            voter_token_owner_record_cookie,
+            voter_cookie,
+            nft_cookie,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn withdraw_nft_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        nft_deposit_record_cookie: &NftDepositRecordCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        voter_token_owner_record_cookie: &TokenOwnerRecordCookie,
+        voter_cookie: &WalletCookie,
+        nft_cookie: &NftCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::WithdrawNft {});
+
+        let accounts = gpl_nft_voter::accounts::WithdrawNft {
+            registrar: registrar_cookie.address,
+            nft_deposit_record: nft_deposit_record_cookie.address,
+            governing_token_owner: voter_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+            token_owner_record: voter_token_owner_record_cookie.address,
+            nft_token_account: nft_cookie.address,
+            nft_mint: nft_cookie.mint_cookie.address,
+            nft_locker_authority: get_nft_locker_authority_address(&registrar_cookie.address),
+            nft_vault: get_nft_vault_address(
+                &registrar_cookie.address,
+                &nft_cookie.mint_cookie.address,
+            ),
+            beneficiary: self.bench.payer.pubkey(),
+            token_program: spl_token::id(),
+        };
+
+        let mut withdraw_nft_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut withdraw_nft_ix);
+
+        let default_signers = &[&voter_cookie.signer];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[withdraw_nft_ix], Some(signers))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_nft_deposit_record_account(
+        &mut self,
+        nft_deposit_record: &Pubkey,
+    ) -> NftDepositRecord {
+        self.bench
+            .get_anchor_account::<NftDepositRecord>(*nft_deposit_record)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_nft_vote_delegation(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        owner_cookie: &WalletCookie,
+        delegate: &Pubkey,
+        collection: Option<Pubkey>,
+        expiry: Option<i64>,
+    ) -> Result<NftVoteDelegationCookie, BanksClientError> {
+        self.with_nft_vote_delegation_using_ix(
+            registrar_cookie,
+            owner_cookie,
+            delegate,
+            collection,
+            expiry,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_nft_vote_delegation_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        owner_cookie: &WalletCookie,
+        delegate: &Pubkey,
+        collection: Option<Pubkey>,
+        expiry: Option<i64>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<NftVoteDelegationCookie, BanksClientError> {
+        let nft_vote_delegation_key =
+            get_nft_vote_delegation_address(&registrar_cookie.address, &owner_cookie.address);
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::CreateNftVoteDelegation {
+                delegate: *delegate,
+                collection,
+                expiry,
+            },
+        );
+
+        let accounts = gpl_nft_voter::accounts::CreateNftVoteDelegation {
+            registrar: registrar_cookie.address,
+            nft_vote_delegation: nft_vote_delegation_key,
+            governing_token_owner: owner_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut create_nft_vote_delegation_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut create_nft_vote_delegation_ix);
+
+        let default_signers = &[&owner_cookie.signer];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[create_nft_vote_delegation_ix], Some(signers))
+            .await?;
+
+        let account = NftVoteDelegation {
+            registrar: registrar_cookie.address,
+            governing_token_owner: owner_cookie.address,
+            delegate: *delegate,
+            collection,
+            expiry,
+            reserved: [0; 32],
+        };
+
+        Ok(NftVoteDelegationCookie {
+            address: nft_vote_delegation_key,
+            account,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn revoke_nft_vote_delegation(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        nft_vote_delegation_cookie: &NftVoteDelegationCookie,
+        owner_cookie: &WalletCookie,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::RevokeNftVoteDelegation {},
+        );
+
+        let accounts = gpl_nft_voter::accounts::RevokeNftVoteDelegation {
+            registrar: registrar_cookie.address,
+            nft_vote_delegation: nft_vote_delegation_cookie.address,
+            governing_token_owner: owner_cookie.address,
+            beneficiary: self.bench.payer.pubkey(),
+        };
+
+        let revoke_nft_vote_delegation_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        self.bench
+            .process_transaction(
+                &[revoke_nft_vote_delegation_ix],
+                Some(&[&owner_cookie.signer]),
+            )
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_nft_vote_delegation_account(
+        &mut self,
+        nft_vote_delegation: &Pubkey,
+    ) -> NftVoteDelegation {
+        self.bench
+            .get_anchor_account::<NftVoteDelegation>(*nft_vote_delegation)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn register_nft_holders(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_holder_cookie: &WalletCookie,
+        nft_cookies: &[&NftCookie],
+    ) -> Result<(), BanksClientError> {
+        self.register_nft_holders_using_ix(
+            registrar_cookie,
+            nft_holder_cookie,
+            nft_cookies,
+            NopOverride,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn register_nft_holders_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_holder_cookie: &WalletCookie,
+        nft_cookies: &[&NftCookie],
+        instruction_override: F,
+    ) -> Result<(), BanksClientError> {
+        let accounts = gpl_nft_voter::accounts::RegisterNftHolders {
+            registrar: registrar_cookie.address,
+            nft_holder: nft_holder_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            mint_denylist: get_mint_denylist_address(&registrar_cookie.address),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+        let mut nft_account_counts = vec![];
+
+        for nft_cookie in nft_cookies {
+            let nft_accounts_start = account_metas.len();
+
+            account_metas.push(AccountMeta::new_readonly(nft_cookie.address, false));
+            account_metas.push(AccountMeta::new_readonly(nft_cookie.metadata, false));
+
+            if let Some(token_record) = nft_cookie.token_record {
+                account_metas.push(AccountMeta::new_readonly(token_record, false));
+            }
+
+            if let Some(ref escrow) = nft_cookie.escrow {
+                account_metas.push(AccountMeta::new_readonly(
+                    escrow.escrow_program_config,
+                    false,
+                ));
+                account_metas.push(AccountMeta::new_readonly(escrow.escrow_receipt, false));
+            }
+
+            if let Some(collection_weight_overrides) = nft_cookie.collection_weight_overrides {
+                account_metas.push(AccountMeta::new_readonly(
+                    collection_weight_overrides,
+                    false,
+                ));
+            }
+
+            nft_account_counts.push((account_metas.len() - nft_accounts_start) as u8);
+
+            account_metas.push(AccountMeta::new(
+                get_nft_holder_registration_address(
+                    &registrar_cookie.address,
+                    &nft_cookie.mint_cookie.address,
+                ),
+                false,
+            ));
+        }
+
+        let data =
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::RegisterNftHolders {
+                nft_account_counts,
+            });
+
+        let mut register_nft_holders_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: account_metas,
+            data,
+        };
+
+        instruction_override(&mut register_nft_holders_ix);
+
+        self.bench
+            .process_transaction(&[register_nft_holders_ix], None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn register_compressed_nft_holders(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        nft_holder_cookie: &WalletCookie,
+        merkle_tree_cookie: &MerkleTreeCookie,
+        compressed_nft_cookies: &[&CompressedNftCookie],
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::RegisterCompressedNftHolders {
+                compressed_nfts: compressed_nft_cookies
+                    .iter()
+                    .map(|c| c.compressed_nft.clone())
+                    .collect(),
+            },
+        );
+
+        let accounts = gpl_nft_voter::accounts::RegisterNftHolders {
+            registrar: registrar_cookie.address,
+            nft_holder: nft_holder_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            mint_denylist: get_mint_denylist_address(&registrar_cookie.address),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        for compressed_nft_cookie in compressed_nft_cookies {
+            account_metas.push(AccountMeta::new_readonly(merkle_tree_cookie.address, false));
+            account_metas.push(AccountMeta::new(
+                get_nft_holder_registration_address(
+                    &registrar_cookie.address,
+                    &compressed_nft_cookie.asset_id,
+                ),
+                false,
+            ));
+
+            for proof_node in
+                merkle_tree_cookie.get_proof(compressed_nft_cookie.compressed_nft.index)
+            {
+                account_metas.push(AccountMeta::new_readonly(proof_node, false));
+            }
+        }
+
+        let register_compressed_nft_holders_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: account_metas,
+            data,
+        };
+
+        self.bench
+            .process_transaction(&[register_compressed_nft_holders_ix], None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_escrow_program(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+    ) -> Result<EscrowProgramConfigCookie, BanksClientError> {
+        self.with_escrow_program_using_ix(registrar_cookie, NopOverride, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_escrow_program_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<EscrowProgramConfigCookie, BanksClientError> {
+        let program_id = Keypair::new().pubkey();
+
+        // Test receipt layout: discriminator, beneficial_owner, mint
+        let receipt_discriminator = [1, 2, 3, 4, 5, 6, 7, 8];
+        let beneficial_owner_offset = 8;
+        let mint_offset = 40;
+
+        let escrow_program_config_key =
+            get_escrow_program_config_address(&registrar_cookie.address, &program_id);
+
+        let data =
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::AddEscrowProgram {
+                program_id,
+                receipt_discriminator,
+                beneficial_owner_offset,
+                mint_offset,
+            });
+
+        let accounts = gpl_nft_voter::accounts::AddEscrowProgram {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            escrow_program_config: escrow_program_config_key,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut add_escrow_program_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut add_escrow_program_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[add_escrow_program_ix], Some(signers))
+            .await?;
+
+        let account = EscrowProgramConfig {
+            registrar: registrar_cookie.address,
+            program_id,
+            receipt_discriminator,
+            beneficial_owner_offset,
+            mint_offset,
+            reserved: [0; 64],
+        };
+
+        Ok(EscrowProgramConfigCookie {
+            address: escrow_program_config_key,
+            account,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_escrow_program(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        escrow_program_config_cookie: &EscrowProgramConfigCookie,
+    ) -> Result<(), BanksClientError> {
+        self.remove_escrow_program_using_ix(
+            registrar_cookie,
+            escrow_program_config_cookie,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_escrow_program_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        escrow_program_config_cookie: &EscrowProgramConfigCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::RemoveEscrowProgram {});
+
+        let accounts = gpl_nft_voter::accounts::RemoveEscrowProgram {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            escrow_program_config: escrow_program_config_cookie.address,
+            beneficiary: self.bench.payer.pubkey(),
+        };
+
+        let mut remove_escrow_program_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut remove_escrow_program_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[remove_escrow_program_ix], Some(signers))
+            .await
+    }
+
+    /// Creates NFT held by the escrow program and the escrow receipt naming the voter as the beneficial owner
+    /// The receipt is written directly to the account owned by the escrow program
+    #[allow(dead_code)]
+    pub async fn with_escrowed_nft(
+        &mut self,
+        escrow_program_config_cookie: &EscrowProgramConfigCookie,
+        nft_collection_cookie: &NftCollectionCookie,
+        beneficial_owner_cookie: &WalletCookie,
+    ) -> Result<NftCookie, BanksClientError> {
+        let escrow_authority_cookie = self.bench.with_wallet().await;
+
+        let mut nft_cookie = self
+            .token_metadata
+            .with_nft_v2(nft_collection_cookie, &escrow_authority_cookie, None)
+            .await?;
+
+        let escrow_program_config = &escrow_program_config_cookie.account;
+
+        let mut data = vec![0; 72];
+        data[0..8].copy_from_slice(&escrow_program_config.receipt_discriminator);
+
+        let beneficial_owner_offset = escrow_program_config.beneficial_owner_offset as usize;
+        data[beneficial_owner_offset..beneficial_owner_offset + 32]
+            .copy_from_slice(beneficial_owner_cookie.address.as_ref());
+
+        let mint_offset = escrow_program_config.mint_offset as usize;
+        data[mint_offset..mint_offset + 32]
+            .copy_from_slice(nft_cookie.mint_cookie.address.as_ref());
+
+        let escrow_receipt_key = Keypair::new().pubkey();
+
+        let escrow_receipt_account = Account {
+            lamports: self.bench.rent.minimum_balance(data.len()),
+            data,
+            owner: escrow_program_config.program_id,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        self.bench
+            .set_account(&escrow_receipt_key, &escrow_receipt_account)
+            .await;
+
+        nft_cookie.escrow = Some(NftEscrowCookie {
+            escrow_program_config: escrow_program_config_cookie.address,
+            escrow_receipt: escrow_receipt_key,
+        });
+
+        Ok(nft_cookie)
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_escrow_program_config_account(
+        &mut self,
+        escrow_program_config: &Pubkey,
+    ) -> EscrowProgramConfig {
+        self.bench
+            .get_anchor_account::<EscrowProgramConfig>(*escrow_program_config)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_conditional_weight_config(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        rules: Vec<ConditionalWeightRule>,
+    ) -> Result<ConditionalWeightConfigCookie, BanksClientError> {
+        self.with_conditional_weight_config_using_ix(registrar_cookie, rules, NopOverride, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_conditional_weight_config_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        rules: Vec<ConditionalWeightRule>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<ConditionalWeightConfigCookie, BanksClientError> {
+        let conditional_weight_config_key =
+            get_conditional_weight_config_address(&registrar_cookie.address);
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::CreateConditionalWeightConfig {
+                rules: rules.clone(),
+            },
+        );
+
+        let accounts = gpl_nft_voter::accounts::CreateConditionalWeightConfig {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            conditional_weight_config: conditional_weight_config_key,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut create_conditional_weight_config_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut create_conditional_weight_config_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[create_conditional_weight_config_ix], Some(signers))
+            .await?;
+
+        let account = ConditionalWeightConfig {
+            registrar: registrar_cookie.address,
+            rules,
+            reserved: [0; 32],
+        };
+
+        Ok(ConditionalWeightConfigCookie {
+            address: conditional_weight_config_key,
+            account,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_conditional_weight_rules(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        conditional_weight_config_cookie: &mut ConditionalWeightConfigCookie,
+        rules: Vec<ConditionalWeightRule>,
+    ) -> Result<(), BanksClientError> {
+        self.configure_conditional_weight_rules_using_ix(
+            registrar_cookie,
+            conditional_weight_config_cookie,
+            rules,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_conditional_weight_rules_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        conditional_weight_config_cookie: &mut ConditionalWeightConfigCookie,
+        rules: Vec<ConditionalWeightRule>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::ConfigureConditionalWeightRules {
+                rules: rules.clone(),
+            },
+        );
+
+        let accounts = gpl_nft_voter::accounts::ConfigureConditionalWeightRules {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            conditional_weight_config: conditional_weight_config_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut configure_conditional_weight_rules_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut configure_conditional_weight_rules_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_conditional_weight_rules_ix], Some(signers))
+            .await?;
+
+        conditional_weight_config_cookie.account.rules = rules;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_conditional_weight_config_account(
+        &mut self,
+        conditional_weight_config: &Pubkey,
+    ) -> ConditionalWeightConfig {
+        self.bench
+            .get_anchor_account::<ConditionalWeightConfig>(*conditional_weight_config)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_mint_denylist(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        mints: Vec<Pubkey>,
+    ) -> Result<MintDenylistCookie, BanksClientError> {
+        self.with_mint_denylist_using_ix(registrar_cookie, mints, NopOverride, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_mint_denylist_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        mints: Vec<Pubkey>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<MintDenylistCookie, BanksClientError> {
+        let mint_denylist_key = get_mint_denylist_address(&registrar_cookie.address);
+
+        let data =
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::CreateMintDenylist {
+                mints: mints.clone(),
+            });
+
+        let accounts = gpl_nft_voter::accounts::CreateMintDenylist {
             registrar: registrar_cookie.address,
             realm: registrar_cookie.account.realm,
             realm_authority: registrar_cookie.realm_authority.pubkey(),
-            collection: nft_collection_cookie.mint,
-            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            mint_denylist: mint_denylist_key,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
         };
 
-        let mut configure_collection_ix = Instruction {
+        let mut create_mint_denylist_ix = Instruction {
             program_id: gpl_nft_voter::id(),
             accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
             data,
         };
 
-        instruction_override(&mut configure_collection_ix);
+        instruction_override(&mut create_mint_denylist_ix);
 
         let default_signers = &[&registrar_cookie.realm_authority];
         let signers = signers_override.unwrap_or(default_signers);
 
         self.bench
-            .process_transaction(&[configure_collection_ix], Some(signers))
+            .process_transaction(&[create_mint_denylist_ix], Some(signers))
             .await?;
 
-        let collection_config = CollectionConfig {
-            collection: nft_collection_cookie.mint,
-            size: args.size,
-            weight: args.weight,
-            reserved: [0; 8],
+        let mut account = MintDenylist {
+            registrar: registrar_cookie.address,
+            mints,
+            reserved: [0; 32],
         };
 
-        Ok(CollectionConfigCookie { collection_config })
+        account.mints.sort();
+
+        Ok(MintDenylistCookie {
+            address: mint_denylist_key,
+            account,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn add_denied_mints(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        mint_denylist_cookie: &mut MintDenylistCookie,
+        mints: Vec<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        self.add_denied_mints_using_ix(
+            registrar_cookie,
+            mint_denylist_cookie,
+            mints,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn add_denied_mints_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        mint_denylist_cookie: &mut MintDenylistCookie,
+        mints: Vec<Pubkey>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::AddDeniedMints {
+                mints: mints.clone(),
+            });
+
+        let accounts = gpl_nft_voter::accounts::AddDeniedMints {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            mint_denylist: mint_denylist_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut add_denied_mints_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut add_denied_mints_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[add_denied_mints_ix], Some(signers))
+            .await?;
+
+        mint_denylist_cookie.account.mints.extend(mints);
+        mint_denylist_cookie.account.mints.sort();
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_denied_mints(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        mint_denylist_cookie: &mut MintDenylistCookie,
+        mints: Vec<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        self.remove_denied_mints_using_ix(
+            registrar_cookie,
+            mint_denylist_cookie,
+            mints,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_denied_mints_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        mint_denylist_cookie: &mut MintDenylistCookie,
+        mints: Vec<Pubkey>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::RemoveDeniedMints {
+                mints: mints.clone(),
+            });
+
+        let accounts = gpl_nft_voter::accounts::RemoveDeniedMints {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            mint_denylist: mint_denylist_cookie.address,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut remove_denied_mints_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut remove_denied_mints_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[remove_denied_mints_ix], Some(signers))
+            .await?;
+
+        mint_denylist_cookie
+            .account
+            .mints
+            .retain(|mint| !mints.contains(mint));
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_mint_denylist_account(&mut self, mint_denylist: &Pubkey) -> MintDenylist {
+        self.bench
+            .get_anchor_account::<MintDenylist>(*mint_denylist)
+            .await
     }
 
     /// Casts NFT Vote and spl-gov Vote
@@ -498,29 +2865,135 @@ impl NftVoterTest {
         nft_cookies: &[&NftCookie],
         proposal_transaction_cookie: Option<ProposalTransactionCookie>,
         args: Option<CastNftVoteArgs>,
+    ) -> Result<Vec<NftVoteRecordCookie>, BanksClientError> {
+        self.cast_nft_vote_using_ix(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            max_voter_weight_record_cookie,
+            proposal_cookie,
+            nft_voter_cookie,
+            voter_token_owner_record_cookie,
+            nft_cookies,
+            proposal_transaction_cookie,
+            args,
+            NopOverride,
+            NopOverride,
+        )
+        .await
+    }
+
+    /// Casts NFT Vote and spl-gov Vote
+    /// cast_vote_instruction_override is applied to the bundled spl-gov CastVote instruction
+    #[allow(dead_code)]
+    pub async fn cast_nft_vote_using_ix<F: Fn(&mut Instruction), G: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        proposal_cookie: &ProposalCookie,
+        nft_voter_cookie: &WalletCookie,
+        voter_token_owner_record_cookie: &TokenOwnerRecordCookie,
+        nft_cookies: &[&NftCookie],
+        proposal_transaction_cookie: Option<ProposalTransactionCookie>,
+        args: Option<CastNftVoteArgs>,
+        instruction_override: F,
+        cast_vote_instruction_override: G,
     ) -> Result<Vec<NftVoteRecordCookie>, BanksClientError> {
         let args = args.unwrap_or_default();
 
-        let data = anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::CastNftVote {});
+        let voter_authority = args
+            .voter_authority
+            .as_ref()
+            .unwrap_or(&nft_voter_cookie.signer);
+
+        let nft_deposit_record_key =
+            get_nft_deposit_record_address(&registrar_cookie.address, &nft_voter_cookie.address);
 
         let accounts = gpl_nft_voter::accounts::CastNftVote {
             registrar: registrar_cookie.address,
             voter_weight_record: voter_weight_record_cookie.address,
             governing_token_owner: nft_voter_cookie.address,
+            voter_authority: voter_authority.pubkey(),
             payer: self.bench.payer.pubkey(),
             system_program: solana_sdk::system_program::id(),
             proposal: proposal_cookie.address,
             instruction_sysvar_account: sysvar::instructions::id(),
             governance_program: self.governance.program_id,
+            conditional_weight_config: get_conditional_weight_config_address(
+                &registrar_cookie.address,
+            ),
+            mint_denylist: get_mint_denylist_address(&registrar_cookie.address),
+            voter_weight_cap_tally: get_voter_weight_cap_tally_address(
+                &proposal_cookie.address,
+                &nft_voter_cookie.address,
+            ),
+            nft_deposit_record: nft_deposit_record_key,
+            nft_deposit_vote_record: get_nft_vote_record_address(
+                &proposal_cookie.address,
+                &nft_deposit_record_key,
+            ),
+            nft_vote_delegation: get_nft_vote_delegation_address(
+                &registrar_cookie.address,
+                &nft_voter_cookie.address,
+            ),
+            token_owner_record: voter_token_owner_record_cookie.address,
         };
 
         let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+        let mut nft_account_counts = vec![];
         let mut nft_vote_record_cookies = vec![];
 
         for nft_cookie in nft_cookies {
+            let nft_accounts_start = account_metas.len();
+
             account_metas.push(AccountMeta::new_readonly(nft_cookie.address, false));
             account_metas.push(AccountMeta::new_readonly(nft_cookie.metadata, false));
 
+            if let Some(token_record) = nft_cookie.token_record {
+                account_metas.push(AccountMeta::new_readonly(token_record, false));
+            }
+
+            if let Some(ref escrow) = nft_cookie.escrow {
+                account_metas.push(AccountMeta::new_readonly(
+                    escrow.escrow_program_config,
+                    false,
+                ));
+                account_metas.push(AccountMeta::new_readonly(escrow.escrow_receipt, false));
+            }
+
+            if let Some(collection_weight_overrides) = nft_cookie.collection_weight_overrides {
+                account_metas.push(AccountMeta::new_readonly(
+                    collection_weight_overrides,
+                    false,
+                ));
+            }
+
+            nft_account_counts.push((account_metas.len() - nft_accounts_start) as u8);
+
+            if registrar_cookie.account.ownership_snapshot_enabled {
+                account_metas.push(AccountMeta::new_readonly(
+                    get_nft_holder_registration_address(
+                        &registrar_cookie.address,
+                        &nft_cookie.mint_cookie.address,
+                    ),
+                    false,
+                ));
+            }
+
+            // With VoteBitmaps the NFT vote is recorded in the VoteBitmap of the NFT collection
+            if registrar_cookie.account.vote_tracking_mode == VoteTrackingMode::VoteBitmaps {
+                account_metas.push(AccountMeta::new(
+                    get_vote_bitmap_address(&proposal_cookie.address, &nft_cookie.collection),
+                    false,
+                ));
+                account_metas.push(AccountMeta::new_readonly(
+                    get_nft_index_map_address(&registrar_cookie.address, &nft_cookie.collection),
+                    false,
+                ));
+
+                continue;
+            }
+
             let nft_vote_record_key = get_nft_vote_record_address(
                 &proposal_cookie.address,
                 &nft_cookie.mint_cookie.address,
@@ -533,6 +3006,7 @@ impl NftVoterTest {
                 governing_token_owner: voter_weight_record_cookie.account.governing_token_owner,
                 account_discriminator: NftVoteRecord::ACCOUNT_DISCRIMINATOR,
                 reserved: [0; 8],
+                payer: self.bench.payer.pubkey(),
             };
 
             nft_vote_record_cookies.push(NftVoteRecordCookie {
@@ -545,23 +3019,180 @@ impl NftVoterTest {
             account_metas.push(AccountMeta::new(prop_tx.address, false));
         }
 
-        let cast_nft_vote_ix = Instruction {
+        let data = anchor_lang::InstructionData::data(&gpl_nft_voter::instruction::CastNftVote {
+            layout: CastNftVoteLayout {
+                nft_account_counts,
+                with_proposal_transaction: proposal_transaction_cookie.is_some(),
+            },
+        });
+
+        let mut cast_nft_vote_ix = Instruction {
             program_id: gpl_nft_voter::id(),
             accounts: account_metas,
             data,
         };
 
+        instruction_override(&mut cast_nft_vote_ix);
+
         let mut instruction = vec![cast_nft_vote_ix];
 
         if args.cast_spl_gov_vote {
-            let cast_vote_ix = cast_vote(
+            let mut cast_vote_ix = cast_vote(
                 &self.governance.program_id,
                 &registrar_cookie.account.realm,
                 &proposal_cookie.account.governance,
                 &proposal_cookie.address,
                 &proposal_cookie.account.token_owner_record,
                 &voter_token_owner_record_cookie.address,
+                &voter_authority.pubkey(),
+                &proposal_cookie.account.governing_token_mint,
+                &self.bench.payer.pubkey(),
+                Some(voter_weight_record_cookie.address),
+                Some(max_voter_weight_record_cookie.address),
+                args.vote_type,
+            );
+
+            cast_vote_instruction_override(&mut cast_vote_ix);
+
+            instruction.push(cast_vote_ix);
+        }
+
+        self.bench
+            .process_transaction(&instruction, Some(&[voter_authority]))
+            .await?;
+
+        Ok(nft_vote_record_cookies)
+    }
+
+    /// Casts compressed NFT Vote and spl-gov Vote
+    #[allow(dead_code)]
+    pub async fn cast_compressed_nft_vote(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        proposal_cookie: &ProposalCookie,
+        nft_voter_cookie: &WalletCookie,
+        voter_token_owner_record_cookie: &TokenOwnerRecordCookie,
+        merkle_tree_cookie: &MerkleTreeCookie,
+        compressed_nft_cookies: &[&CompressedNftCookie],
+        proposal_transaction_cookie: Option<ProposalTransactionCookie>,
+        args: Option<CastNftVoteArgs>,
+    ) -> Result<Vec<NftVoteRecordCookie>, BanksClientError> {
+        let args = args.unwrap_or_default();
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_nft_voter::instruction::CastCompressedNftVote {
+                compressed_nfts: compressed_nft_cookies
+                    .iter()
+                    .map(|c| c.compressed_nft.clone())
+                    .collect(),
+                with_proposal_transaction: proposal_transaction_cookie.is_some(),
+            },
+        );
+
+        let voter_authority = args
+            .voter_authority
+            .as_ref()
+            .unwrap_or(&nft_voter_cookie.signer);
+
+        let nft_deposit_record_key =
+            get_nft_deposit_record_address(&registrar_cookie.address, &nft_voter_cookie.address);
+
+        let accounts = gpl_nft_voter::accounts::CastNftVote {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+            governing_token_owner: nft_voter_cookie.address,
+            voter_authority: voter_authority.pubkey(),
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+            proposal: proposal_cookie.address,
+            instruction_sysvar_account: sysvar::instructions::id(),
+            governance_program: self.governance.program_id,
+            conditional_weight_config: get_conditional_weight_config_address(
+                &registrar_cookie.address,
+            ),
+            mint_denylist: get_mint_denylist_address(&registrar_cookie.address),
+            voter_weight_cap_tally: get_voter_weight_cap_tally_address(
+                &proposal_cookie.address,
+                &nft_voter_cookie.address,
+            ),
+            nft_deposit_record: nft_deposit_record_key,
+            nft_deposit_vote_record: get_nft_vote_record_address(
+                &proposal_cookie.address,
+                &nft_deposit_record_key,
+            ),
+            nft_vote_delegation: get_nft_vote_delegation_address(
+                &registrar_cookie.address,
                 &nft_voter_cookie.address,
+            ),
+            token_owner_record: voter_token_owner_record_cookie.address,
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+        let mut nft_vote_record_cookies = vec![];
+
+        for compressed_nft_cookie in compressed_nft_cookies {
+            account_metas.push(AccountMeta::new_readonly(merkle_tree_cookie.address, false));
+
+            let nft_vote_record_key = get_nft_vote_record_address(
+                &proposal_cookie.address,
+                &compressed_nft_cookie.asset_id,
+            );
+            account_metas.push(AccountMeta::new(nft_vote_record_key, false));
+
+            if registrar_cookie.account.ownership_snapshot_enabled {
+                account_metas.push(AccountMeta::new_readonly(
+                    get_nft_holder_registration_address(
+                        &registrar_cookie.address,
+                        &compressed_nft_cookie.asset_id,
+                    ),
+                    false,
+                ));
+            }
+
+            for proof_node in
+                merkle_tree_cookie.get_proof(compressed_nft_cookie.compressed_nft.index)
+            {
+                account_metas.push(AccountMeta::new_readonly(proof_node, false));
+            }
+
+            let account = NftVoteRecord {
+                proposal: proposal_cookie.address,
+                nft_mint: compressed_nft_cookie.asset_id,
+                governing_token_owner: voter_weight_record_cookie.account.governing_token_owner,
+                account_discriminator: NftVoteRecord::ACCOUNT_DISCRIMINATOR,
+                reserved: [0; 8],
+                payer: self.bench.payer.pubkey(),
+            };
+
+            nft_vote_record_cookies.push(NftVoteRecordCookie {
+                address: nft_vote_record_key,
+                account,
+            })
+        }
+
+        if let Some(ref prop_tx) = proposal_transaction_cookie {
+            account_metas.push(AccountMeta::new(prop_tx.address, false));
+        }
+
+        let cast_compressed_nft_vote_ix = Instruction {
+            program_id: gpl_nft_voter::id(),
+            accounts: account_metas,
+            data,
+        };
+
+        let mut instruction = vec![cast_compressed_nft_vote_ix];
+
+        if args.cast_spl_gov_vote {
+            let cast_vote_ix = cast_vote(
+                &self.governance.program_id,
+                &registrar_cookie.account.realm,
+                &proposal_cookie.account.governance,
+                &proposal_cookie.address,
+                &proposal_cookie.account.token_owner_record,
+                &voter_token_owner_record_cookie.address,
+                &voter_authority.pubkey(),
                 &proposal_cookie.account.governing_token_mint,
                 &self.bench.payer.pubkey(),
                 Some(voter_weight_record_cookie.address),
@@ -573,7 +3204,7 @@ impl NftVoterTest {
         }
 
         self.bench
-            .process_transaction(&instruction, Some(&[&nft_voter_cookie.signer]))
+            .process_transaction(&instruction, Some(&[voter_authority]))
             .await?;
 
         Ok(nft_vote_record_cookies)
@@ -591,6 +3222,44 @@ impl NftVoterTest {
             .await
     }
 
+    #[allow(dead_code)]
+    pub async fn get_vote_bitmap_account(
+        &mut self,
+        proposal: &Pubkey,
+        collection: &Pubkey,
+    ) -> VoteBitmap {
+        self.bench
+            .get_borsh_account::<VoteBitmap>(&get_vote_bitmap_address(proposal, collection))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_nft_holder_registration_account(
+        &mut self,
+        registrar: &Pubkey,
+        nft_mint: &Pubkey,
+    ) -> NftHolderRegistration {
+        self.bench
+            .get_borsh_account::<NftHolderRegistration>(&get_nft_holder_registration_address(
+                registrar, nft_mint,
+            ))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_voter_weight_cap_tally(
+        &mut self,
+        proposal: &Pubkey,
+        governing_token_owner: &Pubkey,
+    ) -> VoterWeightCapTally {
+        self.bench
+            .get_borsh_account::<VoterWeightCapTally>(&get_voter_weight_cap_tally_address(
+                proposal,
+                governing_token_owner,
+            ))
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn get_max_voter_weight_record(
         &self,
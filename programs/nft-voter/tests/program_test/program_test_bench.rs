@@ -8,7 +8,7 @@ use anchor_lang::{
 use solana_program::{borsh::try_from_slice_unchecked, system_program};
 use solana_program_test::{BanksClientError, ProgramTest, ProgramTestContext};
 use solana_sdk::{
-    account::{Account, ReadableAccount},
+    account::{Account, AccountSharedData, ReadableAccount},
     instruction::Instruction,
     program_pack::Pack,
     signature::Keypair,
@@ -89,6 +89,49 @@ impl ProgramTestBench {
             .await
     }
 
+    /// Simulates the transaction and returns the return data set by the given program
+    /// The data is decoded from the 'Program return' log of the program
+    #[allow(dead_code)]
+    pub async fn simulate_transaction_return_data(
+        &self,
+        instructions: &[Instruction],
+        signers: Option<&[&Keypair]>,
+        program_id: &Pubkey,
+    ) -> Result<Option<Vec<u8>>, BanksClientError> {
+        let mut context = self.context.borrow_mut();
+
+        let mut transaction =
+            Transaction::new_with_payer(&instructions, Some(&context.payer.pubkey()));
+
+        let mut all_signers = vec![&context.payer];
+
+        if let Some(signers) = signers {
+            all_signers.extend_from_slice(signers);
+        }
+
+        transaction.sign(&all_signers, context.last_blockhash);
+
+        let simulation = context
+            .banks_client
+            .simulate_transaction(transaction)
+            .await?;
+
+        if let Some(Err(err)) = simulation.result {
+            return Err(BanksClientError::TransactionError(err));
+        }
+
+        let return_log_prefix = format!("Program return: {} ", program_id);
+
+        Ok(simulation.simulation_details.and_then(|details| {
+            details
+                .logs
+                .iter()
+                .rev()
+                .find_map(|log| log.strip_prefix(&return_log_prefix))
+                .map(|data| base64::decode(data).unwrap())
+        }))
+    }
+
     pub async fn get_clock(&self) -> solana_program::clock::Clock {
         self.context
             .borrow_mut()
@@ -107,6 +150,23 @@ impl ProgramTestBench {
             .unwrap();
     }
 
+    #[allow(dead_code)]
+    pub async fn advance_clock_past_timestamp(&self, unix_timestamp: i64) {
+        let mut clock = self.get_clock().await;
+        let mut n = 1;
+
+        while clock.unix_timestamp <= unix_timestamp {
+            // Since the exact time is not deterministic keep warping by arbitrary 400 slots until we pass the requested timestamp
+            self.context
+                .borrow_mut()
+                .warp_to_slot(clock.slot + n * 400)
+                .unwrap();
+
+            n += 1;
+            clock = self.get_clock().await;
+        }
+    }
+
     pub async fn with_mint(&self) -> Result<MintCookie, BanksClientError> {
         let mint_keypair = Keypair::new();
         let mint_authority = Keypair::new();
@@ -251,6 +311,28 @@ impl ProgramTestBench {
         .await
     }
 
+    /// Transfers token account ownership to the new owner
+    #[allow(dead_code)]
+    pub async fn set_token_account_owner(
+        &self,
+        token_account: &Pubkey,
+        owner: &Keypair,
+        new_owner: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let set_authority_instruction = spl_token::instruction::set_authority(
+            &spl_token::id(),
+            token_account,
+            Some(new_owner),
+            spl_token::instruction::AuthorityType::AccountOwner,
+            &owner.pubkey(),
+            &[],
+        )
+        .unwrap();
+
+        self.process_transaction(&[set_authority_instruction], Some(&[owner]))
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn with_wallet(&self) -> WalletCookie {
         let account_rent = self.rent.minimum_balance(0);
@@ -293,6 +375,13 @@ impl ProgramTestBench {
             .unwrap()
     }
 
+    #[allow(dead_code)]
+    pub async fn set_account(&self, address: &Pubkey, account: &Account) {
+        self.context
+            .borrow_mut()
+            .set_account(address, &AccountSharedData::from(account.clone()));
+    }
+
     #[allow(dead_code)]
     pub async fn get_borsh_account<T: BorshDeserialize>(&self, address: &Pubkey) -> T {
         self.get_account(address)
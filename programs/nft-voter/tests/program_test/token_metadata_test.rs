@@ -1,8 +1,15 @@
 use std::{str::FromStr, sync::Arc};
 
 use anchor_lang::prelude::Pubkey;
-use mpl_token_metadata::state::{Collection, CollectionDetails};
+use anchor_lang::{AnchorDeserialize, AnchorSerialize};
+use gpl_nft_voter::tools::token_metadata::{
+    get_token_record_address, TokenDelegateRole, TokenRecord, TokenStandard, TokenState,
+    TOKEN_RECORD_KEY,
+};
+use mpl_token_metadata::state::{Collection, CollectionDetails, Creator};
 use solana_program_test::{BanksClientError, ProgramTest};
+use solana_sdk::account::Account;
+use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 
 use crate::program_test::program_test_bench::{MintCookie, ProgramTestBench, WalletCookie};
@@ -11,6 +18,23 @@ pub struct NftCookie {
     pub address: Pubkey,
     pub metadata: Pubkey,
     pub mint_cookie: MintCookie,
+
+    /// The collection the NFT was created with
+    pub collection: Pubkey,
+
+    /// TokenRecord of programmable NFTs
+    pub token_record: Option<Pubkey>,
+
+    /// Escrow accounts of NFTs held by an escrow program
+    pub escrow: Option<NftEscrowCookie>,
+
+    /// CollectionWeightOverrides of NFTs with weight override
+    pub collection_weight_overrides: Option<Pubkey>,
+}
+
+pub struct NftEscrowCookie {
+    pub escrow_program_config: Pubkey,
+    pub escrow_receipt: Pubkey,
 }
 
 pub struct NftCollectionCookie {
@@ -33,6 +57,20 @@ impl Default for CreateNftArgs {
     }
 }
 
+pub struct CreateProgrammableNftArgs {
+    pub token_state: TokenState,
+    pub delegate_role: Option<TokenDelegateRole>,
+}
+
+impl Default for CreateProgrammableNftArgs {
+    fn default() -> Self {
+        Self {
+            token_state: TokenState::Unlocked,
+            delegate_role: None,
+        }
+    }
+}
+
 pub struct TokenMetadataTest {
     pub bench: Arc<ProgramTestBench>,
     pub program_id: Pubkey,
@@ -300,6 +338,10 @@ impl TokenMetadataTest {
             address: nft_account_cookie.address,
             metadata: metadata_key,
             mint_cookie,
+            collection: nft_collection_cookie.mint,
+            token_record: None,
+            escrow: None,
+            collection_weight_overrides: None,
         })
     }
 
@@ -382,6 +424,104 @@ impl TokenMetadataTest {
             address: nft_account_cookie.address,
             metadata: metadata_key,
             mint_cookie,
+            collection: nft_collection_cookie.mint,
+            token_record: None,
+            escrow: None,
+            collection_weight_overrides: None,
         })
     }
+
+    /// Creates programmable NFT (pNFT)
+    /// The mpl-token-metadata program used in tests doesn't support pNFTs and hence the NFT is created as a regular NFT
+    /// and its Metadata token_standard and TokenRecord are written directly to the accounts
+    #[allow(dead_code)]
+    pub async fn with_programmable_nft(
+        &self,
+        nft_collection_cookie: &NftCollectionCookie,
+        nft_owner_cookie: &WalletCookie,
+        args: Option<CreateProgrammableNftArgs>,
+    ) -> Result<NftCookie, BanksClientError> {
+        let CreateProgrammableNftArgs {
+            token_state,
+            delegate_role,
+        } = args.unwrap_or_default();
+
+        let mut nft_cookie = self
+            .with_nft_v2(nft_collection_cookie, nft_owner_cookie, None)
+            .await?;
+
+        // Set Metadata token_standard to ProgrammableNonFungible
+        let mut metadata_account = self.bench.get_account(&nft_cookie.metadata).await.unwrap();
+
+        let token_standard_offset = {
+            let buf = &mut &metadata_account.data[1..];
+
+            Pubkey::deserialize(buf).unwrap(); // update_authority
+            Pubkey::deserialize(buf).unwrap(); // mint
+            String::deserialize(buf).unwrap(); // name
+            String::deserialize(buf).unwrap(); // symbol
+            String::deserialize(buf).unwrap(); // uri
+            u16::deserialize(buf).unwrap(); // seller_fee_basis_points
+            Option::<Vec<Creator>>::deserialize(buf).unwrap(); // creators
+            bool::deserialize(buf).unwrap(); // primary_sale_happened
+            bool::deserialize(buf).unwrap(); // is_mutable
+            Option::<u8>::deserialize(buf).unwrap(); // edition_nonce
+
+            metadata_account.data.len() - buf.len()
+        };
+
+        let token_standard = Some(TokenStandard::ProgrammableNonFungible)
+            .try_to_vec()
+            .unwrap();
+        let token_standard_len = Option::<TokenStandard>::deserialize(
+            &mut &metadata_account.data[token_standard_offset..],
+        )
+        .unwrap()
+        .try_to_vec()
+        .unwrap()
+        .len();
+
+        // The Metadata account is padded and hence the account size can be preserved
+        let data_len = metadata_account.data.len();
+        metadata_account.data.splice(
+            token_standard_offset..token_standard_offset + token_standard_len,
+            token_standard,
+        );
+        metadata_account.data.truncate(data_len);
+
+        self.bench
+            .set_account(&nft_cookie.metadata, &metadata_account)
+            .await;
+
+        // Create TokenRecord
+        let token_record_key =
+            get_token_record_address(&nft_cookie.mint_cookie.address, &nft_cookie.address);
+
+        let token_record = TokenRecord {
+            key: TOKEN_RECORD_KEY,
+            bump: 255,
+            state: token_state,
+            rule_set_revision: None,
+            delegate: delegate_role.map(|_| Keypair::new().pubkey()),
+            delegate_role,
+        };
+
+        let data = token_record.try_to_vec().unwrap();
+
+        let token_record_account = Account {
+            lamports: self.bench.rent.minimum_balance(data.len()),
+            data,
+            owner: self.program_id,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        self.bench
+            .set_account(&token_record_key, &token_record_account)
+            .await;
+
+        nft_cookie.token_record = Some(token_record_key);
+
+        Ok(nft_cookie)
+    }
 }
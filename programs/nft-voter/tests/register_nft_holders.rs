@@ -0,0 +1,261 @@
+use crate::program_test::nft_voter_test::ConfigureCollectionArgs;
+use gpl_nft_voter::error::NftVoterError;
+use gpl_nft_voter::state::*;
+use program_test::{nft_voter_test::NftVoterTest, tools::assert_nft_voter_err};
+
+use solana_program_test::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_register_nft_holders() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let holder_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &holder_cookie, None)
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &holder_cookie, None)
+        .await?;
+
+    let clock = nft_voter_test.bench.get_clock().await;
+
+    // Act
+    nft_voter_test
+        .register_nft_holders(
+            &registrar_cookie,
+            &holder_cookie,
+            &[&nft_cookie1, &nft_cookie2],
+        )
+        .await?;
+
+    // Assert
+    for nft_cookie in [&nft_cookie1, &nft_cookie2].iter() {
+        let nft_holder_registration = nft_voter_test
+            .get_nft_holder_registration_account(
+                &registrar_cookie.address,
+                &nft_cookie.mint_cookie.address,
+            )
+            .await;
+
+        assert_eq!(nft_holder_registration.registrar, registrar_cookie.address);
+        assert_eq!(
+            nft_holder_registration.nft_mint,
+            nft_cookie.mint_cookie.address
+        );
+        assert_eq!(nft_holder_registration.holder, holder_cookie.address);
+        assert_eq!(nft_holder_registration.registered_at_slot, clock.slot);
+        assert_eq!(nft_holder_registration.registered_at, clock.unix_timestamp);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_register_nft_holders_again_keeps_registration_time() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let holder_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &holder_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .register_nft_holders(&registrar_cookie, &holder_cookie, &[&nft_cookie1])
+        .await?;
+
+    let nft_holder_registration1 = nft_voter_test
+        .get_nft_holder_registration_account(
+            &registrar_cookie.address,
+            &nft_cookie1.mint_cookie.address,
+        )
+        .await;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    nft_voter_test
+        .register_nft_holders(&registrar_cookie, &holder_cookie, &[&nft_cookie1])
+        .await?;
+
+    // Assert
+    let nft_holder_registration2 = nft_voter_test
+        .get_nft_holder_registration_account(
+            &registrar_cookie.address,
+            &nft_cookie1.mint_cookie.address,
+        )
+        .await;
+
+    assert_eq!(nft_holder_registration1, nft_holder_registration2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_register_nft_holders_with_invalid_holder_error() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let holder_cookie = nft_voter_test.bench.with_wallet().await;
+    let other_holder_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &holder_cookie, None)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .register_nft_holders(&registrar_cookie, &other_holder_cookie, &[&nft_cookie1])
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::VoterDoesNotOwnNft);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_register_compressed_nft_holders() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let holder_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let mut merkle_tree_cookie = nft_voter_test.compression.with_merkle_tree(3).await;
+
+    let compressed_nft_cookie1 = nft_voter_test
+        .compression
+        .with_compressed_nft(
+            &mut merkle_tree_cookie,
+            &nft_collection_cookie,
+            &holder_cookie,
+            None,
+        )
+        .await;
+
+    // Act
+    nft_voter_test
+        .register_compressed_nft_holders(
+            &registrar_cookie,
+            &holder_cookie,
+            &merkle_tree_cookie,
+            &[&compressed_nft_cookie1],
+        )
+        .await?;
+
+    // Assert
+    let nft_holder_registration = nft_voter_test
+        .get_nft_holder_registration_account(
+            &registrar_cookie.address,
+            &compressed_nft_cookie1.asset_id,
+        )
+        .await;
+
+    assert_eq!(
+        nft_holder_registration.nft_mint,
+        compressed_nft_cookie1.asset_id
+    );
+    assert_eq!(nft_holder_registration.holder, holder_cookie.address);
+
+    Ok(())
+}
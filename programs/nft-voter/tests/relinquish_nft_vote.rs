@@ -1,5 +1,6 @@
 use crate::program_test::nft_voter_test::ConfigureCollectionArgs;
 use gpl_nft_voter::error::NftVoterError;
+use gpl_nft_voter::state::{get_voter_weight_cap_tally_address, VoterWeightCaps};
 use program_test::nft_voter_test::{CastNftVoteArgs, NftVoterTest};
 use program_test::tools::assert_nft_voter_err;
 use solana_program_test::*;
@@ -173,6 +174,7 @@ async fn test_relinquish_nft_vote_for_proposal_in_voting_state_with_phase_vote()
                     rank: 0,
                     weight_percentage: 100,
                 }]),
+                ..Default::default()
             }),
         )
         .await?;
@@ -288,6 +290,7 @@ async fn test_relinquish_nft_vote_for_proposal_in_voting_state() -> Result<(), B
                     rank: 0,
                     weight_percentage: 100,
                 }]),
+                ..Default::default()
             }),
         )
         .await?;
@@ -564,6 +567,7 @@ async fn test_relinquish_nft_vote_with_unexpired_vote_weight_record() -> Result<
             rank: 0,
             weight_percentage: 100,
         }]),
+        ..Default::default()
     };
 
     // Cast vote with NFT
@@ -602,3 +606,152 @@ async fn test_relinquish_nft_vote_with_unexpired_vote_weight_record() -> Result<
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_relinquish_nft_vote_with_voter_weight_caps_using_multiple_instructions(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    nft_voter_test
+        .configure_voter_weight_caps(
+            &mut registrar_cookie,
+            VoterWeightCaps {
+                max_voter_weight: Some(15),
+                max_collection_weight_share_bps: None,
+            },
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let args = CastNftVoteArgs {
+        cast_spl_gov_vote: false,
+        vote_type: Vote::Approve(vec![VoteChoice {
+            rank: 0,
+            weight_percentage: 100,
+        }]),
+        ..Default::default()
+    };
+
+    let mut nft_vote_record_cookies1 = nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1, &nft_cookie2],
+            None,
+            Some(args),
+        )
+        .await?;
+
+    let nft_vote_record_cookies2 = nft_vote_record_cookies1.split_off(1);
+
+    nft_voter_test.bench.advance_clock().await;
+
+    let voter_weight_cap_tally_address =
+        get_voter_weight_cap_tally_address(&proposal_cookie.address, &voter_cookie.address);
+
+    // Act
+    nft_voter_test
+        .relinquish_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &nft_vote_record_cookies1,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_cap_tally = nft_voter_test
+        .get_voter_weight_cap_tally(&proposal_cookie.address, &voter_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_cap_tally.nft_vote_record_count, 1);
+    assert_eq!(voter_weight_cap_tally.collection_weights[0].weight, 20);
+
+    // Act
+    nft_voter_test
+        .relinquish_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &nft_vote_record_cookies2,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_cap_tally_account = nft_voter_test
+        .bench
+        .get_account(&voter_weight_cap_tally_address)
+        .await;
+
+    assert_eq!(None, voter_weight_cap_tally_account);
+
+    let nft_vote_record = nft_voter_test
+        .bench
+        .get_account(&nft_vote_record_cookies2[0].address)
+        .await;
+
+    assert_eq!(None, nft_vote_record);
+
+    Ok(())
+}
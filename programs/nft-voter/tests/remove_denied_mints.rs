@@ -0,0 +1,109 @@
+use gpl_nft_voter::error::NftVoterError;
+use program_test::{nft_voter_test::NftVoterTest, tools::assert_nft_voter_err};
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_remove_denied_mints() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mints: Vec<_> = (0..5).map(|_| Keypair::new().pubkey()).collect();
+
+    let mut mint_denylist_cookie = nft_voter_test
+        .with_mint_denylist(&registrar_cookie, mints.clone())
+        .await?;
+
+    // Act
+    nft_voter_test
+        .remove_denied_mints(
+            &registrar_cookie,
+            &mut mint_denylist_cookie,
+            vec![mints[1], mints[3]],
+        )
+        .await?;
+
+    // Assert
+    let mint_denylist = nft_voter_test
+        .get_mint_denylist_account(&mint_denylist_cookie.address)
+        .await;
+
+    assert_eq!(mint_denylist_cookie.account, mint_denylist);
+    assert_eq!(mint_denylist.mints.len(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remove_denied_mints_with_not_denied_mint_error() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut mint_denylist_cookie = nft_voter_test
+        .with_mint_denylist(&registrar_cookie, vec![Keypair::new().pubkey()])
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .remove_denied_mints(
+            &registrar_cookie,
+            &mut mint_denylist_cookie,
+            vec![Keypair::new().pubkey()],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidDeniedMint);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remove_denied_mints_with_invalid_realm_authority_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mint = Keypair::new().pubkey();
+
+    let mut mint_denylist_cookie = nft_voter_test
+        .with_mint_denylist(&registrar_cookie, vec![mint])
+        .await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = nft_voter_test
+        .remove_denied_mints_using_ix(
+            &registrar_cookie,
+            &mut mint_denylist_cookie,
+            vec![mint],
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use gpl_nft_voter::error::NftVoterError;
+use program_test::{nft_voter_test::NftVoterTest, tools::assert_nft_voter_err};
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_remove_escrow_program() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let escrow_program_config_cookie = nft_voter_test
+        .with_escrow_program(&registrar_cookie)
+        .await?;
+
+    // Act
+    nft_voter_test
+        .remove_escrow_program(&registrar_cookie, &escrow_program_config_cookie)
+        .await?;
+
+    // Assert
+    let escrow_program_config_account = nft_voter_test
+        .bench
+        .get_account(&escrow_program_config_cookie.address)
+        .await;
+
+    assert_eq!(None, escrow_program_config_account);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remove_escrow_program_with_invalid_realm_authority_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let escrow_program_config_cookie = nft_voter_test
+        .with_escrow_program(&registrar_cookie)
+        .await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = nft_voter_test
+        .remove_escrow_program_using_ix(
+            &registrar_cookie,
+            &escrow_program_config_cookie,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remove_escrow_program_with_invalid_registrar_error() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let realm_cookie2 = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie2 = nft_voter_test.with_registrar(&realm_cookie2).await?;
+
+    let escrow_program_config_cookie = nft_voter_test
+        .with_escrow_program(&registrar_cookie2)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .remove_escrow_program(&registrar_cookie, &escrow_program_config_cookie)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidEscrowProgramConfig);
+
+    Ok(())
+}
@@ -0,0 +1,233 @@
+use gpl_nft_voter::error::NftVoterError;
+use gpl_nft_voter::state::Registrar;
+use program_test::{
+    nft_voter_test::NftVoterTest,
+    tools::{assert_anchor_err, assert_nft_voter_err},
+};
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_resize_registrar() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    for _ in 0..registrar_cookie.max_collections {
+        let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+        nft_voter_test
+            .with_collection(
+                &registrar_cookie,
+                &nft_collection_cookie,
+                &max_voter_weight_record_cookie,
+                None,
+            )
+            .await?;
+    }
+
+    let max_collections = registrar_cookie.max_collections + 2;
+
+    // Act
+    nft_voter_test
+        .resize_registrar(&mut registrar_cookie, max_collections)
+        .await?;
+
+    for _ in 0..2 {
+        let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+        nft_voter_test
+            .with_collection(
+                &registrar_cookie,
+                &nft_collection_cookie,
+                &max_voter_weight_record_cookie,
+                None,
+            )
+            .await?;
+    }
+
+    // Assert
+    let registrar_account = nft_voter_test
+        .bench
+        .get_account(&registrar_cookie.address)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        registrar_account.data.len(),
+        Registrar::get_space(max_collections)
+    );
+
+    let registrar = nft_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.collection_configs.len() as u8, max_collections);
+
+    let max_voter_weight_record = nft_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 36);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resize_registrar_to_smaller_size() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    // Act
+    nft_voter_test
+        .resize_registrar(&mut registrar_cookie, 1)
+        .await?;
+
+    // Assert
+    let registrar_account = nft_voter_test
+        .bench
+        .get_account(&registrar_cookie.address)
+        .await
+        .unwrap();
+
+    assert_eq!(registrar_account.data.len(), Registrar::get_space(1));
+
+    let registrar = nft_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.collection_configs.len(), 1);
+    assert_eq!(
+        registrar.collection_configs[0].collection,
+        nft_collection_cookie.mint
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resize_registrar_below_configured_collections_error() -> Result<(), BanksClientError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    for _ in 0..2 {
+        let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+        nft_voter_test
+            .with_collection(
+                &registrar_cookie,
+                &nft_collection_cookie,
+                &max_voter_weight_record_cookie,
+                None,
+            )
+            .await?;
+    }
+
+    // Act
+    let err = nft_voter_test
+        .resize_registrar(&mut registrar_cookie, 1)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidMaxCollections);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resize_registrar_with_realm_authority_must_sign_error() -> Result<(), BanksClientError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    let err = nft_voter_test
+        .resize_registrar_using_ix(
+            &mut registrar_cookie,
+            20,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resize_registrar_with_invalid_realm_authority_error() -> Result<(), BanksClientError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let mut registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let realm_authority = Keypair::new();
+
+    // Act
+    let err = nft_voter_test
+        .resize_registrar_using_ix(
+            &mut registrar_cookie,
+            20,
+            |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
+            Some(&[&realm_authority]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidRealmAuthority);
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use program_test::nft_voter_test::NftVoterTest;
+
+use solana_program_test::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_revoke_nft_vote_delegation() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let owner_cookie = nft_voter_test.bench.with_wallet().await;
+    let delegate_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let nft_vote_delegation_cookie = nft_voter_test
+        .with_nft_vote_delegation(
+            &registrar_cookie,
+            &owner_cookie,
+            &delegate_cookie.address,
+            None,
+            None,
+        )
+        .await?;
+
+    // Act
+    nft_voter_test
+        .revoke_nft_vote_delegation(
+            &registrar_cookie,
+            &nft_vote_delegation_cookie,
+            &owner_cookie,
+        )
+        .await?;
+
+    // Assert
+    let nft_vote_delegation_account = nft_voter_test
+        .bench
+        .get_account(&nft_vote_delegation_cookie.address)
+        .await;
+
+    assert!(nft_vote_delegation_account.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_revoke_nft_vote_delegation_and_create_new_delegation() -> Result<(), BanksClientError>
+{
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let owner_cookie = nft_voter_test.bench.with_wallet().await;
+    let delegate_cookie = nft_voter_test.bench.with_wallet().await;
+    let delegate_cookie2 = nft_voter_test.bench.with_wallet().await;
+
+    let nft_vote_delegation_cookie = nft_voter_test
+        .with_nft_vote_delegation(
+            &registrar_cookie,
+            &owner_cookie,
+            &delegate_cookie.address,
+            None,
+            None,
+        )
+        .await?;
+
+    nft_voter_test
+        .revoke_nft_vote_delegation(
+            &registrar_cookie,
+            &nft_vote_delegation_cookie,
+            &owner_cookie,
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let nft_vote_delegation_cookie2 = nft_voter_test
+        .with_nft_vote_delegation(
+            &registrar_cookie,
+            &owner_cookie,
+            &delegate_cookie2.address,
+            None,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let nft_vote_delegation = nft_voter_test
+        .get_nft_vote_delegation_account(&nft_vote_delegation_cookie2.address)
+        .await;
+
+    assert_eq!(nft_vote_delegation.delegate, delegate_cookie2.address);
+
+    Ok(())
+}
@@ -0,0 +1,767 @@
+use crate::program_test::nft_voter_test::ConfigureCollectionArgs;
+use gpl_nft_voter::error::NftVoterError;
+use gpl_nft_voter::state::*;
+use program_test::nft_voter_test::NftVoterTest;
+use program_test::tools::*;
+use solana_program_test::*;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_update_voter_weight_tally() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_tally_cookie = nft_voter_test
+        .with_voter_weight_tally(&registrar_cookie, &voter_weight_record_cookie, 10)
+        .await?;
+
+    let nft1_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft2_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft3_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .update_voter_weight_tally(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_weight_tally_cookie,
+            &voter_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft1_cookie, &nft2_cookie],
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+    let clock = nft_voter_test.bench.get_clock().await;
+
+    // Act
+    nft_voter_test
+        .update_voter_weight_tally(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_weight_tally_cookie,
+            &voter_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft3_cookie],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 30);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+    assert_eq!(
+        voter_weight_record.weight_action,
+        Some(VoterWeightAction::CreateProposal.into())
+    );
+    assert_eq!(voter_weight_record.weight_action_target, None);
+
+    let voter_weight_tally = nft_voter_test
+        .get_voter_weight_tally(&voter_weight_tally_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_tally.voter_weight, 30);
+    assert_eq!(
+        voter_weight_tally.nft_mints,
+        vec![
+            nft1_cookie.mint_cookie.address,
+            nft2_cookie.mint_cookie.address,
+            nft3_cookie.mint_cookie.address
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_tally_with_different_action() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_tally_cookie = nft_voter_test
+        .with_voter_weight_tally(&registrar_cookie, &voter_weight_record_cookie, 10)
+        .await?;
+
+    let nft1_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft2_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .update_voter_weight_tally(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_weight_tally_cookie,
+            &voter_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft1_cookie, &nft2_cookie],
+        )
+        .await?;
+
+    // Act
+    nft_voter_test
+        .update_voter_weight_tally(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_weight_tally_cookie,
+            &voter_cookie,
+            VoterWeightAction::CreateGovernance,
+            &[&nft1_cookie],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    // The tally is reset for the new action
+    assert_eq!(voter_weight_record.voter_weight, 10);
+    assert_eq!(
+        voter_weight_record.weight_action,
+        Some(VoterWeightAction::CreateGovernance.into())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_tally_with_already_tallied_nft_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_tally_cookie = nft_voter_test
+        .with_voter_weight_tally(&registrar_cookie, &voter_weight_record_cookie, 10)
+        .await?;
+
+    let nft1_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft2_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .update_voter_weight_tally(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_weight_tally_cookie,
+            &voter_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft1_cookie, &nft2_cookie],
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .update_voter_weight_tally(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_weight_tally_cookie,
+            &voter_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft2_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::DuplicatedNftDetected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_tally_with_tally_is_full_error() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_tally_cookie = nft_voter_test
+        .with_voter_weight_tally(&registrar_cookie, &voter_weight_record_cookie, 2)
+        .await?;
+
+    let nft1_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft2_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft3_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .update_voter_weight_tally(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_weight_tally_cookie,
+            &voter_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft1_cookie, &nft2_cookie],
+        )
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .update_voter_weight_tally(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_weight_tally_cookie,
+            &voter_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft3_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::VoterWeightTallyIsFull);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_tally_with_cast_vote_not_allowed_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_tally_cookie = nft_voter_test
+        .with_voter_weight_tally(&registrar_cookie, &voter_weight_record_cookie, 10)
+        .await?;
+
+    let nft1_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .update_voter_weight_tally(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_weight_tally_cookie,
+            &voter_cookie,
+            VoterWeightAction::CastVote,
+            &[&nft1_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::CastVoteIsNotAllowed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_tally_with_nft_deposit_record() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_tally_cookie = nft_voter_test
+        .with_voter_weight_tally(&registrar_cookie, &voter_weight_record_cookie, 10)
+        .await?;
+
+    let nft1_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft2_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_deposit_record_cookie = nft_voter_test
+        .with_nft_deposit_record(&registrar_cookie, &voter_cookie, 5)
+        .await?;
+
+    nft_voter_test
+        .deposit_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_cookie,
+            &nft1_cookie,
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    nft_voter_test
+        .update_voter_weight_tally(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_weight_tally_cookie,
+            &voter_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft2_cookie],
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 20);
+
+    // The locked NFTs are evaluated on every update and aren't stored in the tally
+    let voter_weight_tally = nft_voter_test
+        .get_voter_weight_tally(&voter_weight_tally_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_tally.voter_weight, 10);
+    assert_eq!(
+        voter_weight_tally.nft_mints,
+        vec![nft2_cookie.mint_cookie.address]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_tally_with_invalid_voter_authority_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_tally_cookie = nft_voter_test
+        .with_voter_weight_tally(&registrar_cookie, &voter_weight_record_cookie, 10)
+        .await?;
+
+    let nft1_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft2_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let other_voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    // Act
+    let err = nft_voter_test
+        .update_voter_weight_tally_using_ix(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_weight_tally_cookie,
+            &other_voter_cookie.signer,
+            VoterWeightAction::CreateProposal,
+            &[&nft1_cookie, &nft2_cookie],
+            NopOverride,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::InvalidVoterAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_tally_with_nft_tallied_by_other_voter_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_tally_cookie = nft_voter_test
+        .with_voter_weight_tally(&registrar_cookie, &voter_weight_record_cookie, 10)
+        .await?;
+
+    let nft1_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft2_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .update_voter_weight_tally(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_weight_tally_cookie,
+            &voter_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft1_cookie, &nft2_cookie],
+        )
+        .await?;
+
+    let new_owner_cookie = nft_voter_test.bench.with_wallet().await;
+
+    nft_voter_test
+        .bench
+        .set_token_account_owner(
+            &nft1_cookie.address,
+            &voter_cookie.signer,
+            &new_owner_cookie.address,
+        )
+        .await?;
+
+    let new_owner_voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &new_owner_cookie)
+        .await?;
+
+    let new_owner_voter_weight_tally_cookie = nft_voter_test
+        .with_voter_weight_tally(&registrar_cookie, &new_owner_voter_weight_record_cookie, 10)
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .update_voter_weight_tally(
+            &registrar_cookie,
+            &new_owner_voter_weight_record_cookie,
+            &new_owner_voter_weight_tally_cookie,
+            &new_owner_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft1_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftAlreadyTallied);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_voter_weight_tally() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_tally_cookie = nft_voter_test
+        .with_voter_weight_tally(&registrar_cookie, &voter_weight_record_cookie, 10)
+        .await?;
+
+    let nft1_cookie = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .update_voter_weight_tally(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_weight_tally_cookie,
+            &voter_cookie,
+            VoterWeightAction::CreateProposal,
+            &[&nft1_cookie],
+        )
+        .await?;
+
+    // Act
+    nft_voter_test
+        .close_voter_weight_tally(
+            &voter_weight_record_cookie,
+            &voter_weight_tally_cookie,
+            &voter_cookie,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_tally_account = nft_voter_test
+        .bench
+        .get_account(&voter_weight_tally_cookie.address)
+        .await;
+
+    assert_eq!(None, voter_weight_tally_account);
+
+    Ok(())
+}
@@ -0,0 +1,592 @@
+use anchor_spl::token::TokenAccount;
+use gpl_nft_voter::error::NftVoterError;
+use gpl_nft_voter::state::*;
+use program_test::nft_voter_test::{
+    CastNftVoteArgs, ConfigureCollectionArgs, NftVoteRecordCookie, NftVoterTest,
+};
+use program_test::tools::assert_nft_voter_err;
+use solana_program_test::*;
+use spl_governance::state::vote_record::{Vote, VoteChoice};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_withdraw_nft() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_deposit_record_cookie = nft_voter_test
+        .with_nft_deposit_record(&registrar_cookie, &voter_cookie, 5)
+        .await?;
+
+    nft_voter_test
+        .deposit_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_cookie,
+            &nft_cookie1,
+        )
+        .await?;
+
+    // Act
+    nft_voter_test
+        .withdraw_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_weight_record_cookie,
+            &voter_token_owner_record_cookie,
+            &voter_cookie,
+            &nft_cookie1,
+        )
+        .await?;
+
+    // Assert
+    let nft_deposit_record = nft_voter_test
+        .get_nft_deposit_record_account(&nft_deposit_record_cookie.address)
+        .await;
+
+    assert_eq!(nft_deposit_record, nft_deposit_record_cookie.account);
+
+    let nft_token_account = nft_voter_test
+        .bench
+        .get_anchor_account::<TokenAccount>(nft_cookie1.address)
+        .await;
+
+    assert_eq!(nft_token_account.amount, 1);
+
+    let nft_vault_account = nft_voter_test
+        .bench
+        .get_account(&get_nft_vault_address(
+            &registrar_cookie.address,
+            &nft_cookie1.mint_cookie.address,
+        ))
+        .await;
+
+    assert_eq!(nft_vault_account, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_withdraw_nft_with_active_votes_error() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_deposit_record_cookie = nft_voter_test
+        .with_nft_deposit_record(&registrar_cookie, &voter_cookie, 5)
+        .await?;
+
+    nft_voter_test
+        .deposit_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_cookie,
+            &nft_cookie1,
+        )
+        .await?;
+
+    let clock = nft_voter_test.bench.get_clock().await;
+    nft_voter_test
+        .bench
+        .advance_clock_past_timestamp(clock.unix_timestamp)
+        .await;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_add_tx(&proposal_cookie)
+        .await?;
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[],
+            None,
+            None,
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .withdraw_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_weight_record_cookie,
+            &voter_token_owner_record_cookie,
+            &voter_cookie,
+            &nft_cookie1,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::CannotWithdrawNftWithActiveVotes);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_withdraw_nft_with_nft_not_deposited_error() -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+    let voter_cookie2 = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie2, None)
+        .await?;
+
+    let nft_deposit_record_cookie = nft_voter_test
+        .with_nft_deposit_record(&registrar_cookie, &voter_cookie, 5)
+        .await?;
+
+    let nft_deposit_record_cookie2 = nft_voter_test
+        .with_nft_deposit_record(&registrar_cookie, &voter_cookie2, 5)
+        .await?;
+
+    // The NFT is deposited by another voter
+    nft_voter_test
+        .deposit_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie2,
+            &voter_cookie2,
+            &nft_cookie1,
+        )
+        .await?;
+
+    // Act
+    let err = nft_voter_test
+        .withdraw_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_weight_record_cookie,
+            &voter_token_owner_record_cookie,
+            &voter_cookie,
+            &nft_cookie1,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::NftNotDeposited);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_withdraw_nft_with_unrelinquished_nft_deposit_vote_record_error(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_deposit_record_cookie = nft_voter_test
+        .with_nft_deposit_record(&registrar_cookie, &voter_cookie, 5)
+        .await?;
+
+    nft_voter_test
+        .deposit_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_cookie,
+            &nft_cookie1,
+        )
+        .await?;
+
+    let clock = nft_voter_test.bench.get_clock().await;
+    nft_voter_test
+        .bench
+        .advance_clock_past_timestamp(clock.unix_timestamp)
+        .await;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    // Count the locked NFT for the Proposal without casting the vote in spl-gov
+    nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[],
+            None,
+            Some(CastNftVoteArgs {
+                cast_spl_gov_vote: false,
+                vote_type: Vote::Approve(vec![VoteChoice {
+                    rank: 0,
+                    weight_percentage: 100,
+                }]),
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = nft_voter_test
+        .withdraw_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_weight_record_cookie,
+            &voter_token_owner_record_cookie,
+            &voter_cookie,
+            &nft_cookie1,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_nft_voter_err(err, NftVoterError::CannotWithdrawNftWithActiveVotes);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_withdraw_nft_with_relinquished_nft_deposit_vote_record_and_vote_again(
+) -> Result<(), BanksClientError> {
+    // Arrange
+    let mut nft_voter_test = NftVoterTest::start_new().await;
+
+    let realm_cookie = nft_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = nft_voter_test.with_registrar(&realm_cookie).await?;
+
+    let nft_collection_cookie = nft_voter_test.token_metadata.with_nft_collection().await?;
+
+    let max_voter_weight_record_cookie = nft_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    nft_voter_test
+        .with_collection(
+            &registrar_cookie,
+            &nft_collection_cookie,
+            &max_voter_weight_record_cookie,
+            Some(ConfigureCollectionArgs {
+                weight: 10,
+                size: 20,
+            }),
+        )
+        .await?;
+
+    let voter_cookie = nft_voter_test.bench.with_wallet().await;
+
+    let voter_token_owner_record_cookie = nft_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    let voter_weight_record_cookie = nft_voter_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let nft_cookie1 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_cookie2 = nft_voter_test
+        .token_metadata
+        .with_nft_v2(&nft_collection_cookie, &voter_cookie, None)
+        .await?;
+
+    let nft_deposit_record_cookie = nft_voter_test
+        .with_nft_deposit_record(&registrar_cookie, &voter_cookie, 5)
+        .await?;
+
+    nft_voter_test
+        .deposit_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_cookie,
+            &nft_cookie1,
+        )
+        .await?;
+
+    nft_voter_test
+        .deposit_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_cookie,
+            &nft_cookie2,
+        )
+        .await?;
+
+    let clock = nft_voter_test.bench.get_clock().await;
+    nft_voter_test
+        .bench
+        .advance_clock_past_timestamp(clock.unix_timestamp)
+        .await;
+
+    let proposal_cookie = nft_voter_test
+        .governance
+        .with_proposal(&realm_cookie, None)
+        .await?;
+
+    nft_voter_test
+        .governance
+        .with_sign_off_proposal(&proposal_cookie, &realm_cookie)
+        .await?;
+
+    let args = CastNftVoteArgs {
+        cast_spl_gov_vote: false,
+        vote_type: Vote::Approve(vec![VoteChoice {
+            rank: 0,
+            weight_percentage: 100,
+        }]),
+        ..Default::default()
+    };
+
+    // Count the locked NFTs for the Proposal without casting the vote in spl-gov
+    nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[],
+            None,
+            Some(args),
+        )
+        .await?;
+
+    nft_voter_test.bench.advance_clock().await;
+
+    let nft_deposit_vote_record_address =
+        get_nft_vote_record_address(&proposal_cookie.address, &nft_deposit_record_cookie.address);
+
+    let nft_deposit_vote_record_cookie = NftVoteRecordCookie {
+        address: nft_deposit_vote_record_address,
+        account: nft_voter_test
+            .get_nf_vote_record_account(&nft_deposit_vote_record_address)
+            .await,
+    };
+
+    nft_voter_test
+        .relinquish_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &vec![nft_deposit_vote_record_cookie],
+        )
+        .await?;
+
+    // Act
+    nft_voter_test
+        .withdraw_nft(
+            &registrar_cookie,
+            &nft_deposit_record_cookie,
+            &voter_weight_record_cookie,
+            &voter_token_owner_record_cookie,
+            &voter_cookie,
+            &nft_cookie1,
+        )
+        .await?;
+
+    // The withdrawn NFT votes directly and the NFT which is still locked is counted again
+    nft_voter_test
+        .cast_nft_vote(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &max_voter_weight_record_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &voter_token_owner_record_cookie,
+            &[&nft_cookie1],
+            None,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = nft_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 20);
+
+    let nft_deposit_record = nft_voter_test
+        .get_nft_deposit_record_account(&nft_deposit_record_cookie.address)
+        .await;
+
+    assert_eq!(nft_deposit_record.locked_nfts.len(), 1);
+    assert_eq!(nft_deposit_record.nft_vote_record_count, 1);
+
+    Ok(())
+}
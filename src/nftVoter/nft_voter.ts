@@ -73,6 +73,48 @@ export type NftVoter = {
         }
       ]
     },
+    {
+      "name": "resizeRegistrar",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar to resize"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxCollections",
+          "type": "u8"
+        }
+      ]
+    },
     {
       "name": "createVoterWeightRecord",
       "accounts": [
@@ -177,6 +219,24 @@ export type NftVoter = {
           "name": "voterWeightRecord",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "MintDenylist of the Registrar",
+            "The account doesn't have to exist and no NFT mints are denied in such case"
+          ]
+        },
+        {
+          "name": "nftDepositRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "NftDepositRecord of the voter",
+            "The account doesn't have to exist and only the NFTs provided in remaining accounts are used in such case"
+          ]
         }
       ],
       "args": [
@@ -185,11 +245,15 @@ export type NftVoter = {
           "type": {
             "defined": "VoterWeightAction"
           }
+        },
+        {
+          "name": "nftAccountCounts",
+          "type": "bytes"
         }
       ]
     },
     {
-      "name": "relinquishNftVote",
+      "name": "getVoterWeight",
       "accounts": [
         {
           "name": "registrar",
@@ -200,113 +264,186 @@ export type NftVoter = {
           ]
         },
         {
-          "name": "voterWeightRecord",
-          "isMut": true,
+          "name": "governingTokenOwner",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "governance",
+          "name": "mintDenylist",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "Governance account the Proposal is for"
+            "MintDenylist of the Registrar",
+            "The account doesn't have to exist and no NFT mints are denied in such case"
           ]
         },
         {
-          "name": "proposal",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "governingTokenOwner",
+          "name": "nftDepositRecord",
           "isMut": false,
-          "isSigner": true,
+          "isSigner": false,
           "docs": [
-            "The token owner who cast the original vote"
+            "NftDepositRecord of the voter",
+            "The account doesn't have to exist and only the NFTs provided in remaining accounts are quoted in such case"
           ]
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "voteRecord",
+          "name": "nftAccountCounts",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "createVoterWeightTally",
+      "accounts": [
+        {
+          "name": "registrar",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The account is used to validate that it doesn't exist and if it doesn't then Anchor owner check throws error",
-            "The check is disabled here and performed inside the instruction",
-            "#[account(owner = registrar.governance_program_id)]"
+            "The NFT voting Registrar"
           ]
         },
         {
-          "name": "beneficiary",
+          "name": "voterWeightRecord",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "voterWeightTally",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "maxNfts",
+          "type": "u16"
+        }
+      ]
     },
     {
-      "name": "configureCollection",
+      "name": "updateVoterWeightTally",
       "accounts": [
         {
           "name": "registrar",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false,
           "docs": [
-            "Registrar for which we configure this Collection"
+            "The NFT voting Registrar"
           ]
         },
         {
-          "name": "realm",
-          "isMut": false,
+          "name": "voterWeightRecord",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "realmAuthority",
+          "name": "voterWeightTally",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token owner the tally is updated for"
+          ]
+        },
+        {
+          "name": "voterAuthority",
           "isMut": false,
           "isSigner": true,
           "docs": [
-            "Authority of the Realm must sign and match Realm.authority"
+            "The authority who updates the tally: governing_token_owner, the delegate from the owner's NftVoteDelegation",
+            "or the governance_delegate from the owner's spl-gov TokenOwnerRecord"
           ]
         },
         {
-          "name": "collection",
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The account which pays for NftTallyRecords"
+          ]
+        },
+        {
+          "name": "mintDenylist",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "MintDenylist of the Registrar",
+            "The account doesn't have to exist and no NFT mints are denied in such case"
+          ]
         },
         {
-          "name": "metadata",
+          "name": "nftDepositRecord",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "NftDepositRecord of the voter",
+            "The account doesn't have to exist and only the NFTs provided in remaining accounts are used in such case"
+          ]
         },
         {
-          "name": "maxVoterWeightRecord",
-          "isMut": true,
+          "name": "nftVoteDelegation",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "NftVoteDelegation of the governing_token_owner",
+            "The account doesn't have to exist and it's only used when voter_authority is not the owner"
+          ]
+        },
+        {
+          "name": "tokenOwnerRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "spl-gov TokenOwnerRecord of the governing_token_owner",
+            "It's only used when voter_authority is not the owner to check TokenOwnerRecord.governance_delegate"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "weight",
-          "type": "u64"
+          "name": "voterWeightAction",
+          "type": {
+            "defined": "VoterWeightAction"
+          }
         },
         {
-          "name": "size",
-          "type": "u64"
+          "name": "nftAccountCounts",
+          "type": "bytes"
         }
       ]
     },
     {
-      "name": "castNftVote",
+      "name": "closeVoterWeightTally",
       "accounts": [
         {
-          "name": "registrar",
+          "name": "voterWeightRecord",
           "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "The NFT voting registrar"
-          ]
+          "isSigner": false
         },
         {
-          "name": "voterWeightRecord",
+          "name": "voterWeightTally",
           "isMut": true,
           "isSigner": false
         },
@@ -315,75 +452,6935 @@ export type NftVoter = {
           "isMut": false,
           "isSigner": true,
           "docs": [
-            "The token owner who casts the vote"
+            "The owner of the VoterWeightRecord"
           ]
         },
         {
-          "name": "payer",
+          "name": "beneficiary",
           "isMut": true,
-          "isSigner": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "relinquishNftVote",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
           "docs": [
-            "The account which pays for the transaction"
+            "The NFT voting Registrar"
           ]
         },
         {
-          "name": "proposal",
+          "name": "voterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governance",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "CHECK"
+            "Governance account the Proposal is for"
           ]
         },
         {
-          "name": "instructionSysvarAccount",
+          "name": "proposal",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "governanceProgram",
+          "name": "governingTokenOwner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true,
+          "docs": [
+            "The token owner who cast the original vote"
+          ]
         },
         {
-          "name": "systemProgram",
+          "name": "voteRecord",
           "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The account is used to validate that it doesn't exist and if it doesn't then Anchor owner check throws error",
+            "The check is disabled here and performed inside the instruction",
+            "#[account(owner = registrar.governance_program_id)]"
+          ]
+        },
+        {
+          "name": "beneficiary",
+          "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "voterWeightCapTally",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "VoterWeightCapTally of the voter for the Proposal",
+            "The account only exists if the Registrar had voter_weight caps when the vote was cast"
+          ]
+        },
+        {
+          "name": "nftDepositRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NftDepositRecord of the voter",
+            "The account doesn't have to exist and it's only updated when the NftVoteRecord of the deposit record is relinquished"
+          ]
         }
       ],
       "args": []
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "nftVoteRecord",
-      "docs": [
-        "NftVoteRecord exported to IDL without account_discriminator",
-        "TODO: Once we can support these accounts in Anchor via remaining_accounts then it should be possible to remove it"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "proposal",
-            "docs": [
-              "Proposal which was voted on"
-            ],
-            "type": "publicKey"
-          },
+      "name": "revokeNftVote",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "voterWeightRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "VoterWeightRecord of the previous owner who cast the vote with the NFTs"
+          ]
+        },
+        {
+          "name": "governance",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Governance account the Proposal is for"
+          ]
+        },
+        {
+          "name": "proposal",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The current owner of the NFTs"
+          ]
+        },
+        {
+          "name": "voteRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "VoteRecord of the previous owner",
+            "The account can be already disposed if the previous owner withdrew the vote and hence the owner is checked in the instruction"
+          ]
+        },
+        {
+          "name": "beneficiary",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "MintDenylist of the Registrar",
+            "The account doesn't have to exist and no NFT mints are denied in such case"
+          ]
+        },
+        {
+          "name": "voterWeightCapTally",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "VoterWeightCapTally of the previous owner for the Proposal",
+            "The account only exists if the Registrar had voter_weight caps when the previous owner cast the vote"
+          ]
+        },
+        {
+          "name": "instructionSysvarAccount",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nftAccountCounts",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "closeNftVoteRecords",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "governance",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Governance account the Proposal is for"
+          ]
+        },
+        {
+          "name": "proposal",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "configureCollection",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we configure this Collection"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "maxVoterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionWeightOverrides",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "CollectionWeightOverrides of the collection used to calculate max voter weight",
+            "The account doesn't have to exist if the collection has no NFT weight overrides"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "weight",
+          "type": "u64"
+        },
+        {
+          "name": "size",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "removeCollection",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar from which we remove the Collection"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "maxVoterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collection",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "createCollectionWeightOverrides",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we create the overrides"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "collectionWeightOverrides",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "maxVoterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collection",
+          "type": "publicKey"
+        },
+        {
+          "name": "overrides",
+          "type": {
+            "vec": {
+              "defined": "NftWeightOverride"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "configureCollectionWeightOverrides",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we configure the overrides"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "collectionWeightOverrides",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "maxVoterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "overrides",
+          "type": {
+            "vec": {
+              "defined": "NftWeightOverride"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "addCollectionWeightOverrides",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar the overrides belong to"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "collectionWeightOverrides",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "maxVoterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "overrides",
+          "type": {
+            "vec": {
+              "defined": "NftWeightOverride"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeCollectionWeightOverrides",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar the overrides belong to"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "collectionWeightOverrides",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "maxVoterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nftMints",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "configureProgrammableNftPolicy",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we configure the policy"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "programmableNftPolicy",
+          "type": {
+            "defined": "ProgrammableNftPolicy"
+          }
+        }
+      ]
+    },
+    {
+      "name": "configureVoterWeightCaps",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we configure the caps"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "voterWeightCaps",
+          "type": {
+            "defined": "VoterWeightCaps"
+          }
+        }
+      ]
+    },
+    {
+      "name": "configureVoterWeightingMode",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we configure the weighting mode"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "maxVoterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "voterWeightingMode",
+          "type": {
+            "defined": "VoterWeightingMode"
+          }
+        }
+      ]
+    },
+    {
+      "name": "configureOwnershipSnapshot",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we configure the ownership snapshot"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "ownershipSnapshotEnabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "configureVoteTrackingMode",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we configure the vote tracking mode"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "voteTrackingMode",
+          "type": {
+            "defined": "VoteTrackingMode"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createNftIndexMap",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we create the index map"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "nftIndexMap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collection",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "addNftIndexMapMints",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Registrar the index map belongs to"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "nftIndexMap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collection",
+          "type": "publicKey"
+        },
+        {
+          "name": "mints",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "addEscrowProgram",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we add the escrow program"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "escrowProgramConfig",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The allowlist entry of the escrow program"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "programId",
+          "type": "publicKey"
+        },
+        {
+          "name": "receiptDiscriminator",
+          "type": {
+            "array": [
+              "u8",
+              8
+            ]
+          }
+        },
+        {
+          "name": "beneficialOwnerOffset",
+          "type": "u32"
+        },
+        {
+          "name": "mintOffset",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "removeEscrowProgram",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we remove the escrow program"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "escrowProgramConfig",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The allowlist entry of the escrow program"
+          ]
+        },
+        {
+          "name": "beneficiary",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Beneficiary which receives lamports from the disposed EscrowProgramConfig account"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createConditionalWeightConfig",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we create the config"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "conditionalWeightConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "rules",
+          "type": {
+            "vec": {
+              "defined": "ConditionalWeightRule"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "configureConditionalWeightRules",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we configure the rules"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "conditionalWeightConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "rules",
+          "type": {
+            "vec": {
+              "defined": "ConditionalWeightRule"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createMintDenylist",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we create the denylist"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "mints",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "addDeniedMints",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Registrar the denylist belongs to"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "mints",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeDeniedMints",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Registrar the denylist belongs to"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "mints",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createNftDepositRecord",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "nftDepositRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The voter who deposits the NFTs"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxNfts",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "depositNft",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "nftDepositRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The voter who deposits the NFT"
+          ]
+        },
+        {
+          "name": "nftTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The token account holding the NFT"
+          ]
+        },
+        {
+          "name": "nftMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftLockerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT locker authority which owns the vaults"
+          ]
+        },
+        {
+          "name": "nftVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault the NFT is locked in"
+          ]
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "MintDenylist of the Registrar",
+            "The account doesn't have to exist and no NFT mints are denied in such case"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawNft",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "nftDepositRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The voter who deposited the NFT"
+          ]
+        },
+        {
+          "name": "voterWeightRecord",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenOwnerRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "TokenOwnerRecord of the voter",
+            "The account doesn't have to exist if the voter never deposited to spl-gov or voted"
+          ]
+        },
+        {
+          "name": "nftTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The token account the NFT is withdrawn to"
+          ]
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftLockerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT locker authority which owns the vaults"
+          ]
+        },
+        {
+          "name": "nftVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault the NFT is locked in",
+            "The vault is closed once the NFT is withdrawn"
+          ]
+        },
+        {
+          "name": "beneficiary",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createNftVoteDelegation",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "nftVoteDelegation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner of the NFTs who delegates the voting power"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "publicKey"
+        },
+        {
+          "name": "collection",
+          "type": {
+            "option": "publicKey"
+          }
+        },
+        {
+          "name": "expiry",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "revokeNftVoteDelegation",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "nftVoteDelegation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner of the NFTs who delegated the voting power"
+          ]
+        },
+        {
+          "name": "beneficiary",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "registerNftHolders",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "nftHolder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The account which pays for the registrations"
+          ]
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "MintDenylist of the Registrar",
+            "The account doesn't have to exist and no NFT mints are denied in such case"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nftAccountCounts",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "registerCompressedNftHolders",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "nftHolder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The account which pays for the registrations"
+          ]
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "MintDenylist of the Registrar",
+            "The account doesn't have to exist and no NFT mints are denied in such case"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "compressedNfts",
+          "type": {
+            "vec": {
+              "defined": "CompressedNftAsset"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "castNftVote",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting registrar"
+          ]
+        },
+        {
+          "name": "voterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token owner the vote is cast for"
+          ]
+        },
+        {
+          "name": "voterAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The authority who casts the vote: governing_token_owner, the delegate from the owner's NftVoteDelegation",
+            "or the governance_delegate from the owner's spl-gov TokenOwnerRecord"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The account which pays for the transaction"
+          ]
+        },
+        {
+          "name": "proposal",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionSysvarAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "governanceProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalWeightConfig",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "ConditionalWeightConfig of the Registrar",
+            "The account doesn't have to exist and the default conditional weight rules are used in such case"
+          ]
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "MintDenylist of the Registrar",
+            "The account doesn't have to exist and no NFT mints are denied in such case"
+          ]
+        },
+        {
+          "name": "voterWeightCapTally",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "VoterWeightCapTally of the voter for the Proposal",
+            "The account is only created and used when the Registrar has voter_weight caps"
+          ]
+        },
+        {
+          "name": "nftDepositRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NftDepositRecord of the voter",
+            "The account doesn't have to exist and only the NFTs provided in remaining accounts are used in such case"
+          ]
+        },
+        {
+          "name": "nftDepositVoteRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NftVoteRecord of the NFTs locked in the NftDepositRecord",
+            "It's created when the locked NFTs are counted for the Proposal for the first time"
+          ]
+        },
+        {
+          "name": "nftVoteDelegation",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "NftVoteDelegation of the governing_token_owner",
+            "The account doesn't have to exist and it's only used when voter_authority is not the owner"
+          ]
+        },
+        {
+          "name": "tokenOwnerRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "spl-gov TokenOwnerRecord of the governing_token_owner",
+            "It's only used when voter_authority is not the owner to check TokenOwnerRecord.governance_delegate"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "layout",
+          "type": {
+            "defined": "CastNftVoteLayout"
+          }
+        }
+      ]
+    },
+    {
+      "name": "castCompressedNftVote",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting registrar"
+          ]
+        },
+        {
+          "name": "voterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token owner the vote is cast for"
+          ]
+        },
+        {
+          "name": "voterAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The authority who casts the vote: governing_token_owner, the delegate from the owner's NftVoteDelegation",
+            "or the governance_delegate from the owner's spl-gov TokenOwnerRecord"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The account which pays for the transaction"
+          ]
+        },
+        {
+          "name": "proposal",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionSysvarAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "governanceProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalWeightConfig",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "ConditionalWeightConfig of the Registrar",
+            "The account doesn't have to exist and the default conditional weight rules are used in such case"
+          ]
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "MintDenylist of the Registrar",
+            "The account doesn't have to exist and no NFT mints are denied in such case"
+          ]
+        },
+        {
+          "name": "voterWeightCapTally",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "VoterWeightCapTally of the voter for the Proposal",
+            "The account is only created and used when the Registrar has voter_weight caps"
+          ]
+        },
+        {
+          "name": "nftDepositRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NftDepositRecord of the voter",
+            "The account doesn't have to exist and only the NFTs provided in remaining accounts are used in such case"
+          ]
+        },
+        {
+          "name": "nftDepositVoteRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NftVoteRecord of the NFTs locked in the NftDepositRecord",
+            "It's created when the locked NFTs are counted for the Proposal for the first time"
+          ]
+        },
+        {
+          "name": "nftVoteDelegation",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "NftVoteDelegation of the governing_token_owner",
+            "The account doesn't have to exist and it's only used when voter_authority is not the owner"
+          ]
+        },
+        {
+          "name": "tokenOwnerRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "spl-gov TokenOwnerRecord of the governing_token_owner",
+            "It's only used when voter_authority is not the owner to check TokenOwnerRecord.governance_delegate"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "compressedNfts",
+          "type": {
+            "vec": {
+              "defined": "CompressedNftAsset"
+            }
+          }
+        },
+        {
+          "name": "withProposalTransaction",
+          "type": "bool"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "collectionWeightOverrides",
+      "docs": [
+        "Per NFT weight overrides (rarity table) for a collection configured on the Registrar",
+        "The PDA of the overrides is [\"collection-weight-overrides\",registrar,collection]",
+        "",
+        "Overrides can only boost governance power and override weights lower than the collection weight are rejected",
+        "It ensures voters can't gain governance power by omitting the overrides account",
+        "If the collection weight is raised above an override later then the collection weight is used for the NFT"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registrar",
+            "docs": [
+              "Registrar the overrides belong to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "docs": [
+              "The collection of the NFTs"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "overrides",
+            "docs": [
+              "The weight overrides of the NFTs"
+            ],
+            "type": {
+              "vec": {
+                "defined": "NftWeightOverride"
+              }
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "conditionalWeightConfig",
+      "docs": [
+        "Conditional weight rules of the Registrar",
+        "The PDA of the config is [\"conditional-weight-config\",registrar]",
+        "",
+        "If the config doesn't exist then the default rules are used (phase protocol reverts are ApproveOnly)",
+        "Once the config is created its rules replace the default rules"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registrar",
+            "docs": [
+              "Registrar the config belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rules",
+            "docs": [
+              "The configured rules"
+            ],
+            "type": {
+              "vec": {
+                "defined": "ConditionalWeightRule"
+              }
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "escrowProgramConfig",
+      "docs": [
+        "Escrow (staking) program trusted by the Registrar to hold NFTs on behalf of voters",
+        "The PDA of the config is [\"escrow-program-config\",registrar,program_id]",
+        "",
+        "NFTs held by an escrow program can vote if the escrow program issued a receipt account",
+        "which names the voter as the beneficial owner of the NFT",
+        "The layout of the receipt is described by the config and hence any escrow program can be plugged in"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registrar",
+            "docs": [
+              "Registrar the config belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "programId",
+            "docs": [
+              "The escrow program which owns the receipt accounts"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "receiptDiscriminator",
+            "docs": [
+              "The account discriminator of the receipt accounts stored at the beginning of the receipt data"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "beneficialOwnerOffset",
+            "docs": [
+              "The offset of the beneficial owner Pubkey in the receipt data"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "mintOffset",
+            "docs": [
+              "The offset of the NFT mint Pubkey in the receipt data"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "nftVoteRecord",
+      "docs": [
+        "NftVoteRecord exported to IDL without account_discriminator",
+        "TODO: Once we can support these accounts in Anchor via remaining_accounts then it should be possible to remove it"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposal",
+            "docs": [
+              "Proposal which was voted on"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "nftMint",
+            "docs": [
+              "The mint of the NFT which was used for the vote"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "governingTokenOwner",
+            "docs": [
+              "The voter who casted this vote",
+              "It's a Realm member pubkey corresponding to TokenOwnerRecord.governing_token_owner"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "The payer of the NftVoteRecord rent"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "voteBitmap",
+      "docs": [
+        "VoteBitmap exported to IDL without account_discriminator"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposal",
+            "docs": [
+              "Proposal which was voted on"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "docs": [
+              "The collection of the NFTs"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "The payer of the VoteBitmap rent"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "bits",
+            "docs": [
+              "The bits of the NFTs which voted on the Proposal"
+            ],
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "nftHolderRegistration",
+      "docs": [
+        "NftHolderRegistration exported to IDL without account_discriminator"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registrar",
+            "docs": [
+              "Registrar the registration belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "nftMint",
+            "docs": [
+              "The mint of the NFT",
+              "For compressed NFTs it's the asset id of the NFT"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "holder",
+            "docs": [
+              "The holder of the NFT"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "registeredAt",
+            "docs": [
+              "The time when the holder was first observed holding the NFT"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "registeredAtSlot",
+            "docs": [
+              "The slot when the holder was first observed holding the NFT"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "voterWeightCapTally",
+      "docs": [
+        "VoterWeightCapTally exported to IDL without account_discriminator"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposal",
+            "docs": [
+              "Proposal which was voted on"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "governingTokenOwner",
+            "docs": [
+              "The voter who casted the votes"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collectionWeights",
+            "docs": [
+              "Uncapped weight of the cast NFTs for each collection configured on the Registrar",
+              "For the non linear weighting modes it's the number of the cast NFTs"
+            ],
+            "type": {
+              "vec": {
+                "defined": "CollectionVoterWeight"
+              }
+            }
+          },
+          {
+            "name": "nftVoteRecordCount",
+            "docs": [
+              "The number of NftVoteRecords of the voter for the Proposal tallied by the tally",
+              "The tally is disposed when the last of the NftVoteRecords is relinquished"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "nftTallyRecord",
+      "docs": [
+        "NftTallyRecord exported to IDL without account_discriminator"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registrar",
+            "docs": [
+              "Registrar the record belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "nftMint",
+            "docs": [
+              "The mint of the NFT"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "voterWeightTally",
+            "docs": [
+              "The VoterWeightTally which counted the NFT"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tallyStartSlot",
+            "docs": [
+              "The start_slot of the VoterWeightTally when it counted the NFT"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "maxVoterWeightRecord",
+      "docs": [
+        "MaxVoterWeightRecord account as defined in spl-governance-addin-api",
+        "It's redefined here without account_discriminator for Anchor to treat it as native account",
+        "",
+        "The account is used as an api interface to provide max voting power to the governance program from external addin contracts"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "realm",
+            "docs": [
+              "The Realm the MaxVoterWeightRecord belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "governingTokenMint",
+            "docs": [
+              "Governing Token Mint the MaxVoterWeightRecord is associated with",
+              "Note: The addin can take deposits of any tokens and is not restricted to the community or council tokens only"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "maxVoterWeight",
+            "docs": [
+              "Max voter weight",
+              "The max voter weight provided by the addin for the given realm and governing_token_mint"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxVoterWeightExpiry",
+            "docs": [
+              "The slot when the max voting weight expires",
+              "It should be set to None if the weight never expires",
+              "If the max vote weight decays with time, for example for time locked based weights, then the expiry must be set",
+              "As a pattern Revise instruction to update the max weight should be invoked before governance instruction within the same transaction",
+              "and the expiry set to the current slot to provide up to date weight"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved space for future versions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintDenylist",
+      "docs": [
+        "NFT mints which are not allowed to vote, for example stolen or exploited NFTs",
+        "The PDA of the denylist is [\"mint-denylist\",registrar]",
+        "",
+        "The mints are kept sorted to binary search them in the account data without deserializing the whole list",
+        "If the denylist doesn't exist then no mints are denied"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registrar",
+            "docs": [
+              "Registrar the denylist belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mints",
+            "docs": [
+              "Sorted list of the denied mints"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "nftDepositRecord",
+      "docs": [
+        "Deposit record of the NFTs the voter locked in the program vaults",
+        "The PDA of the record is [\"nft-deposit-record\",registrar,governing_token_owner]",
+        "",
+        "The locked NFTs give the voter persistent governance power and they don't have to be supplied",
+        "with their token and metadata accounts when the voter casts vote or updates voter_weight",
+        "Every NFT is held in its own vault token account with PDA [\"nft-vault\",registrar,nft_mint]",
+        "owned by the locker authority PDA [\"nft-locker-authority\",registrar]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registrar",
+            "docs": [
+              "Registrar the deposit record belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "governingTokenOwner",
+            "docs": [
+              "The voter who deposited the NFTs"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "totalWeight",
+            "docs": [
+              "The total weight of the locked NFTs"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lockedNfts",
+            "docs": [
+              "The NFTs locked in the vaults"
+            ],
+            "type": {
+              "vec": {
+                "defined": "LockedNft"
+              }
+            }
+          },
+          {
+            "name": "nftVoteRecordCount",
+            "docs": [
+              "The number of the Proposals the locked NFTs are counted for with NftVoteRecord of the deposit record",
+              "The NFTs can't be withdrawn until the NftVoteRecords are relinquished"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                28
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "nftIndexMap",
+      "docs": [
+        "Index map of the NFTs from a collection used to track votes with VoteBitmaps",
+        "The PDA of the map is [\"nft-index-map\",registrar,collection]",
+        "",
+        "Every NFT is assigned the next index when it's added to the map, usually its position in the collection",
+        "The indexes are stable because they address the NFT bits in VoteBitmaps and NFTs can't be removed from the map",
+        "",
+        "The NFTs are kept sorted by mint to binary search them in the account data without deserializing the whole map",
+        "Large collections don't fit into the heap and the map is only read and extended in place"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registrar",
+            "docs": [
+              "Registrar the map belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "docs": [
+              "The collection of the NFTs"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades",
+              "Note: The field precedes nfts to extend the account in place when NFTs are added"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nfts",
+            "docs": [
+              "NFT indexes sorted by mint"
+            ],
+            "type": {
+              "vec": {
+                "defined": "NftIndex"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "nftVoteDelegation",
+      "docs": [
+        "Delegation of the NFT voting power of the governing_token_owner to the delegate",
+        "The PDA of the delegation is [\"nft-vote-delegation\",registrar,governing_token_owner]",
+        "",
+        "The delegate can sign CastNftVote on behalf of the owner and the NftVoteRecords still record the owner as the voter",
+        "The delegation can be scoped to the NFTs from a single collection and it can expire",
+        "",
+        "Note: The delegation only applies to the nft-voter instructions and spl-gov CastVote must still be signed",
+        "by the owner or TokenOwnerRecord.governance_delegate",
+        "For the delegate to vote without the owner's signature the owner has to set the delegate as the governance_delegate as well"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registrar",
+            "docs": [
+              "Registrar the delegation belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "governingTokenOwner",
+            "docs": [
+              "The owner of the NFTs who delegated the voting power"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "delegate",
+            "docs": [
+              "The delegate who can vote with the owner's NFTs"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "docs": [
+              "If set then the delegate can only vote with the NFTs from the collection"
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "If set then the delegation expires at the given time"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "registrar",
+      "docs": [
+        "Registrar which stores NFT voting configuration for the given Realm"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "governanceProgramId",
+            "docs": [
+              "spl-governance program the Realm belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "realm",
+            "docs": [
+              "Realm of the Registrar"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "governingTokenMint",
+            "docs": [
+              "Governing token mint the Registrar is for",
+              "It can either be the Community or the Council mint of the Realm",
+              "When the plugin is used the mint is only used as identity of the governing power (voting population)",
+              "and the actual token of the mint is not used"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collectionConfigs",
+            "docs": [
+              "MPL Collection used for voting"
+            ],
+            "type": {
+              "vec": {
+                "defined": "CollectionConfig"
+              }
+            }
+          },
+          {
+            "name": "programmableNftPolicy",
+            "docs": [
+              "Policy applied to programmable NFTs used for voting"
+            ],
+            "type": {
+              "defined": "ProgrammableNftPolicy"
+            }
+          },
+          {
+            "name": "voterWeightCaps",
+            "docs": [
+              "Caps applied to voter_weight cast on a Proposal by a single voter"
+            ],
+            "type": {
+              "defined": "VoterWeightCaps"
+            }
+          },
+          {
+            "name": "voterWeightingMode",
+            "docs": [
+              "Weighting mode used to calculate voter_weight of the NFTs owned by a voter"
+            ],
+            "type": {
+              "defined": "VoterWeightingMode"
+            }
+          },
+          {
+            "name": "ownershipSnapshotEnabled",
+            "docs": [
+              "Indicates whether NFTs must be registered to the voter using NftHolderRegistration",
+              "before the Proposal voting started in order to vote on the Proposal",
+              "It prevents acquiring NFTs in the middle of the vote to swing the outcome"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "voteTrackingMode",
+            "docs": [
+              "Mode used to track the NFTs which voted on a Proposal"
+            ],
+            "type": {
+              "defined": "VoteTrackingMode"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                112
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "voterWeightRecord",
+      "docs": [
+        "VoterWeightRecord account as defined in spl-governance-addin-api",
+        "It's redefined here without account_discriminator for Anchor to treat it as native account",
+        "",
+        "The account is used as an api interface to provide voting power to the governance program from external addin contracts"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "realm",
+            "docs": [
+              "The Realm the VoterWeightRecord belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "governingTokenMint",
+            "docs": [
+              "Governing Token Mint the VoterWeightRecord is associated with",
+              "Note: The addin can take deposits of any tokens and is not restricted to the community or council tokens only"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "governingTokenOwner",
+            "docs": [
+              "The owner of the governing token and voter",
+              "This is the actual owner (voter) and corresponds to TokenOwnerRecord.governing_token_owner"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "voterWeight",
+            "docs": [
+              "Voter's weight",
+              "The weight of the voter provided by the addin for the given realm, governing_token_mint and governing_token_owner (voter)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "voterWeightExpiry",
+            "docs": [
+              "The slot when the voting weight expires",
+              "It should be set to None if the weight never expires",
+              "If the voter weight decays with time, for example for time locked based weights, then the expiry must be set",
+              "As a common pattern Revise instruction to update the weight should be invoked before governance instruction within the same transaction",
+              "and the expiry set to the current slot to provide up to date weight"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "weightAction",
+            "docs": [
+              "The governance action the voter's weight pertains to",
+              "It allows to provided voter's weight specific to the particular action the weight is evaluated for",
+              "When the action is provided then the governance program asserts the executing action is the same as specified by the addin"
+            ],
+            "type": {
+              "option": {
+                "defined": "VoterWeightAction"
+              }
+            }
+          },
+          {
+            "name": "weightActionTarget",
+            "docs": [
+              "The target the voter's weight  action pertains to",
+              "It allows to provided voter's weight specific to the target the weight is evaluated for",
+              "For example when addin supplies weight to vote on a particular proposal then it must specify the proposal as the action target",
+              "When the target is provided then the governance program asserts the target is the same as specified by the addin"
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved space for future versions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "voterWeightTally",
+      "docs": [
+        "Bookkeeping account used to accumulate voter_weight for non voting actions (CreateProposal, CreateGovernance etc...)",
+        "using several transactions",
+        "The PDA of the tally is [\"voter-weight-tally\",voter_weight_record]",
+        "",
+        "The tally stores the NFTs which were already counted to ensure the same NFT can't be counted twice",
+        "It's reset when it's used for a different action or when it's older than VOTER_WEIGHT_TALLY_MAX_SLOTS"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "voterWeightRecord",
+            "docs": [
+              "VoterWeightRecord the tally accumulates voter_weight for"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "weightAction",
+            "docs": [
+              "The governance action the tally accumulates voter_weight for"
+            ],
+            "type": {
+              "option": {
+                "defined": "VoterWeightAction"
+              }
+            }
+          },
+          {
+            "name": "startSlot",
+            "docs": [
+              "The slot when the tally was started"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "voterWeight",
+            "docs": [
+              "The accumulated voter_weight",
+              "For the non linear weighting modes it's the number of the counted NFTs",
+              "and the weighting mode curve is applied to it when VoterWeightRecord is updated"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "nftMints",
+            "docs": [
+              "The NFTs which were already counted"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "CastNftVoteLayout",
+      "docs": [
+        "Layout of the remaining accounts provided to CastNftVote",
+        "The layout is provided explicitly to ensure mis-shaped accounts are rejected instead of being parsed as NFT accounts"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nftAccountCounts",
+            "docs": [
+              "The number of the accounts of each NFT provided in the remaining accounts",
+              "The accounts of the NFT are: nft, nft_metadata, token_record (only for programmable NFTs),",
+              "escrow_program_config and escrow_receipt (only for NFTs held by an escrow program)",
+              "and collection_weight_overrides (only for NFTs with weight override)",
+              "Note: The accounts used to track the vote of the NFT follow the accounts of the NFT and they aren't counted"
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "withProposalTransaction",
+            "docs": [
+              "Indicates whether ProposalTransaction is provided as the last remaining account",
+              "It's required to cast vote on Proposals matching a conditional weight rule"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollectionConfig",
+      "docs": [
+        "Configuration of an NFT collection used for governance power"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "docs": [
+              "The NFT collection used for governance"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "size",
+            "docs": [
+              "The size of the NFT collection used to calculate max voter weight",
+              "Note: At the moment the size is not captured on Metaplex accounts",
+              "and it has to be manually updated on the Registrar"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "weight",
+            "docs": [
+              "Governance power weight of the collection",
+              "Each NFT in the collection has governance power = 1 * weight",
+              "Note: The weight is scaled accordingly to the governing_token_mint decimals",
+              "Ex: if the the mint has 2 decimal places then weight of 1 should be stored as 100"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxWeightBonus",
+            "docs": [
+              "Max governance power added on top of size * weight by the NFT weight overrides of the collection",
+              "Note: The value is computed from CollectionWeightOverrides when the collection or its overrides are configured"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "NftWeightOverride",
+      "docs": [
+        "Governance power weight of an individual NFT which overrides its collection weight"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nftMint",
+            "docs": [
+              "The mint of the NFT"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "weight",
+            "docs": [
+              "Governance power weight of the NFT"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompressedNftAsset",
+      "docs": [
+        "Compressed NFT (Bubblegum) leaf data used to prove the voter owns the compressed NFT",
+        "and the NFT belongs to a verified collection",
+        "",
+        "The leaf is recomputed from the data and verified against the root of the Bubblegum Merkle tree"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nonce",
+            "docs": [
+              "The nonce of the leaf used to derive the asset id"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "docs": [
+              "The index of the leaf in the Merkle tree"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "leafDelegate",
+            "docs": [
+              "The delegate of the leaf",
+              "If the compressed NFT has no delegate then it's the same as the owner"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "metadata",
+            "docs": [
+              "The metadata of the compressed NFT"
+            ],
+            "type": {
+              "defined": "MetadataArgs"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConditionalWeightRule",
+      "docs": [
+        "Rule which changes voter_weight for Proposals executing an instruction of the given program",
+        "The rule matches Proposals with a single ProposalTransaction in the option labelled option_label",
+        "and the ProposalTransaction must have a single instruction for program_id",
+        "with instruction data starting with one of the discriminators"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "docs": [
+              "The program executed by the ProposalTransaction"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "discriminators",
+            "docs": [
+              "The 8 bytes instruction discriminators the rule applies to"
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  8
+                ]
+              }
+            }
+          },
+          {
+            "name": "weightPolicy",
+            "docs": [
+              "The policy applied to voter_weight when the rule matches"
+            ],
+            "type": {
+              "defined": "ConditionalWeightPolicy"
+            }
+          },
+          {
+            "name": "optionLabel",
+            "docs": [
+              "The label of the Proposal option with the ProposalTransaction",
+              "If None then the rule applies to the option with any label"
+            ],
+            "type": {
+              "option": "string"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollectionVoterWeight",
+      "docs": [
+        "Weight of the NFTs from a collection cast by the voter before the caps are applied"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "docs": [
+              "The collection of the NFTs"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "weight",
+            "docs": [
+              "The total weight of the NFTs from the collection"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LockedNft",
+      "docs": [
+        "NFT locked in the NftDepositRecord"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the NFT"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "docs": [
+              "The verified collection of the NFT"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "weight",
+            "docs": [
+              "Governance power weight of the NFT when it was deposited"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "depositedAt",
+            "docs": [
+              "The time when the NFT was deposited"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "NftIndex",
+      "docs": [
+        "Index of an NFT in NftIndexMap"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the NFT",
+              "For compressed NFTs it's the asset id of the NFT"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "docs": [
+              "The index of the NFT bit in VoteBitmaps"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VoterWeightCaps",
+      "docs": [
+        "Caps applied to voter_weight cast on a Proposal by a single governing_token_owner",
+        "The caps limit the governance power of whales holding large numbers of NFTs"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxVoterWeight",
+            "docs": [
+              "Max voter_weight a single governing_token_owner can cast on a Proposal"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxCollectionWeightShareBps",
+            "docs": [
+              "Max share of max_voter_weight a single governing_token_owner can cast on a Proposal",
+              "with NFTs from any one collection",
+              "The share is expressed in basis points (1/100 of a percent)"
+            ],
+            "type": {
+              "option": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "NftVoterWeightQuote",
+      "docs": [
+        "Voter weight quote of an NFT provided to GetVoterWeight"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the NFT",
+              "If the NFT token account can't be read then it's the address of the token account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "weight",
+            "docs": [
+              "Governance power weight of the NFT",
+              "It's 0 if the NFT was rejected"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rejectionCode",
+            "docs": [
+              "The error the NFT was rejected with encoded as ProgramError",
+              "For NftVoterError and Anchor errors it's the error code",
+              "None if the NFT can be used to vote"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "VoterWeightQuote",
+      "docs": [
+        "Voter weight quote returned by GetVoterWeight using the instruction return data"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "voterWeight",
+            "docs": [
+              "The voter_weight of the voter with the Registrar voter_weighting_mode and voter_weight caps applied"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "depositedNftCount",
+            "docs": [
+              "The number of NFTs locked in the voter's NftDepositRecord counted in voter_weight"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "depositedNftWeight",
+            "docs": [
+              "The total weight of the NFTs locked in the voter's NftDepositRecord"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "nfts",
+            "docs": [
+              "Quotes of the NFTs provided in the remaining accounts in the same order"
+            ],
+            "type": {
+              "vec": {
+                "defined": "NftVoterWeightQuote"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Uses",
+      "docs": [
+        "Uses as defined in Bubblegum"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "useMethod",
+            "type": {
+              "defined": "UseMethod"
+            }
+          },
+          {
+            "name": "remaining",
+            "type": "u64"
+          },
+          {
+            "name": "total",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Collection",
+      "docs": [
+        "Collection as defined in Bubblegum"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "key",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Creator",
+      "docs": [
+        "Creator as defined in Bubblegum"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "share",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MetadataArgs",
+      "docs": [
+        "MetadataArgs as defined in Bubblegum",
+        "The layout must match Bubblegum exactly because the leaf data_hash is computed from its serialized form"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "primarySaleHappened",
+            "type": "bool"
+          },
+          {
+            "name": "isMutable",
+            "type": "bool"
+          },
+          {
+            "name": "editionNonce",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "tokenStandard",
+            "type": {
+              "option": {
+                "defined": "TokenStandard"
+              }
+            }
+          },
+          {
+            "name": "collection",
+            "type": {
+              "option": {
+                "defined": "Collection"
+              }
+            }
+          },
+          {
+            "name": "uses",
+            "type": {
+              "option": {
+                "defined": "Uses"
+              }
+            }
+          },
+          {
+            "name": "tokenProgramVersion",
+            "type": {
+              "defined": "TokenProgramVersion"
+            }
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "Creator"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenRecord",
+      "docs": [
+        "TokenRecord account as defined in mpl-token-metadata",
+        "Only the fields used by the program are defined"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "TokenState"
+            }
+          },
+          {
+            "name": "ruleSetRevision",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "delegate",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "delegateRole",
+            "type": {
+              "option": {
+                "defined": "TokenDelegateRole"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConditionalWeightPolicy",
+      "docs": [
+        "Policy applied to voter_weight when a vote is cast on a Proposal matching a ConditionalWeightRule"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "ApproveOnly"
+          },
+          {
+            "name": "DenyOnly"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProgrammableNftPolicy",
+      "docs": [
+        "Policy applied to programmable NFTs (pNFTs) used for voting",
+        "The policy is evaluated using the TokenRecord of the pNFT token account"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "AllowAll"
+          },
+          {
+            "name": "RejectLockedOrDelegated"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VoteTrackingMode",
+      "docs": [
+        "Mode used to track the NFTs which voted on a Proposal and prevent them from voting twice"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NftVoteRecords"
+          },
+          {
+            "name": "VoteBitmaps"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VoterWeightAction",
+      "docs": [
+        "VoterWeightAction enum as defined in spl-governance-addin-api",
+        "It's redefined here for Anchor to export it to IDL"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "CastVote"
+          },
+          {
+            "name": "CommentProposal"
+          },
+          {
+            "name": "CreateGovernance"
+          },
+          {
+            "name": "CreateProposal"
+          },
+          {
+            "name": "SignOffProposal"
+          },
+          {
+            "name": "RevokeVote"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VoterWeightingMode",
+      "docs": [
+        "Weighting mode used to calculate voter_weight of the NFTs owned by a voter",
+        "",
+        "For the non linear modes voter_weight is a function of the number of NFTs the voter owns",
+        "and the collection weights are only used to determine whether an NFT has governance power"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Linear"
+          },
+          {
+            "name": "Quadratic"
+          },
+          {
+            "name": "Logarithmic"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenProgramVersion",
+      "docs": [
+        "TokenProgramVersion as defined in Bubblegum"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Original"
+          },
+          {
+            "name": "Token2022"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UseMethod",
+      "docs": [
+        "UseMethod as defined in Bubblegum"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Burn"
+          },
+          {
+            "name": "Multiple"
+          },
+          {
+            "name": "Single"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenStandard",
+      "docs": [
+        "TokenStandard as defined in mpl-token-metadata",
+        "It's redefined here because the programmable standards are not supported by the mpl-token-metadata version used by the program"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NonFungible"
+          },
+          {
+            "name": "FungibleAsset"
+          },
+          {
+            "name": "Fungible"
+          },
+          {
+            "name": "NonFungibleEdition"
+          },
+          {
+            "name": "ProgrammableNonFungible"
+          },
+          {
+            "name": "ProgrammableNonFungibleEdition"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenState",
+      "docs": [
+        "TokenState as defined in mpl-token-metadata"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unlocked"
+          },
+          {
+            "name": "Locked"
+          },
+          {
+            "name": "Listed"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenDelegateRole",
+      "docs": [
+        "TokenDelegateRole as defined in mpl-token-metadata"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Sale"
+          },
+          {
+            "name": "Transfer"
+          },
+          {
+            "name": "Utility"
+          },
+          {
+            "name": "Staking"
+          },
+          {
+            "name": "Standard"
+          },
+          {
+            "name": "LockedTransfer"
+          },
+          {
+            "name": "Migration"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "RegistrarCreated",
+      "fields": [
+        {
+          "name": "registrar",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governanceProgramId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "realm",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governingTokenMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "maxCollections",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CollectionConfigured",
+      "fields": [
+        {
+          "name": "registrar",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collection",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oldWeight",
+          "type": {
+            "option": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "newWeight",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "oldSize",
+          "type": {
+            "option": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "newSize",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maxVoterWeight",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "NftVoteCast",
+      "fields": [
+        {
+          "name": "registrar",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposal",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "nftMints",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "voterWeightAdded",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "voterWeight",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "NftVoteRelinquished",
+      "fields": [
+        {
+          "name": "registrar",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposal",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "nftMints",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "beneficiary",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "InvalidRealmAuthority",
+      "msg": "Invalid Realm Authority"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidRealmForRegistrar",
+      "msg": "Invalid Realm for Registrar"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidCollectionSize",
+      "msg": "Invalid Collection Size"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidMaxVoterWeightRecordRealm",
+      "msg": "Invalid MaxVoterWeightRecord Realm"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidMaxVoterWeightRecordMint",
+      "msg": "Invalid MaxVoterWeightRecord Mint"
+    },
+    {
+      "code": 6005,
+      "name": "CastVoteIsNotAllowed",
+      "msg": "CastVote Is Not Allowed"
+    },
+    {
+      "code": 6006,
+      "name": "InvalidVoterWeightRecordRealm",
+      "msg": "Invalid VoterWeightRecord Realm"
+    },
+    {
+      "code": 6007,
+      "name": "InvalidVoterWeightRecordMint",
+      "msg": "Invalid VoterWeightRecord Mint"
+    },
+    {
+      "code": 6008,
+      "name": "InvalidTokenOwnerForVoterWeightRecord",
+      "msg": "Invalid TokenOwner for VoterWeightRecord"
+    },
+    {
+      "code": 6009,
+      "name": "CollectionMustBeVerified",
+      "msg": "Collection must be verified"
+    },
+    {
+      "code": 6010,
+      "name": "VoterDoesNotOwnNft",
+      "msg": "Voter does not own NFT"
+    },
+    {
+      "code": 6011,
+      "name": "CollectionNotFound",
+      "msg": "Collection not found"
+    },
+    {
+      "code": 6012,
+      "name": "MissingMetadataCollection",
+      "msg": "Missing Metadata collection"
+    },
+    {
+      "code": 6013,
+      "name": "TokenMetadataDoesNotMatch",
+      "msg": "Token Metadata doesn't match"
+    },
+    {
+      "code": 6014,
+      "name": "InvalidAccountOwner",
+      "msg": "Invalid account owner"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidTokenMetadataAccount",
+      "msg": "Invalid token metadata account"
+    },
+    {
+      "code": 6016,
+      "name": "DuplicatedNftDetected",
+      "msg": "Duplicated NFT detected"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidNftAmount",
+      "msg": "Invalid NFT amount"
+    },
+    {
+      "code": 6018,
+      "name": "NftAlreadyVoted",
+      "msg": "NFT already voted"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidProposalForNftVoteRecord",
+      "msg": "Invalid Proposal for NftVoteRecord"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidTokenOwnerForNftVoteRecord",
+      "msg": "Invalid TokenOwner for NftVoteRecord"
+    },
+    {
+      "code": 6021,
+      "name": "VoteRecordMustBeWithdrawn",
+      "msg": "VoteRecord must be withdrawn"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidVoteRecordForNftVoteRecord",
+      "msg": "Invalid VoteRecord for NftVoteRecord"
+    },
+    {
+      "code": 6023,
+      "name": "VoterWeightRecordMustBeExpired",
+      "msg": "VoterWeightRecord must be expired"
+    },
+    {
+      "code": 6024,
+      "name": "CannotConfigureCollectionWithVotingProposals",
+      "msg": "Cannot configure collection with voting proposals"
+    },
+    {
+      "code": 6025,
+      "name": "MustIncludeProposalTransactionForPhaseVotes",
+      "msg": "Must include proposals transaction since the proposal ressembles a phase vote"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidMaxCollections",
+      "msg": "Max collections can't be less than the number of configured collections"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidMerkleTreeAccount",
+      "msg": "Invalid Merkle tree account"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidMerkleTreeAuthority",
+      "msg": "Merkle tree must be a Bubblegum tree"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidMerkleProof",
+      "msg": "Invalid Merkle proof for compressed NFT"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidTokenRecord",
+      "msg": "Invalid TokenRecord account"
+    },
+    {
+      "code": 6031,
+      "name": "ProgrammableNftLocked",
+      "msg": "Programmable NFT is locked"
+    },
+    {
+      "code": 6032,
+      "name": "ProgrammableNftDelegated",
+      "msg": "Programmable NFT is delegated"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidEscrowProgramConfig",
+      "msg": "Invalid EscrowProgramConfig"
+    },
+    {
+      "code": 6034,
+      "name": "EscrowProgramNotAllowed",
+      "msg": "Escrow program is not allowed"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidEscrowReceipt",
+      "msg": "Invalid escrow receipt"
+    },
+    {
+      "code": 6036,
+      "name": "RevokeVoteIsNotAllowed",
+      "msg": "RevokeVote Is Not Allowed"
+    },
+    {
+      "code": 6037,
+      "name": "CannotRevokeOwnNftVote",
+      "msg": "Cannot revoke own NFT vote"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidProposalStateForRevoke",
+      "msg": "Invalid Proposal state for revoke"
+    },
+    {
+      "code": 6039,
+      "name": "InvalidNftMintForNftVoteRecord",
+      "msg": "Invalid NFT mint for NftVoteRecord"
+    },
+    {
+      "code": 6040,
+      "name": "VoterWeightTallyIsFull",
+      "msg": "VoterWeightTally is full"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidGovernanceProgram",
+      "msg": "Invalid governance program for Registrar"
+    },
+    {
+      "code": 6042,
+      "name": "InvalidConditionalWeightConfig",
+      "msg": "Invalid ConditionalWeightConfig"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidConditionalWeightRule",
+      "msg": "Invalid conditional weight rule"
+    },
+    {
+      "code": 6044,
+      "name": "InvalidCastVoteInstruction",
+      "msg": "Invalid spl-governance CastVote instruction"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidRemainingAccountsLayout",
+      "msg": "Remaining accounts don't match the provided layout"
+    },
+    {
+      "code": 6046,
+      "name": "InvalidCollectionWeightOverrides",
+      "msg": "Invalid CollectionWeightOverrides"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidNftWeightOverride",
+      "msg": "Invalid NFT weight override"
+    },
+    {
+      "code": 6048,
+      "name": "InvalidMintDenylist",
+      "msg": "Invalid MintDenylist"
+    },
+    {
+      "code": 6049,
+      "name": "InvalidDeniedMint",
+      "msg": "Invalid denied mint"
+    },
+    {
+      "code": 6050,
+      "name": "NftMintDenied",
+      "msg": "NFT mint is denied"
+    },
+    {
+      "code": 6051,
+      "name": "InvalidVoterWeightCaps",
+      "msg": "Invalid voter weight caps"
+    },
+    {
+      "code": 6052,
+      "name": "CannotConfigureVoterWeightCapsWithVotingProposals",
+      "msg": "Cannot configure voter weight caps with voting proposals"
+    },
+    {
+      "code": 6053,
+      "name": "CannotConfigureVoterWeightingModeWithVotingProposals",
+      "msg": "Cannot configure voter weighting mode with voting proposals"
+    },
+    {
+      "code": 6054,
+      "name": "InvalidNftHolderRegistration",
+      "msg": "Invalid NFT holder registration"
+    },
+    {
+      "code": 6055,
+      "name": "NftHolderNotRegistered",
+      "msg": "NFT holder is not registered"
+    },
+    {
+      "code": 6056,
+      "name": "NftAcquiredAfterVotingStarted",
+      "msg": "NFT was acquired after voting started"
+    },
+    {
+      "code": 6057,
+      "name": "ProposalVotingNotStarted",
+      "msg": "Proposal voting not started"
+    },
+    {
+      "code": 6058,
+      "name": "CannotConfigureOwnershipSnapshotWithVotingProposals",
+      "msg": "Cannot configure ownership snapshot with voting proposals"
+    },
+    {
+      "code": 6059,
+      "name": "NftDepositRecordIsFull",
+      "msg": "NftDepositRecord is full"
+    },
+    {
+      "code": 6060,
+      "name": "NftNotDeposited",
+      "msg": "NFT is not deposited"
+    },
+    {
+      "code": 6061,
+      "name": "CannotDepositProgrammableNft",
+      "msg": "Programmable NFTs can't be deposited"
+    },
+    {
+      "code": 6062,
+      "name": "CannotWithdrawNftWithActiveVotes",
+      "msg": "Cannot withdraw NFT with active votes"
+    },
+    {
+      "code": 6063,
+      "name": "InvalidTokenOwnerRecordForNftDepositRecord",
+      "msg": "Invalid TokenOwnerRecord for NftDepositRecord"
+    },
+    {
+      "code": 6064,
+      "name": "InvalidVoterAuthority",
+      "msg": "Voter authority must be the owner or its delegate"
+    },
+    {
+      "code": 6065,
+      "name": "NftVoteDelegationExpired",
+      "msg": "NftVoteDelegation expired"
+    },
+    {
+      "code": 6066,
+      "name": "NftCollectionNotDelegated",
+      "msg": "NFT collection is not delegated"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidNftVoteDelegationExpiry",
+      "msg": "Invalid NftVoteDelegation expiry"
+    },
+    {
+      "code": 6068,
+      "name": "ProposalVotingNotFinalized",
+      "msg": "Proposal voting is not finalized"
+    },
+    {
+      "code": 6069,
+      "name": "InvalidPayerForNftVoteRecord",
+      "msg": "Invalid payer for NftVoteRecord"
+    },
+    {
+      "code": 6070,
+      "name": "CannotConfigureVoteTrackingModeWithVotingProposals",
+      "msg": "Cannot configure vote tracking mode with voting proposals"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidNftIndexMap",
+      "msg": "Invalid NftIndexMap"
+    },
+    {
+      "code": 6072,
+      "name": "NftIndexMapIsFull",
+      "msg": "NftIndexMap is full"
+    },
+    {
+      "code": 6073,
+      "name": "NftAlreadyInIndexMap",
+      "msg": "NFT is already in NftIndexMap"
+    },
+    {
+      "code": 6074,
+      "name": "NftNotInIndexMap",
+      "msg": "NFT is not in NftIndexMap"
+    },
+    {
+      "code": 6075,
+      "name": "InvalidVoteBitmap",
+      "msg": "Invalid VoteBitmap"
+    },
+    {
+      "code": 6076,
+      "name": "NftIndexOutOfRange",
+      "msg": "NFT index is out of VoteBitmap range"
+    },
+    {
+      "code": 6077,
+      "name": "CannotRevokeNftVoteWithVoteBitmaps",
+      "msg": "NFT votes tracked with VoteBitmaps can't be revoked"
+    },
+    {
+      "code": 6078,
+      "name": "CannotCloseNftDepositVoteRecord",
+      "msg": "NftVoteRecord of NftDepositRecord must be relinquished by the voter"
+    },
+    {
+      "code": 6079,
+      "name": "CannotConfigureConditionalWeightRulesWithVotingProposals",
+      "msg": "Cannot configure conditional weight rules with voting proposals"
+    },
+    {
+      "code": 6080,
+      "name": "MustIncludeRevokeVoteInstruction",
+      "msg": "RevokeNftVote must be bundled with spl-governance RevokeVote instruction"
+    },
+    {
+      "code": 6081,
+      "name": "InvalidRevokeVoteInstruction",
+      "msg": "Invalid spl-governance RevokeVote instruction"
+    },
+    {
+      "code": 6082,
+      "name": "InvalidNftTallyRecord",
+      "msg": "Invalid NftTallyRecord"
+    },
+    {
+      "code": 6083,
+      "name": "NftAlreadyTallied",
+      "msg": "NFT is already counted by VoterWeightTally of another voter"
+    }
+  ]
+};
+
+export const IDL: NftVoter = {
+  "version": "0.1.1",
+  "name": "nft_voter",
+  "instructions": [
+    {
+      "name": "createRegistrar",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar",
+            "There can only be a single registrar per governance Realm and governing mint of the Realm"
+          ]
+        },
+        {
+          "name": "governanceProgramId",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The program id of the spl-governance program the realm belongs to"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "An spl-governance Realm",
+            "",
+            "Realm is validated in the instruction:",
+            "- Realm is owned by the governance_program_id",
+            "- governing_token_mint must be the community or council mint",
+            "- realm_authority is realm.authority"
+          ]
+        },
+        {
+          "name": "governingTokenMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Either the realm community mint or the council mint.",
+            "It must match Realm.community_mint or Realm.config.council_mint",
+            "",
+            "Note: Once the NFT plugin is enabled the governing_token_mint is used only as identity",
+            "for the voting population and the tokens of that are no longer used"
+          ]
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "realm_authority must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxCollections",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "resizeRegistrar",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar to resize"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxCollections",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createVoterWeightRecord",
+      "accounts": [
+        {
+          "name": "voterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceProgramId",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The program id of the spl-governance program the realm belongs to"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmGoverningTokenMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Either the realm community mint or the council mint."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "governingTokenOwner",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "createMaxVoterWeightRecord",
+      "accounts": [
+        {
+          "name": "maxVoterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceProgramId",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The program id of the spl-governance program the realm belongs to"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmGoverningTokenMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Either the realm community mint or the council mint."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateVoterWeightRecord",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "voterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "MintDenylist of the Registrar",
+            "The account doesn't have to exist and no NFT mints are denied in such case"
+          ]
+        },
+        {
+          "name": "nftDepositRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "NftDepositRecord of the voter",
+            "The account doesn't have to exist and only the NFTs provided in remaining accounts are used in such case"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "voterWeightAction",
+          "type": {
+            "defined": "VoterWeightAction"
+          }
+        },
+        {
+          "name": "nftAccountCounts",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "getVoterWeight",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "MintDenylist of the Registrar",
+            "The account doesn't have to exist and no NFT mints are denied in such case"
+          ]
+        },
+        {
+          "name": "nftDepositRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "NftDepositRecord of the voter",
+            "The account doesn't have to exist and only the NFTs provided in remaining accounts are quoted in such case"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "nftAccountCounts",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "createVoterWeightTally",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "voterWeightRecord",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "voterWeightTally",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxNfts",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "updateVoterWeightTally",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "voterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "voterWeightTally",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token owner the tally is updated for"
+          ]
+        },
+        {
+          "name": "voterAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The authority who updates the tally: governing_token_owner, the delegate from the owner's NftVoteDelegation",
+            "or the governance_delegate from the owner's spl-gov TokenOwnerRecord"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The account which pays for NftTallyRecords"
+          ]
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "MintDenylist of the Registrar",
+            "The account doesn't have to exist and no NFT mints are denied in such case"
+          ]
+        },
+        {
+          "name": "nftDepositRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "NftDepositRecord of the voter",
+            "The account doesn't have to exist and only the NFTs provided in remaining accounts are used in such case"
+          ]
+        },
+        {
+          "name": "nftVoteDelegation",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "NftVoteDelegation of the governing_token_owner",
+            "The account doesn't have to exist and it's only used when voter_authority is not the owner"
+          ]
+        },
+        {
+          "name": "tokenOwnerRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "spl-gov TokenOwnerRecord of the governing_token_owner",
+            "It's only used when voter_authority is not the owner to check TokenOwnerRecord.governance_delegate"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "voterWeightAction",
+          "type": {
+            "defined": "VoterWeightAction"
+          }
+        },
+        {
+          "name": "nftAccountCounts",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "closeVoterWeightTally",
+      "accounts": [
+        {
+          "name": "voterWeightRecord",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "voterWeightTally",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner of the VoterWeightRecord"
+          ]
+        },
+        {
+          "name": "beneficiary",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "relinquishNftVote",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "voterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governance",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Governance account the Proposal is for"
+          ]
+        },
+        {
+          "name": "proposal",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The token owner who cast the original vote"
+          ]
+        },
+        {
+          "name": "voteRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The account is used to validate that it doesn't exist and if it doesn't then Anchor owner check throws error",
+            "The check is disabled here and performed inside the instruction",
+            "#[account(owner = registrar.governance_program_id)]"
+          ]
+        },
+        {
+          "name": "beneficiary",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "voterWeightCapTally",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "VoterWeightCapTally of the voter for the Proposal",
+            "The account only exists if the Registrar had voter_weight caps when the vote was cast"
+          ]
+        },
+        {
+          "name": "nftDepositRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NftDepositRecord of the voter",
+            "The account doesn't have to exist and it's only updated when the NftVoteRecord of the deposit record is relinquished"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revokeNftVote",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "voterWeightRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "VoterWeightRecord of the previous owner who cast the vote with the NFTs"
+          ]
+        },
+        {
+          "name": "governance",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Governance account the Proposal is for"
+          ]
+        },
+        {
+          "name": "proposal",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The current owner of the NFTs"
+          ]
+        },
+        {
+          "name": "voteRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "VoteRecord of the previous owner",
+            "The account can be already disposed if the previous owner withdrew the vote and hence the owner is checked in the instruction"
+          ]
+        },
+        {
+          "name": "beneficiary",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "MintDenylist of the Registrar",
+            "The account doesn't have to exist and no NFT mints are denied in such case"
+          ]
+        },
+        {
+          "name": "voterWeightCapTally",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "VoterWeightCapTally of the previous owner for the Proposal",
+            "The account only exists if the Registrar had voter_weight caps when the previous owner cast the vote"
+          ]
+        },
+        {
+          "name": "instructionSysvarAccount",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nftAccountCounts",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "closeNftVoteRecords",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "governance",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Governance account the Proposal is for"
+          ]
+        },
+        {
+          "name": "proposal",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "configureCollection",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we configure this Collection"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "maxVoterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collectionWeightOverrides",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "CollectionWeightOverrides of the collection used to calculate max voter weight",
+            "The account doesn't have to exist if the collection has no NFT weight overrides"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "weight",
+          "type": "u64"
+        },
+        {
+          "name": "size",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "removeCollection",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar from which we remove the Collection"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "maxVoterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collection",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "createCollectionWeightOverrides",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we create the overrides"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "collectionWeightOverrides",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "maxVoterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collection",
+          "type": "publicKey"
+        },
+        {
+          "name": "overrides",
+          "type": {
+            "vec": {
+              "defined": "NftWeightOverride"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "configureCollectionWeightOverrides",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we configure the overrides"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "collectionWeightOverrides",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "maxVoterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "overrides",
+          "type": {
+            "vec": {
+              "defined": "NftWeightOverride"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "addCollectionWeightOverrides",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar the overrides belong to"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "collectionWeightOverrides",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "maxVoterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "overrides",
+          "type": {
+            "vec": {
+              "defined": "NftWeightOverride"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeCollectionWeightOverrides",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar the overrides belong to"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "collectionWeightOverrides",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "maxVoterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nftMints",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "configureProgrammableNftPolicy",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we configure the policy"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "programmableNftPolicy",
+          "type": {
+            "defined": "ProgrammableNftPolicy"
+          }
+        }
+      ]
+    },
+    {
+      "name": "configureVoterWeightCaps",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we configure the caps"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "voterWeightCaps",
+          "type": {
+            "defined": "VoterWeightCaps"
+          }
+        }
+      ]
+    },
+    {
+      "name": "configureVoterWeightingMode",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we configure the weighting mode"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "maxVoterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "voterWeightingMode",
+          "type": {
+            "defined": "VoterWeightingMode"
+          }
+        }
+      ]
+    },
+    {
+      "name": "configureOwnershipSnapshot",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we configure the ownership snapshot"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "ownershipSnapshotEnabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "configureVoteTrackingMode",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we configure the vote tracking mode"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "voteTrackingMode",
+          "type": {
+            "defined": "VoteTrackingMode"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createNftIndexMap",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we create the index map"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "nftIndexMap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collection",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "addNftIndexMapMints",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Registrar the index map belongs to"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "nftIndexMap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collection",
+          "type": "publicKey"
+        },
+        {
+          "name": "mints",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "addEscrowProgram",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we add the escrow program"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "escrowProgramConfig",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The allowlist entry of the escrow program"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "programId",
+          "type": "publicKey"
+        },
+        {
+          "name": "receiptDiscriminator",
+          "type": {
+            "array": [
+              "u8",
+              8
+            ]
+          }
+        },
+        {
+          "name": "beneficialOwnerOffset",
+          "type": "u32"
+        },
+        {
+          "name": "mintOffset",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "removeEscrowProgram",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we remove the escrow program"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "escrowProgramConfig",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The allowlist entry of the escrow program"
+          ]
+        },
+        {
+          "name": "beneficiary",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Beneficiary which receives lamports from the disposed EscrowProgramConfig account"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createConditionalWeightConfig",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we create the config"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "conditionalWeightConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "rules",
+          "type": {
+            "vec": {
+              "defined": "ConditionalWeightRule"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "configureConditionalWeightRules",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we configure the rules"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "conditionalWeightConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "rules",
+          "type": {
+            "vec": {
+              "defined": "ConditionalWeightRule"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createMintDenylist",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Registrar for which we create the denylist"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "mints",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "addDeniedMints",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Registrar the denylist belongs to"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "mints",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeDeniedMints",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Registrar the denylist belongs to"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the Realm must sign and match Realm.authority"
+          ]
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "mints",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createNftDepositRecord",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "nftDepositRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The voter who deposits the NFTs"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxNfts",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "depositNft",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "nftDepositRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The voter who deposits the NFT"
+          ]
+        },
+        {
+          "name": "nftTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The token account holding the NFT"
+          ]
+        },
+        {
+          "name": "nftMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftLockerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT locker authority which owns the vaults"
+          ]
+        },
+        {
+          "name": "nftVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault the NFT is locked in"
+          ]
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "MintDenylist of the Registrar",
+            "The account doesn't have to exist and no NFT mints are denied in such case"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawNft",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "nftDepositRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The voter who deposited the NFT"
+          ]
+        },
+        {
+          "name": "voterWeightRecord",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenOwnerRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "TokenOwnerRecord of the voter",
+            "The account doesn't have to exist if the voter never deposited to spl-gov or voted"
+          ]
+        },
+        {
+          "name": "nftTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The token account the NFT is withdrawn to"
+          ]
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftLockerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT locker authority which owns the vaults"
+          ]
+        },
+        {
+          "name": "nftVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault the NFT is locked in",
+            "The vault is closed once the NFT is withdrawn"
+          ]
+        },
+        {
+          "name": "beneficiary",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createNftVoteDelegation",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "nftVoteDelegation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner of the NFTs who delegates the voting power"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "publicKey"
+        },
+        {
+          "name": "collection",
+          "type": {
+            "option": "publicKey"
+          }
+        },
+        {
+          "name": "expiry",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "revokeNftVoteDelegation",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "nftVoteDelegation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner of the NFTs who delegated the voting power"
+          ]
+        },
+        {
+          "name": "beneficiary",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "registerNftHolders",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "nftHolder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The account which pays for the registrations"
+          ]
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "MintDenylist of the Registrar",
+            "The account doesn't have to exist and no NFT mints are denied in such case"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nftAccountCounts",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "registerCompressedNftHolders",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting Registrar"
+          ]
+        },
+        {
+          "name": "nftHolder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The account which pays for the registrations"
+          ]
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "MintDenylist of the Registrar",
+            "The account doesn't have to exist and no NFT mints are denied in such case"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "compressedNfts",
+          "type": {
+            "vec": {
+              "defined": "CompressedNftAsset"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "castNftVote",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting registrar"
+          ]
+        },
+        {
+          "name": "voterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token owner the vote is cast for"
+          ]
+        },
+        {
+          "name": "voterAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The authority who casts the vote: governing_token_owner, the delegate from the owner's NftVoteDelegation",
+            "or the governance_delegate from the owner's spl-gov TokenOwnerRecord"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The account which pays for the transaction"
+          ]
+        },
+        {
+          "name": "proposal",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionSysvarAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "governanceProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalWeightConfig",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "ConditionalWeightConfig of the Registrar",
+            "The account doesn't have to exist and the default conditional weight rules are used in such case"
+          ]
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "MintDenylist of the Registrar",
+            "The account doesn't have to exist and no NFT mints are denied in such case"
+          ]
+        },
+        {
+          "name": "voterWeightCapTally",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "VoterWeightCapTally of the voter for the Proposal",
+            "The account is only created and used when the Registrar has voter_weight caps"
+          ]
+        },
+        {
+          "name": "nftDepositRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NftDepositRecord of the voter",
+            "The account doesn't have to exist and only the NFTs provided in remaining accounts are used in such case"
+          ]
+        },
+        {
+          "name": "nftDepositVoteRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NftVoteRecord of the NFTs locked in the NftDepositRecord",
+            "It's created when the locked NFTs are counted for the Proposal for the first time"
+          ]
+        },
+        {
+          "name": "nftVoteDelegation",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "NftVoteDelegation of the governing_token_owner",
+            "The account doesn't have to exist and it's only used when voter_authority is not the owner"
+          ]
+        },
+        {
+          "name": "tokenOwnerRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "spl-gov TokenOwnerRecord of the governing_token_owner",
+            "It's only used when voter_authority is not the owner to check TokenOwnerRecord.governance_delegate"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "layout",
+          "type": {
+            "defined": "CastNftVoteLayout"
+          }
+        }
+      ]
+    },
+    {
+      "name": "castCompressedNftVote",
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The NFT voting registrar"
+          ]
+        },
+        {
+          "name": "voterWeightRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token owner the vote is cast for"
+          ]
+        },
+        {
+          "name": "voterAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The authority who casts the vote: governing_token_owner, the delegate from the owner's NftVoteDelegation",
+            "or the governance_delegate from the owner's spl-gov TokenOwnerRecord"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The account which pays for the transaction"
+          ]
+        },
+        {
+          "name": "proposal",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionSysvarAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "governanceProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "conditionalWeightConfig",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "ConditionalWeightConfig of the Registrar",
+            "The account doesn't have to exist and the default conditional weight rules are used in such case"
+          ]
+        },
+        {
+          "name": "mintDenylist",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "MintDenylist of the Registrar",
+            "The account doesn't have to exist and no NFT mints are denied in such case"
+          ]
+        },
+        {
+          "name": "voterWeightCapTally",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "VoterWeightCapTally of the voter for the Proposal",
+            "The account is only created and used when the Registrar has voter_weight caps"
+          ]
+        },
+        {
+          "name": "nftDepositRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NftDepositRecord of the voter",
+            "The account doesn't have to exist and only the NFTs provided in remaining accounts are used in such case"
+          ]
+        },
+        {
+          "name": "nftDepositVoteRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NftVoteRecord of the NFTs locked in the NftDepositRecord",
+            "It's created when the locked NFTs are counted for the Proposal for the first time"
+          ]
+        },
+        {
+          "name": "nftVoteDelegation",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "NftVoteDelegation of the governing_token_owner",
+            "The account doesn't have to exist and it's only used when voter_authority is not the owner"
+          ]
+        },
+        {
+          "name": "tokenOwnerRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "spl-gov TokenOwnerRecord of the governing_token_owner",
+            "It's only used when voter_authority is not the owner to check TokenOwnerRecord.governance_delegate"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "compressedNfts",
+          "type": {
+            "vec": {
+              "defined": "CompressedNftAsset"
+            }
+          }
+        },
+        {
+          "name": "withProposalTransaction",
+          "type": "bool"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "collectionWeightOverrides",
+      "docs": [
+        "Per NFT weight overrides (rarity table) for a collection configured on the Registrar",
+        "The PDA of the overrides is [\"collection-weight-overrides\",registrar,collection]",
+        "",
+        "Overrides can only boost governance power and override weights lower than the collection weight are rejected",
+        "It ensures voters can't gain governance power by omitting the overrides account",
+        "If the collection weight is raised above an override later then the collection weight is used for the NFT"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registrar",
+            "docs": [
+              "Registrar the overrides belong to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "docs": [
+              "The collection of the NFTs"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "overrides",
+            "docs": [
+              "The weight overrides of the NFTs"
+            ],
+            "type": {
+              "vec": {
+                "defined": "NftWeightOverride"
+              }
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "conditionalWeightConfig",
+      "docs": [
+        "Conditional weight rules of the Registrar",
+        "The PDA of the config is [\"conditional-weight-config\",registrar]",
+        "",
+        "If the config doesn't exist then the default rules are used (phase protocol reverts are ApproveOnly)",
+        "Once the config is created its rules replace the default rules"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registrar",
+            "docs": [
+              "Registrar the config belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rules",
+            "docs": [
+              "The configured rules"
+            ],
+            "type": {
+              "vec": {
+                "defined": "ConditionalWeightRule"
+              }
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "escrowProgramConfig",
+      "docs": [
+        "Escrow (staking) program trusted by the Registrar to hold NFTs on behalf of voters",
+        "The PDA of the config is [\"escrow-program-config\",registrar,program_id]",
+        "",
+        "NFTs held by an escrow program can vote if the escrow program issued a receipt account",
+        "which names the voter as the beneficial owner of the NFT",
+        "The layout of the receipt is described by the config and hence any escrow program can be plugged in"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registrar",
+            "docs": [
+              "Registrar the config belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "programId",
+            "docs": [
+              "The escrow program which owns the receipt accounts"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "receiptDiscriminator",
+            "docs": [
+              "The account discriminator of the receipt accounts stored at the beginning of the receipt data"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "beneficialOwnerOffset",
+            "docs": [
+              "The offset of the beneficial owner Pubkey in the receipt data"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "mintOffset",
+            "docs": [
+              "The offset of the NFT mint Pubkey in the receipt data"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "nftVoteRecord",
+      "docs": [
+        "NftVoteRecord exported to IDL without account_discriminator",
+        "TODO: Once we can support these accounts in Anchor via remaining_accounts then it should be possible to remove it"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposal",
+            "docs": [
+              "Proposal which was voted on"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "nftMint",
+            "docs": [
+              "The mint of the NFT which was used for the vote"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "governingTokenOwner",
+            "docs": [
+              "The voter who casted this vote",
+              "It's a Realm member pubkey corresponding to TokenOwnerRecord.governing_token_owner"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "The payer of the NftVoteRecord rent"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "voteBitmap",
+      "docs": [
+        "VoteBitmap exported to IDL without account_discriminator"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposal",
+            "docs": [
+              "Proposal which was voted on"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "docs": [
+              "The collection of the NFTs"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "The payer of the VoteBitmap rent"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "bits",
+            "docs": [
+              "The bits of the NFTs which voted on the Proposal"
+            ],
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "nftHolderRegistration",
+      "docs": [
+        "NftHolderRegistration exported to IDL without account_discriminator"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registrar",
+            "docs": [
+              "Registrar the registration belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "nftMint",
+            "docs": [
+              "The mint of the NFT",
+              "For compressed NFTs it's the asset id of the NFT"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "holder",
+            "docs": [
+              "The holder of the NFT"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "registeredAt",
+            "docs": [
+              "The time when the holder was first observed holding the NFT"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "registeredAtSlot",
+            "docs": [
+              "The slot when the holder was first observed holding the NFT"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "voterWeightCapTally",
+      "docs": [
+        "VoterWeightCapTally exported to IDL without account_discriminator"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "nftMint",
+            "name": "proposal",
             "docs": [
-              "The mint of the NFT which was used for the vote"
+              "Proposal which was voted on"
             ],
             "type": "publicKey"
           },
           {
             "name": "governingTokenOwner",
             "docs": [
-              "The voter who casted this vote",
-              "It's a Realm member pubkey corresponding to TokenOwnerRecord.governing_token_owner"
+              "The voter who casted the votes"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collectionWeights",
+            "docs": [
+              "Uncapped weight of the cast NFTs for each collection configured on the Registrar",
+              "For the non linear weighting modes it's the number of the cast NFTs"
+            ],
+            "type": {
+              "vec": {
+                "defined": "CollectionVoterWeight"
+              }
+            }
+          },
+          {
+            "name": "nftVoteRecordCount",
+            "docs": [
+              "The number of NftVoteRecords of the voter for the Proposal tallied by the tally",
+              "The tally is disposed when the last of the NftVoteRecords is relinquished"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "nftTallyRecord",
+      "docs": [
+        "NftTallyRecord exported to IDL without account_discriminator"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registrar",
+            "docs": [
+              "Registrar the record belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "nftMint",
+            "docs": [
+              "The mint of the NFT"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "voterWeightTally",
+            "docs": [
+              "The VoterWeightTally which counted the NFT"
             ],
             "type": "publicKey"
+          },
+          {
+            "name": "tallyStartSlot",
+            "docs": [
+              "The start_slot of the VoterWeightTally when it counted the NFT"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
           }
         ]
       }
@@ -402,48 +7399,286 @@ export type NftVoter = {
           {
             "name": "realm",
             "docs": [
-              "The Realm the MaxVoterWeightRecord belongs to"
+              "The Realm the MaxVoterWeightRecord belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "governingTokenMint",
+            "docs": [
+              "Governing Token Mint the MaxVoterWeightRecord is associated with",
+              "Note: The addin can take deposits of any tokens and is not restricted to the community or council tokens only"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "maxVoterWeight",
+            "docs": [
+              "Max voter weight",
+              "The max voter weight provided by the addin for the given realm and governing_token_mint"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxVoterWeightExpiry",
+            "docs": [
+              "The slot when the max voting weight expires",
+              "It should be set to None if the weight never expires",
+              "If the max vote weight decays with time, for example for time locked based weights, then the expiry must be set",
+              "As a pattern Revise instruction to update the max weight should be invoked before governance instruction within the same transaction",
+              "and the expiry set to the current slot to provide up to date weight"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved space for future versions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintDenylist",
+      "docs": [
+        "NFT mints which are not allowed to vote, for example stolen or exploited NFTs",
+        "The PDA of the denylist is [\"mint-denylist\",registrar]",
+        "",
+        "The mints are kept sorted to binary search them in the account data without deserializing the whole list",
+        "If the denylist doesn't exist then no mints are denied"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registrar",
+            "docs": [
+              "Registrar the denylist belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mints",
+            "docs": [
+              "Sorted list of the denied mints"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "nftDepositRecord",
+      "docs": [
+        "Deposit record of the NFTs the voter locked in the program vaults",
+        "The PDA of the record is [\"nft-deposit-record\",registrar,governing_token_owner]",
+        "",
+        "The locked NFTs give the voter persistent governance power and they don't have to be supplied",
+        "with their token and metadata accounts when the voter casts vote or updates voter_weight",
+        "Every NFT is held in its own vault token account with PDA [\"nft-vault\",registrar,nft_mint]",
+        "owned by the locker authority PDA [\"nft-locker-authority\",registrar]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registrar",
+            "docs": [
+              "Registrar the deposit record belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "governingTokenOwner",
+            "docs": [
+              "The voter who deposited the NFTs"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "totalWeight",
+            "docs": [
+              "The total weight of the locked NFTs"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lockedNfts",
+            "docs": [
+              "The NFTs locked in the vaults"
+            ],
+            "type": {
+              "vec": {
+                "defined": "LockedNft"
+              }
+            }
+          },
+          {
+            "name": "nftVoteRecordCount",
+            "docs": [
+              "The number of the Proposals the locked NFTs are counted for with NftVoteRecord of the deposit record",
+              "The NFTs can't be withdrawn until the NftVoteRecords are relinquished"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                28
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "nftIndexMap",
+      "docs": [
+        "Index map of the NFTs from a collection used to track votes with VoteBitmaps",
+        "The PDA of the map is [\"nft-index-map\",registrar,collection]",
+        "",
+        "Every NFT is assigned the next index when it's added to the map, usually its position in the collection",
+        "The indexes are stable because they address the NFT bits in VoteBitmaps and NFTs can't be removed from the map",
+        "",
+        "The NFTs are kept sorted by mint to binary search them in the account data without deserializing the whole map",
+        "Large collections don't fit into the heap and the map is only read and extended in place"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registrar",
+            "docs": [
+              "Registrar the map belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "docs": [
+              "The collection of the NFTs"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades",
+              "Note: The field precedes nfts to extend the account in place when NFTs are added"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nfts",
+            "docs": [
+              "NFT indexes sorted by mint"
+            ],
+            "type": {
+              "vec": {
+                "defined": "NftIndex"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "nftVoteDelegation",
+      "docs": [
+        "Delegation of the NFT voting power of the governing_token_owner to the delegate",
+        "The PDA of the delegation is [\"nft-vote-delegation\",registrar,governing_token_owner]",
+        "",
+        "The delegate can sign CastNftVote on behalf of the owner and the NftVoteRecords still record the owner as the voter",
+        "The delegation can be scoped to the NFTs from a single collection and it can expire",
+        "",
+        "Note: The delegation only applies to the nft-voter instructions and spl-gov CastVote must still be signed",
+        "by the owner or TokenOwnerRecord.governance_delegate",
+        "For the delegate to vote without the owner's signature the owner has to set the delegate as the governance_delegate as well"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registrar",
+            "docs": [
+              "Registrar the delegation belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "governingTokenOwner",
+            "docs": [
+              "The owner of the NFTs who delegated the voting power"
             ],
             "type": "publicKey"
           },
           {
-            "name": "governingTokenMint",
+            "name": "delegate",
             "docs": [
-              "Governing Token Mint the MaxVoterWeightRecord is associated with",
-              "Note: The addin can take deposits of any tokens and is not restricted to the community or council tokens only"
+              "The delegate who can vote with the owner's NFTs"
             ],
             "type": "publicKey"
           },
           {
-            "name": "maxVoterWeight",
+            "name": "collection",
             "docs": [
-              "Max voter weight",
-              "The max voter weight provided by the addin for the given realm and governing_token_mint"
+              "If set then the delegate can only vote with the NFTs from the collection"
             ],
-            "type": "u64"
+            "type": {
+              "option": "publicKey"
+            }
           },
           {
-            "name": "maxVoterWeightExpiry",
+            "name": "expiry",
             "docs": [
-              "The slot when the max voting weight expires",
-              "It should be set to None if the weight never expires",
-              "If the max vote weight decays with time, for example for time locked based weights, then the expiry must be set",
-              "As a pattern Revise instruction to update the max weight should be invoked before governance instruction within the same transaction",
-              "and the expiry set to the current slot to provide up to date weight"
+              "If set then the delegation expires at the given time"
             ],
             "type": {
-              "option": "u64"
+              "option": "i64"
             }
           },
           {
             "name": "reserved",
             "docs": [
-              "Reserved space for future versions"
+              "Reserved for future upgrades"
             ],
             "type": {
               "array": [
                 "u8",
-                8
+                32
               ]
             }
           }
@@ -493,6 +7728,51 @@ export type NftVoter = {
               }
             }
           },
+          {
+            "name": "programmableNftPolicy",
+            "docs": [
+              "Policy applied to programmable NFTs used for voting"
+            ],
+            "type": {
+              "defined": "ProgrammableNftPolicy"
+            }
+          },
+          {
+            "name": "voterWeightCaps",
+            "docs": [
+              "Caps applied to voter_weight cast on a Proposal by a single voter"
+            ],
+            "type": {
+              "defined": "VoterWeightCaps"
+            }
+          },
+          {
+            "name": "voterWeightingMode",
+            "docs": [
+              "Weighting mode used to calculate voter_weight of the NFTs owned by a voter"
+            ],
+            "type": {
+              "defined": "VoterWeightingMode"
+            }
+          },
+          {
+            "name": "ownershipSnapshotEnabled",
+            "docs": [
+              "Indicates whether NFTs must be registered to the voter using NftHolderRegistration",
+              "before the Proposal voting started in order to vote on the Proposal",
+              "It prevents acquiring NFTs in the middle of the vote to swing the outcome"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "voteTrackingMode",
+            "docs": [
+              "Mode used to track the NFTs which voted on a Proposal"
+            ],
+            "type": {
+              "defined": "VoteTrackingMode"
+            }
+          },
           {
             "name": "reserved",
             "docs": [
@@ -501,7 +7781,7 @@ export type NftVoter = {
             "type": {
               "array": [
                 "u8",
-                128
+                112
               ]
             }
           }
@@ -592,914 +7872,1056 @@ export type NftVoter = {
             "name": "reserved",
             "docs": [
               "Reserved space for future versions"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                8
-              ]
-            }
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "CollectionConfig",
-      "docs": [
-        "Configuration of an NFT collection used for governance power"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "collection",
-            "docs": [
-              "The NFT collection used for governance"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "size",
-            "docs": [
-              "The size of the NFT collection used to calculate max voter weight",
-              "Note: At the moment the size is not captured on Metaplex accounts",
-              "and it has to be manually updated on the Registrar"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "weight",
-            "docs": [
-              "Governance power weight of the collection",
-              "Each NFT in the collection has governance power = 1 * weight",
-              "Note: The weight is scaled accordingly to the governing_token_mint decimals",
-              "Ex: if the the mint has 2 decimal places then weight of 1 should be stored as 100"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "reserved",
-            "docs": [
-              "Reserved for future upgrades"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                8
-              ]
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "VoterWeightAction",
-      "docs": [
-        "VoterWeightAction enum as defined in spl-governance-addin-api",
-        "It's redefined here for Anchor to export it to IDL"
-      ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "CastVote"
-          },
-          {
-            "name": "CommentProposal"
-          },
-          {
-            "name": "CreateGovernance"
-          },
-          {
-            "name": "CreateProposal"
-          },
-          {
-            "name": "SignOffProposal"
-          }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "InvalidRealmAuthority",
-      "msg": "Invalid Realm Authority"
-    },
-    {
-      "code": 6001,
-      "name": "InvalidRealmForRegistrar",
-      "msg": "Invalid Realm for Registrar"
-    },
-    {
-      "code": 6002,
-      "name": "InvalidCollectionSize",
-      "msg": "Invalid Collection Size"
-    },
-    {
-      "code": 6003,
-      "name": "InvalidMaxVoterWeightRecordRealm",
-      "msg": "Invalid MaxVoterWeightRecord Realm"
-    },
-    {
-      "code": 6004,
-      "name": "InvalidMaxVoterWeightRecordMint",
-      "msg": "Invalid MaxVoterWeightRecord Mint"
-    },
-    {
-      "code": 6005,
-      "name": "CastVoteIsNotAllowed",
-      "msg": "CastVote Is Not Allowed"
-    },
-    {
-      "code": 6006,
-      "name": "InvalidVoterWeightRecordRealm",
-      "msg": "Invalid VoterWeightRecord Realm"
-    },
-    {
-      "code": 6007,
-      "name": "InvalidVoterWeightRecordMint",
-      "msg": "Invalid VoterWeightRecord Mint"
-    },
-    {
-      "code": 6008,
-      "name": "InvalidTokenOwnerForVoterWeightRecord",
-      "msg": "Invalid TokenOwner for VoterWeightRecord"
-    },
-    {
-      "code": 6009,
-      "name": "CollectionMustBeVerified",
-      "msg": "Collection must be verified"
-    },
-    {
-      "code": 6010,
-      "name": "VoterDoesNotOwnNft",
-      "msg": "Voter does not own NFT"
-    },
-    {
-      "code": 6011,
-      "name": "CollectionNotFound",
-      "msg": "Collection not found"
-    },
-    {
-      "code": 6012,
-      "name": "MissingMetadataCollection",
-      "msg": "Missing Metadata collection"
-    },
-    {
-      "code": 6013,
-      "name": "TokenMetadataDoesNotMatch",
-      "msg": "Token Metadata doesn't match"
-    },
-    {
-      "code": 6014,
-      "name": "InvalidAccountOwner",
-      "msg": "Invalid account owner"
-    },
-    {
-      "code": 6015,
-      "name": "InvalidTokenMetadataAccount",
-      "msg": "Invalid token metadata account"
-    },
-    {
-      "code": 6016,
-      "name": "DuplicatedNftDetected",
-      "msg": "Duplicated NFT detected"
-    },
-    {
-      "code": 6017,
-      "name": "InvalidNftAmount",
-      "msg": "Invalid NFT amount"
-    },
-    {
-      "code": 6018,
-      "name": "NftAlreadyVoted",
-      "msg": "NFT already voted"
-    },
-    {
-      "code": 6019,
-      "name": "InvalidProposalForNftVoteRecord",
-      "msg": "Invalid Proposal for NftVoteRecord"
-    },
-    {
-      "code": 6020,
-      "name": "InvalidTokenOwnerForNftVoteRecord",
-      "msg": "Invalid TokenOwner for NftVoteRecord"
-    },
-    {
-      "code": 6021,
-      "name": "VoteRecordMustBeWithdrawn",
-      "msg": "VoteRecord must be withdrawn"
-    },
-    {
-      "code": 6022,
-      "name": "InvalidVoteRecordForNftVoteRecord",
-      "msg": "Invalid VoteRecord for NftVoteRecord"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6023,
-      "name": "VoterWeightRecordMustBeExpired",
-      "msg": "VoterWeightRecord must be expired"
-    },
+      "name": "voterWeightTally",
+      "docs": [
+        "Bookkeeping account used to accumulate voter_weight for non voting actions (CreateProposal, CreateGovernance etc...)",
+        "using several transactions",
+        "The PDA of the tally is [\"voter-weight-tally\",voter_weight_record]",
+        "",
+        "The tally stores the NFTs which were already counted to ensure the same NFT can't be counted twice",
+        "It's reset when it's used for a different action or when it's older than VOTER_WEIGHT_TALLY_MAX_SLOTS"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "voterWeightRecord",
+            "docs": [
+              "VoterWeightRecord the tally accumulates voter_weight for"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "weightAction",
+            "docs": [
+              "The governance action the tally accumulates voter_weight for"
+            ],
+            "type": {
+              "option": {
+                "defined": "VoterWeightAction"
+              }
+            }
+          },
+          {
+            "name": "startSlot",
+            "docs": [
+              "The slot when the tally was started"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "voterWeight",
+            "docs": [
+              "The accumulated voter_weight",
+              "For the non linear weighting modes it's the number of the counted NFTs",
+              "and the weighting mode curve is applied to it when VoterWeightRecord is updated"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "nftMints",
+            "docs": [
+              "The NFTs which were already counted"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved for future upgrades"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
     {
-      "code": 6024,
-      "name": "CannotConfigureCollectionWithVotingProposals",
-      "msg": "Cannot configure collection with voting proposals"
+      "name": "CastNftVoteLayout",
+      "docs": [
+        "Layout of the remaining accounts provided to CastNftVote",
+        "The layout is provided explicitly to ensure mis-shaped accounts are rejected instead of being parsed as NFT accounts"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nftAccountCounts",
+            "docs": [
+              "The number of the accounts of each NFT provided in the remaining accounts",
+              "The accounts of the NFT are: nft, nft_metadata, token_record (only for programmable NFTs),",
+              "escrow_program_config and escrow_receipt (only for NFTs held by an escrow program)",
+              "and collection_weight_overrides (only for NFTs with weight override)",
+              "Note: The accounts used to track the vote of the NFT follow the accounts of the NFT and they aren't counted"
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "withProposalTransaction",
+            "docs": [
+              "Indicates whether ProposalTransaction is provided as the last remaining account",
+              "It's required to cast vote on Proposals matching a conditional weight rule"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6025,
-      "name": "MustIncludeProposalTransactionForPhaseVotes",
-      "msg": "Must include proposals transaction since the proposal ressembles a phase vote"
-    }
-  ]
-};
-
-export const IDL: NftVoter = {
-  "version": "0.1.1",
-  "name": "nft_voter",
-  "instructions": [
-    {
-      "name": "createRegistrar",
-      "accounts": [
-        {
-          "name": "registrar",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "The NFT voting Registrar",
-            "There can only be a single registrar per governance Realm and governing mint of the Realm"
-          ]
-        },
-        {
-          "name": "governanceProgramId",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "The program id of the spl-governance program the realm belongs to"
-          ]
-        },
-        {
-          "name": "realm",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "An spl-governance Realm",
-            "",
-            "Realm is validated in the instruction:",
-            "- Realm is owned by the governance_program_id",
-            "- governing_token_mint must be the community or council mint",
-            "- realm_authority is realm.authority"
-          ]
-        },
-        {
-          "name": "governingTokenMint",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Either the realm community mint or the council mint.",
-            "It must match Realm.community_mint or Realm.config.council_mint",
-            "",
-            "Note: Once the NFT plugin is enabled the governing_token_mint is used only as identity",
-            "for the voting population and the tokens of that are no longer used"
-          ]
-        },
-        {
-          "name": "realmAuthority",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "realm_authority must sign and match Realm.authority"
-          ]
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "CollectionConfig",
+      "docs": [
+        "Configuration of an NFT collection used for governance power"
       ],
-      "args": [
-        {
-          "name": "maxCollections",
-          "type": "u8"
-        }
-      ]
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "docs": [
+              "The NFT collection used for governance"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "size",
+            "docs": [
+              "The size of the NFT collection used to calculate max voter weight",
+              "Note: At the moment the size is not captured on Metaplex accounts",
+              "and it has to be manually updated on the Registrar"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "weight",
+            "docs": [
+              "Governance power weight of the collection",
+              "Each NFT in the collection has governance power = 1 * weight",
+              "Note: The weight is scaled accordingly to the governing_token_mint decimals",
+              "Ex: if the the mint has 2 decimal places then weight of 1 should be stored as 100"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxWeightBonus",
+            "docs": [
+              "Max governance power added on top of size * weight by the NFT weight overrides of the collection",
+              "Note: The value is computed from CollectionWeightOverrides when the collection or its overrides are configured"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "createVoterWeightRecord",
-      "accounts": [
-        {
-          "name": "voterWeightRecord",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "governanceProgramId",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "The program id of the spl-governance program the realm belongs to"
-          ]
-        },
-        {
-          "name": "realm",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "realmGoverningTokenMint",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Either the realm community mint or the council mint."
-          ]
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "NftWeightOverride",
+      "docs": [
+        "Governance power weight of an individual NFT which overrides its collection weight"
       ],
-      "args": [
-        {
-          "name": "governingTokenOwner",
-          "type": "publicKey"
-        }
-      ]
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nftMint",
+            "docs": [
+              "The mint of the NFT"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "weight",
+            "docs": [
+              "Governance power weight of the NFT"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "createMaxVoterWeightRecord",
-      "accounts": [
-        {
-          "name": "maxVoterWeightRecord",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "governanceProgramId",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "The program id of the spl-governance program the realm belongs to"
-          ]
-        },
-        {
-          "name": "realm",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "realmGoverningTokenMint",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Either the realm community mint or the council mint."
-          ]
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "CompressedNftAsset",
+      "docs": [
+        "Compressed NFT (Bubblegum) leaf data used to prove the voter owns the compressed NFT",
+        "and the NFT belongs to a verified collection",
+        "",
+        "The leaf is recomputed from the data and verified against the root of the Bubblegum Merkle tree"
       ],
-      "args": []
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nonce",
+            "docs": [
+              "The nonce of the leaf used to derive the asset id"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "docs": [
+              "The index of the leaf in the Merkle tree"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "leafDelegate",
+            "docs": [
+              "The delegate of the leaf",
+              "If the compressed NFT has no delegate then it's the same as the owner"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "metadata",
+            "docs": [
+              "The metadata of the compressed NFT"
+            ],
+            "type": {
+              "defined": "MetadataArgs"
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "updateVoterWeightRecord",
-      "accounts": [
-        {
-          "name": "registrar",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "The NFT voting Registrar"
-          ]
-        },
-        {
-          "name": "voterWeightRecord",
-          "isMut": true,
-          "isSigner": false
-        }
+      "name": "ConditionalWeightRule",
+      "docs": [
+        "Rule which changes voter_weight for Proposals executing an instruction of the given program",
+        "The rule matches Proposals with a single ProposalTransaction in the option labelled option_label",
+        "and the ProposalTransaction must have a single instruction for program_id",
+        "with instruction data starting with one of the discriminators"
       ],
-      "args": [
-        {
-          "name": "voterWeightAction",
-          "type": {
-            "defined": "VoterWeightAction"
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "docs": [
+              "The program executed by the ProposalTransaction"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "discriminators",
+            "docs": [
+              "The 8 bytes instruction discriminators the rule applies to"
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  8
+                ]
+              }
+            }
+          },
+          {
+            "name": "weightPolicy",
+            "docs": [
+              "The policy applied to voter_weight when the rule matches"
+            ],
+            "type": {
+              "defined": "ConditionalWeightPolicy"
+            }
+          },
+          {
+            "name": "optionLabel",
+            "docs": [
+              "The label of the Proposal option with the ProposalTransaction",
+              "If None then the rule applies to the option with any label"
+            ],
+            "type": {
+              "option": "string"
+            }
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "relinquishNftVote",
-      "accounts": [
-        {
-          "name": "registrar",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "The NFT voting Registrar"
-          ]
-        },
-        {
-          "name": "voterWeightRecord",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "governance",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Governance account the Proposal is for"
-          ]
-        },
-        {
-          "name": "proposal",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "governingTokenOwner",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "The token owner who cast the original vote"
-          ]
-        },
-        {
-          "name": "voteRecord",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "The account is used to validate that it doesn't exist and if it doesn't then Anchor owner check throws error",
-            "The check is disabled here and performed inside the instruction",
-            "#[account(owner = registrar.governance_program_id)]"
-          ]
-        },
-        {
-          "name": "beneficiary",
-          "isMut": true,
-          "isSigner": false
-        }
+      "name": "CollectionVoterWeight",
+      "docs": [
+        "Weight of the NFTs from a collection cast by the voter before the caps are applied"
       ],
-      "args": []
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "docs": [
+              "The collection of the NFTs"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "weight",
+            "docs": [
+              "The total weight of the NFTs from the collection"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "configureCollection",
-      "accounts": [
-        {
-          "name": "registrar",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Registrar for which we configure this Collection"
-          ]
-        },
-        {
-          "name": "realm",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "realmAuthority",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "Authority of the Realm must sign and match Realm.authority"
-          ]
-        },
-        {
-          "name": "collection",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "metadata",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "maxVoterWeightRecord",
-          "isMut": true,
-          "isSigner": false
-        }
+      "name": "LockedNft",
+      "docs": [
+        "NFT locked in the NftDepositRecord"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the NFT"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collection",
+            "docs": [
+              "The verified collection of the NFT"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "weight",
+            "docs": [
+              "Governance power weight of the NFT when it was deposited"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "depositedAt",
+            "docs": [
+              "The time when the NFT was deposited"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "NftIndex",
+      "docs": [
+        "Index of an NFT in NftIndexMap"
       ],
-      "args": [
-        {
-          "name": "weight",
-          "type": "u64"
-        },
-        {
-          "name": "size",
-          "type": "u64"
-        }
-      ]
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the NFT",
+              "For compressed NFTs it's the asset id of the NFT"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "docs": [
+              "The index of the NFT bit in VoteBitmaps"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
     },
     {
-      "name": "castNftVote",
-      "accounts": [
-        {
-          "name": "registrar",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "The NFT voting registrar"
-          ]
-        },
-        {
-          "name": "voterWeightRecord",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "governingTokenOwner",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "The token owner who casts the vote"
-          ]
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "The account which pays for the transaction"
-          ]
-        },
-        {
-          "name": "proposal",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "CHECK"
-          ]
-        },
-        {
-          "name": "instructionSysvarAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "governanceProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "VoterWeightCaps",
+      "docs": [
+        "Caps applied to voter_weight cast on a Proposal by a single governing_token_owner",
+        "The caps limit the governance power of whales holding large numbers of NFTs"
       ],
-      "args": []
-    }
-  ],
-  "accounts": [
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxVoterWeight",
+            "docs": [
+              "Max voter_weight a single governing_token_owner can cast on a Proposal"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxCollectionWeightShareBps",
+            "docs": [
+              "Max share of max_voter_weight a single governing_token_owner can cast on a Proposal",
+              "with NFTs from any one collection",
+              "The share is expressed in basis points (1/100 of a percent)"
+            ],
+            "type": {
+              "option": "u16"
+            }
+          }
+        ]
+      }
+    },
     {
-      "name": "nftVoteRecord",
+      "name": "NftVoterWeightQuote",
       "docs": [
-        "NftVoteRecord exported to IDL without account_discriminator",
-        "TODO: Once we can support these accounts in Anchor via remaining_accounts then it should be possible to remove it"
+        "Voter weight quote of an NFT provided to GetVoterWeight"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "proposal",
+            "name": "mint",
             "docs": [
-              "Proposal which was voted on"
+              "The mint of the NFT",
+              "If the NFT token account can't be read then it's the address of the token account"
             ],
             "type": "publicKey"
           },
           {
-            "name": "nftMint",
+            "name": "weight",
             "docs": [
-              "The mint of the NFT which was used for the vote"
+              "Governance power weight of the NFT",
+              "It's 0 if the NFT was rejected"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rejectionCode",
+            "docs": [
+              "The error the NFT was rejected with encoded as ProgramError",
+              "For NftVoterError and Anchor errors it's the error code",
+              "None if the NFT can be used to vote"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "VoterWeightQuote",
+      "docs": [
+        "Voter weight quote returned by GetVoterWeight using the instruction return data"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "voterWeight",
+            "docs": [
+              "The voter_weight of the voter with the Registrar voter_weighting_mode and voter_weight caps applied"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "depositedNftCount",
+            "docs": [
+              "The number of NFTs locked in the voter's NftDepositRecord counted in voter_weight"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "depositedNftWeight",
+            "docs": [
+              "The total weight of the NFTs locked in the voter's NftDepositRecord"
             ],
+            "type": "u64"
+          },
+          {
+            "name": "nfts",
+            "docs": [
+              "Quotes of the NFTs provided in the remaining accounts in the same order"
+            ],
+            "type": {
+              "vec": {
+                "defined": "NftVoterWeightQuote"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Uses",
+      "docs": [
+        "Uses as defined in Bubblegum"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "useMethod",
+            "type": {
+              "defined": "UseMethod"
+            }
+          },
+          {
+            "name": "remaining",
+            "type": "u64"
+          },
+          {
+            "name": "total",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Collection",
+      "docs": [
+        "Collection as defined in Bubblegum"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "key",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Creator",
+      "docs": [
+        "Creator as defined in Bubblegum"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
             "type": "publicKey"
           },
           {
-            "name": "governingTokenOwner",
-            "docs": [
-              "The voter who casted this vote",
-              "It's a Realm member pubkey corresponding to TokenOwnerRecord.governing_token_owner"
-            ],
-            "type": "publicKey"
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "share",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MetadataArgs",
+      "docs": [
+        "MetadataArgs as defined in Bubblegum",
+        "The layout must match Bubblegum exactly because the leaf data_hash is computed from its serialized form"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "primarySaleHappened",
+            "type": "bool"
+          },
+          {
+            "name": "isMutable",
+            "type": "bool"
+          },
+          {
+            "name": "editionNonce",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "tokenStandard",
+            "type": {
+              "option": {
+                "defined": "TokenStandard"
+              }
+            }
+          },
+          {
+            "name": "collection",
+            "type": {
+              "option": {
+                "defined": "Collection"
+              }
+            }
+          },
+          {
+            "name": "uses",
+            "type": {
+              "option": {
+                "defined": "Uses"
+              }
+            }
+          },
+          {
+            "name": "tokenProgramVersion",
+            "type": {
+              "defined": "TokenProgramVersion"
+            }
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "Creator"
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "maxVoterWeightRecord",
+      "name": "TokenRecord",
       "docs": [
-        "MaxVoterWeightRecord account as defined in spl-governance-addin-api",
-        "It's redefined here without account_discriminator for Anchor to treat it as native account",
-        "",
-        "The account is used as an api interface to provide max voting power to the governance program from external addin contracts"
+        "TokenRecord account as defined in mpl-token-metadata",
+        "Only the fields used by the program are defined"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "realm",
-            "docs": [
-              "The Realm the MaxVoterWeightRecord belongs to"
-            ],
-            "type": "publicKey"
+            "name": "key",
+            "type": "u8"
           },
           {
-            "name": "governingTokenMint",
-            "docs": [
-              "Governing Token Mint the MaxVoterWeightRecord is associated with",
-              "Note: The addin can take deposits of any tokens and is not restricted to the community or council tokens only"
-            ],
-            "type": "publicKey"
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "maxVoterWeight",
-            "docs": [
-              "Max voter weight",
-              "The max voter weight provided by the addin for the given realm and governing_token_mint"
-            ],
-            "type": "u64"
+            "name": "state",
+            "type": {
+              "defined": "TokenState"
+            }
           },
           {
-            "name": "maxVoterWeightExpiry",
-            "docs": [
-              "The slot when the max voting weight expires",
-              "It should be set to None if the weight never expires",
-              "If the max vote weight decays with time, for example for time locked based weights, then the expiry must be set",
-              "As a pattern Revise instruction to update the max weight should be invoked before governance instruction within the same transaction",
-              "and the expiry set to the current slot to provide up to date weight"
-            ],
+            "name": "ruleSetRevision",
             "type": {
               "option": "u64"
             }
           },
           {
-            "name": "reserved",
-            "docs": [
-              "Reserved space for future versions"
-            ],
+            "name": "delegate",
             "type": {
-              "array": [
-                "u8",
-                8
-              ]
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "delegateRole",
+            "type": {
+              "option": {
+                "defined": "TokenDelegateRole"
+              }
             }
           }
         ]
       }
     },
     {
-      "name": "registrar",
+      "name": "ConditionalWeightPolicy",
       "docs": [
-        "Registrar which stores NFT voting configuration for the given Realm"
+        "Policy applied to voter_weight when a vote is cast on a Proposal matching a ConditionalWeightRule"
       ],
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "governanceProgramId",
-            "docs": [
-              "spl-governance program the Realm belongs to"
-            ],
-            "type": "publicKey"
+            "name": "ApproveOnly"
           },
           {
-            "name": "realm",
-            "docs": [
-              "Realm of the Registrar"
-            ],
-            "type": "publicKey"
+            "name": "DenyOnly"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProgrammableNftPolicy",
+      "docs": [
+        "Policy applied to programmable NFTs (pNFTs) used for voting",
+        "The policy is evaluated using the TokenRecord of the pNFT token account"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "AllowAll"
           },
           {
-            "name": "governingTokenMint",
-            "docs": [
-              "Governing token mint the Registrar is for",
-              "It can either be the Community or the Council mint of the Realm",
-              "When the plugin is used the mint is only used as identity of the governing power (voting population)",
-              "and the actual token of the mint is not used"
-            ],
-            "type": "publicKey"
+            "name": "RejectLockedOrDelegated"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VoteTrackingMode",
+      "docs": [
+        "Mode used to track the NFTs which voted on a Proposal and prevent them from voting twice"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NftVoteRecords"
           },
           {
-            "name": "collectionConfigs",
-            "docs": [
-              "MPL Collection used for voting"
-            ],
-            "type": {
-              "vec": {
-                "defined": "CollectionConfig"
-              }
-            }
+            "name": "VoteBitmaps"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VoterWeightAction",
+      "docs": [
+        "VoterWeightAction enum as defined in spl-governance-addin-api",
+        "It's redefined here for Anchor to export it to IDL"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "CastVote"
           },
           {
-            "name": "reserved",
-            "docs": [
-              "Reserved for future upgrades"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                128
-              ]
-            }
+            "name": "CommentProposal"
+          },
+          {
+            "name": "CreateGovernance"
+          },
+          {
+            "name": "CreateProposal"
+          },
+          {
+            "name": "SignOffProposal"
+          },
+          {
+            "name": "RevokeVote"
           }
         ]
       }
     },
     {
-      "name": "voterWeightRecord",
+      "name": "VoterWeightingMode",
       "docs": [
-        "VoterWeightRecord account as defined in spl-governance-addin-api",
-        "It's redefined here without account_discriminator for Anchor to treat it as native account",
+        "Weighting mode used to calculate voter_weight of the NFTs owned by a voter",
         "",
-        "The account is used as an api interface to provide voting power to the governance program from external addin contracts"
+        "For the non linear modes voter_weight is a function of the number of NFTs the voter owns",
+        "and the collection weights are only used to determine whether an NFT has governance power"
       ],
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "realm",
-            "docs": [
-              "The Realm the VoterWeightRecord belongs to"
-            ],
-            "type": "publicKey"
+            "name": "Linear"
           },
           {
-            "name": "governingTokenMint",
-            "docs": [
-              "Governing Token Mint the VoterWeightRecord is associated with",
-              "Note: The addin can take deposits of any tokens and is not restricted to the community or council tokens only"
-            ],
-            "type": "publicKey"
+            "name": "Quadratic"
           },
           {
-            "name": "governingTokenOwner",
-            "docs": [
-              "The owner of the governing token and voter",
-              "This is the actual owner (voter) and corresponds to TokenOwnerRecord.governing_token_owner"
-            ],
-            "type": "publicKey"
+            "name": "Logarithmic"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenProgramVersion",
+      "docs": [
+        "TokenProgramVersion as defined in Bubblegum"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Original"
           },
           {
-            "name": "voterWeight",
-            "docs": [
-              "Voter's weight",
-              "The weight of the voter provided by the addin for the given realm, governing_token_mint and governing_token_owner (voter)"
-            ],
-            "type": "u64"
+            "name": "Token2022"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UseMethod",
+      "docs": [
+        "UseMethod as defined in Bubblegum"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Burn"
           },
           {
-            "name": "voterWeightExpiry",
-            "docs": [
-              "The slot when the voting weight expires",
-              "It should be set to None if the weight never expires",
-              "If the voter weight decays with time, for example for time locked based weights, then the expiry must be set",
-              "As a common pattern Revise instruction to update the weight should be invoked before governance instruction within the same transaction",
-              "and the expiry set to the current slot to provide up to date weight"
-            ],
-            "type": {
-              "option": "u64"
-            }
+            "name": "Multiple"
           },
           {
-            "name": "weightAction",
-            "docs": [
-              "The governance action the voter's weight pertains to",
-              "It allows to provided voter's weight specific to the particular action the weight is evaluated for",
-              "When the action is provided then the governance program asserts the executing action is the same as specified by the addin"
-            ],
-            "type": {
-              "option": {
-                "defined": "VoterWeightAction"
-              }
-            }
+            "name": "Single"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenStandard",
+      "docs": [
+        "TokenStandard as defined in mpl-token-metadata",
+        "It's redefined here because the programmable standards are not supported by the mpl-token-metadata version used by the program"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NonFungible"
           },
           {
-            "name": "weightActionTarget",
-            "docs": [
-              "The target the voter's weight  action pertains to",
-              "It allows to provided voter's weight specific to the target the weight is evaluated for",
-              "For example when addin supplies weight to vote on a particular proposal then it must specify the proposal as the action target",
-              "When the target is provided then the governance program asserts the target is the same as specified by the addin"
-            ],
-            "type": {
-              "option": "publicKey"
-            }
+            "name": "FungibleAsset"
           },
           {
-            "name": "reserved",
-            "docs": [
-              "Reserved space for future versions"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                8
-              ]
-            }
+            "name": "Fungible"
+          },
+          {
+            "name": "NonFungibleEdition"
+          },
+          {
+            "name": "ProgrammableNonFungible"
+          },
+          {
+            "name": "ProgrammableNonFungibleEdition"
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "CollectionConfig",
+      "name": "TokenState",
       "docs": [
-        "Configuration of an NFT collection used for governance power"
+        "TokenState as defined in mpl-token-metadata"
       ],
       "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "collection",
-            "docs": [
-              "The NFT collection used for governance"
-            ],
-            "type": "publicKey"
-          },
+        "kind": "enum",
+        "variants": [
           {
-            "name": "size",
-            "docs": [
-              "The size of the NFT collection used to calculate max voter weight",
-              "Note: At the moment the size is not captured on Metaplex accounts",
-              "and it has to be manually updated on the Registrar"
-            ],
-            "type": "u64"
+            "name": "Unlocked"
           },
           {
-            "name": "weight",
-            "docs": [
-              "Governance power weight of the collection",
-              "Each NFT in the collection has governance power = 1 * weight",
-              "Note: The weight is scaled accordingly to the governing_token_mint decimals",
-              "Ex: if the the mint has 2 decimal places then weight of 1 should be stored as 100"
-            ],
-            "type": "u64"
+            "name": "Locked"
           },
           {
-            "name": "reserved",
-            "docs": [
-              "Reserved for future upgrades"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                8
-              ]
-            }
+            "name": "Listed"
           }
         ]
       }
     },
     {
-      "name": "VoterWeightAction",
+      "name": "TokenDelegateRole",
       "docs": [
-        "VoterWeightAction enum as defined in spl-governance-addin-api",
-        "It's redefined here for Anchor to export it to IDL"
+        "TokenDelegateRole as defined in mpl-token-metadata"
       ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "CastVote"
+            "name": "Sale"
           },
           {
-            "name": "CommentProposal"
+            "name": "Transfer"
           },
           {
-            "name": "CreateGovernance"
+            "name": "Utility"
           },
           {
-            "name": "CreateProposal"
+            "name": "Staking"
           },
           {
-            "name": "SignOffProposal"
+            "name": "Standard"
+          },
+          {
+            "name": "LockedTransfer"
+          },
+          {
+            "name": "Migration"
           }
         ]
       }
     }
   ],
+  "events": [
+    {
+      "name": "RegistrarCreated",
+      "fields": [
+        {
+          "name": "registrar",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governanceProgramId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "realm",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governingTokenMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "maxCollections",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CollectionConfigured",
+      "fields": [
+        {
+          "name": "registrar",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collection",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oldWeight",
+          "type": {
+            "option": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "newWeight",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "oldSize",
+          "type": {
+            "option": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "newSize",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maxVoterWeight",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "NftVoteCast",
+      "fields": [
+        {
+          "name": "registrar",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposal",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "nftMints",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "voterWeightAdded",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "voterWeight",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "NftVoteRelinquished",
+      "fields": [
+        {
+          "name": "registrar",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposal",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governingTokenOwner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "nftMints",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "beneficiary",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    }
+  ],
   "errors": [
     {
       "code": 6000,
@@ -1630,6 +9052,296 @@ export const IDL: NftVoter = {
       "code": 6025,
       "name": "MustIncludeProposalTransactionForPhaseVotes",
       "msg": "Must include proposals transaction since the proposal ressembles a phase vote"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidMaxCollections",
+      "msg": "Max collections can't be less than the number of configured collections"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidMerkleTreeAccount",
+      "msg": "Invalid Merkle tree account"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidMerkleTreeAuthority",
+      "msg": "Merkle tree must be a Bubblegum tree"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidMerkleProof",
+      "msg": "Invalid Merkle proof for compressed NFT"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidTokenRecord",
+      "msg": "Invalid TokenRecord account"
+    },
+    {
+      "code": 6031,
+      "name": "ProgrammableNftLocked",
+      "msg": "Programmable NFT is locked"
+    },
+    {
+      "code": 6032,
+      "name": "ProgrammableNftDelegated",
+      "msg": "Programmable NFT is delegated"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidEscrowProgramConfig",
+      "msg": "Invalid EscrowProgramConfig"
+    },
+    {
+      "code": 6034,
+      "name": "EscrowProgramNotAllowed",
+      "msg": "Escrow program is not allowed"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidEscrowReceipt",
+      "msg": "Invalid escrow receipt"
+    },
+    {
+      "code": 6036,
+      "name": "RevokeVoteIsNotAllowed",
+      "msg": "RevokeVote Is Not Allowed"
+    },
+    {
+      "code": 6037,
+      "name": "CannotRevokeOwnNftVote",
+      "msg": "Cannot revoke own NFT vote"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidProposalStateForRevoke",
+      "msg": "Invalid Proposal state for revoke"
+    },
+    {
+      "code": 6039,
+      "name": "InvalidNftMintForNftVoteRecord",
+      "msg": "Invalid NFT mint for NftVoteRecord"
+    },
+    {
+      "code": 6040,
+      "name": "VoterWeightTallyIsFull",
+      "msg": "VoterWeightTally is full"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidGovernanceProgram",
+      "msg": "Invalid governance program for Registrar"
+    },
+    {
+      "code": 6042,
+      "name": "InvalidConditionalWeightConfig",
+      "msg": "Invalid ConditionalWeightConfig"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidConditionalWeightRule",
+      "msg": "Invalid conditional weight rule"
+    },
+    {
+      "code": 6044,
+      "name": "InvalidCastVoteInstruction",
+      "msg": "Invalid spl-governance CastVote instruction"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidRemainingAccountsLayout",
+      "msg": "Remaining accounts don't match the provided layout"
+    },
+    {
+      "code": 6046,
+      "name": "InvalidCollectionWeightOverrides",
+      "msg": "Invalid CollectionWeightOverrides"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidNftWeightOverride",
+      "msg": "Invalid NFT weight override"
+    },
+    {
+      "code": 6048,
+      "name": "InvalidMintDenylist",
+      "msg": "Invalid MintDenylist"
+    },
+    {
+      "code": 6049,
+      "name": "InvalidDeniedMint",
+      "msg": "Invalid denied mint"
+    },
+    {
+      "code": 6050,
+      "name": "NftMintDenied",
+      "msg": "NFT mint is denied"
+    },
+    {
+      "code": 6051,
+      "name": "InvalidVoterWeightCaps",
+      "msg": "Invalid voter weight caps"
+    },
+    {
+      "code": 6052,
+      "name": "CannotConfigureVoterWeightCapsWithVotingProposals",
+      "msg": "Cannot configure voter weight caps with voting proposals"
+    },
+    {
+      "code": 6053,
+      "name": "CannotConfigureVoterWeightingModeWithVotingProposals",
+      "msg": "Cannot configure voter weighting mode with voting proposals"
+    },
+    {
+      "code": 6054,
+      "name": "InvalidNftHolderRegistration",
+      "msg": "Invalid NFT holder registration"
+    },
+    {
+      "code": 6055,
+      "name": "NftHolderNotRegistered",
+      "msg": "NFT holder is not registered"
+    },
+    {
+      "code": 6056,
+      "name": "NftAcquiredAfterVotingStarted",
+      "msg": "NFT was acquired after voting started"
+    },
+    {
+      "code": 6057,
+      "name": "ProposalVotingNotStarted",
+      "msg": "Proposal voting not started"
+    },
+    {
+      "code": 6058,
+      "name": "CannotConfigureOwnershipSnapshotWithVotingProposals",
+      "msg": "Cannot configure ownership snapshot with voting proposals"
+    },
+    {
+      "code": 6059,
+      "name": "NftDepositRecordIsFull",
+      "msg": "NftDepositRecord is full"
+    },
+    {
+      "code": 6060,
+      "name": "NftNotDeposited",
+      "msg": "NFT is not deposited"
+    },
+    {
+      "code": 6061,
+      "name": "CannotDepositProgrammableNft",
+      "msg": "Programmable NFTs can't be deposited"
+    },
+    {
+      "code": 6062,
+      "name": "CannotWithdrawNftWithActiveVotes",
+      "msg": "Cannot withdraw NFT with active votes"
+    },
+    {
+      "code": 6063,
+      "name": "InvalidTokenOwnerRecordForNftDepositRecord",
+      "msg": "Invalid TokenOwnerRecord for NftDepositRecord"
+    },
+    {
+      "code": 6064,
+      "name": "InvalidVoterAuthority",
+      "msg": "Voter authority must be the owner or its delegate"
+    },
+    {
+      "code": 6065,
+      "name": "NftVoteDelegationExpired",
+      "msg": "NftVoteDelegation expired"
+    },
+    {
+      "code": 6066,
+      "name": "NftCollectionNotDelegated",
+      "msg": "NFT collection is not delegated"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidNftVoteDelegationExpiry",
+      "msg": "Invalid NftVoteDelegation expiry"
+    },
+    {
+      "code": 6068,
+      "name": "ProposalVotingNotFinalized",
+      "msg": "Proposal voting is not finalized"
+    },
+    {
+      "code": 6069,
+      "name": "InvalidPayerForNftVoteRecord",
+      "msg": "Invalid payer for NftVoteRecord"
+    },
+    {
+      "code": 6070,
+      "name": "CannotConfigureVoteTrackingModeWithVotingProposals",
+      "msg": "Cannot configure vote tracking mode with voting proposals"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidNftIndexMap",
+      "msg": "Invalid NftIndexMap"
+    },
+    {
+      "code": 6072,
+      "name": "NftIndexMapIsFull",
+      "msg": "NftIndexMap is full"
+    },
+    {
+      "code": 6073,
+      "name": "NftAlreadyInIndexMap",
+      "msg": "NFT is already in NftIndexMap"
+    },
+    {
+      "code": 6074,
+      "name": "NftNotInIndexMap",
+      "msg": "NFT is not in NftIndexMap"
+    },
+    {
+      "code": 6075,
+      "name": "InvalidVoteBitmap",
+      "msg": "Invalid VoteBitmap"
+    },
+    {
+      "code": 6076,
+      "name": "NftIndexOutOfRange",
+      "msg": "NFT index is out of VoteBitmap range"
+    },
+    {
+      "code": 6077,
+      "name": "CannotRevokeNftVoteWithVoteBitmaps",
+      "msg": "NFT votes tracked with VoteBitmaps can't be revoked"
+    },
+    {
+      "code": 6078,
+      "name": "CannotCloseNftDepositVoteRecord",
+      "msg": "NftVoteRecord of NftDepositRecord must be relinquished by the voter"
+    },
+    {
+      "code": 6079,
+      "name": "CannotConfigureConditionalWeightRulesWithVotingProposals",
+      "msg": "Cannot configure conditional weight rules with voting proposals"
+    },
+    {
+      "code": 6080,
+      "name": "MustIncludeRevokeVoteInstruction",
+      "msg": "RevokeNftVote must be bundled with spl-governance RevokeVote instruction"
+    },
+    {
+      "code": 6081,
+      "name": "InvalidRevokeVoteInstruction",
+      "msg": "Invalid spl-governance RevokeVote instruction"
+    },
+    {
+      "code": 6082,
+      "name": "InvalidNftTallyRecord",
+      "msg": "Invalid NftTallyRecord"
+    },
+    {
+      "code": 6083,
+      "name": "NftAlreadyTallied",
+      "msg": "NFT is already counted by VoterWeightTally of another voter"
     }
   ]
 };